@@ -57,6 +57,29 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("bitmap aligned or 2^{}", log2_size), |b| {
             b.iter(|| bench_arrow2(&bitmap, &bitmap1))
         });
+
+        c.bench_function(&format!("bitmap aligned and 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let r = &bitmap & &bitmap1;
+                assert!(r.unset_bits() > 0);
+            })
+        });
+
+        c.bench_function(&format!("bitmap aligned xor 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let r = &bitmap ^ &bitmap1;
+                assert!(r.unset_bits() > 0);
+            })
+        });
+
+        let lhs = bitmap.clone().slice(1, size - 1);
+        let rhs = bitmap1.clone().slice(1, size - 1);
+        c.bench_function(&format!("bitmap and 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let r = &lhs & &rhs;
+                assert!(r.unset_bits() > 0);
+            })
+        });
     });
 }
 
@@ -239,6 +239,17 @@ impl<O: Offset> BinaryArray<O> {
         self.validity = validity;
     }
 
+    /// Applies a function `f` to the validity of this array.
+    ///
+    /// This is an API to leverage clone-on-write
+    /// # Panics
+    /// This function panics if the function `f` modifies the length of the [`Bitmap`].
+    pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
+        if let Some(validity) = std::mem::take(&mut self.validity) {
+            self.set_validity(Some(f(validity)))
+        }
+    }
+
     /// Try to convert this `BinaryArray` to a `MutableBinaryArray`
     pub fn into_mut(mut self) -> Either<Self, MutableBinaryArray<O>> {
         use Either::*;
@@ -0,0 +1,47 @@
+use crate::{array::ToFfi, bitmap::align};
+
+use super::BinaryViewArray;
+
+unsafe impl ToFfi for BinaryViewArray {
+    fn buffers(&self) -> Vec<Option<*const u8>> {
+        // the sizes of the data buffers (the last buffer) are exported by `ArrowArray::new`
+        let mut buffers = vec![
+            self.validity.as_ref().map(|x| x.as_ptr()),
+            Some(self.views.as_ptr().cast::<u8>()),
+        ];
+        buffers.extend(self.buffers.iter().map(|buffer| Some(buffer.as_ptr())));
+        buffers
+    }
+
+    fn offset(&self) -> Option<usize> {
+        let offset = self.views.offset();
+        if let Some(bitmap) = self.validity.as_ref() {
+            if bitmap.offset() == offset {
+                Some(offset)
+            } else {
+                None
+            }
+        } else {
+            Some(offset)
+        }
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        let offset = self.views.offset();
+
+        let validity = self.validity.as_ref().map(|bitmap| {
+            if bitmap.offset() == offset {
+                bitmap.clone()
+            } else {
+                align(bitmap, offset)
+            }
+        });
+
+        Self {
+            data_type: self.data_type.clone(),
+            views: self.views.clone(),
+            buffers: self.buffers.clone(),
+            validity,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::{iter::FromIterator, sync::Arc};
+
+use crate::{array::BinaryArray, bitmap::MutableBitmap, buffer::Buffer, datatypes::DataType};
+
+use super::{BinaryViewArray, View};
+
+/// Builds [`View`]s of values, appending the values that are not inlined to a single data buffer.
+#[derive(Debug, Default)]
+pub(crate) struct ViewsBuilder {
+    views: Vec<View>,
+    data: Vec<u8>,
+}
+
+impl ViewsBuilder {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            views: Vec::with_capacity(capacity),
+            data: vec![],
+        }
+    }
+
+    /// Pushes a view of `value`.
+    /// # Panics
+    /// This function panics iff the data buffer would exceed `u32::MAX` bytes.
+    pub fn push(&mut self, value: &[u8]) {
+        let offset = u32::try_from(self.data.len()).expect("the data buffer to fit in a u32");
+        let view = View::new(value, 0, offset);
+        if !view.is_inline() {
+            self.data.extend_from_slice(value);
+        }
+        self.views.push(view);
+    }
+
+    /// Pushes an empty view, used for null slots.
+    pub fn push_null(&mut self) {
+        self.views.push(View::default())
+    }
+
+    /// Returns the views and the data buffers they reference.
+    pub fn into_inner(self) -> (Buffer<View>, Arc<[Buffer<u8>]>) {
+        let buffers: Arc<[Buffer<u8>]> = if self.data.is_empty() {
+            Arc::new([])
+        } else {
+            Arc::new([self.data.into()])
+        };
+        (self.views.into(), buffers)
+    }
+}
+
+impl<P: AsRef<[u8]>> FromIterator<Option<P>> for BinaryViewArray {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Option<P>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut builder = ViewsBuilder::with_capacity(lower);
+        let mut validity = MutableBitmap::with_capacity(lower);
+        for item in iter {
+            match item {
+                Some(value) => {
+                    builder.push(value.as_ref());
+                    validity.push(true);
+                }
+                None => {
+                    builder.push_null();
+                    validity.push(false);
+                }
+            }
+        }
+        let (views, buffers) = builder.into_inner();
+        // soundness: the builder only creates valid views
+        unsafe { Self::new_unchecked(Self::default_data_type(), views, buffers, validity.into()) }
+    }
+}
+
+impl From<BinaryArray<i32>> for BinaryViewArray {
+    /// Converts a [`BinaryArray`] into a [`BinaryViewArray`] whose single data buffer is the
+    /// values of `array`, so that only the views are allocated.
+    fn from(array: BinaryArray<i32>) -> Self {
+        let values = array.values();
+        let views = array
+            .offsets()
+            .buffer()
+            .windows(2)
+            .map(|window| {
+                let (start, end) = (window[0] as usize, window[1] as usize);
+                // offsets of an `i32` array are non-negative and fit in a `u32`
+                View::new(&values[start..end], 0, window[0] as u32)
+            })
+            .collect::<Vec<_>>();
+        let buffers: Arc<[Buffer<u8>]> = Arc::new([values.clone()]);
+        // soundness: every view references its value in the values of `array`
+        unsafe {
+            Self::new_unchecked(
+                DataType::BinaryView,
+                views.into(),
+                buffers,
+                array.validity().cloned(),
+            )
+        }
+    }
+}
+
+impl From<BinaryViewArray> for BinaryArray<i32> {
+    /// Converts a [`BinaryViewArray`] into a [`BinaryArray`], copying its values.
+    /// # Panics
+    /// This function panics iff the sum of the lengths of the values exceeds `i32::MAX`.
+    fn from(array: BinaryViewArray) -> Self {
+        BinaryArray::<i32>::from_trusted_len_values_iter(array.values_iter())
+            .with_validity(array.validity().cloned())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    array::{ArrayAccessor, ArrayValuesIter},
+    bitmap::utils::{BitmapIter, ZipValidity},
+};
+
+use super::BinaryViewArray;
+
+unsafe impl<'a> ArrayAccessor<'a> for BinaryViewArray {
+    type Item = &'a [u8];
+
+    #[inline]
+    unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
+        self.value_unchecked(index)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Iterator of values of a [`BinaryViewArray`].
+pub type BinaryViewValueIter<'a> = ArrayValuesIter<'a, BinaryViewArray>;
+
+impl<'a> IntoIterator for &'a BinaryViewArray {
+    type Item = Option<&'a [u8]>;
+    type IntoIter = ZipValidity<&'a [u8], BinaryViewValueIter<'a>, BitmapIter<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
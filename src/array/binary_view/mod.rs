@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use crate::{
+    bitmap::{
+        utils::{BitmapIter, ZipValidity},
+        Bitmap,
+    },
+    buffer::Buffer,
+    datatypes::DataType,
+    error::Error,
+};
+
+use super::Array;
+
+mod ffi;
+pub(super) mod fmt;
+mod from;
+pub(crate) use from::ViewsBuilder;
+mod iterator;
+pub use iterator::*;
+mod view;
+pub use view::View;
+pub(crate) use view::{try_check_utf8_views, try_check_views};
+
+/// A [`BinaryViewArray`] is Arrow's semantically equivalent of an immutable `Vec<Option<Vec<u8>>>`
+/// whose values are stored as [`View`]s: values of up to [`View::MAX_INLINE_SIZE`] bytes are
+/// stored inline in their view, while longer values are stored in shared data buffers.
+/// It implements [`Array`].
+///
+/// Unlike [`BinaryArray`](super::BinaryArray), the data buffers can be shared between arrays and
+/// do not need to be contiguous nor in order, so that operations such as filter or take only copy
+/// the views.
+/// # Example
+/// ```
+/// use arrow2::array::BinaryViewArray;
+///
+/// let array = BinaryViewArray::from([Some(&b"hello"[..]), None, Some(&b"a longer value"[..])]);
+/// assert_eq!(array.value(0), b"hello");
+/// assert_eq!(array.get(1), None);
+/// assert_eq!(array.value(2), b"a longer value");
+/// // the underlying representation:
+/// assert!(array.views()[0].is_inline());
+/// assert!(!array.views()[2].is_inline());
+/// assert_eq!(array.data_buffers().len(), 1);
+/// ```
+///
+/// # Safety
+/// The following invariants hold:
+/// * every view that is not inlined references a valid slice of `buffers` whose first 4 bytes are
+///   its prefix.
+/// * `len` is equal to `validity.len()`, when defined.
+#[derive(Clone)]
+pub struct BinaryViewArray {
+    data_type: DataType,
+    views: Buffer<View>,
+    buffers: Arc<[Buffer<u8>]>,
+    validity: Option<Bitmap>,
+}
+
+impl BinaryViewArray {
+    /// Returns a [`BinaryViewArray`] created from its internal representation.
+    ///
+    /// # Errors
+    /// This function returns an error iff:
+    /// * a view that is not inlined does not reference a slice of `buffers`, or its prefix is not
+    ///   equal to the first 4 bytes of that slice.
+    /// * the validity's length is not equal to `views.len()`.
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to `BinaryView`.
+    /// # Implementation
+    /// This function is `O(N)`, where `N` is the number of views.
+    pub fn try_new(
+        data_type: DataType,
+        views: Buffer<View>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, Error> {
+        try_check_views(&views, &buffers)?;
+
+        if validity
+            .as_ref()
+            .map_or(false, |validity| validity.len() != views.len())
+        {
+            return Err(Error::oos(
+                "validity mask length must match the number of values",
+            ));
+        }
+
+        if data_type.to_physical_type() != Self::default_data_type().to_physical_type() {
+            return Err(Error::oos(
+                "BinaryViewArray can only be initialized with DataType::BinaryView",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            views,
+            buffers,
+            validity,
+        })
+    }
+
+    /// Alias for unwrapping [`Self::try_new`]
+    pub fn new(
+        data_type: DataType,
+        views: Buffer<View>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self::try_new(data_type, views, buffers, validity).unwrap()
+    }
+
+    /// Creates a new [`BinaryViewArray`] without checking its views.
+    /// # Panics
+    /// This function panics iff:
+    /// * the validity's length is not equal to `views.len()`.
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to `BinaryView`.
+    /// # Safety
+    /// Every view that is not inlined must reference a slice of `buffers` whose first 4 bytes are
+    /// its prefix.
+    pub unsafe fn new_unchecked(
+        data_type: DataType,
+        views: Buffer<View>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        assert!(
+            validity
+                .as_ref()
+                .map_or(true, |validity| validity.len() == views.len()),
+            "validity mask length must match the number of values"
+        );
+        assert_eq!(
+            data_type.to_physical_type(),
+            Self::default_data_type().to_physical_type(),
+            "BinaryViewArray can only be initialized with DataType::BinaryView"
+        );
+        Self {
+            data_type,
+            views,
+            buffers,
+            validity,
+        }
+    }
+
+    /// Returns the default [`DataType`], `DataType::BinaryView`
+    pub fn default_data_type() -> DataType {
+        DataType::BinaryView
+    }
+
+    /// Creates an empty [`BinaryViewArray`], i.e. whose `.len` is zero.
+    pub fn new_empty(data_type: DataType) -> Self {
+        Self::new(data_type, Buffer::new(), Arc::new([]), None)
+    }
+
+    /// Creates a null [`BinaryViewArray`], i.e. whose `.null_count() == .len()`.
+    #[inline]
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        Self::new(
+            data_type,
+            vec![View::default(); length].into(),
+            Arc::new([]),
+            Some(Bitmap::new_zeroed(length)),
+        )
+    }
+
+    /// Creates a new [`BinaryViewArray`] from slices of `&[u8]`.
+    pub fn from_slice<T: AsRef<[u8]>, P: AsRef<[T]>>(slice: P) -> Self {
+        Self::from_iter_values(slice.as_ref().iter())
+    }
+
+    /// Creates a new [`BinaryViewArray`] from a slice of optional `&[u8]`.
+    // Note: this can't be `impl From` because Rust does not allow double `AsRef` on it.
+    pub fn from<T: AsRef<[u8]>, P: AsRef<[Option<T>]>>(slice: P) -> Self {
+        slice.as_ref().iter().map(|x| x.as_ref()).collect()
+    }
+
+    /// Returns a new [`BinaryViewArray`] from an [`Iterator`] of `&[u8]`.
+    ///
+    /// The [`BinaryViewArray`] is guaranteed to not have a validity
+    pub fn from_iter_values<T: AsRef<[u8]>, I: Iterator<Item = T>>(iterator: I) -> Self {
+        let mut builder = ViewsBuilder::with_capacity(iterator.size_hint().0);
+        iterator.for_each(|value| builder.push(value.as_ref()));
+        let (views, buffers) = builder.into_inner();
+        // soundness: the builder only creates valid views
+        unsafe { Self::new_unchecked(Self::default_data_type(), views, buffers, None) }
+    }
+
+    /// Returns an iterator of `Option<&[u8]>` over every element of this array.
+    pub fn iter(&self) -> ZipValidity<&[u8], BinaryViewValueIter<'_>, BitmapIter<'_>> {
+        ZipValidity::new_with_validity(self.values_iter(), self.validity.as_ref())
+    }
+
+    /// Returns an iterator of `&[u8]` over every element of this array, ignoring the validity
+    pub fn values_iter(&self) -> BinaryViewValueIter<'_> {
+        BinaryViewValueIter::new(self)
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns the element at index `i`
+    /// # Panics
+    /// iff `i >= self.len()`
+    #[inline]
+    pub fn value(&self, i: usize) -> &[u8] {
+        assert!(i < self.len());
+        unsafe { self.value_unchecked(i) }
+    }
+
+    /// Returns the element at index `i`
+    /// # Safety
+    /// Assumes that the `i < self.len`.
+    #[inline]
+    pub unsafe fn value_unchecked(&self, i: usize) -> &[u8] {
+        // soundness: the invariant of the function and of the struct
+        self.views.get_unchecked(i).get_unchecked(&self.buffers)
+    }
+
+    /// Returns the element at index `i` or `None` if it is null
+    /// # Panics
+    /// iff `i >= self.len()`
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&[u8]> {
+        if self.is_null(i) {
+            None
+        } else {
+            Some(self.value(i))
+        }
+    }
+
+    /// Returns the [`DataType`] of this array.
+    #[inline]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the views of this [`BinaryViewArray`].
+    #[inline]
+    pub fn views(&self) -> &Buffer<View> {
+        &self.views
+    }
+
+    /// Returns the data buffers referenced by the views of this [`BinaryViewArray`].
+    #[inline]
+    pub fn data_buffers(&self) -> &Arc<[Buffer<u8>]> {
+        &self.buffers
+    }
+
+    /// The optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Creates a new [`BinaryViewArray`] by slicing this [`BinaryViewArray`].
+    /// # Implementation
+    /// This function is `O(1)`: all data will be shared between both arrays.
+    /// # Panics
+    /// iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new Buffer cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Creates a new [`BinaryViewArray`] by slicing this [`BinaryViewArray`].
+    /// # Implementation
+    /// This function is `O(1)`: all data will be shared between both arrays.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|bitmap| bitmap.slice_unchecked(offset, length))
+            .and_then(|bitmap| (bitmap.unset_bits() > 0).then(|| bitmap));
+        Self {
+            data_type: self.data_type.clone(),
+            views: self.views.clone().slice_unchecked(offset, length),
+            buffers: self.buffers.clone(),
+            validity,
+        }
+    }
+
+    /// Boxes self into a [`Box<dyn Array>`].
+    pub fn boxed(self) -> Box<dyn Array> {
+        Box::new(self)
+    }
+
+    /// Boxes self into a [`std::sync::Arc<dyn Array>`].
+    pub fn arced(self) -> std::sync::Arc<dyn Array> {
+        std::sync::Arc::new(self)
+    }
+
+    /// Returns this [`BinaryViewArray`] with a new validity.
+    /// # Panic
+    /// Panics iff `validity.len() != self.len()`.
+    #[must_use]
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity of this [`BinaryViewArray`].
+    /// # Panics
+    /// This function panics iff `values.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity must be equal to the array's length")
+        }
+        self.validity = validity;
+    }
+
+    /// Applies a function `f` to the validity of this array.
+    ///
+    /// This is an API to leverage clone-on-write
+    /// # Panics
+    /// This function panics if the function `f` modifies the length of the [`Bitmap`].
+    pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
+        if let Some(validity) = std::mem::take(&mut self.validity) {
+            self.set_validity(Some(f(validity)))
+        }
+    }
+
+    /// Returns the fields of this [`BinaryViewArray`].
+    pub fn into_inner(self) -> (DataType, Buffer<View>, Arc<[Buffer<u8>]>, Option<Bitmap>) {
+        (self.data_type, self.views, self.buffers, self.validity)
+    }
+}
+
+impl Array for BinaryViewArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.clone().with_validity(validity))
+    }
+    fn to_boxed(&self) -> Box<dyn Array> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{buffer::Buffer, error::Error};
+
+/// The 16-byte view of a slot of a [`BinaryViewArray`](super::BinaryViewArray) or a
+/// [`StringViewArray`](crate::array::StringViewArray).
+///
+/// Values of up to [`View::MAX_INLINE_SIZE`] bytes are stored inline, in the 12 bytes that follow
+/// `length`. Longer values are stored in one of the data buffers of the array, and the view holds
+/// their first 4 bytes (`prefix`) and their location (`buf_index` and `buf_offset`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct View {
+    /// The length of the value in bytes.
+    pub length: u32,
+    /// The first 4 bytes of the value.
+    pub prefix: [u8; 4],
+    /// The index of the data buffer containing the value, when it is not inlined.
+    pub buf_index: u32,
+    /// The offset of the value within its data buffer, when it is not inlined.
+    pub buf_offset: u32,
+}
+
+impl View {
+    /// The maximum length of a value stored inline.
+    pub const MAX_INLINE_SIZE: u32 = 12;
+
+    /// Returns a new [`View`] storing `value` inline.
+    /// # Panics
+    /// This function panics iff `value.len() > View::MAX_INLINE_SIZE`.
+    pub fn new_inline(value: &[u8]) -> Self {
+        assert!(value.len() <= Self::MAX_INLINE_SIZE as usize);
+        let mut bytes = [0u8; 12];
+        bytes[..value.len()].copy_from_slice(value);
+        Self {
+            length: value.len() as u32,
+            prefix: bytes[..4].try_into().unwrap(),
+            // native endianness so that the in-memory bytes following `length` are `value`
+            buf_index: u32::from_ne_bytes(bytes[4..8].try_into().unwrap()),
+            buf_offset: u32::from_ne_bytes(bytes[8..].try_into().unwrap()),
+        }
+    }
+
+    /// Returns a new [`View`] of `value`: inlined when it is at most [`View::MAX_INLINE_SIZE`]
+    /// bytes long, and located at `buf_offset` of the data buffer `buf_index` otherwise.
+    /// # Panics
+    /// This function panics iff `value.len()` does not fit in a `u32`.
+    pub fn new(value: &[u8], buf_index: u32, buf_offset: u32) -> Self {
+        let length = u32::try_from(value.len()).expect("the length of a view to fit in a u32");
+        if length <= Self::MAX_INLINE_SIZE {
+            Self::new_inline(value)
+        } else {
+            Self {
+                length,
+                prefix: value[..4].try_into().unwrap(),
+                buf_index,
+                buf_offset,
+            }
+        }
+    }
+
+    /// Returns whether the value of this [`View`] is stored inline.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.length <= Self::MAX_INLINE_SIZE
+    }
+
+    /// Returns the value of this [`View`].
+    /// # Safety
+    /// When the value is not inlined, `buf_index` must be a valid index of `buffers` and
+    /// `buf_offset + length` must be within that buffer.
+    #[inline]
+    pub unsafe fn get_unchecked<'a>(&'a self, buffers: &'a [Buffer<u8>]) -> &'a [u8] {
+        if self.is_inline() {
+            // soundness: `View` is `repr(C)` without padding, so its 12 bytes following `length`
+            // are contiguous and contain the inlined value.
+            let ptr = (self as *const Self as *const u8).add(std::mem::size_of::<u32>());
+            std::slice::from_raw_parts(ptr, self.length as usize)
+        } else {
+            let start = self.buf_offset as usize;
+            buffers
+                .get_unchecked(self.buf_index as usize)
+                .get_unchecked(start..start + self.length as usize)
+        }
+    }
+}
+
+/// Checks that every view references a value within `buffers` whose first 4 bytes are its prefix.
+pub(crate) fn try_check_views(views: &[View], buffers: &[Buffer<u8>]) -> Result<(), Error> {
+    views.iter().try_for_each(|view| {
+        if view.is_inline() {
+            return Ok(());
+        }
+        let start = view.buf_offset as usize;
+        let value = buffers
+            .get(view.buf_index as usize)
+            .and_then(|buffer| buffer.get(start..start + view.length as usize))
+            .ok_or_else(|| Error::oos("A view must reference a value within the data buffers"))?;
+        if value[..4] != view.prefix {
+            return Err(Error::oos(
+                "The prefix of a view must be equal to the first 4 bytes of its value",
+            ));
+        }
+        Ok(())
+    })
+}
+
+/// Checks that the values of all views are valid utf8.
+/// # Safety
+/// The views must be valid with respect to `buffers`, see [`try_check_views`].
+pub(crate) unsafe fn try_check_utf8_views(
+    views: &[View],
+    buffers: &[Buffer<u8>],
+) -> Result<(), Error> {
+    views.iter().try_for_each(|view| {
+        simdutf8::basic::from_utf8(view.get_unchecked(buffers))?;
+        Ok(())
+    })
+}
@@ -197,6 +197,17 @@ impl BooleanArray {
         self.validity = validity;
     }
 
+    /// Applies a function `f` to the validity of this array.
+    ///
+    /// This is an API to leverage clone-on-write
+    /// # Panics
+    /// This function panics if the function `f` modifies the length of the [`Bitmap`].
+    pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
+        if let Some(validity) = std::mem::take(&mut self.validity) {
+            self.set_validity(Some(f(validity)))
+        }
+    }
+
     /// Returns a clone of this [`BooleanArray`] with new values.
     /// # Panics
     /// This function panics iff `values.len() != self.len()`.
@@ -556,7 +556,7 @@ impl PartialEq for MutableBooleanArray {
 
 impl TryExtendFromSelf for MutableBooleanArray {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<(), Error> {
-        extend_validity(self.len(), &mut self.validity, &other.validity);
+        extend_validity(self.len(), &mut self.validity, other.len(), &other.validity);
 
         let slice = other.values.as_slice();
         self.values.extend_from_slice(slice, 0, other.values.len());
@@ -4,7 +4,10 @@ use std::{collections::hash_map::DefaultHasher, sync::Arc};
 use hash_hasher::HashedMap;
 
 use crate::{
-    array::{primitive::MutablePrimitiveArray, Array, MutableArray, TryExtend, TryPush},
+    array::{
+        primitive::MutablePrimitiveArray, Array, MutableArray, TryExtend, TryExtendFromSelf,
+        TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{Error, Result},
@@ -30,9 +33,11 @@ use super::{DictionaryArray, DictionaryKey};
 #[derive(Debug)]
 pub struct MutableDictionaryArray<K: DictionaryKey, M: MutableArray> {
     data_type: DataType,
+    // invariant: every valid key is `< values.len()`
     keys: MutablePrimitiveArray<K>,
+    // the key of each (hashed) value, used to push each distinct value once
+    // invariant: every value of `map` is `< values.len()`
     map: HashedMap<u64, K>,
-    // invariant: `keys.len() <= values.len()`
     values: M,
 }
 
@@ -82,6 +87,17 @@ impl<K: DictionaryKey, M: MutableArray + Default> MutableDictionaryArray<K, M> {
     }
 }
 
+impl<K: DictionaryKey, M: MutableArray + Default> MutableDictionaryArray<K, M> {
+    /// Creates an empty [`MutableDictionaryArray`] with capacity for `keys` slots and `values`
+    /// distinct values.
+    pub fn with_capacities(keys: usize, values: usize) -> Self {
+        let mut array = Self::new();
+        array.reserve(keys);
+        array.reserve_values(values);
+        array
+    }
+}
+
 impl<K: DictionaryKey, M: MutableArray + Default> Default for MutableDictionaryArray<K, M> {
     fn default() -> Self {
         Self::new()
@@ -100,7 +116,7 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
                 Ok(false)
             }
             None => {
-                let key = K::try_from(self.map.len()).map_err(|_| Error::Overflow)?;
+                let key = K::try_from(self.values.len()).map_err(|_| Error::Overflow)?;
                 self.map.insert(hash, key);
                 self.keys.push(Some(key));
                 Ok(true)
@@ -113,6 +129,28 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
         self.keys.push(None)
     }
 
+    /// Pushes a valid slot whose value is the value of the dictionary at `key`, for callers
+    /// that intern the values themselves.
+    ///
+    /// The value is not hashed, so pushing an equal value afterwards via [`TryPush`] may
+    /// append it to the dictionary again.
+    /// # Errors
+    /// Errors iff `key` is negative or not smaller than the number of values.
+    pub fn try_push_valid_key(&mut self, key: K) -> Result<()> {
+        let index: Option<usize> = key.try_into().ok();
+        match index {
+            Some(index) if index < self.values.len() => {
+                self.keys.push(Some(key));
+                Ok(())
+            }
+            _ => Err(Error::oos(format!(
+                "The key {:?} is out of bounds of the {} values of the dictionary",
+                key,
+                self.values.len()
+            ))),
+        }
+    }
+
     /// returns a mutable reference to the inner values.
     fn mut_values(&mut self) -> &mut M {
         &mut self.values
@@ -140,6 +178,12 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
         self.keys.reserve(additional);
     }
 
+    /// Reserves `additional` distinct values.
+    pub fn reserve_values(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.map.reserve(additional);
+    }
+
     /// Shrinks the capacity of the [`MutableDictionaryArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -246,3 +290,55 @@ where
         }
     }
 }
+
+impl<K, M> TryExtendFromSelf for MutableDictionaryArray<K, M>
+where
+    K: DictionaryKey,
+    M: MutableArray + TryExtendFromSelf,
+{
+    /// Extends `self` with the items of `other`, remapping the keys of `other` to the keys of the
+    /// values that `self` already contains.
+    ///
+    /// The values of `other` are appended to the values of `self` in bulk, so that values present
+    /// in both remain in the values of `self` but are not referenced by any key.
+    fn try_extend_from_self(&mut self, other: &Self) -> Result<()> {
+        let offset = self.values.len();
+
+        // by default, the keys of `other` reference the values of `other` appended to `self`
+        let mut remap = (offset..offset + other.values.len())
+            .map(|key| K::try_from(key).map_err(|_| Error::Overflow))
+            .collect::<Result<Vec<_>>>()?;
+        for (hash, key) in other.map.iter() {
+            // Safety: invariant of the struct
+            let key = unsafe { key.as_usize() };
+            match self.map.get(hash) {
+                Some(new_key) => remap[key] = *new_key,
+                None => {
+                    self.map.insert(*hash, remap[key]);
+                }
+            }
+        }
+        self.values.try_extend_from_self(&other.values)?;
+
+        let keys = other
+            .keys
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                if other.keys.is_valid(i) {
+                    // Safety: invariant of the struct
+                    remap[unsafe { key.as_usize() }]
+                } else {
+                    K::default()
+                }
+            })
+            .collect();
+        let keys = MutablePrimitiveArray::try_new(
+            other.keys.data_type().clone(),
+            keys,
+            other.keys.validity().cloned(),
+        )?;
+        self.keys.try_extend_from_self(&keys)
+    }
+}
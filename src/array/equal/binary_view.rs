@@ -0,0 +1,5 @@
+use crate::array::BinaryViewArray;
+
+pub(super) fn equal(lhs: &BinaryViewArray, rhs: &BinaryViewArray) -> bool {
+    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+}
@@ -4,6 +4,7 @@ use crate::types::NativeType;
 use super::*;
 
 mod binary;
+mod binary_view;
 mod boolean;
 mod dictionary;
 mod fixed_size_binary;
@@ -12,6 +13,8 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
+mod string_view;
 mod struct_;
 mod union;
 mod utf8;
@@ -76,6 +79,30 @@ impl PartialEq<&dyn Array> for BooleanArray {
     }
 }
 
+impl PartialEq<BinaryViewArray> for BinaryViewArray {
+    fn eq(&self, other: &Self) -> bool {
+        binary_view::equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for BinaryViewArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
+impl PartialEq<StringViewArray> for StringViewArray {
+    fn eq(&self, other: &Self) -> bool {
+        string_view::equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for StringViewArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl<O: Offset> PartialEq<Utf8Array<O>> for Utf8Array<O> {
     fn eq(&self, other: &Self) -> bool {
         utf8::equal(self, other)
@@ -196,6 +223,18 @@ impl PartialEq<&dyn Array> for MapArray {
     }
 }
 
+impl<T: NativeType> PartialEq<RunEndEncodedArray<T>> for RunEndEncodedArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        run_end_encoded::equal(self, other)
+    }
+}
+
+impl<T: NativeType> PartialEq<&dyn Array> for RunEndEncodedArray<T> {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 /// Logically compares two [`Array`]s.
 /// Two arrays are logically equal if and only if:
 /// * their data types are equal
@@ -242,6 +281,16 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             binary::equal::<i64>(lhs, rhs)
         }
+        BinaryView => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            binary_view::equal(lhs, rhs)
+        }
+        Utf8View => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            string_view::equal(lhs, rhs)
+        }
         List => {
             let lhs = lhs.as_any().downcast_ref().unwrap();
             let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -284,5 +333,13 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             map::equal(lhs, rhs)
         }
+        RunEndEncoded => {
+            let primitive = super::values_primitive_type(lhs.data_type());
+            with_match_primitive_type!(primitive, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                run_end_encoded::equal::<$T>(lhs, rhs)
+            })
+        }
     }
 }
@@ -0,0 +1,9 @@
+use crate::array::RunEndEncodedArray;
+use crate::types::NativeType;
+
+pub(super) fn equal<T: NativeType>(
+    lhs: &RunEndEncodedArray<T>,
+    rhs: &RunEndEncodedArray<T>,
+) -> bool {
+    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+}
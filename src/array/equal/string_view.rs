@@ -0,0 +1,5 @@
+use crate::array::StringViewArray;
+
+pub(super) fn equal(lhs: &StringViewArray, rhs: &StringViewArray) -> bool {
+    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+}
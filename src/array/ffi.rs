@@ -65,12 +65,26 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
         FixedSizeBinary => ffi_dyn!(array, FixedSizeBinaryArray),
         Utf8 => ffi_dyn!(array, Utf8Array::<i32>),
         LargeUtf8 => ffi_dyn!(array, Utf8Array::<i64>),
+        BinaryView => ffi_dyn!(array, BinaryViewArray),
+        Utf8View => ffi_dyn!(array, StringViewArray),
         List => ffi_dyn!(array, ListArray::<i32>),
         LargeList => ffi_dyn!(array, ListArray::<i64>),
         FixedSizeList => ffi_dyn!(array, FixedSizeListArray),
         Struct => ffi_dyn!(array, StructArray),
         Union => ffi_dyn!(array, UnionArray),
         Map => ffi_dyn!(array, MapArray),
+        RunEndEncoded => {
+            let primitive = super::values_primitive_type(array.data_type());
+            with_match_primitive_type!(primitive, |$T| {
+                let array = array.as_any().downcast_ref::<RunEndEncodedArray<$T>>().unwrap();
+                (
+                    ToFfi::offset(array).unwrap(),
+                    array.buffers(),
+                    array.children(),
+                    None,
+                )
+            })
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
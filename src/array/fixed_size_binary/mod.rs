@@ -305,8 +305,7 @@ impl FixedSizeBinaryArray {
 
     /// Creates a [`FixedSizeBinaryArray`] from a slice of arrays of bytes
     pub fn from_slice<const N: usize, P: AsRef<[[u8; N]]>>(a: P) -> Self {
-        let values = a.as_ref().iter().flatten().copied().collect::<Vec<_>>();
-        Self::new(DataType::FixedSizeBinary(N), values.into(), None)
+        MutableFixedSizeBinaryArray::from_slice(a).into()
     }
 
     /// Creates a new [`FixedSizeBinaryArray`] from a slice of optional `[u8]`.
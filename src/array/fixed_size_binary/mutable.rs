@@ -104,6 +104,31 @@ impl MutableFixedSizeBinaryArray {
         Self::try_new(DataType::FixedSizeBinary(N), values, validity.into()).unwrap()
     }
 
+    /// Creates a new [`MutableFixedSizeBinaryArray`] of `data_type` from a slice of `[u8; N]`.
+    /// # Errors
+    /// This function returns an error iff:
+    /// * The `data_type`'s physical type is not [`crate::datatypes::PhysicalType::FixedSizeBinary`]
+    /// * The size in `data_type` is not equal to `N`
+    pub fn try_from_slice<const N: usize, P: AsRef<[[u8; N]]>>(
+        data_type: DataType,
+        slice: P,
+    ) -> Result<Self, Error> {
+        let size = FixedSizeBinaryArray::maybe_get_size(&data_type)?;
+        if size != N {
+            return Err(Error::InvalidArgumentError(format!(
+                "FixedSizeBinaryArray of size {} cannot be created from items of length {}",
+                size, N
+            )));
+        }
+        let values = slice.as_ref().iter().flatten().copied().collect::<Vec<_>>();
+        Self::try_new(data_type, values, None)
+    }
+
+    /// Creates a new [`MutableFixedSizeBinaryArray`] from a slice of `[u8; N]`.
+    pub fn from_slice<const N: usize, P: AsRef<[[u8; N]]>>(slice: P) -> Self {
+        Self::try_from_slice(DataType::FixedSizeBinary(N), slice).unwrap()
+    }
+
     /// tries to push a new entry to [`MutableFixedSizeBinaryArray`].
     /// # Error
     /// Errors iff the size of `value` is not equal to its own size.
@@ -113,9 +138,11 @@ impl MutableFixedSizeBinaryArray {
             Some(bytes) => {
                 let bytes = bytes.as_ref();
                 if self.size != bytes.len() {
-                    return Err(Error::InvalidArgumentError(
-                        "FixedSizeBinaryArray requires every item to be of its length".to_string(),
-                    ));
+                    return Err(Error::InvalidArgumentError(format!(
+                        "FixedSizeBinaryArray requires every item to be of its length ({}) but an item has length {}",
+                        self.size,
+                        bytes.len()
+                    )));
                 }
                 self.values.extend_from_slice(bytes);
 
@@ -212,6 +239,24 @@ impl MutableFixedSizeBinaryArray {
         std::slice::from_raw_parts(self.values.as_ptr().add(i * self.size), self.size)
     }
 
+    /// Sets the validity.
+    /// # Panic
+    /// Panics iff the validity's len is not equal to the existing values' length.
+    pub fn set_validity(&mut self, validity: Option<MutableBitmap>) {
+        if let Some(validity) = &validity {
+            assert_eq!(self.len(), validity.len())
+        }
+        self.validity = validity;
+    }
+
+    /// Applies a function `f` to the values of this array, each of them of length
+    /// [`Self::size`].
+    /// # Panics
+    /// This function panics iff `f` panics
+    pub fn apply_values<F: Fn(&mut [u8])>(&mut self, f: F) {
+        self.values.chunks_exact_mut(self.size).for_each(f);
+    }
+
     /// Reserves `additional` slots.
     pub fn reserve(&mut self, additional: usize) {
         self.values.reserve(additional * self.size);
@@ -253,7 +298,7 @@ impl MutableArray for MutableFixedSizeBinaryArray {
 
     fn as_box(&mut self) -> Box<dyn Array> {
         FixedSizeBinaryArray::new(
-            DataType::FixedSizeBinary(self.size),
+            self.data_type.clone(),
             std::mem::take(&mut self.values).into(),
             std::mem::take(&mut self.validity).map(|x| x.into()),
         )
@@ -262,7 +307,7 @@ impl MutableArray for MutableFixedSizeBinaryArray {
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
         FixedSizeBinaryArray::new(
-            DataType::FixedSizeBinary(self.size),
+            self.data_type.clone(),
             std::mem::take(&mut self.values).into(),
             std::mem::take(&mut self.validity).map(|x| x.into()),
         )
@@ -294,6 +339,19 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     }
 }
 
+impl<const N: usize> FromIterator<Option<[u8; N]>> for MutableFixedSizeBinaryArray {
+    fn from_iter<I: IntoIterator<Item = Option<[u8; N]>>>(iter: I) -> Self {
+        Self::try_from_iter(iter, N).unwrap()
+    }
+}
+
+impl<const N: usize> FromIterator<[u8; N]> for MutableFixedSizeBinaryArray {
+    fn from_iter<I: IntoIterator<Item = [u8; N]>>(iter: I) -> Self {
+        let values = iter.into_iter().flatten().collect::<Vec<_>>();
+        Self::try_new(DataType::FixedSizeBinary(N), values, None).unwrap()
+    }
+}
+
 impl FixedSizeBinaryValues for MutableFixedSizeBinaryArray {
     #[inline]
     fn values(&self) -> &[u8] {
@@ -314,7 +372,7 @@ impl PartialEq for MutableFixedSizeBinaryArray {
 
 impl TryExtendFromSelf for MutableFixedSizeBinaryArray {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<(), Error> {
-        extend_validity(self.len(), &mut self.validity, &other.validity);
+        extend_validity(self.len(), &mut self.validity, other.len(), &other.validity);
 
         let slice = other.values.as_slice();
         self.values.extend_from_slice(slice);
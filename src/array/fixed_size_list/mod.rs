@@ -1,11 +1,12 @@
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::{DataType, Field},
     error::Error,
+    types::NativeType,
 };
 use std::sync::Arc;
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{new_empty_array, new_null_array, Array, PrimitiveArray};
 
 mod ffi;
 pub(super) mod fmt;
@@ -79,6 +80,60 @@ impl FixedSizeListArray {
         Self::try_new(data_type, values, validity).unwrap()
     }
 
+    /// Creates a new [`FixedSizeListArray`] of valid lists of `size` items out of a flat
+    /// [`PrimitiveArray`], e.g. a `4 x 3` matrix out of 12 values.
+    /// # Implementation
+    /// This operation is `O(1)` (zero-copy). Lists can be made null with
+    /// [`FixedSizeListArray::with_validity`], in which case they still occupy `size` items.
+    /// # Errors
+    /// This function errors iff `size` is zero or the length of `values` is not a multiple
+    /// of `size`.
+    pub fn try_from_values<T: NativeType>(
+        values: PrimitiveArray<T>,
+        size: usize,
+    ) -> Result<Self, Error> {
+        let data_type = Self::default_datatype(values.data_type().clone(), size);
+        Self::try_new(data_type, values.boxed(), None)
+    }
+
+    /// Returns the values of this [`FixedSizeListArray`] as a flat [`PrimitiveArray`] of
+    /// `self.len() * self.size()` items, the inverse of [`FixedSizeListArray::try_from_values`].
+    ///
+    /// The items of null lists are null.
+    /// # Implementation
+    /// The values are not copied; when there are null lists, a new validity is computed.
+    /// # Errors
+    /// This function errors iff the values of this array are not a [`PrimitiveArray<T>`].
+    pub fn into_flat_values<T: NativeType>(self) -> Result<PrimitiveArray<T>, Error> {
+        let values = self
+            .values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .ok_or_else(|| {
+                Error::oos(format!(
+                    "The values of this FixedSizeListArray are of type {:?}, not a PrimitiveArray of {:?}",
+                    self.values.data_type(),
+                    T::PRIMITIVE
+                ))
+            })?
+            .clone();
+
+        let validity = match self.validity {
+            Some(validity) if validity.unset_bits() > 0 => validity,
+            _ => return Ok(values),
+        };
+        let mut items = MutableBitmap::with_capacity(values.len());
+        validity
+            .iter()
+            .for_each(|is_valid| items.extend_constant(self.size, is_valid));
+        let items: Bitmap = items.into();
+        let validity = match values.validity() {
+            Some(values_validity) => values_validity & &items,
+            None => items,
+        };
+        Ok(values.with_validity(Some(validity)))
+    }
+
     /// Returns the size (number of elements per slot) of this [`FixedSizeListArray`].
     pub const fn size(&self) -> usize {
         self.size
@@ -188,6 +243,13 @@ impl FixedSizeListArray {
         &self.values
     }
 
+    /// Returns the number of items of each list of this array, which is the fixed size
+    /// of the lists. Null lists have a null length.
+    pub fn lengths(&self) -> PrimitiveArray<i32> {
+        let values = vec![self.size as i32; self.len()];
+        PrimitiveArray::new(DataType::Int32, values.into(), self.validity.clone())
+    }
+
     /// Returns the `Vec<T>` at position `i`.
     /// # Panic:
     /// panics iff `i >= self.len()`
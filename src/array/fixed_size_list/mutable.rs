@@ -107,6 +107,12 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
     #[inline]
     fn push_null(&mut self) {
         (0..self.size).for_each(|_| self.values.push_null());
+        self.push_invalid()
+    }
+
+    /// Marks the last slot as null, assuming its `size` values were already pushed.
+    #[inline]
+    fn push_invalid(&mut self) {
         match &mut self.validity {
             Some(validity) => validity.push(false),
             None => self.init_validity(),
@@ -115,7 +121,7 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
 
     /// Reserves `additional` slots.
     pub fn reserve(&mut self, additional: usize) {
-        self.values.reserve(additional);
+        self.values.reserve(additional * self.size);
         if let Some(x) = self.validity.as_mut() {
             x.reserve(additional)
         }
@@ -171,14 +177,7 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
 
     #[inline]
     fn push_null(&mut self) {
-        (0..self.size).for_each(|_| {
-            self.values.push_null();
-        });
-        if let Some(validity) = &mut self.validity {
-            validity.push(false)
-        } else {
-            self.init_validity()
-        }
+        self.push_null()
     }
 
     fn reserve(&mut self, additional: usize) {
@@ -209,10 +208,26 @@ where
     M: MutableArray + TryExtend<Option<T>>,
     I: IntoIterator<Item = Option<T>>,
 {
+    /// Pushes a slot to this array.
+    /// # Errors
+    /// Errors iff pushing its values errors or `item` does not have exactly `size` values, in
+    /// which case a null slot is pushed instead so that the array remains consistent.
     #[inline]
     fn try_push(&mut self, item: Option<I>) -> Result<()> {
         if let Some(items) = item {
-            self.values.try_extend(items)?;
+            let size = self.size;
+            let mut items = items.into_iter();
+            let mut count = 0;
+            self.values
+                .try_extend(items.by_ref().take(size).inspect(|_| count += 1))?;
+            if count != size || items.next().is_some() {
+                (count..size).for_each(|_| self.values.push_null());
+                self.push_invalid();
+                return Err(Error::InvalidArgumentError(format!(
+                    "A FixedSizeListArray of size {} can only be extended by values of {} items",
+                    size, size
+                )));
+            }
             self.try_push_valid()?;
         } else {
             self.push_null();
@@ -226,7 +241,7 @@ where
     M: MutableArray + TryExtendFromSelf,
 {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<()> {
-        extend_validity(self.len(), &mut self.validity, &other.validity);
+        extend_validity(self.len(), &mut self.validity, other.len(), &other.validity);
 
         self.values.try_extend_from_self(&other.values)
     }
@@ -58,6 +58,12 @@ pub fn get_value_display<'a, F: Write + 'a>(
                 f,
             )
         }),
+        BinaryView => Box::new(|f, index| {
+            super::binary_view::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, f)
+        }),
+        Utf8View => Box::new(|f, index| {
+            super::string_view::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, f)
+        }),
         List => Box::new(move |f, index| {
             super::list::fmt::write_value::<i32, _>(
                 array.as_any().downcast_ref().unwrap(),
@@ -89,6 +95,19 @@ pub fn get_value_display<'a, F: Write + 'a>(
             super::union::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, null, f)
         }),
         Map => todo!(),
+        RunEndEncoded => {
+            let primitive = super::values_primitive_type(array.data_type());
+            with_match_primitive_type!(primitive, |$T| {
+                Box::new(move |f, index| {
+                    super::run_end_encoded::fmt::write_value::<$T, _>(
+                        array.as_any().downcast_ref().unwrap(),
+                        index,
+                        null,
+                        f,
+                    )
+                })
+            })
+        }
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             Box::new(move |f, index| {
                 super::dictionary::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
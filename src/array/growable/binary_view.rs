@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, BinaryViewArray, View},
+    bitmap::MutableBitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+};
+
+use super::{
+    utils::{build_extend_null_bits, ExtendNullBits},
+    Growable,
+};
+
+/// Extends `views` with the views of `array_views[start..start + len]`, shifting the buffer index
+/// of the views that are not inlined by `buffer_offset`.
+pub(super) fn extend_views(
+    views: &mut Vec<View>,
+    array_views: &[View],
+    buffer_offset: u32,
+    start: usize,
+    len: usize,
+) {
+    views.extend(array_views[start..start + len].iter().map(|view| {
+        let mut view = *view;
+        if !view.is_inline() {
+            view.buf_index += buffer_offset;
+        }
+        view
+    }));
+}
+
+/// Returns the data buffers of all arrays, and the index of the first buffer of each array.
+/// This is `O(B)` where `B` is the total number of data buffers, as buffers are not copied.
+pub(super) fn concat_buffers<'a, I: Iterator<Item = &'a [Buffer<u8>]>>(
+    buffers: I,
+) -> (Vec<Buffer<u8>>, Vec<u32>) {
+    let mut all = vec![];
+    let offsets = buffers
+        .map(|buffers| {
+            let offset = all.len() as u32;
+            all.extend(buffers.iter().cloned());
+            offset
+        })
+        .collect();
+    (all, offsets)
+}
+
+/// Concrete [`Growable`] for the [`BinaryViewArray`].
+/// The data buffers of the arrays are shared with the resulting array, so that only the views
+/// are copied.
+pub struct GrowableBinaryView<'a> {
+    arrays: Vec<&'a BinaryViewArray>,
+    data_type: DataType,
+    validity: MutableBitmap,
+    views: Vec<View>,
+    buffers: Arc<[Buffer<u8>]>,
+    buffer_offsets: Vec<u32>,
+    extend_null_bits: Vec<ExtendNullBits<'a>>,
+}
+
+impl<'a> GrowableBinaryView<'a> {
+    /// Creates a new [`GrowableBinaryView`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a BinaryViewArray>, mut use_validity: bool, capacity: usize) -> Self {
+        let data_type = arrays[0].data_type().clone();
+
+        // if any of the arrays has nulls, insertions from any array requires setting bits
+        // as there is at least one array with nulls.
+        if arrays.iter().any(|array| array.null_count() > 0) {
+            use_validity = true;
+        };
+
+        let extend_null_bits = arrays
+            .iter()
+            .map(|array| build_extend_null_bits(*array, use_validity))
+            .collect();
+
+        let (buffers, buffer_offsets) =
+            concat_buffers(arrays.iter().map(|array| array.data_buffers().as_ref()));
+
+        Self {
+            arrays,
+            data_type,
+            views: Vec::with_capacity(capacity),
+            buffers: buffers.into(),
+            buffer_offsets,
+            validity: MutableBitmap::with_capacity(capacity),
+            extend_null_bits,
+        }
+    }
+
+    fn to(&mut self) -> BinaryViewArray {
+        let validity = std::mem::take(&mut self.validity);
+        let views = std::mem::take(&mut self.views);
+
+        // soundness: the views were shifted to reference the same values in `buffers`
+        unsafe {
+            BinaryViewArray::new_unchecked(
+                self.data_type.clone(),
+                views.into(),
+                self.buffers.clone(),
+                validity.into(),
+            )
+        }
+    }
+}
+
+impl<'a> Growable<'a> for GrowableBinaryView<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        (self.extend_null_bits[index])(&mut self.validity, start, len);
+
+        let array = self.arrays[index];
+        extend_views(
+            &mut self.views,
+            array.views(),
+            self.buffer_offsets[index],
+            start,
+            len,
+        );
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.views
+            .extend(std::iter::repeat(View::default()).take(additional));
+        self.validity.extend_constant(additional, false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.to().arced()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.to().boxed()
+    }
+}
+
+impl<'a> From<GrowableBinaryView<'a>> for BinaryViewArray {
+    fn from(mut val: GrowableBinaryView<'a>) -> Self {
+        val.to()
+    }
+}
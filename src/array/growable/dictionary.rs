@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
+use ahash::AHashMap;
+
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
+    array::{Array, BinaryArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array},
     bitmap::MutableBitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
+    offset::Offset,
+    types::NativeType,
 };
 
 use super::{
@@ -14,39 +18,154 @@ use super::{
 
 /// Concrete [`Growable`] for the [`DictionaryArray`].
 /// # Implementation
-/// This growable does not perform collision checks and instead concatenates
-/// the values of each [`DictionaryArray`] one after the other.
+/// The values of each [`DictionaryArray`] are merged into a single dictionary with
+/// [`merge_dictionary_values`] and the keys are remapped to it as slots are appended.
+///
+/// A single [`DictionaryArray`] is not merged: its values and keys are used as they are.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
     data_type: DataType,
     keys_values: Vec<&'a [K]>,
     key_values: Vec<K>,
     key_validity: MutableBitmap,
-    offsets: Vec<usize>,
+    // the position in `values` of each value of each array, `None` for a single array
+    remaps: Option<Vec<Vec<usize>>>,
     values: Box<dyn Array>,
     extend_null_bits: Vec<ExtendNullBits<'a>>,
 }
 
-fn concatenate_values<K: DictionaryKey>(
-    arrays_keys: &[&PrimitiveArray<K>],
-    arrays_values: &[&dyn Array],
-    capacity: usize,
-) -> (Box<dyn Array>, Vec<usize>) {
+/// The `(array, index)` of each unique value of a set of arrays, and for each array, the
+/// position in the unique values of each of its values.
+type MergedValues = (Vec<(usize, usize)>, Vec<Vec<usize>>);
+
+/// Merges the values of each of `arrays` (represented by their bytes, `None` for nulls),
+/// in order of first appearance.
+fn merge_values<'a, I, V>(arrays: I) -> MergedValues
+where
+    I: Iterator<Item = V>,
+    V: Iterator<Item = Option<&'a [u8]>>,
+{
+    let mut positions = AHashMap::<Option<&'a [u8]>, usize>::new();
+    let mut unique = vec![];
+    let remaps = arrays
+        .enumerate()
+        .map(|(array, values)| {
+            values
+                .enumerate()
+                .map(|(index, value)| {
+                    *positions.entry(value).or_insert_with(|| {
+                        unique.push((array, index));
+                        unique.len() - 1
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    (unique, remaps)
+}
+
+/// Merges the values of dictionaries into unique values, or returns `None` if their type
+/// cannot be merged.
+fn merge_unique_values(values: &[&dyn Array]) -> Option<MergedValues> {
+    fn merge_primitive<T: NativeType>(values: &[&dyn Array]) -> MergedValues {
+        merge_values(values.iter().map(|values| {
+            let values = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+            values.iter().map(|x| x.map(bytemuck::bytes_of))
+        }))
+    }
+
+    fn merge_binary<O: Offset>(values: &[&dyn Array]) -> MergedValues {
+        merge_values(values.iter().map(|values| {
+            values
+                .as_any()
+                .downcast_ref::<BinaryArray<O>>()
+                .unwrap()
+                .iter()
+        }))
+    }
+
+    fn merge_utf8<O: Offset>(values: &[&dyn Array]) -> MergedValues {
+        merge_values(values.iter().map(|values| {
+            let values = values.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+            values.iter().map(|x| x.map(str::as_bytes))
+        }))
+    }
+
+    use PhysicalType::*;
+    Some(match values[0].data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            merge_primitive::<$T>(values)
+        }),
+        Binary => merge_binary::<i32>(values),
+        LargeBinary => merge_binary::<i64>(values),
+        Utf8 => merge_utf8::<i32>(values),
+        LargeUtf8 => merge_utf8::<i64>(values),
+        _ => return None,
+    })
+}
+
+fn concatenate_values(arrays_values: &[&dyn Array]) -> (Box<dyn Array>, Vec<Vec<usize>>) {
+    let capacity = arrays_values.iter().map(|values| values.len()).sum();
     let mut mutable = make_growable(arrays_values, false, capacity);
-    let mut offsets = Vec::with_capacity(arrays_keys.len() + 1);
-    offsets.push(0);
-    for (i, values) in arrays_values.iter().enumerate() {
-        mutable.extend(i, 0, values.len());
-        offsets.push(offsets[i] + values.len());
+    let mut offset = 0;
+    let remaps = arrays_values
+        .iter()
+        .enumerate()
+        .map(|(i, values)| {
+            mutable.extend(i, 0, values.len());
+            offset += values.len();
+            (offset - values.len()..offset).collect()
+        })
+        .collect();
+    (mutable.as_box(), remaps)
+}
+
+/// Merges the values of dictionaries into a single array, and returns it together with the
+/// position in it of each value of each dictionary.
+///
+/// The merged values are unique when they are primitive, binary or utf8 (compared by their
+/// bytes, so e.g. different `NaN`s are not merged), in order of first appearance. Otherwise,
+/// or when the dictionaries are sorted, they are concatenated one after the other.
+pub(crate) fn merge_dictionary_values(
+    arrays_values: &[&dyn Array],
+    is_sorted: bool,
+) -> (Box<dyn Array>, Vec<Vec<usize>>) {
+    let merged = (!is_sorted)
+        .then(|| merge_unique_values(arrays_values))
+        .flatten();
+    match merged {
+        Some((unique, remaps)) => {
+            let mut mutable = make_growable(arrays_values, true, unique.len());
+            unique
+                .into_iter()
+                .for_each(|(array, index)| mutable.extend(array, index, 1));
+            (mutable.as_box(), remaps)
+        }
+        None => concatenate_values(arrays_values),
     }
-    (mutable.as_box(), offsets)
+}
+
+/// Returns the position of `key` in the merged values, given the position `remap` of each
+/// value of its dictionary (see [`merge_dictionary_values`]). Keys of null slots may be any
+/// key, and keys out of bounds are remapped to 0.
+#[inline]
+pub(crate) fn remap_key<K: DictionaryKey>(key: K, remap: &[usize]) -> usize {
+    key.try_into()
+        .ok()
+        .and_then(|key: usize| remap.get(key))
+        .copied()
+        .unwrap_or(0)
 }
 
 impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
     /// Creates a new [`GrowableDictionary`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
-    /// If `arrays` is empty.
+    /// If `arrays` is empty or the merged values of `arrays` do not fit in keys of type `T`.
     pub fn new(arrays: &[&'a DictionaryArray<T>], mut use_validity: bool, capacity: usize) -> Self {
         let data_type = arrays[0].data_type().clone();
+        let is_sorted = match data_type.to_logical_type() {
+            DataType::Dictionary(_, _, is_sorted) => *is_sorted,
+            _ => unreachable!(),
+        };
 
         // if any of the arrays has nulls, insertions from any array requires setting bits
         // as there is at least one array with nulls.
@@ -54,10 +173,9 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
             use_validity = true;
         };
 
-        let arrays_keys = arrays.iter().map(|array| array.keys()).collect::<Vec<_>>();
-        let keys_values = arrays_keys
+        let keys_values = arrays
             .iter()
-            .map(|array| array.values().as_slice())
+            .map(|array| array.keys().values().as_slice())
             .collect::<Vec<_>>();
 
         let extend_null_bits = arrays
@@ -65,16 +183,26 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
             .map(|array| build_extend_null_bits(array.keys(), use_validity))
             .collect();
 
-        let arrays_values = arrays
-            .iter()
-            .map(|array| array.values().as_ref())
-            .collect::<Vec<_>>();
-
-        let (values, offsets) = concatenate_values(&arrays_keys, &arrays_values, capacity);
+        let (values, remaps) = if arrays.len() == 1 {
+            (arrays[0].values().clone(), None)
+        } else {
+            let arrays_values = arrays
+                .iter()
+                .map(|array| array.values().as_ref())
+                .collect::<Vec<_>>();
+            let (values, remaps) = merge_dictionary_values(&arrays_values, is_sorted);
+            if T::try_from(values.len().saturating_sub(1)).is_err() {
+                panic!(
+                    "The merged values of the dictionaries do not fit in keys of type {:?}",
+                    T::KEY_TYPE
+                )
+            }
+            (values, Some(remaps))
+        };
 
         Self {
             data_type,
-            offsets,
+            remaps,
             values,
             keys_values,
             key_values: Vec::with_capacity(capacity),
@@ -113,23 +241,17 @@ impl<'a, T: DictionaryKey> Growable<'a> for GrowableDictionary<'a, T> {
         (self.extend_null_bits[index])(&mut self.key_validity, start, len);
 
         let values = &self.keys_values[index][start..start + len];
-        let offset = self.offsets[index];
-        self.key_values.extend(
-            values
-                .iter()
-                // `.unwrap_or(0)` because this operation does not check for null values, which may contain any key.
-                .map(|x| {
-                    let x: usize = offset + (*x).try_into().unwrap_or(0);
-                    let x: T = match x.try_into() {
-                        Ok(key) => key,
-                        // todo: convert this to an error.
-                        Err(_) => {
-                            panic!("The maximum key is too small")
-                        }
-                    };
-                    x
-                }),
-        );
+        match &self.remaps {
+            Some(remaps) => {
+                let remap = &remaps[index];
+                self.key_values.extend(values.iter().map(|key| {
+                    // `unwrap_or_default` never happens, since `new` checks that the merged
+                    // values fit in `T`
+                    T::try_from(remap_key(*key, remap)).unwrap_or_default()
+                }))
+            }
+            None => self.key_values.extend_from_slice(values),
+        }
     }
 
     #[inline]
@@ -141,7 +263,7 @@ impl<'a, T: DictionaryKey> Growable<'a> for GrowableDictionary<'a, T> {
 
     #[inline]
     fn as_arc(&mut self) -> Arc<dyn Array> {
-        Arc::new(self.to())
+        self.as_box().into()
     }
 
     #[inline]
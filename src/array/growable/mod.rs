@@ -7,6 +7,8 @@ use std::sync::Arc;
 
 mod binary;
 pub use binary::GrowableBinary;
+mod binary_view;
+pub use binary_view::GrowableBinaryView;
 mod union;
 pub use union::GrowableUnion;
 mod boolean;
@@ -19,14 +21,21 @@ mod primitive;
 pub use primitive::GrowablePrimitive;
 mod list;
 pub use list::GrowableList;
+mod map;
+pub use map::GrowableMap;
 mod structure;
 pub use structure::GrowableStruct;
 mod fixed_size_list;
 pub use fixed_size_list::GrowableFixedSizeList;
 mod utf8;
 pub use utf8::GrowableUtf8;
+mod string_view;
+pub use string_view::GrowableStringView;
 mod dictionary;
 pub use dictionary::GrowableDictionary;
+pub(crate) use dictionary::{merge_dictionary_values, remap_key};
+mod run_end_encoded;
+pub use run_end_encoded::GrowableRunEndEncoded;
 
 mod utils;
 
@@ -98,6 +107,18 @@ pub fn make_growable<'a>(
             use_validity,
             capacity
         ),
+        BinaryView => dyn_growable!(
+            binary_view::GrowableBinaryView,
+            arrays,
+            use_validity,
+            capacity
+        ),
+        Utf8View => dyn_growable!(
+            string_view::GrowableStringView,
+            arrays,
+            use_validity,
+            capacity
+        ),
         FixedSizeBinary => dyn_growable!(
             fixed_binary::GrowableFixedSizeBinary,
             arrays,
@@ -120,7 +141,17 @@ pub fn make_growable<'a>(
                 .collect::<Vec<_>>();
             Box::new(union::GrowableUnion::new(arrays, capacity))
         }
-        Map => todo!(),
+        Map => dyn_growable!(map::GrowableMap, arrays, use_validity, capacity),
+        RunEndEncoded => {
+            let primitive = super::values_primitive_type(data_type);
+            with_match_primitive_type!(primitive, |$T| {
+                let arrays = arrays
+                    .iter()
+                    .map(|array| array.as_any().downcast_ref().unwrap())
+                    .collect::<Vec<_>>();
+                Box::new(run_end_encoded::GrowableRunEndEncoded::<$T>::new(arrays))
+            })
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let arrays = arrays
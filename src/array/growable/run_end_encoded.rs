@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableRunEndEncodedArray, RunEndEncodedArray},
+    types::NativeType,
+};
+
+use super::Growable;
+
+/// Concrete [`Growable`] for the [`RunEndEncodedArray`].
+/// # Implementation
+/// Runs are extended run by run (not slot by slot), and adjacent runs of equal values from
+/// different slices are merged. Null slots (from [`Growable::extend_validity`]) are a run of
+/// null values.
+pub struct GrowableRunEndEncoded<'a, T: NativeType> {
+    arrays: Vec<&'a RunEndEncodedArray<T>>,
+    array: MutableRunEndEncodedArray<T>,
+}
+
+impl<'a, T: NativeType> GrowableRunEndEncoded<'a, T> {
+    /// Creates a new [`GrowableRunEndEncoded`] bound to `arrays`.
+    /// # Panics
+    /// Panics iff
+    /// * `arrays` is empty.
+    /// * any of the arrays has a different [`crate::datatypes::DataType`].
+    pub fn new(arrays: Vec<&'a RunEndEncodedArray<T>>) -> Self {
+        let data_type = arrays[0].data_type();
+        assert!(arrays.iter().all(|x| x.data_type() == data_type));
+
+        let array = MutableRunEndEncodedArray::with_capacity_from(0, data_type.clone());
+        Self { arrays, array }
+    }
+
+    fn to(&mut self) -> RunEndEncodedArray<T> {
+        let data_type = self.arrays[0].data_type().clone();
+        let array = std::mem::replace(
+            &mut self.array,
+            MutableRunEndEncodedArray::with_capacity_from(0, data_type),
+        );
+        array.into()
+    }
+}
+
+impl<'a, T: NativeType> Growable<'a> for GrowableRunEndEncoded<'a, T> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let array = self.arrays[index];
+        let values = array.values();
+        for (run, length) in array.runs(start, len) {
+            let value = if values.is_null(run) {
+                None
+            } else {
+                Some(values.value(run))
+            };
+            self.array.extend_constant(length, value);
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.array.extend_constant(additional, None);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.to().arced()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.to().boxed()
+    }
+}
+
+impl<'a, T: NativeType> From<GrowableRunEndEncoded<'a, T>> for RunEndEncodedArray<T> {
+    fn from(mut val: GrowableRunEndEncoded<'a, T>) -> Self {
+        val.to()
+    }
+}
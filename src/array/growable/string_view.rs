@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, StringViewArray, View},
+    bitmap::MutableBitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+};
+
+use super::{
+    binary_view::{concat_buffers, extend_views},
+    utils::{build_extend_null_bits, ExtendNullBits},
+    Growable,
+};
+
+/// Concrete [`Growable`] for the [`StringViewArray`].
+/// The data buffers of the arrays are shared with the resulting array, so that only the views
+/// are copied.
+pub struct GrowableStringView<'a> {
+    arrays: Vec<&'a StringViewArray>,
+    data_type: DataType,
+    validity: MutableBitmap,
+    views: Vec<View>,
+    buffers: Arc<[Buffer<u8>]>,
+    buffer_offsets: Vec<u32>,
+    extend_null_bits: Vec<ExtendNullBits<'a>>,
+}
+
+impl<'a> GrowableStringView<'a> {
+    /// Creates a new [`GrowableStringView`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a StringViewArray>, mut use_validity: bool, capacity: usize) -> Self {
+        let data_type = arrays[0].data_type().clone();
+
+        // if any of the arrays has nulls, insertions from any array requires setting bits
+        // as there is at least one array with nulls.
+        if arrays.iter().any(|array| array.null_count() > 0) {
+            use_validity = true;
+        };
+
+        let extend_null_bits = arrays
+            .iter()
+            .map(|array| build_extend_null_bits(*array, use_validity))
+            .collect();
+
+        let (buffers, buffer_offsets) =
+            concat_buffers(arrays.iter().map(|array| array.data_buffers().as_ref()));
+
+        Self {
+            arrays,
+            data_type,
+            views: Vec::with_capacity(capacity),
+            buffers: buffers.into(),
+            buffer_offsets,
+            validity: MutableBitmap::with_capacity(capacity),
+            extend_null_bits,
+        }
+    }
+
+    fn to(&mut self) -> StringViewArray {
+        let validity = std::mem::take(&mut self.validity);
+        let views = std::mem::take(&mut self.views);
+
+        // soundness: the views were shifted to reference the same (utf8) values in `buffers`
+        unsafe {
+            StringViewArray::new_unchecked(
+                self.data_type.clone(),
+                views.into(),
+                self.buffers.clone(),
+                validity.into(),
+            )
+        }
+    }
+}
+
+impl<'a> Growable<'a> for GrowableStringView<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        (self.extend_null_bits[index])(&mut self.validity, start, len);
+
+        let array = self.arrays[index];
+        extend_views(
+            &mut self.views,
+            array.views(),
+            self.buffer_offsets[index],
+            start,
+            len,
+        );
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.views
+            .extend(std::iter::repeat(View::default()).take(additional));
+        self.validity.extend_constant(additional, false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.to().arced()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.to().boxed()
+    }
+}
+
+impl<'a> From<GrowableStringView<'a>> for StringViewArray {
+    fn from(mut val: GrowableStringView<'a>) -> Self {
+        val.to()
+    }
+}
@@ -3,7 +3,6 @@ use std::sync::Arc;
 use crate::{
     array::{Array, StructArray},
     bitmap::MutableBitmap,
-    datatypes::DataType,
 };
 
 use super::{
@@ -36,11 +35,6 @@ impl<'a> GrowableStruct<'a> {
             .map(|array| build_extend_null_bits(*array, use_validity))
             .collect();
 
-        let arrays = arrays
-            .iter()
-            .map(|array| array.as_any().downcast_ref::<StructArray>().unwrap())
-            .collect::<Vec<_>>();
-
         // ([field1, field2], [field3, field4]) -> ([field1, field3], [field2, field3])
         let values = (0..arrays[0].values().len())
             .map(|i| {
@@ -68,11 +62,7 @@ impl<'a> GrowableStruct<'a> {
         let values = std::mem::take(&mut self.values);
         let values = values.into_iter().map(|mut x| x.as_box()).collect();
 
-        StructArray::new(
-            DataType::Struct(self.arrays[0].fields().to_vec()),
-            values,
-            validity.into(),
-        )
+        StructArray::new(self.arrays[0].data_type().clone(), values, validity.into())
     }
 }
 
@@ -81,22 +71,35 @@ impl<'a> Growable<'a> for GrowableStruct<'a> {
         (self.extend_null_bits[index])(&mut self.validity, start, len);
 
         let array = self.arrays[index];
-        if array.null_count() == 0 {
-            self.values
-                .iter_mut()
-                .for_each(|child| child.extend(index, start, len))
-        } else {
-            (start..start + len).for_each(|i| {
-                if array.is_valid(i) {
-                    self.values
-                        .iter_mut()
-                        .for_each(|child| child.extend(index, i, 1))
-                } else {
-                    self.values
-                        .iter_mut()
-                        .for_each(|child| child.extend_validity(1))
-                }
-            })
+        let validity = match array.validity() {
+            Some(validity) if array.null_count() > 0 => validity,
+            _ => {
+                self.values
+                    .iter_mut()
+                    .for_each(|child| child.extend(index, start, len));
+                return;
+            }
+        };
+
+        // extend the children by runs of valid and null slots
+        let end = start + len;
+        let mut run_start = start;
+        while run_start < end {
+            let is_valid = validity.get_bit(run_start);
+            let run_end = (run_start + 1..end)
+                .find(|i| validity.get_bit(*i) != is_valid)
+                .unwrap_or(end);
+            let run_length = run_end - run_start;
+            if is_valid {
+                self.values
+                    .iter_mut()
+                    .for_each(|child| child.extend(index, run_start, run_length))
+            } else {
+                self.values
+                    .iter_mut()
+                    .for_each(|child| child.extend_validity(run_length))
+            }
+            run_start = run_end;
         }
     }
 
@@ -117,13 +120,7 @@ impl<'a> Growable<'a> for GrowableStruct<'a> {
 }
 
 impl<'a> From<GrowableStruct<'a>> for StructArray {
-    fn from(val: GrowableStruct<'a>) -> Self {
-        let values = val.values.into_iter().map(|mut x| x.as_box()).collect();
-
-        StructArray::new(
-            DataType::Struct(val.arrays[0].fields().to_vec()),
-            values,
-            val.validity.into(),
-        )
+    fn from(mut val: GrowableStruct<'a>) -> Self {
+        val.to()
     }
 }
@@ -1,15 +1,23 @@
 use std::sync::Arc;
 
 use crate::array::{Array, UnionArray};
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
 
 use super::{make_growable, Growable};
 
 /// Concrete [`Growable`] for the [`UnionArray`].
+/// # Implementation
+/// Null slots (from [`Growable::extend_validity`]) are null slots of the first field.
 pub struct GrowableUnion<'a> {
     arrays: Vec<&'a UnionArray>,
     types: Vec<i8>,
     offsets: Option<Vec<i32>>,
     fields: Vec<Box<dyn Growable<'a> + 'a>>,
+    // the current length of each of the fields, used for the offsets of dense unions
+    lengths: Vec<usize>,
+    // the type id of the first field
+    null_type: i8,
 }
 
 impl<'a> GrowableUnion<'a> {
@@ -17,12 +25,35 @@ impl<'a> GrowableUnion<'a> {
     /// # Panics
     /// Panics iff
     /// * `arrays` is empty.
-    /// * any of the arrays has a different
+    /// * any of the arrays has a different data type than the first one.
     pub fn new(arrays: Vec<&'a UnionArray>, capacity: usize) -> Self {
-        let first = arrays[0].data_type();
-        assert!(arrays.iter().all(|x| x.data_type() == first));
+        Self::try_new(arrays, capacity).unwrap()
+    }
+
+    /// Creates a new [`GrowableUnion`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Errors
+    /// Errors iff `arrays` is empty or any of the arrays has a different data type (i.e. a
+    /// different mode, fields or type ids) than the first one.
+    pub fn try_new(arrays: Vec<&'a UnionArray>, capacity: usize) -> Result<Self> {
+        let first = arrays
+            .first()
+            .ok_or_else(|| {
+                Error::InvalidArgumentError("GrowableUnion requires at least one array".to_string())
+            })?
+            .data_type();
+        if let Some(array) = arrays.iter().find(|x| x.data_type() != first) {
+            return Err(Error::InvalidArgumentError(format!(
+                "GrowableUnion requires all arrays to have the same data type ({:?} != {:?})",
+                first,
+                array.data_type()
+            )));
+        }
 
         let has_offsets = arrays[0].offsets().is_some();
+        let null_type = match first.to_logical_type() {
+            DataType::Union(_, Some(ids), _) => ids[0] as i8,
+            _ => 0,
+        };
 
         let fields = (0..arrays[0].fields().len())
             .map(|i| {
@@ -31,14 +62,15 @@ impl<'a> GrowableUnion<'a> {
                         .iter()
                         .map(|x| x.fields()[i].as_ref())
                         .collect::<Vec<_>>(),
-                    false,
+                    true,
                     capacity,
                 )
             })
             .collect::<Vec<Box<dyn Growable>>>();
 
-        Self {
+        Ok(Self {
             arrays,
+            lengths: vec![0; fields.len()],
             fields,
             offsets: if has_offsets {
                 Some(Vec::with_capacity(capacity))
@@ -46,7 +78,8 @@ impl<'a> GrowableUnion<'a> {
                 None
             },
             types: Vec::with_capacity(capacity),
-        }
+            null_type,
+        })
     }
 
     fn to(&mut self) -> UnionArray {
@@ -71,22 +104,46 @@ impl<'a> Growable<'a> for GrowableUnion<'a> {
         let types = &array.types()[start..start + len];
         self.types.extend(types);
         if let Some(x) = self.offsets.as_mut() {
-            let offsets = &array.offsets().unwrap()[start..start + len];
-
-            x.extend(offsets);
-            // in a dense union, each slot has its own offset. We extend the fields accordingly.
-            for (&type_, &offset) in types.iter().zip(offsets.iter()) {
-                self.fields[type_ as usize].extend(index, offset as usize, 1);
+            // in a dense union, each slot has its own offset. We extend the fields accordingly
+            // and point the new offsets to the end of the fields. Consecutive slots of the
+            // same field with consecutive offsets are extended at once.
+            let end = start + len;
+            let mut i = start;
+            while i < end {
+                let (field, offset) = array.index(i);
+                let run = (i + 1..end)
+                    .position(|j| array.index(j) != (field, offset + j - i))
+                    .map_or(end - i, |run| run + 1);
+
+                let length = self.lengths[field];
+                x.extend((length..length + run).map(|offset| offset as i32));
+                self.lengths[field] += run;
+                self.fields[field].extend(index, offset, run);
+                i += run;
             }
-        } else {
+        } else if len > 0 {
             // in a sparse union, every field has the same length => extend all fields equally
+            let (_, start) = array.index(start);
             self.fields
                 .iter_mut()
                 .for_each(|field| field.extend(index, start, len))
         }
     }
 
-    fn extend_validity(&mut self, _additional: usize) {}
+    fn extend_validity(&mut self, additional: usize) {
+        self.types
+            .extend(std::iter::repeat(self.null_type).take(additional));
+        if let Some(x) = self.offsets.as_mut() {
+            let length = self.lengths[0];
+            x.extend((length..length + additional).map(|offset| offset as i32));
+            self.lengths[0] += additional;
+            self.fields[0].extend_validity(additional);
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional))
+        }
+    }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
         self.to().arced()
@@ -98,14 +155,7 @@ impl<'a> Growable<'a> for GrowableUnion<'a> {
 }
 
 impl<'a> From<GrowableUnion<'a>> for UnionArray {
-    fn from(val: GrowableUnion<'a>) -> Self {
-        let fields = val.fields.into_iter().map(|mut x| x.as_box()).collect();
-
-        UnionArray::new(
-            val.arrays[0].data_type().clone(),
-            val.types.into(),
-            fields,
-            val.offsets.map(|x| x.into()),
-        )
+    fn from(mut val: GrowableUnion<'a>) -> Self {
+        val.to()
     }
 }
@@ -6,7 +6,7 @@ use crate::{
 };
 use std::sync::Arc;
 
-use super::{new_empty_array, specification::try_check_offsets_bounds, Array};
+use super::{new_empty_array, specification::try_check_offsets_bounds, Array, PrimitiveArray};
 
 mod ffi;
 pub(super) mod fmt;
@@ -214,6 +214,18 @@ impl<O: Offset> ListArray<O> {
     pub fn values(&self) -> &Box<dyn Array> {
         &self.values
     }
+
+    /// Returns the number of items of each list of this array.
+    /// Null lists have a null length.
+    pub fn lengths(&self) -> PrimitiveArray<O> {
+        let values = self
+            .offsets
+            .buffer()
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(O::PRIMITIVE.into(), values.into(), self.validity.clone())
+    }
 }
 
 impl<O: Offset> ListArray<O> {
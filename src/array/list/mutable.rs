@@ -104,7 +104,7 @@ where
     M: MutableArray + TryExtendFromSelf,
 {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<()> {
-        extend_validity(self.len(), &mut self.validity, &other.validity);
+        extend_validity(self.len(), &mut self.validity, other.len(), &other.validity);
 
         self.values.try_extend_from_self(&other.values)?;
         self.offsets.try_extend_from_self(&other.offsets)
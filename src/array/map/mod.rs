@@ -5,12 +5,17 @@ use crate::{
     offset::OffsetsBuffer,
 };
 
-use super::{new_empty_array, specification::try_check_offsets_bounds, Array};
+use super::{
+    new_empty_array, ord::build_compare, specification::try_check_offsets_bounds, Array,
+    MutableArray, StructArray, TryExtend, TryPush,
+};
 
 mod ffi;
 mod fmt;
 mod iterator;
 pub use iterator::*;
+mod mutable;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
 #[derive(Clone)]
@@ -31,6 +36,8 @@ impl MapArray {
     /// * The `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Map`]
     /// * The fields' `data_type` is not equal to the inner field of `data_type`
     /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    /// * The keys contain nulls
+    /// * The `data_type` declares its keys as sorted and the keys of a map are not sorted
     pub fn try_new(
         data_type: DataType,
         offsets: OffsetsBuffer<i32>,
@@ -66,6 +73,19 @@ impl MapArray {
             ));
         }
 
+        let keys = field
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .values()[0]
+            .as_ref();
+        if keys.null_count() > 0 {
+            return Err(Error::oos("The keys of a MapArray must not be null"));
+        }
+        if let DataType::Map(_, true) = data_type.to_logical_type() {
+            try_check_sorted_keys(&offsets, keys)?;
+        }
+
         Ok(Self {
             data_type,
             field,
@@ -88,6 +108,35 @@ impl MapArray {
         Self::try_new(data_type, offsets, field, validity).unwrap()
     }
 
+    /// Creates a [`MapArray`] from an iterator of optional maps, each an iterator of
+    /// `(key, value)` entries whose keys and values are built by `K` and `V`.
+    /// Its data type is [`MapArray::default_datatype`].
+    /// # Example
+    /// ```
+    /// use arrow2::array::{MapArray, MutablePrimitiveArray, MutableUtf8Array};
+    ///
+    /// type Keys = MutableUtf8Array<i32>;
+    /// type Values = MutablePrimitiveArray<i64>;
+    ///
+    /// let rows = [Some(vec![("a", Some(1)), ("b", None)]), None, Some(vec![])];
+    /// let array = MapArray::try_from_iter::<Keys, Values, _, _, _, _>(rows).unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.keys().len(), 2);
+    /// ```
+    /// # Errors
+    /// This function errors iff pushing a key or a value errors or the offsets overflow.
+    pub fn try_from_iter<K, V, KT, VT, P, I>(iter: I) -> Result<Self, Error>
+    where
+        K: MutableArray + Default + TryPush<Option<KT>>,
+        V: MutableArray + Default + TryPush<VT>,
+        P: IntoIterator<Item = (KT, VT)>,
+        I: IntoIterator<Item = Option<P>>,
+    {
+        let mut array = MutableMapArray::<K, V>::new();
+        array.try_extend(iter)?;
+        Ok(array.into())
+    }
+
     /// Returns a new null [`MapArray`] of `length`.
     pub fn new_null(data_type: DataType, length: usize) -> Self {
         let field = new_empty_array(Self::get_field(&data_type).data_type().clone());
@@ -165,6 +214,16 @@ impl MapArray {
         }
     }
 
+    /// Returns a [`DataType::Map`] whose entries are a non-nullable `key` of `key_type`
+    /// and a nullable `value` of `value_type`.
+    pub fn default_datatype(key_type: DataType, value_type: DataType) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("key", key_type, false),
+            Field::new("value", value_type, true),
+        ]);
+        DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+    }
+
     pub(crate) fn try_get_field(data_type: &DataType) -> Result<&Field, Error> {
         if let DataType::Map(field, _) = data_type.to_logical_type() {
             Ok(field.as_ref())
@@ -180,6 +239,25 @@ impl MapArray {
     }
 }
 
+/// Checks that the keys of every map are in ascending order. Keys that cannot be
+/// compared are not checked.
+fn try_check_sorted_keys(offsets: &OffsetsBuffer<i32>, keys: &dyn Array) -> Result<(), Error> {
+    let compare = match build_compare(keys, keys) {
+        Ok(compare) => compare,
+        Err(_) => return Ok(()),
+    };
+    let is_sorted = offsets.buffer().windows(2).all(|window| {
+        let (start, end) = (window[0] as usize, window[1] as usize);
+        (start + 1..end).all(|i| compare(i - 1, i) != std::cmp::Ordering::Greater)
+    });
+    if !is_sorted {
+        return Err(Error::oos(
+            "The keys of every map must be sorted when the DataType declares them as sorted",
+        ));
+    }
+    Ok(())
+}
+
 // Accessors
 impl MapArray {
     /// Returns the length of this array
@@ -200,6 +278,23 @@ impl MapArray {
         &self.field
     }
 
+    /// Returns the keys of the entries of all maps, i.e. disregarding the offsets
+    #[inline]
+    pub fn keys(&self) -> &Box<dyn Array> {
+        &self.entries().values()[0]
+    }
+
+    /// Returns the values of the entries of all maps, i.e. disregarding the offsets
+    #[inline]
+    pub fn values(&self) -> &Box<dyn Array> {
+        &self.entries().values()[1]
+    }
+
+    #[inline]
+    fn entries(&self) -> &StructArray {
+        self.field.as_any().downcast_ref().unwrap()
+    }
+
     /// Returns the element at index `i`.
     #[inline]
     pub fn value(&self, i: usize) -> Box<dyn Array> {
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::Offsets,
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`]. The keys and values of its entries are
+/// built by two [`MutableArray`]s of the same length.
+#[derive(Debug, Clone)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    offsets: Offsets<i32>,
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> MutableMapArray<K, V> {
+    /// Creates a new empty [`MutableMapArray`].
+    pub fn new() -> Self {
+        Self::new_with_capacity(K::default(), V::default(), 0)
+    }
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> Default for MutableMapArray<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Creates a new [`MutableMapArray`] of [`DataType::Map`] from empty keys and values.
+    /// # Errors
+    /// This function errors iff:
+    /// * `keys` or `values` are not empty
+    /// * The `data_type`'s logical type is not [`DataType::Map`] of a `Struct` with 2 fields
+    /// * The data types of the fields are not equal to the data types of `keys` and `values`
+    pub fn try_new(data_type: DataType, keys: K, values: V) -> Result<Self> {
+        if !keys.is_empty() || !values.is_empty() {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray must be created from empty keys and values".to_string(),
+            ));
+        }
+        let fields = match MapArray::try_get_field(&data_type)?.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => fields,
+            _ => {
+                return Err(Error::InvalidArgumentError(
+                    "MapArray's inner `Struct` must have 2 fields (keys and maps)".to_string(),
+                ))
+            }
+        };
+        if fields[0].data_type() != keys.data_type() || fields[1].data_type() != values.data_type()
+        {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects the keys and values to match its inner fields".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets: Offsets::new(),
+            keys,
+            values,
+            validity: None,
+        })
+    }
+
+    /// Creates a new [`MutableMapArray`] from empty keys and values with a capacity.
+    /// Its data type is [`MapArray::default_datatype`] of the data types of `keys` and `values`.
+    /// # Panics
+    /// This function panics iff `keys` or `values` are not empty.
+    pub fn new_with_capacity(keys: K, values: V, capacity: usize) -> Self {
+        let data_type =
+            MapArray::default_datatype(keys.data_type().clone(), values.data_type().clone());
+        let mut array = Self::try_new(data_type, keys, values).unwrap();
+        array.reserve(capacity);
+        array
+    }
+
+    /// Appends an entry to the map that is currently being built. The map is
+    /// finished by [`MutableMapArray::try_push_valid`].
+    /// # Errors
+    /// This function errors iff pushing `key` or `value` errors.
+    pub fn append_key_value<KT, VT>(&mut self, key: KT, value: VT) -> Result<()>
+    where
+        K: TryPush<Option<KT>>,
+        V: TryPush<VT>,
+    {
+        self.keys.try_push(Some(key))?;
+        self.values.try_push(value)
+    }
+
+    /// Needs to be called when the entries of a valid map were appended to this array.
+    /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// This function errors iff the keys and values have different lengths or the
+    /// offsets overflow.
+    #[inline]
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let total_length = self.keys.len();
+        if total_length != self.values.len() {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray's keys and values must have the same length".to_string(),
+            ));
+        }
+        let offset = *self.offsets.last() as usize;
+        let length = total_length.checked_sub(offset).ok_or(Error::Overflow)?;
+
+        self.offsets.try_push_usize(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &Offsets<i32> {
+        &self.offsets
+    }
+
+    /// The keys
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+
+    /// The values
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// The keys
+    pub fn mut_keys(&mut self) -> &mut K {
+        &mut self.keys
+    }
+
+    /// The values
+    pub fn mut_values(&mut self) -> &mut V {
+        &mut self.values
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    fn take_into(&mut self) -> MapArray {
+        let fields = match MapArray::get_field(&self.data_type).data_type() {
+            DataType::Struct(fields) => fields.clone(),
+            _ => unreachable!(),
+        };
+        let field = StructArray::new(
+            DataType::Struct(fields),
+            vec![self.keys.as_box(), self.values.as_box()],
+            None,
+        );
+        MapArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            field.boxed(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> From<MutableMapArray<K, V>> for MapArray {
+    fn from(mut other: MutableMapArray<K, V>) -> Self {
+        other.take_into()
+    }
+}
+
+impl<K, V, I, KT, VT> TryPush<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<Option<KT>>,
+    V: MutableArray + TryPush<VT>,
+    I: IntoIterator<Item = (KT, VT)>,
+{
+    #[inline]
+    fn try_push(&mut self, item: Option<I>) -> Result<()> {
+        if let Some(entries) = item {
+            for (key, value) in entries {
+                self.append_key_value(key, value)?;
+            }
+            self.try_push_valid()?;
+        } else {
+            self.push_null();
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, I, KT, VT> TryExtend<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<Option<KT>>,
+    V: MutableArray + TryPush<VT>,
+    I: IntoIterator<Item = (KT, VT)>,
+{
+    fn try_extend<II: IntoIterator<Item = Option<I>>>(&mut self, iter: II) -> Result<()> {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for entries in iter {
+            self.try_push(entries)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, KT, VT> From<Vec<HashMap<KT, VT>>> for MutableMapArray<K, V>
+where
+    K: MutableArray + Default + TryPush<Option<KT>>,
+    V: MutableArray + Default + TryPush<VT>,
+{
+    /// Creates a [`MutableMapArray`] with a valid map per [`HashMap`], whose entries
+    /// follow the iteration order of the [`HashMap`].
+    /// # Panics
+    /// This function panics iff pushing a key or a value errors, e.g. when the offsets overflow.
+    fn from(maps: Vec<HashMap<KT, VT>>) -> Self {
+        let mut array = Self::new_with_capacity(K::default(), V::default(), maps.len());
+        array.try_extend(maps.into_iter().map(Some)).unwrap();
+        array
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn len(&self) -> usize {
+        MutableMapArray::len(self)
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_into().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_into().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
@@ -110,6 +110,13 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
 
     /// Clone a `&dyn Array` to an owned `Box<dyn Array>`.
     fn to_boxed(&self) -> Box<dyn Array>;
+
+    /// Returns the (heap) size of the buffers of the [`Array`] in bytes, only counting the
+    /// region referenced by its slice.
+    /// See [`crate::compute::aggregate::estimated_bytes_size`] for details.
+    fn estimated_bytes_size(&self) -> usize {
+        crate::compute::aggregate::estimated_bytes_size_of(self)
+    }
 }
 
 dyn_clone::clone_trait_object!(Array);
@@ -293,6 +300,8 @@ impl std::fmt::Debug for dyn Array + '_ {
             FixedSizeBinary => fmt_dyn!(self, FixedSizeBinaryArray, f),
             Utf8 => fmt_dyn!(self, Utf8Array::<i32>, f),
             LargeUtf8 => fmt_dyn!(self, Utf8Array::<i64>, f),
+            BinaryView => fmt_dyn!(self, BinaryViewArray, f),
+            Utf8View => fmt_dyn!(self, StringViewArray, f),
             List => fmt_dyn!(self, ListArray::<i32>, f),
             LargeList => fmt_dyn!(self, ListArray::<i64>, f),
             FixedSizeList => fmt_dyn!(self, FixedSizeListArray, f),
@@ -304,14 +313,29 @@ impl std::fmt::Debug for dyn Array + '_ {
                 })
             }
             Map => fmt_dyn!(self, MapArray, f),
+            RunEndEncoded => {
+                let primitive = values_primitive_type(self.data_type());
+                with_match_primitive_type!(primitive, |$T| {
+                    fmt_dyn!(self, RunEndEncodedArray<$T>, f)
+                })
+            }
         }
     }
 }
 
 /// Creates a new [`Array`] with a [`Array::len`] of 0.
+/// # Panics
+/// This function panics iff [`try_new_empty_array`] errors.
 pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
+    try_new_empty_array(data_type).unwrap()
+}
+
+/// Creates a new [`Array`] with a [`Array::len`] of 0.
+/// # Errors
+/// Errors iff `data_type` is a [`DataType::RunEndEncoded`] whose values are not primitive.
+pub fn try_new_empty_array(data_type: DataType) -> Result<Box<dyn Array>> {
     use crate::datatypes::PhysicalType::*;
-    match data_type.to_physical_type() {
+    Ok(match data_type.to_physical_type() {
         Null => Box::new(NullArray::new_empty(data_type)),
         Boolean => Box::new(BooleanArray::new_empty(data_type)),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
@@ -322,26 +346,45 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
         FixedSizeBinary => Box::new(FixedSizeBinaryArray::new_empty(data_type)),
         Utf8 => Box::new(Utf8Array::<i32>::new_empty(data_type)),
         LargeUtf8 => Box::new(Utf8Array::<i64>::new_empty(data_type)),
+        BinaryView => Box::new(BinaryViewArray::new_empty(data_type)),
+        Utf8View => Box::new(StringViewArray::new_empty(data_type)),
         List => Box::new(ListArray::<i32>::new_empty(data_type)),
         LargeList => Box::new(ListArray::<i64>::new_empty(data_type)),
         FixedSizeList => Box::new(FixedSizeListArray::new_empty(data_type)),
         Struct => Box::new(StructArray::new_empty(data_type)),
         Union => Box::new(UnionArray::new_empty(data_type)),
         Map => Box::new(MapArray::new_empty(data_type)),
+        RunEndEncoded => {
+            let primitive = try_values_primitive_type(&data_type)?;
+            with_match_primitive_type!(primitive, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::new_empty(data_type))
+            })
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 Box::new(DictionaryArray::<$T>::new_empty(data_type))
             })
         }
-    }
+    })
 }
 
 /// Creates a new [`Array`] of [`DataType`] `data_type` and `length`.
 /// The array is guaranteed to have [`Array::null_count`] equal to [`Array::len`]
-/// for all types except Union, which does not have a validity.
+/// for all types except Union and RunEndEncoded, which do not have a validity.
+/// # Panics
+/// This function panics iff [`try_new_null_array`] errors.
 pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
+    try_new_null_array(data_type, length).unwrap()
+}
+
+/// Creates a new [`Array`] of [`DataType`] `data_type` and `length`.
+/// The array is guaranteed to have [`Array::null_count`] equal to [`Array::len`]
+/// for all types except Union and RunEndEncoded, which do not have a validity.
+/// # Errors
+/// Errors iff `data_type` is a [`DataType::RunEndEncoded`] whose values are not primitive.
+pub fn try_new_null_array(data_type: DataType, length: usize) -> Result<Box<dyn Array>> {
     use crate::datatypes::PhysicalType::*;
-    match data_type.to_physical_type() {
+    Ok(match data_type.to_physical_type() {
         Null => Box::new(NullArray::new_null(data_type, length)),
         Boolean => Box::new(BooleanArray::new_null(data_type, length)),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
@@ -352,18 +395,26 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
         FixedSizeBinary => Box::new(FixedSizeBinaryArray::new_null(data_type, length)),
         Utf8 => Box::new(Utf8Array::<i32>::new_null(data_type, length)),
         LargeUtf8 => Box::new(Utf8Array::<i64>::new_null(data_type, length)),
+        BinaryView => Box::new(BinaryViewArray::new_null(data_type, length)),
+        Utf8View => Box::new(StringViewArray::new_null(data_type, length)),
         List => Box::new(ListArray::<i32>::new_null(data_type, length)),
         LargeList => Box::new(ListArray::<i64>::new_null(data_type, length)),
         FixedSizeList => Box::new(FixedSizeListArray::new_null(data_type, length)),
         Struct => Box::new(StructArray::new_null(data_type, length)),
         Union => Box::new(UnionArray::new_null(data_type, length)),
         Map => Box::new(MapArray::new_null(data_type, length)),
+        RunEndEncoded => {
+            let primitive = try_values_primitive_type(&data_type)?;
+            with_match_primitive_type!(primitive, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::new_null(data_type, length))
+            })
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 Box::new(DictionaryArray::<$T>::new_null(data_type, length))
             })
         }
-    }
+    })
 }
 
 macro_rules! clone_dyn {
@@ -390,12 +441,20 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
         FixedSizeBinary => clone_dyn!(array, FixedSizeBinaryArray),
         Utf8 => clone_dyn!(array, Utf8Array::<i32>),
         LargeUtf8 => clone_dyn!(array, Utf8Array::<i64>),
+        BinaryView => clone_dyn!(array, BinaryViewArray),
+        Utf8View => clone_dyn!(array, StringViewArray),
         List => clone_dyn!(array, ListArray::<i32>),
         LargeList => clone_dyn!(array, ListArray::<i64>),
         FixedSizeList => clone_dyn!(array, FixedSizeListArray),
         Struct => clone_dyn!(array, StructArray),
         Union => clone_dyn!(array, UnionArray),
         Map => clone_dyn!(array, MapArray),
+        RunEndEncoded => {
+            let primitive = values_primitive_type(array.data_type());
+            with_match_primitive_type!(primitive, |$T| {
+                clone_dyn!(array, RunEndEncodedArray<$T>)
+            })
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 clone_dyn!(array, DictionaryArray::<$T>)
@@ -413,6 +472,7 @@ impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
 }
 
 mod binary;
+mod binary_view;
 mod boolean;
 mod dictionary;
 mod fixed_size_binary;
@@ -421,7 +481,9 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 mod specification;
+mod string_view;
 mod struct_;
 mod union;
 mod utf8;
@@ -435,21 +497,27 @@ pub mod ord;
 
 pub(crate) use iterator::ArrayAccessor;
 pub use iterator::ArrayValuesIter;
+pub(crate) use run_end_encoded::{try_values_primitive_type, values_primitive_type};
 
 pub use equal::equal;
 pub use fmt::{get_display, get_value_display};
 
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray, MutableBinaryValuesArray};
+pub use binary_view::{BinaryViewArray, BinaryViewValueIter, View};
 pub use boolean::{BooleanArray, MutableBooleanArray};
 pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
 pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
 pub use list::{ListArray, ListValuesIter, MutableListArray};
-pub use map::MapArray;
+pub use map::{MapArray, MutableMapArray};
 pub use null::NullArray;
 pub use primitive::*;
+pub use run_end_encoded::{
+    ree_decode, ree_encode, MutableRunEndEncodedArray, RunEndEncodedArray, RunEndEncodedIter,
+};
+pub use string_view::{StringViewArray, StringViewValueIter};
 pub use struct_::{MutableStructArray, StructArray};
-pub use union::UnionArray;
+pub use union::{MutableUnionArray, UnionArray};
 pub use utf8::{MutableUtf8Array, MutableUtf8ValuesArray, Utf8Array, Utf8ValuesIter};
 
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
@@ -210,10 +210,12 @@ where
     (offsets, values)
 }
 
-/// Extends `validity` with all items from `other`
+/// Extends `validity` (of an array of `length` slots) with the validity `other` of an array
+/// of `other_length` slots.
 pub(crate) fn extend_validity(
     length: usize,
     validity: &mut Option<MutableBitmap>,
+    other_length: usize,
     other: &Option<MutableBitmap>,
 ) {
     if let Some(other) = other {
@@ -225,5 +227,7 @@ pub(crate) fn extend_validity(
             new_validity.extend_from_slice(other.as_slice(), 0, other.len());
             *validity = Some(new_validity);
         }
+    } else if let Some(validity) = validity {
+        validity.extend_constant(other_length, true)
     }
 }
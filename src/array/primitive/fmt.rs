@@ -132,7 +132,19 @@ pub fn get_write_value<'a, T: NativeType, F: Write>(
             let display = move |x: i256| {
                 let base = x.0 / factor;
                 let decimals = (x.0 - base * factor).abs();
-                format!("{}.{}", base, decimals)
+                // the sign of e.g. -0.5 is lost in its integer part
+                let sign = if x.0.is_negative() && base == 0 {
+                    "-"
+                } else {
+                    ""
+                };
+                format!(
+                    "{}{}.{:0width$}",
+                    sign,
+                    base,
+                    decimals,
+                    width = scale as usize
+                )
             };
             dyn_primitive!(array, i256, display)
         }
@@ -297,6 +297,33 @@ impl<T: NativeType> PrimitiveArray<T> {
         self.values.get_mut().map(|x| x.as_mut())
     }
 
+    /// Applies a function `f` to the values of this array, in place if they are not shared
+    /// (clone-on-write).
+    ///
+    /// The values are copied to a new region iff they are shared with another array or
+    /// this array is a slice (see [`Buffer::get_mut`]). `f` is applied to all values,
+    /// including those on null slots.
+    /// # Example
+    /// ```
+    /// use arrow2::array::Int32Array;
+    ///
+    /// let mut array = Int32Array::from([Some(1), None, Some(3)]);
+    /// let ptr = array.values().as_slice().as_ptr();
+    /// array.apply_values_mut(|values| values.iter_mut().for_each(|x| *x *= 10));
+    /// assert_eq!(array, Int32Array::from([Some(10), None, Some(30)]));
+    /// // the values were not shared and were thus mutated in place
+    /// assert_eq!(array.values().as_slice().as_ptr(), ptr);
+    /// ```
+    pub fn apply_values_mut<F: FnOnce(&mut [T])>(&mut self, f: F) {
+        if let Some(values) = self.get_mut_values() {
+            f(values)
+        } else {
+            let mut values = self.values.to_vec();
+            f(&mut values);
+            self.values = values.into();
+        }
+    }
+
     /// Returns its internal representation
     #[must_use]
     pub fn into_inner(self) -> (DataType, Buffer<T>, Option<Bitmap>) {
@@ -659,7 +659,7 @@ impl<T: NativeType> PartialEq for MutablePrimitiveArray<T> {
 
 impl<T: NativeType> TryExtendFromSelf for MutablePrimitiveArray<T> {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<(), Error> {
-        extend_validity(self.len(), &mut self.validity, &other.validity);
+        extend_validity(self.len(), &mut self.validity, other.len(), &other.validity);
 
         let slice = other.values.as_slice();
         self.values.extend_from_slice(slice);
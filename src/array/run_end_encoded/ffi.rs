@@ -0,0 +1,64 @@
+use crate::{
+    array::{FromFfi, PrimitiveArray},
+    datatypes::DataType,
+    error::{Error, Result},
+    ffi,
+    types::NativeType,
+};
+
+use super::super::{ffi::ToFfi, Array};
+use super::RunEndEncodedArray;
+
+unsafe impl<T: NativeType> ToFfi for RunEndEncodedArray<T> {
+    fn buffers(&self) -> Vec<Option<*const u8>> {
+        vec![]
+    }
+
+    fn children(&self) -> Vec<Box<dyn Array>> {
+        vec![
+            PrimitiveArray::new(DataType::Int32, self.run_ends.clone(), None).boxed(),
+            self.values.clone().boxed(),
+        ]
+    }
+
+    fn offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T: NativeType, A: ffi::ArrowArrayRef> FromFfi<A> for RunEndEncodedArray<T> {
+    unsafe fn try_from_ffi(array: A) -> Result<Self> {
+        let data_type = array.data_type().clone();
+        let length = array.array().len();
+        let offset = array.array().offset();
+
+        let run_ends = ffi::try_from(array.child(0)?)?;
+        let run_ends = run_ends
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .ok_or_else(|| Error::oos("The run ends of a RunEndEncodedArray must be i32"))?;
+        if run_ends.null_count() > 0 {
+            return Err(Error::oos(
+                "The run ends of a RunEndEncodedArray cannot contain nulls",
+            ));
+        }
+
+        let values = ffi::try_from(array.child(1)?)?;
+        let values = values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .ok_or_else(|| Error::oos("The values of a RunEndEncodedArray must be primitive"))?;
+
+        let array = Self::try_new(data_type, run_ends.values().clone(), values.clone())?;
+        if offset + length > array.len() {
+            return Err(Error::oos(
+                "The offset and length of a RunEndEncodedArray must be within its runs",
+            ));
+        }
+        Ok(array.slice(offset, length))
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use crate::types::NativeType;
+
+use super::super::fmt::{get_display, write_vec};
+use super::RunEndEncodedArray;
+
+pub fn write_value<T: NativeType, W: Write>(
+    array: &RunEndEncodedArray<T>,
+    index: usize,
+    null: &'static str,
+    f: &mut W,
+) -> Result {
+    let index = array.get_physical_index(index);
+
+    get_display(array.values(), null)(f, index)
+}
+
+impl<T: NativeType> Debug for RunEndEncodedArray<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
+
+        write!(f, "RunEndEncodedArray")?;
+        write_vec(f, writer, None, self.len(), "None", false)
+    }
+}
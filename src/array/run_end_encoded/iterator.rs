@@ -0,0 +1,82 @@
+use super::RunEndEncodedArray;
+use crate::{array::Array, trusted_len::TrustedLen, types::NativeType};
+
+/// Iterator of the slots of a [`RunEndEncodedArray`].
+/// # Implementation
+/// The runs are traversed in order, so iterating over the array is `O(N + R)` where `N` is
+/// its length and `R` its number of runs.
+#[derive(Debug, Clone)]
+pub struct RunEndEncodedIter<'a, T: NativeType> {
+    array: &'a RunEndEncodedArray<T>,
+    // the index of the run of the next slot
+    run: usize,
+    current: usize,
+}
+
+impl<'a, T: NativeType> RunEndEncodedIter<'a, T> {
+    /// Returns a new [`RunEndEncodedIter`]
+    #[inline]
+    pub fn new(array: &'a RunEndEncodedArray<T>) -> Self {
+        let run = if array.is_empty() {
+            0
+        } else {
+            array.get_physical_index(0)
+        };
+        Self {
+            array,
+            run,
+            current: 0,
+        }
+    }
+}
+
+impl<'a, T: NativeType> Iterator for RunEndEncodedIter<'a, T> {
+    type Item = Option<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.array.len() {
+            return None;
+        }
+        let position = self.array.offset() + self.current;
+        while self.array.run_ends()[self.run] as usize <= position {
+            self.run += 1;
+        }
+        self.current += 1;
+
+        let values = self.array.values();
+        Some(if values.is_null(self.run) {
+            None
+        } else {
+            Some(values.value(self.run))
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.array.len() - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: NativeType> IntoIterator for &'a RunEndEncodedArray<T> {
+    type Item = Option<T>;
+    type IntoIter = RunEndEncodedIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: NativeType> RunEndEncodedArray<T> {
+    /// Returns an iterator of the slots of this array.
+    #[inline]
+    pub fn iter(&'a self) -> RunEndEncodedIter<'a, T> {
+        RunEndEncodedIter::new(self)
+    }
+}
+
+impl<'a, T: NativeType> std::iter::ExactSizeIterator for RunEndEncodedIter<'a, T> {}
+
+unsafe impl<'a, T: NativeType> TrustedLen for RunEndEncodedIter<'a, T> {}
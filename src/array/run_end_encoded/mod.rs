@@ -0,0 +1,398 @@
+use crate::{
+    bitmap::{Bitmap, MutableBitmap},
+    buffer::Buffer,
+    datatypes::{DataType, Field, PhysicalType, PrimitiveType},
+    error::Error,
+    types::NativeType,
+};
+
+use super::{Array, PrimitiveArray};
+
+mod ffi;
+pub(super) mod fmt;
+mod iterator;
+mod mutable;
+
+pub use iterator::RunEndEncodedIter;
+pub use mutable::MutableRunEndEncodedArray;
+
+/// [`RunEndEncodedArray`] represents an array of [`NativeType`]s as runs of equal slots:
+/// the `i`-th run has the value `values[i]` and ends at the logical slot `run_ends[i]`
+/// (exclusive).
+///
+/// Runs of nulls are represented by null values; the array itself has no validity.
+/// Cloning and slicing this array is `O(1)`.
+/// # Example
+/// ```
+/// use arrow2::array::{ree_decode, ree_encode, Int32Array};
+///
+/// let array = Int32Array::from([Some(1), Some(1), None, None, Some(2)]);
+/// let encoded = ree_encode(&array);
+/// assert_eq!(encoded.run_ends().as_slice(), &[2, 4, 5]);
+/// assert_eq!(encoded.values(), &Int32Array::from([Some(1), None, Some(2)]));
+/// assert_eq!(encoded.get(3), None);
+/// assert_eq!(ree_decode(&encoded), array);
+/// ```
+#[derive(Clone)]
+pub struct RunEndEncodedArray<T: NativeType> {
+    data_type: DataType,
+    // Invariant: `run_ends` is strictly increasing and its first run end is positive
+    // Invariant: `run_ends.len() == values.len()`
+    run_ends: Buffer<i32>,
+    values: PrimitiveArray<T>,
+    // the logical offset and length of this array over the runs.
+    // Invariant: `offset + length <= run_ends.last()`
+    offset: usize,
+    length: usize,
+}
+
+impl<T: NativeType> RunEndEncodedArray<T> {
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`PhysicalType::RunEndEncoded`]
+    /// * the run ends' field of `data_type` is not [`DataType::Int32`]
+    /// * the values' field of `data_type` has a different data type than `values`
+    /// * `run_ends` and `values` have different lengths
+    /// * `run_ends` is not strictly increasing or its first run end is not positive
+    pub fn try_new(
+        data_type: DataType,
+        run_ends: Buffer<i32>,
+        values: PrimitiveArray<T>,
+    ) -> Result<Self, Error> {
+        let (run_ends_field, values_field) = Self::try_get_fields(&data_type)?;
+        if run_ends_field.data_type() != &DataType::Int32 {
+            return Err(Error::oos(
+                "The run ends of a RunEndEncodedArray must be of type DataType::Int32",
+            ));
+        }
+        if values_field.data_type() != values.data_type() {
+            return Err(Error::oos(format!(
+                "The values of a RunEndEncodedArray must be of type {:?}, but they are of type {:?}",
+                values_field.data_type(),
+                values.data_type()
+            )));
+        }
+        if run_ends.len() != values.len() {
+            return Err(Error::oos(format!(
+                "A RunEndEncodedArray must have as many run ends ({}) as values ({})",
+                run_ends.len(),
+                values.len()
+            )));
+        }
+        let mut previous = 0;
+        for &run_end in run_ends.iter() {
+            if run_end <= previous {
+                return Err(Error::oos(
+                    "The run ends of a RunEndEncodedArray must be positive and strictly increasing",
+                ));
+            }
+            previous = run_end;
+        }
+
+        Ok(Self {
+            data_type,
+            run_ends,
+            values,
+            offset: 0,
+            length: previous as usize,
+        })
+    }
+
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Panics
+    /// This function panics iff [`Self::try_new`] errors.
+    pub fn new(data_type: DataType, run_ends: Buffer<i32>, values: PrimitiveArray<T>) -> Self {
+        Self::try_new(data_type, run_ends, values).unwrap()
+    }
+
+    /// Returns the default [`DataType`] of a [`RunEndEncodedArray`] whose values are of type
+    /// `values`: `DataType::RunEndEncoded` with non-nullable [`DataType::Int32`] run ends.
+    pub fn default_data_type(values: DataType) -> DataType {
+        DataType::RunEndEncoded(
+            Box::new(Field::new("run_ends", DataType::Int32, false)),
+            Box::new(Field::new("values", values, true)),
+        )
+    }
+
+    /// Returns a new empty [`RunEndEncodedArray`].
+    pub fn new_empty(data_type: DataType) -> Self {
+        let values = PrimitiveArray::new_empty(Self::get_fields(&data_type).1.data_type().clone());
+        Self::new(data_type, Buffer::new(), values)
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] whose `length` slots are null, as a single run.
+    /// # Panics
+    /// This function panics iff `length > i32::MAX`.
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        if length == 0 {
+            return Self::new_empty(data_type);
+        }
+        let run_end = i32::try_from(length)
+            .expect("The length of a RunEndEncodedArray must be smaller than i32::MAX");
+        let values =
+            PrimitiveArray::new_null(Self::get_fields(&data_type).1.data_type().clone(), 1);
+        Self::new(data_type, vec![run_end].into(), values)
+    }
+
+    /// Boxes self into a [`Box<dyn Array>`].
+    pub fn boxed(self) -> Box<dyn Array> {
+        Box::new(self)
+    }
+
+    /// Boxes self into a [`std::sync::Arc<dyn Array>`].
+    pub fn arced(self) -> std::sync::Arc<dyn Array> {
+        std::sync::Arc::new(self)
+    }
+}
+
+impl<T: NativeType> RunEndEncodedArray<T> {
+    /// Returns a slice of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(1)`: the runs are shared with the new array.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a slice of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(1)`: the runs are shared with the new array.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        debug_assert!(offset + length <= self.len());
+        Self {
+            data_type: self.data_type.clone(),
+            run_ends: self.run_ends.clone(),
+            values: self.values.clone(),
+            offset: self.offset + offset,
+            length,
+        }
+    }
+}
+
+impl<T: NativeType> RunEndEncodedArray<T> {
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether this array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The run ends of this array. They are not affected by slicing: they are relative to
+    /// [`Self::offset`] slots before the first slot of this array.
+    #[inline]
+    pub fn run_ends(&self) -> &Buffer<i32> {
+        &self.run_ends
+    }
+
+    /// The values of the runs of this array.
+    #[inline]
+    pub fn values(&self) -> &PrimitiveArray<T> {
+        &self.values
+    }
+
+    /// The logical offset of this array over its runs.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the run of slot `i`, i.e. the index of its value in [`Self::values`].
+    /// # Implementation
+    /// This operation is `O(log(R))` where `R` is the number of runs.
+    /// # Panics
+    /// This function panics iff `i >= self.len()`.
+    #[inline]
+    pub fn get_physical_index(&self, i: usize) -> usize {
+        assert!(i < self.len());
+        // cannot overflow: `self.offset + i` is smaller than the last run end
+        let i = (self.offset + i) as i32;
+        self.run_ends.partition_point(|&run_end| run_end <= i)
+    }
+
+    /// Returns the value of slot `i`, or `None` if it is null.
+    /// # Implementation
+    /// This operation is `O(log(R))` where `R` is the number of runs.
+    /// # Panics
+    /// This function panics iff `i >= self.len()`.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<T> {
+        let index = self.get_physical_index(i);
+        if self.values.is_null(index) {
+            None
+        } else {
+            Some(self.values.value(index))
+        }
+    }
+
+    /// Returns an iterator of the index and length of the runs over the slots
+    /// `offset..offset + length` of this array. The first and last runs are truncated to
+    /// these slots.
+    pub(crate) fn runs(
+        &self,
+        offset: usize,
+        length: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let first = if length == 0 {
+            self.run_ends.len()
+        } else {
+            self.get_physical_index(offset)
+        };
+        let start = self.offset + offset;
+        let end = start + length;
+        self.run_ends[first..]
+            .iter()
+            .enumerate()
+            .scan(start, move |position, (index, run_end)| {
+                if *position >= end {
+                    return None;
+                }
+                let run_end = (*run_end as usize).min(end);
+                let length = run_end - *position;
+                *position = run_end;
+                Some((first + index, length))
+            })
+    }
+
+    /// The [`DataType`] of this array.
+    #[inline]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+}
+
+impl<T: NativeType> RunEndEncodedArray<T> {
+    fn try_get_fields(data_type: &DataType) -> Result<(&Field, &Field), Error> {
+        match data_type.to_logical_type() {
+            DataType::RunEndEncoded(run_ends, values) => Ok((run_ends.as_ref(), values.as_ref())),
+            _ => Err(Error::oos(
+                "The RunEndEncodedArray requires a logical type of DataType::RunEndEncoded",
+            )),
+        }
+    }
+
+    /// Returns the run ends' and values' fields of a [`DataType::RunEndEncoded`].
+    /// # Panic
+    /// Panics iff `data_type`'s logical type is not [`DataType::RunEndEncoded`].
+    pub fn get_fields(data_type: &DataType) -> (&Field, &Field) {
+        Self::try_get_fields(data_type).unwrap()
+    }
+}
+
+/// Returns the [`PrimitiveType`] of the values of a [`DataType::RunEndEncoded`].
+/// # Errors
+/// Errors iff `data_type`'s logical type is not a [`DataType::RunEndEncoded`] of primitive values.
+pub(crate) fn try_values_primitive_type(data_type: &DataType) -> Result<PrimitiveType, Error> {
+    match data_type.to_logical_type() {
+        DataType::RunEndEncoded(_, values) => match values.data_type().to_physical_type() {
+            PhysicalType::Primitive(primitive) => Ok(primitive),
+            _ => Err(Error::NotYetImplemented(format!(
+                "RunEndEncodedArray with values of type {:?}",
+                values.data_type()
+            ))),
+        },
+        _ => Err(Error::oos(
+            "The RunEndEncodedArray requires a logical type of DataType::RunEndEncoded",
+        )),
+    }
+}
+
+/// Returns the [`PrimitiveType`] of the values of the [`DataType`] of a [`RunEndEncodedArray`].
+/// # Panic
+/// Panics iff [`try_values_primitive_type`] errors, which never happens for the data type of an
+/// array, since [`RunEndEncodedArray::try_new`] validates it. Use [`try_values_primitive_type`]
+/// for any other [`DataType`].
+pub(crate) fn values_primitive_type(data_type: &DataType) -> PrimitiveType {
+    try_values_primitive_type(data_type).unwrap()
+}
+
+impl<T: NativeType> Array for RunEndEncodedArray<T> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        None
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+    fn with_validity(&self, _: Option<Bitmap>) -> Box<dyn Array> {
+        panic!("cannot set validity of a run-end encoded array")
+    }
+    fn to_boxed(&self) -> Box<dyn Array> {
+        Box::new(self.clone())
+    }
+}
+
+/// Returns the [`RunEndEncodedArray`] of the runs of equal slots of `array`. Consecutive nulls
+/// form a single run.
+///
+/// The values are compared with [`PartialEq`], so `NaN`s form runs of length 1.
+/// # Implementation
+/// This operation is `O(N)` where `N` is the length of `array`.
+/// # Panics
+/// This function panics iff `array.len() > i32::MAX`.
+pub fn ree_encode<T: NativeType>(array: &PrimitiveArray<T>) -> RunEndEncodedArray<T> {
+    let data_type = RunEndEncodedArray::<T>::default_data_type(array.data_type().clone());
+    let mut encoded = MutableRunEndEncodedArray::<T>::with_capacity_from(0, data_type);
+    for value in array.iter() {
+        encoded.push(value.copied());
+    }
+    encoded.into()
+}
+
+/// Returns the [`PrimitiveArray`] of the slots of `array`, i.e. the inverse of [`ree_encode`].
+/// # Implementation
+/// This operation is `O(N)` where `N` is the length of `array`.
+pub fn ree_decode<T: NativeType>(array: &RunEndEncodedArray<T>) -> PrimitiveArray<T> {
+    let values = array.values();
+
+    let mut decoded = Vec::<T>::with_capacity(array.len());
+    let mut validity = (values.null_count() > 0).then(|| MutableBitmap::with_capacity(array.len()));
+    for (index, length) in array.runs(0, array.len()) {
+        decoded.extend(std::iter::repeat(values.value(index)).take(length));
+        if let Some(validity) = validity.as_mut() {
+            validity.extend_constant(length, values.is_valid(index));
+        }
+    }
+
+    PrimitiveArray::new(
+        values.data_type().clone(),
+        decoded.into(),
+        validity.map(|x| x.into()),
+    )
+}
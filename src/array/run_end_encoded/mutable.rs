@@ -0,0 +1,229 @@
+use std::{iter::FromIterator, sync::Arc};
+
+use crate::{
+    array::{Array, MutableArray, MutablePrimitiveArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::Error,
+    types::NativeType,
+};
+
+use super::RunEndEncodedArray;
+
+/// The mutable version of [`RunEndEncodedArray`]: pushing a slot equal to the last one
+/// extends the last run instead of adding a new one.
+/// Converting a [`MutableRunEndEncodedArray`] into a [`RunEndEncodedArray`] is `O(1)`.
+#[derive(Debug, Clone)]
+pub struct MutableRunEndEncodedArray<T: NativeType> {
+    data_type: DataType,
+    // Invariant: `run_ends` is strictly increasing and its first run end is positive
+    // Invariant: `run_ends.len() == values.len()`
+    run_ends: Vec<i32>,
+    values: MutablePrimitiveArray<T>,
+}
+
+impl<T: NativeType> From<MutableRunEndEncodedArray<T>> for RunEndEncodedArray<T> {
+    fn from(other: MutableRunEndEncodedArray<T>) -> Self {
+        RunEndEncodedArray::new(other.data_type, other.run_ends.into(), other.values.into())
+    }
+}
+
+impl<T: NativeType> Default for MutableRunEndEncodedArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: NativeType> MutableRunEndEncodedArray<T> {
+    /// Creates a new empty [`MutableRunEndEncodedArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new [`MutableRunEndEncodedArray`] with a capacity of `capacity` runs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_from(
+            capacity,
+            RunEndEncodedArray::<T>::default_data_type(T::PRIMITIVE.into()),
+        )
+    }
+
+    /// Creates a new [`MutableRunEndEncodedArray`] of [`DataType`] `data_type` with a capacity
+    /// of `capacity` runs.
+    /// # Panics
+    /// This function panics iff `data_type`'s logical type is not [`DataType::RunEndEncoded`].
+    pub fn with_capacity_from(capacity: usize, data_type: DataType) -> Self {
+        let values = RunEndEncodedArray::<T>::get_fields(&data_type)
+            .1
+            .data_type()
+            .clone();
+        Self {
+            data_type,
+            run_ends: Vec::with_capacity(capacity),
+            values: MutablePrimitiveArray::with_capacity_from(capacity, values),
+        }
+    }
+
+    /// The run ends of this array.
+    pub fn run_ends(&self) -> &[i32] {
+        &self.run_ends
+    }
+
+    /// The values of the runs of this array.
+    pub fn values(&self) -> &MutablePrimitiveArray<T> {
+        &self.values
+    }
+
+    /// Returns the length of this array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.run_ends.last().map(|x| *x as usize).unwrap_or(0)
+    }
+
+    /// Returns whether this array is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.run_ends.is_empty()
+    }
+
+    /// Returns whether `value` equals the value of the last run.
+    fn equals_last(&self, value: Option<T>) -> bool {
+        let last = match self.values.len().checked_sub(1) {
+            Some(last) => last,
+            None => return false,
+        };
+        let is_valid = self.values.is_valid(last);
+        match value {
+            Some(value) => is_valid && self.values.values()[last] == value,
+            None => !is_valid,
+        }
+    }
+
+    /// Tries to extend this array with `additional` slots of `value`, extending the last run
+    /// iff its value equals `value`.
+    /// # Errors
+    /// Errors iff the length of this array would exceed `i32::MAX`.
+    pub fn try_extend_constant(
+        &mut self,
+        additional: usize,
+        value: Option<T>,
+    ) -> Result<(), Error> {
+        if additional == 0 {
+            return Ok(());
+        }
+        let run_end = self
+            .len()
+            .checked_add(additional)
+            .and_then(|x| i32::try_from(x).ok())
+            .ok_or(Error::Overflow)?;
+
+        if self.equals_last(value) {
+            *self.run_ends.last_mut().unwrap() = run_end;
+        } else {
+            self.run_ends.push(run_end);
+            self.values.push(value);
+        }
+        Ok(())
+    }
+
+    /// Extends this array with `additional` slots of `value`.
+    /// # Panics
+    /// This function panics iff the length of this array would exceed `i32::MAX`.
+    pub fn extend_constant(&mut self, additional: usize, value: Option<T>) {
+        self.try_extend_constant(additional, value).unwrap()
+    }
+
+    /// Pushes a new slot to this array.
+    /// # Panics
+    /// This function panics iff the length of this array would exceed `i32::MAX`.
+    #[inline]
+    pub fn push(&mut self, value: Option<T>) {
+        self.extend_constant(1, value)
+    }
+
+    /// Reserves `additional` runs.
+    pub fn reserve(&mut self, additional: usize) {
+        self.run_ends.reserve(additional);
+        self.values.reserve(additional);
+    }
+
+    /// Shrinks the capacity of this array to fit its runs.
+    pub fn shrink_to_fit(&mut self) {
+        self.run_ends.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+
+    /// Converts itself into an [`Array`], leaving an empty array of the same [`DataType`].
+    fn take_into(&mut self) -> RunEndEncodedArray<T> {
+        let empty = Self::with_capacity_from(0, self.data_type.clone());
+        std::mem::replace(self, empty).into()
+    }
+}
+
+impl<T: NativeType> MutableArray for MutableRunEndEncodedArray<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_into().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_into().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.push(None)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+impl<T: NativeType> TryPush<Option<T>> for MutableRunEndEncodedArray<T> {
+    fn try_push(&mut self, item: Option<T>) -> Result<(), Error> {
+        self.try_extend_constant(1, item)
+    }
+}
+
+impl<T: NativeType> TryExtend<Option<T>> for MutableRunEndEncodedArray<T> {
+    fn try_extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) -> Result<(), Error> {
+        iter.into_iter().try_for_each(|x| self.try_push(x))
+    }
+}
+
+impl<T: NativeType> Extend<Option<T>> for MutableRunEndEncodedArray<T> {
+    fn extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push(x))
+    }
+}
+
+impl<T: NativeType> FromIterator<Option<T>> for MutableRunEndEncodedArray<T> {
+    fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
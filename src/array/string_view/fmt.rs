@@ -0,0 +1,17 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use super::super::fmt::write_vec;
+use super::StringViewArray;
+
+pub fn write_value<W: Write>(array: &StringViewArray, index: usize, f: &mut W) -> Result {
+    write!(f, "{}", array.value(index))
+}
+
+impl Debug for StringViewArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, f);
+
+        write!(f, "StringViewArray")?;
+        write_vec(f, writer, self.validity(), self.len(), "None", false)
+    }
+}
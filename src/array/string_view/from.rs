@@ -0,0 +1,61 @@
+use std::iter::FromIterator;
+
+use crate::{
+    array::{binary_view::ViewsBuilder, BinaryArray, BinaryViewArray, Utf8Array},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+};
+
+use super::StringViewArray;
+
+impl<P: AsRef<str>> FromIterator<Option<P>> for StringViewArray {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Option<P>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut builder = ViewsBuilder::with_capacity(lower);
+        let mut validity = MutableBitmap::with_capacity(lower);
+        for item in iter {
+            match item {
+                Some(value) => {
+                    builder.push(value.as_ref().as_bytes());
+                    validity.push(true);
+                }
+                None => {
+                    builder.push_null();
+                    validity.push(false);
+                }
+            }
+        }
+        let (views, buffers) = builder.into_inner();
+        // soundness: the builder only creates valid views, of `&str`
+        unsafe { Self::new_unchecked(Self::default_data_type(), views, buffers, validity.into()) }
+    }
+}
+
+impl From<Utf8Array<i32>> for StringViewArray {
+    /// Converts a [`Utf8Array`] into a [`StringViewArray`] whose single data buffer is the
+    /// values of `array`, so that only the views are allocated.
+    fn from(array: Utf8Array<i32>) -> Self {
+        let binary = BinaryArray::<i32>::new(
+            DataType::Binary,
+            array.offsets().clone(),
+            array.values().clone(),
+            array.validity().cloned(),
+        );
+        let binary: BinaryViewArray = binary.into();
+        let (_, views, buffers, validity) = binary.into_inner();
+        // soundness: the values of `array` are valid utf8
+        unsafe { Self::new_unchecked(DataType::Utf8View, views, buffers, validity) }
+    }
+}
+
+impl From<StringViewArray> for Utf8Array<i32> {
+    /// Converts a [`StringViewArray`] into a [`Utf8Array`], copying its values.
+    /// # Panics
+    /// This function panics iff the sum of the lengths of the values exceeds `i32::MAX`.
+    fn from(array: StringViewArray) -> Self {
+        Utf8Array::<i32>::from_trusted_len_values_iter(array.values_iter())
+            .with_validity(array.validity().cloned())
+    }
+}
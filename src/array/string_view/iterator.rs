@@ -0,0 +1,32 @@
+use crate::{
+    array::{ArrayAccessor, ArrayValuesIter},
+    bitmap::utils::{BitmapIter, ZipValidity},
+};
+
+use super::StringViewArray;
+
+unsafe impl<'a> ArrayAccessor<'a> for StringViewArray {
+    type Item = &'a str;
+
+    #[inline]
+    unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
+        self.value_unchecked(index)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Iterator of values of a [`StringViewArray`].
+pub type StringViewValueIter<'a> = ArrayValuesIter<'a, StringViewArray>;
+
+impl<'a> IntoIterator for &'a StringViewArray {
+    type Item = Option<&'a str>;
+    type IntoIter = ZipValidity<&'a str, StringViewValueIter<'a>, BitmapIter<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,399 @@
+use std::sync::Arc;
+
+use crate::{
+    bitmap::{
+        utils::{BitmapIter, ZipValidity},
+        Bitmap,
+    },
+    buffer::Buffer,
+    datatypes::DataType,
+    error::Error,
+};
+
+use super::{
+    binary_view::{try_check_utf8_views, try_check_views, View, ViewsBuilder},
+    Array, BinaryViewArray,
+};
+
+mod ffi;
+pub(super) mod fmt;
+mod from;
+mod iterator;
+pub use iterator::*;
+
+/// A [`StringViewArray`] is Arrow's semantically equivalent of an immutable `Vec<Option<String>>`
+/// whose values are stored as [`View`]s: strings of up to [`View::MAX_INLINE_SIZE`] bytes are
+/// stored inline in their view, while longer strings are stored in shared data buffers.
+/// It implements [`Array`].
+///
+/// Cloning and slicing this struct is `O(1)`, and converting a [`Utf8Array<i32>`] into it only
+/// allocates the views.
+/// # Example
+/// ```
+/// use arrow2::array::StringViewArray;
+///
+/// let array = StringViewArray::from([Some("hello"), None, Some("a longer string")]);
+/// assert_eq!(array.value(0), "hello");
+/// assert_eq!(array.get(1), None);
+/// assert_eq!(array.iter().collect::<Vec<_>>(), vec![Some("hello"), None, Some("a longer string")]);
+/// // the underlying representation:
+/// assert!(array.views()[0].is_inline());
+/// assert!(!array.views()[2].is_inline());
+/// ```
+///
+/// # Safety
+/// The following invariants hold:
+/// * every view that is not inlined references a valid slice of `buffers` whose first 4 bytes are
+///   its prefix.
+/// * the value of every view is valid utf8.
+/// * `len` is equal to `validity.len()`, when defined.
+///
+/// [`Utf8Array<i32>`]: super::Utf8Array
+#[derive(Clone)]
+pub struct StringViewArray {
+    data_type: DataType,
+    views: Buffer<View>,
+    buffers: Arc<[Buffer<u8>]>,
+    validity: Option<Bitmap>,
+}
+
+impl StringViewArray {
+    /// Returns a [`StringViewArray`] created from its internal representation.
+    ///
+    /// # Errors
+    /// This function returns an error iff:
+    /// * a view that is not inlined does not reference a slice of `buffers`, or its prefix is not
+    ///   equal to the first 4 bytes of that slice.
+    /// * the value of a view is not valid utf8.
+    /// * the validity's length is not equal to `views.len()`.
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to `Utf8View`.
+    /// # Implementation
+    /// This function is `O(N)` - checking utf8 is `O(N)`
+    pub fn try_new(
+        data_type: DataType,
+        views: Buffer<View>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, Error> {
+        try_check_views(&views, &buffers)?;
+        // soundness: the views were checked above
+        unsafe { try_check_utf8_views(&views, &buffers)? };
+
+        if validity
+            .as_ref()
+            .map_or(false, |validity| validity.len() != views.len())
+        {
+            return Err(Error::oos(
+                "validity mask length must match the number of values",
+            ));
+        }
+
+        if data_type.to_physical_type() != Self::default_data_type().to_physical_type() {
+            return Err(Error::oos(
+                "StringViewArray can only be initialized with DataType::Utf8View",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            views,
+            buffers,
+            validity,
+        })
+    }
+
+    /// Alias for unwrapping [`Self::try_new`]
+    pub fn new(
+        data_type: DataType,
+        views: Buffer<View>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self::try_new(data_type, views, buffers, validity).unwrap()
+    }
+
+    /// Creates a new [`StringViewArray`] without checking its views nor utf8-validity.
+    /// # Panics
+    /// This function panics iff:
+    /// * the validity's length is not equal to `views.len()`.
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to `Utf8View`.
+    /// # Safety
+    /// This function is unsound iff:
+    /// * a view that is not inlined does not reference a slice of `buffers` whose first 4 bytes
+    ///   are its prefix.
+    /// * the value of a view is not valid utf8.
+    pub unsafe fn new_unchecked(
+        data_type: DataType,
+        views: Buffer<View>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        assert!(
+            validity
+                .as_ref()
+                .map_or(true, |validity| validity.len() == views.len()),
+            "validity mask length must match the number of values"
+        );
+        assert_eq!(
+            data_type.to_physical_type(),
+            Self::default_data_type().to_physical_type(),
+            "StringViewArray can only be initialized with DataType::Utf8View"
+        );
+        Self {
+            data_type,
+            views,
+            buffers,
+            validity,
+        }
+    }
+
+    /// Returns the default [`DataType`], `DataType::Utf8View`
+    pub fn default_data_type() -> DataType {
+        DataType::Utf8View
+    }
+
+    /// Creates an empty [`StringViewArray`], i.e. whose `.len` is zero.
+    pub fn new_empty(data_type: DataType) -> Self {
+        Self::new(data_type, Buffer::new(), Arc::new([]), None)
+    }
+
+    /// Creates a null [`StringViewArray`], i.e. whose `.null_count() == .len()`.
+    #[inline]
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        Self::new(
+            data_type,
+            vec![View::default(); length].into(),
+            Arc::new([]),
+            Some(Bitmap::new_zeroed(length)),
+        )
+    }
+
+    /// Creates a new [`StringViewArray`] from a slice of `&str`.
+    pub fn from_slice<T: AsRef<str>, P: AsRef<[T]>>(slice: P) -> Self {
+        Self::from_iter_values(slice.as_ref().iter())
+    }
+
+    /// Creates a new [`StringViewArray`] from a slice of optional `&str`.
+    // Note: this can't be `impl From` because Rust does not allow double `AsRef` on it.
+    pub fn from<T: AsRef<str>, P: AsRef<[Option<T>]>>(slice: P) -> Self {
+        slice.as_ref().iter().map(|x| x.as_ref()).collect()
+    }
+
+    /// Creates a new [`StringViewArray`] from an [`Iterator`] of `&str`.
+    ///
+    /// The [`StringViewArray`] is guaranteed to not have a validity
+    pub fn from_iter_values<T: AsRef<str>, I: Iterator<Item = T>>(iterator: I) -> Self {
+        let mut builder = ViewsBuilder::with_capacity(iterator.size_hint().0);
+        iterator.for_each(|value| builder.push(value.as_ref().as_bytes()));
+        let (views, buffers) = builder.into_inner();
+        // soundness: the builder only creates valid views, of `&str`
+        unsafe { Self::new_unchecked(Self::default_data_type(), views, buffers, None) }
+    }
+
+    /// Returns an iterator of `Option<&str>` over every element of this array.
+    pub fn iter(&self) -> ZipValidity<&str, StringViewValueIter<'_>, BitmapIter<'_>> {
+        ZipValidity::new_with_validity(self.values_iter(), self.validity.as_ref())
+    }
+
+    /// Returns an iterator of `&str` over every element of this array, ignoring the validity
+    pub fn values_iter(&self) -> StringViewValueIter<'_> {
+        StringViewValueIter::new(self)
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns the value of the element at index `i`, ignoring the array's validity.
+    /// # Panic
+    /// This function panics iff `i >= self.len`.
+    #[inline]
+    pub fn value(&self, i: usize) -> &str {
+        assert!(i < self.len());
+        unsafe { self.value_unchecked(i) }
+    }
+
+    /// Returns the value of the element at index `i`, ignoring the array's validity.
+    /// # Safety
+    /// This function is safe iff `i < self.len`.
+    #[inline]
+    pub unsafe fn value_unchecked(&self, i: usize) -> &str {
+        // soundness: the invariant of the function and of the struct
+        let bytes = self.views.get_unchecked(i).get_unchecked(&self.buffers);
+
+        // soundness: the invariant of the struct
+        std::str::from_utf8_unchecked(bytes)
+    }
+
+    /// Returns the element at index `i` or `None` if it is null
+    /// # Panics
+    /// iff `i >= self.len()`
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&str> {
+        if self.is_null(i) {
+            None
+        } else {
+            Some(self.value(i))
+        }
+    }
+
+    /// Returns the [`DataType`] of this array.
+    #[inline]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the views of this [`StringViewArray`].
+    #[inline]
+    pub fn views(&self) -> &Buffer<View> {
+        &self.views
+    }
+
+    /// Returns the data buffers referenced by the views of this [`StringViewArray`].
+    #[inline]
+    pub fn data_buffers(&self) -> &Arc<[Buffer<u8>]> {
+        &self.buffers
+    }
+
+    /// The optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Returns a slice of this [`StringViewArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new Buffer cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a slice of this [`StringViewArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|bitmap| bitmap.slice_unchecked(offset, length))
+            .and_then(|bitmap| (bitmap.unset_bits() > 0).then(|| bitmap));
+        Self {
+            data_type: self.data_type.clone(),
+            views: self.views.clone().slice_unchecked(offset, length),
+            buffers: self.buffers.clone(),
+            validity,
+        }
+    }
+
+    /// Boxes self into a [`Box<dyn Array>`].
+    pub fn boxed(self) -> Box<dyn Array> {
+        Box::new(self)
+    }
+
+    /// Boxes self into a [`std::sync::Arc<dyn Array>`].
+    pub fn arced(self) -> std::sync::Arc<dyn Array> {
+        std::sync::Arc::new(self)
+    }
+
+    /// Returns this [`StringViewArray`] with a new validity.
+    /// # Panic
+    /// Panics iff `validity.len() != self.len()`.
+    #[must_use]
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity of this [`StringViewArray`].
+    /// # Panics
+    /// This function panics iff `values.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity must be equal to the array's length")
+        }
+        self.validity = validity;
+    }
+
+    /// Applies a function `f` to the validity of this array.
+    ///
+    /// This is an API to leverage clone-on-write
+    /// # Panics
+    /// This function panics if the function `f` modifies the length of the [`Bitmap`].
+    pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
+        if let Some(validity) = std::mem::take(&mut self.validity) {
+            self.set_validity(Some(f(validity)))
+        }
+    }
+
+    /// Returns its internal representation
+    #[must_use]
+    pub fn into_inner(self) -> (DataType, Buffer<View>, Arc<[Buffer<u8>]>, Option<Bitmap>) {
+        (self.data_type, self.views, self.buffers, self.validity)
+    }
+
+    /// Returns a [`BinaryViewArray`] sharing the views and data buffers of this array.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn to_binary(&self) -> BinaryViewArray {
+        // soundness: the invariant of the struct
+        unsafe {
+            BinaryViewArray::new_unchecked(
+                DataType::BinaryView,
+                self.views.clone(),
+                self.buffers.clone(),
+                self.validity.clone(),
+            )
+        }
+    }
+}
+
+impl Array for StringViewArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.clone().with_validity(validity))
+    }
+    fn to_boxed(&self) -> Box<dyn Array> {
+        Box::new(self.clone())
+    }
+}
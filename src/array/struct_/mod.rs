@@ -254,6 +254,33 @@ impl StructArray {
     pub fn fields(&self) -> &[Field] {
         Self::get_fields(&self.data_type)
     }
+
+    /// Returns the index of the field named `name`, if any.
+    ///
+    /// If several fields are named `name`, the index of the first one is returned.
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields().iter().position(|field| field.name == name)
+    }
+
+    /// Returns the values of the field named `name`, if any.
+    ///
+    /// If several fields are named `name`, the values of the first one are returned.
+    /// # Example
+    /// ```
+    /// use arrow2::array::*;
+    /// use arrow2::datatypes::*;
+    ///
+    /// let int = Int32Array::from_slice([42, 28]).boxed();
+    /// let fields = vec![Field::new("c", DataType::Int32, false)];
+    /// let array = StructArray::new(DataType::Struct(fields), vec![int.clone()], None);
+    ///
+    /// assert_eq!(array.column_by_name("c"), Some(int.as_ref()));
+    /// assert!(array.column_by_name("d").is_none());
+    /// ```
+    pub fn column_by_name(&self, name: &str) -> Option<&dyn Array> {
+        self.field_index(name)
+            .map(|index| self.values[index].as_ref())
+    }
 }
 
 impl StructArray {
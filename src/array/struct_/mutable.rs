@@ -127,9 +127,9 @@ impl MutableStructArray {
         &self.values
     }
 
-    /// Return the `i`th child array.
+    /// Return the `i`th child array, or `None` if it does not exist or is not of type `A`.
     pub fn value<A: MutableArray + 'static>(&mut self, i: usize) -> Option<&mut A> {
-        self.values[i].as_mut_any().downcast_mut::<A>()
+        self.values.get_mut(i)?.as_mut_any().downcast_mut::<A>()
     }
 }
 
@@ -144,7 +144,7 @@ impl MutableStructArray {
         }
     }
 
-    /// Call this once for each "row" of children you push.
+    /// Call this once for each "row" of children you push, after pushing a value to each child.
     pub fn push(&mut self, valid: bool) {
         match &mut self.validity {
             Some(validity) => validity.push(valid),
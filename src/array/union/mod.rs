@@ -11,6 +11,8 @@ use super::{new_empty_array, new_null_array, Array};
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+mod mutable;
+pub use mutable::*;
 
 type UnionComponents<'a> = (&'a [Field], Option<&'a [i32]>, UnionMode);
 
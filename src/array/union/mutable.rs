@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`]. Every value is pushed to the child of its type id,
+/// while the types and the offsets (dense mode) or the nulls of the other children
+/// (sparse mode) are maintained by this array.
+/// Converting a [`MutableUnionArray`] into a [`UnionArray`] is `O(1)`.
+/// # Example
+/// ```
+/// use arrow2::array::{MutablePrimitiveArray, MutableUnionArray, MutableUtf8Array, UnionArray};
+/// use arrow2::datatypes::{DataType, Field, UnionMode};
+///
+/// let fields = vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let data_type = DataType::Union(fields, None, UnionMode::Dense);
+/// let mut array = MutableUnionArray::try_new(
+///     data_type,
+///     vec![
+///         Box::new(MutablePrimitiveArray::<i32>::new()),
+///         Box::new(MutableUtf8Array::<i32>::new()),
+///     ],
+/// )
+/// .unwrap();
+/// array.try_push::<MutablePrimitiveArray<i32>, _>(0, Some(1)).unwrap();
+/// array.try_push::<MutableUtf8Array<i32>, _>(1, Some("a")).unwrap();
+///
+/// let array: UnionArray = array.into();
+/// assert_eq!(format!("{:?}", array), "UnionArray[1, a]");
+/// ```
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    fields: Vec<Box<dyn MutableArray>>,
+    // invariant: `Some` iff the union is dense
+    offsets: Option<Vec<i32>>,
+}
+
+impl MutableUnionArray {
+    /// Creates a new [`MutableUnionArray`] of [`DataType::Union`] from empty `fields`.
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`]
+    /// * the number of `fields` is different from the number of children of `data_type`,
+    ///   or larger than `i8::MAX`
+    /// * any of the fields' data type is different from its corresponding child's data type
+    /// * any of the fields is not empty
+    pub fn try_new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let (children, _, mode) = UnionArray::try_get_all(&data_type)?;
+
+        if children.len() != fields.len() {
+            return Err(Error::oos(
+                "The number of `fields` must equal the number of children fields in DataType::Union",
+            ));
+        }
+        if fields.len() > i8::MAX as usize {
+            return Err(Error::oos(
+                "The number of `fields` cannot be larger than i8::MAX",
+            ));
+        }
+        if children
+            .iter()
+            .zip(fields.iter())
+            .any(|(child, field)| child.data_type() != field.data_type())
+        {
+            return Err(Error::oos(
+                "The children DataTypes of a MutableUnionArray must equal the children data types",
+            ));
+        }
+        if fields.iter().any(|field| !field.is_empty()) {
+            return Err(Error::InvalidArgumentError(
+                "MutableUnionArray must be created from empty fields".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            types: vec![],
+            fields,
+            offsets: (!mode.is_sparse()).then(Vec::new),
+        })
+    }
+
+    /// Returns the index in the fields of `type_id`.
+    fn field_index(&self, type_id: i8) -> Result<usize> {
+        let index = match UnionArray::get_all(&self.data_type).1 {
+            Some(ids) => ids.iter().position(|id| *id == type_id as i32),
+            None => usize::try_from(type_id)
+                .ok()
+                .filter(|index| *index < self.fields.len()),
+        };
+        index.ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "The type id {} is not part of the union's data type {:?}",
+                type_id, self.data_type
+            ))
+        })
+    }
+
+    /// Pushes `value` to the field of `type_id`, which must be of type `A`.
+    /// # Errors
+    /// This function errors iff:
+    /// * `type_id` is not a type id of the union's data type
+    /// * the field of `type_id` is not of type `A`
+    /// * pushing `value` to the field errors, or the offsets overflow
+    pub fn try_push<A, T>(&mut self, type_id: i8, value: T) -> Result<()>
+    where
+        A: MutableArray + TryPush<T> + 'static,
+    {
+        let index = self.field_index(type_id)?;
+        let offset = self.next_offset(index)?;
+        self.fields[index]
+            .as_mut_any()
+            .downcast_mut::<A>()
+            .ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The field of type id {} is not a {}",
+                    type_id,
+                    std::any::type_name::<A>()
+                ))
+            })?
+            .try_push(value)?;
+        self.push_type(type_id, index, offset);
+        Ok(())
+    }
+
+    /// Pushes a null to the field of `type_id`.
+    /// # Errors
+    /// This function errors iff `type_id` is not a type id of the union's data type or the
+    /// offsets overflow.
+    pub fn try_push_null(&mut self, type_id: i8) -> Result<()> {
+        let index = self.field_index(type_id)?;
+        let offset = self.next_offset(index)?;
+        self.fields[index].push_null();
+        self.push_type(type_id, index, offset);
+        Ok(())
+    }
+
+    /// The offset of the next value of the field `index`, in dense mode.
+    fn next_offset(&self, index: usize) -> Result<Option<i32>> {
+        self.offsets
+            .as_ref()
+            .map(|_| i32::try_from(self.fields[index].len()).map_err(|_| Error::Overflow))
+            .transpose()
+    }
+
+    /// Records a value pushed to the field `index` of `type_id`.
+    fn push_type(&mut self, type_id: i8, index: usize, offset: Option<i32>) {
+        self.types.push(type_id);
+        match (&mut self.offsets, offset) {
+            (Some(offsets), Some(offset)) => offsets.push(offset),
+            _ => self
+                .fields
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .for_each(|(_, field)| field.push_null()),
+        }
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// The types
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The offsets, set iff the union is dense
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    /// The fields
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        if let Some(offsets) = self.offsets.as_mut() {
+            offsets.reserve(additional)
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.reserve(additional))
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        if let Some(offsets) = self.offsets.as_mut() {
+            offsets.shrink_to_fit()
+        }
+        self.fields
+            .iter_mut()
+            .for_each(|field| field.shrink_to_fit());
+    }
+
+    fn take_into(&mut self) -> UnionArray {
+        UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            self.fields.iter_mut().map(|field| field.as_box()).collect(),
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        )
+    }
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(mut other: MutableUnionArray) -> Self {
+        other.take_into()
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_into().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_into().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Pushes a null to the first field of the union.
+    /// # Panics
+    /// This function panics iff the union has no fields or the offsets overflow.
+    fn push_null(&mut self) {
+        let type_id = match UnionArray::get_all(&self.data_type).1 {
+            Some(ids) => ids[0] as i8,
+            None => 0,
+        };
+        self.try_push_null(type_id).unwrap()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
@@ -14,7 +14,7 @@ use either::Either;
 
 use super::{
     specification::{try_check_offsets_bounds, try_check_utf8},
-    Array, GenericBinaryArray,
+    Array, GenericBinaryArray, PrimitiveArray,
 };
 
 mod ffi;
@@ -195,6 +195,28 @@ impl<O: Offset> Utf8Array<O> {
         self.validity.as_ref()
     }
 
+    /// Returns the number of bytes of each string of this array.
+    /// Null strings have a null length.
+    pub fn lengths(&self) -> PrimitiveArray<O> {
+        let values = self
+            .offsets
+            .buffer()
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(O::PRIMITIVE.into(), values.into(), self.validity.clone())
+    }
+
+    /// Returns the number of characters (unicode scalar values) of each string of this array.
+    /// Null strings have a null length.
+    pub fn char_lengths(&self) -> PrimitiveArray<O> {
+        let values = self
+            .values_iter()
+            .map(|x| O::from_as_usize(x.chars().count()))
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(O::PRIMITIVE.into(), values.into(), self.validity.clone())
+    }
+
     /// Returns a slice of this [`Utf8Array`].
     /// # Implementation
     /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
@@ -260,6 +282,17 @@ impl<O: Offset> Utf8Array<O> {
         self.validity = validity;
     }
 
+    /// Applies a function `f` to the validity of this array.
+    ///
+    /// This is an API to leverage clone-on-write
+    /// # Panics
+    /// This function panics if the function `f` modifies the length of the [`Bitmap`].
+    pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
+        if let Some(validity) = std::mem::take(&mut self.validity) {
+            self.set_validity(Some(f(validity)))
+        }
+    }
+
     /// Try to convert this `Utf8Array` to a `MutableUtf8Array`
     pub fn into_mut(mut self) -> Either<Self, MutableUtf8Array<O>> {
         use Either::*;
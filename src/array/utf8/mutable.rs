@@ -510,7 +510,7 @@ impl<O: Offset> PartialEq for MutableUtf8Array<O> {
 
 impl<O: Offset> TryExtendFromSelf for MutableUtf8Array<O> {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<()> {
-        extend_validity(self.len(), &mut self.validity, &other.validity);
+        extend_validity(self.len(), &mut self.validity, other.len(), &other.validity);
 
         self.values.try_extend_from_self(&other.values)
     }
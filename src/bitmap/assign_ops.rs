@@ -167,6 +167,32 @@ impl<'a> std::ops::BitAnd<&'a Bitmap> for MutableBitmap {
     }
 }
 
+#[inline]
+/// Compute bitwise `& !` between `lhs` and `rhs`, assigning it to `lhs`
+fn and_not_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
+    if rhs.unset_bits() == rhs.len() {
+        assert_eq!(lhs.len(), rhs.len());
+        // bitmap remains
+    } else if rhs.unset_bits() == 0 {
+        assert_eq!(lhs.len(), rhs.len());
+        lhs.clear();
+        lhs.extend_constant(rhs.len(), false);
+    } else {
+        binary_assign(lhs, rhs, |x: T, y| x & !y)
+    }
+}
+
+impl MutableBitmap {
+    /// Unsets the bits of this [`MutableBitmap`] that are set in `other`, i.e. computes
+    /// `self & !other` in-place.
+    /// # Panics
+    /// This function panics iff `self.len() != other.len()`
+    #[inline]
+    pub fn and_not_with(&mut self, other: &Bitmap) {
+        and_not_assign::<u64>(self, other)
+    }
+}
+
 #[inline]
 /// Compute bitwise XOR operation
 fn xor_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
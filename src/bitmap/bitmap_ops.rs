@@ -2,9 +2,10 @@ use crate::bitmap::MutableBitmap;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
 use crate::trusted_len::TrustedLen;
+use crate::types::Index;
 
 use super::{
-    utils::{BitChunk, BitChunkIterExact, BitChunksExact},
+    utils::{BitChunk, BitChunkIterExact},
     Bitmap,
 };
 
@@ -100,12 +101,84 @@ where
     Bitmap::from_u8_vec(buffer, length)
 }
 
+/// Applies `op` to the bytes of `lhs` and `rhs` as `u64`s, writing the result to `out`.
+/// The loop has no dependencies between iterations so that it is auto-vectorized.
+#[inline(always)]
+fn binary_bytes_impl<F>(lhs: &[u8], rhs: &[u8], out: &mut [u8], op: F)
+where
+    F: Fn(u64, u64) -> u64,
+{
+    let mut lhs_chunks = lhs.chunks_exact(8);
+    let mut rhs_chunks = rhs.chunks_exact(8);
+    let mut out_chunks = out.chunks_exact_mut(8);
+    out_chunks
+        .by_ref()
+        .zip(lhs_chunks.by_ref())
+        .zip(rhs_chunks.by_ref())
+        .for_each(|((out, lhs), rhs)| {
+            let lhs = u64::from_ne_bytes(lhs.try_into().unwrap());
+            let rhs = u64::from_ne_bytes(rhs.try_into().unwrap());
+            out.copy_from_slice(&op(lhs, rhs).to_ne_bytes());
+        });
+
+    let out = out_chunks.into_remainder();
+    if !out.is_empty() {
+        let mut lhs = [0u8; 8];
+        let mut rhs = [0u8; 8];
+        lhs[..out.len()].copy_from_slice(lhs_chunks.remainder());
+        rhs[..out.len()].copy_from_slice(rhs_chunks.remainder());
+        let result = op(u64::from_ne_bytes(lhs), u64::from_ne_bytes(rhs)).to_ne_bytes();
+        out.copy_from_slice(&result[..out.len()]);
+    }
+}
+
+/// [`binary_bytes_impl`] compiled with AVX2, so that it is vectorized over 256 bits.
+/// # Safety
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn binary_bytes_avx2<F>(lhs: &[u8], rhs: &[u8], out: &mut [u8], op: F)
+where
+    F: Fn(u64, u64) -> u64,
+{
+    binary_bytes_impl(lhs, rhs, out, op)
+}
+
+/// Applies `op` to the bytes of `lhs` and `rhs` (of equal length), using AVX2 when the CPU
+/// supports it. Other targets (e.g. aarch64, where NEON is always available) are vectorized
+/// by the compiler on their default features.
+fn binary_bytes<F>(lhs: &[u8], rhs: &[u8], op: F) -> Vec<u8>
+where
+    F: Fn(u64, u64) -> u64,
+{
+    debug_assert_eq!(lhs.len(), rhs.len());
+    let mut out = vec![0; lhs.len()];
+
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        // Safety: AVX2 is supported
+        unsafe { binary_bytes_avx2(lhs, rhs, &mut out, op) };
+        return out;
+    }
+
+    binary_bytes_impl(lhs, rhs, &mut out, op);
+    out
+}
+
 /// Apply a bitwise operation `op` to two inputs and return the result as a [`Bitmap`].
 pub fn binary<F>(lhs: &Bitmap, rhs: &Bitmap, op: F) -> Bitmap
 where
     F: Fn(u64, u64) -> u64,
 {
     assert_eq!(lhs.len(), rhs.len());
+    let (lhs_slice, lhs_offset, length) = lhs.as_slice();
+    let (rhs_slice, rhs_offset, _) = rhs.as_slice();
+    if lhs_offset == 0 && rhs_offset == 0 {
+        // both are byte-aligned: operate on the bytes directly
+        let buffer = binary_bytes(lhs_slice, rhs_slice, op);
+        return Bitmap::from_u8_vec(buffer, length);
+    }
+
     let lhs_chunks = lhs.chunks();
     let rhs_chunks = rhs.chunks();
     let rem_lhs = lhs_chunks.remainder();
@@ -143,8 +216,9 @@ where
 {
     let (slice, offset, length) = lhs.as_slice();
     if offset == 0 {
-        let iter = BitChunksExact::<u64>::new(slice, length);
-        unary_impl(iter, op, lhs.len())
+        // the unary operation is applied as a binary one so that it uses the same vectorized path
+        let buffer = binary_bytes(slice, slice, |x, _| op(x));
+        Bitmap::from_u8_vec(buffer, length)
     } else {
         let iter = lhs.chunks::<u64>();
         unary_impl(iter, op, lhs.len())
@@ -163,6 +237,18 @@ pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     bitmap.slice(new_offset, length)
 }
 
+/// Returns the validity of the slots `indices` of an array whose validity is `validity`
+/// (see [`Bitmap::take`]).
+///
+/// Returns `None` when `validity` is `None` or has no unset bits, since every taken slot
+/// is then valid. In this case, `indices` are not bounds-checked.
+#[inline]
+pub fn take_validity<I: Index>(validity: Option<&Bitmap>, indices: &[I]) -> Option<Bitmap> {
+    validity
+        .filter(|validity| validity.unset_bits() > 0)
+        .map(|validity| validity.take(indices))
+}
+
 #[inline]
 /// Compute bitwise AND operation
 pub fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
@@ -211,6 +297,22 @@ pub fn xor(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     }
 }
 
+#[inline]
+/// Compute bitwise AND NOT operation, `lhs & !rhs`: the bits set in `lhs` and not in `rhs`.
+///
+/// Unlike `and(lhs, &!rhs)`, this does not allocate a temporary [`Bitmap`].
+pub fn and_not(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
+    if lhs.unset_bits() == lhs.len() || rhs.unset_bits() == 0 {
+        assert_eq!(lhs.len(), rhs.len());
+        Bitmap::new_zeroed(lhs.len())
+    } else if rhs.unset_bits() == rhs.len() {
+        assert_eq!(lhs.len(), rhs.len());
+        lhs.clone()
+    } else {
+        binary(lhs, rhs, |x, y| x & !y)
+    }
+}
+
 fn eq(lhs: &Bitmap, rhs: &Bitmap) -> bool {
     if lhs.len() != rhs.len() {
         return false;
@@ -2,7 +2,7 @@ use std::{iter::FromIterator, ops::Deref, sync::Arc};
 
 use either::Either;
 
-use crate::{buffer::Bytes, error::Error, trusted_len::TrustedLen};
+use crate::{buffer::Bytes, error::Error, trusted_len::TrustedLen, types::Index};
 
 use super::{
     chunk_iter_to_vec,
@@ -167,6 +167,10 @@ impl Bitmap {
     }
 
     /// Slices `self`, offsetting by `offset` and truncating up to `length` bits.
+    ///
+    /// This is a zero-copy operation for any `offset`, including offsets that are not a
+    /// multiple of 8: the returned [`Bitmap`] shares the bytes of `self` and only its
+    /// bit offset and length change. Its number of unset bits is recomputed in `O(length)`.
     /// # Panic
     /// Panics iff `offset + length > self.length`, i.e. if the offset and `length`
     /// exceeds the allocated capacity of `self`.
@@ -179,7 +183,7 @@ impl Bitmap {
 
     /// Slices `self`, offseting by `offset` and truncating up to `length` bits.
     /// # Safety
-    /// The caller must ensure that `self.offset + offset + length <= self.len()`
+    /// The caller must ensure that `offset + length <= self.len()`
     #[inline]
     pub unsafe fn slice_unchecked(mut self, offset: usize, length: usize) -> Self {
         // count the smallest chunk
@@ -214,6 +218,26 @@ impl Bitmap {
         get_bit_unchecked(&self.bytes, self.offset + i)
     }
 
+    /// Returns a new [`Bitmap`] with the bits of `self` at each of the `indices`, i.e.
+    /// whose bit `i` is the bit `indices[i]` of `self`.
+    /// # Panics
+    /// Panics iff any of the `indices` is `>= self.len()`.
+    pub fn take<I: Index>(&self, indices: &[I]) -> Self {
+        let length = self.length;
+        let iter = indices.iter().map(|index| {
+            let index = index.to_usize();
+            assert!(
+                index < length,
+                "index {} is out of bounds of a bitmap of length {}",
+                index,
+                length
+            );
+            // Safety: checked above
+            unsafe { self.get_bit_unchecked(index) }
+        });
+        MutableBitmap::from_trusted_len_iter(iter).into()
+    }
+
     /// Returns a pointer to the start of this [`Bitmap`] (ignores `offsets`)
     /// This pointer is allocated iff `self.len() > 0`.
     pub(crate) fn as_ptr(&self) -> *const u8 {
@@ -239,7 +263,9 @@ impl Bitmap {
             Arc::get_mut(&mut self.bytes).and_then(|b| b.get_vec()),
         ) {
             (0, Some(v)) => {
-                let data = std::mem::take(v);
+                let mut data = std::mem::take(v);
+                // drop the bytes beyond the length of a bitmap sliced without an offset
+                data.truncate(self.length.saturating_add(7) / 8);
                 Either::Right(MutableBitmap::from_vec(data, self.length))
             }
             _ => Either::Left(self),
@@ -294,6 +320,35 @@ impl Bitmap {
         Bitmap::try_new(vec, length).unwrap()
     }
 
+    /// Creates a new [`Bitmap`] of `length` bits where only the bits at `indices` are set.
+    ///
+    /// This function is `O(length / 8 + indices)` and does not allocate an intermediate
+    /// `Vec<bool>`, which makes it suitable to build sparse bitmaps.
+    /// # Panics
+    /// This function panics iff `indices` are not sorted in ascending order or an index is not
+    /// smaller than `length`.
+    pub fn from_sorted_indices<I: IntoIterator<Item = usize>>(length: usize, indices: I) -> Self {
+        let mut bytes = vec![0u8; length.saturating_add(7) / 8];
+        let mut previous = 0;
+        for index in indices {
+            assert!(
+                index >= previous,
+                "indices must be sorted, but {} is after {}",
+                index,
+                previous
+            );
+            assert!(
+                index < length,
+                "index {} is out of bounds for a bitmap of length {}",
+                index,
+                length
+            );
+            bytes[index / 8] |= 1 << (index % 8);
+            previous = index;
+        }
+        Self::from_u8_vec(bytes, length)
+    }
+
     /// Returns whether the bit at position `i` is set.
     #[inline]
     pub fn get(&self, i: usize) -> Option<bool> {
@@ -6,7 +6,7 @@ use crate::error::Error;
 use crate::trusted_len::TrustedLen;
 
 use super::utils::{
-    count_zeros, fmt, get_bit, set, set_bit, BitChunk, BitChunksExactMut, BitmapIter,
+    count_zeros, fmt, get_bit, set, set_bit, BitChunk, BitChunks, BitChunksExactMut, BitmapIter,
 };
 use super::Bitmap;
 
@@ -151,6 +151,45 @@ impl MutableBitmap {
         set_bit(self.buffer.as_mut_slice(), index, value)
     }
 
+    /// Sets all bits in the range `start..end` to `value`, filling whole bytes at once.
+    fn set_range_value(&mut self, start: usize, end: usize, value: bool) {
+        assert!(
+            start <= end && end <= self.length,
+            "range {}..{} is out of bounds for a bitmap of length {}",
+            start,
+            end,
+            self.length
+        );
+        let bytes = self.buffer.as_mut_slice();
+
+        // bits before the first byte boundary
+        let head_end = end.min(start.div_ceil(8) * 8);
+        (start..head_end).for_each(|index| set_bit(bytes, index, value));
+
+        // whole bytes
+        let bytes_end = head_end.max(end / 8 * 8);
+        bytes[head_end / 8..bytes_end / 8].fill(if value { u8::MAX } else { 0 });
+
+        // bits after the last byte boundary
+        (bytes_end..end).for_each(|index| set_bit(bytes, index, value));
+    }
+
+    /// Sets all bits in the range `start..end`.
+    /// # Panics
+    /// Panics iff `start > end` or `end > self.len()`.
+    #[inline]
+    pub fn set_range(&mut self, start: usize, end: usize) {
+        self.set_range_value(start, end, true)
+    }
+
+    /// Unsets all bits in the range `start..end`.
+    /// # Panics
+    /// Panics iff `start > end` or `end > self.len()`.
+    #[inline]
+    pub fn clear_range(&mut self, start: usize, end: usize) {
+        self.set_range_value(start, end, false)
+    }
+
     /// constructs a new iterator over the bits of [`MutableBitmap`].
     pub fn iter(&self) -> BitmapIter {
         BitmapIter::new(&self.buffer, 0, self.length)
@@ -668,11 +707,33 @@ impl MutableBitmap {
         self.length += length;
     }
 
+    // `self.length % 8 == 0` and `offset % 8 != 0`: shift `slice` in chunks of 64 bits
+    fn extend_from_unaligned_slice(&mut self, slice: &[u8], offset: usize, length: usize) {
+        debug_assert_eq!(self.length % 8, 0); // assumed invariant
+
+        let mut chunks = BitChunks::<u64>::new(slice, offset, length);
+        self.buffer.reserve(length.saturating_add(7) / 8);
+        chunks
+            .by_ref()
+            .for_each(|chunk| self.buffer.extend_from_slice(&chunk.to_ne_bytes()));
+
+        let remainder_len = chunks.remainder_len();
+        if remainder_len > 0 {
+            // the bits of the remainder beyond `remainder_len` are not part of `slice`'s range
+            let remainder = chunks.remainder() & ((1u64 << remainder_len) - 1);
+            let bytes = remainder.to_ne_bytes();
+            self.buffer
+                .extend_from_slice(&bytes[..remainder_len.saturating_add(7) / 8]);
+        }
+        self.length += length;
+    }
+
     /// Extends the [`MutableBitmap`] from a slice of bytes with optional offset.
     /// This is the fastest way to extend a [`MutableBitmap`].
     /// # Implementation
     /// When both [`MutableBitmap`]'s length and `offset` are both multiples of 8,
-    /// this function performs a memcopy. Else, it first aligns bit by bit and then performs a memcopy.
+    /// this function performs a memcopy. Else, it first aligns bit by bit and then copies
+    /// the (shifted) bits in chunks of 64 bits.
     #[inline]
     pub fn extend_from_slice(&mut self, slice: &[u8], offset: usize, length: usize) {
         assert!(offset + length <= slice.len() * 8);
@@ -684,8 +745,15 @@ impl MutableBitmap {
         match (is_aligned, other_is_aligned) {
             (true, true) => self.extend_aligned(slice, offset, length),
             (false, true) => self.extend_unaligned(slice, offset, length),
-            // todo: further optimize the other branches.
-            _ => self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, length)),
+            (true, false) => self.extend_from_unaligned_slice(slice, offset, length),
+            (false, false) => {
+                // align `self` bit by bit, and then extend it with the remaining bits
+                let head = (8 - self.length % 8).min(length);
+                self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, head));
+                if head < length {
+                    self.extend_from_slice(slice, offset + head, length - head);
+                }
+            }
         }
         // internal invariant:
         debug_assert_eq!(self.length.saturating_add(7) / 8, self.buffer.len());
@@ -3,6 +3,7 @@ mod chunk_iterator;
 mod chunks_exact_mut;
 mod fmt;
 mod iterator;
+mod set_positions;
 mod slice_iterator;
 mod zip_validity;
 
@@ -13,6 +14,7 @@ pub use chunk_iterator::{BitChunk, BitChunkIterExact, BitChunks, BitChunksExact}
 pub use chunks_exact_mut::BitChunksExactMut;
 pub use fmt::fmt;
 pub use iterator::BitmapIter;
+pub use set_positions::{set_positions, SetPositions};
 pub use slice_iterator::SlicesIterator;
 pub use zip_validity::{ZipValidity, ZipValidityIter};
 
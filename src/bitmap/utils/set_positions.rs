@@ -0,0 +1,92 @@
+use crate::bitmap::Bitmap;
+use crate::trusted_len::TrustedLen;
+
+use super::BitChunks;
+
+/// Iterator over the positions of the set bits of a [`Bitmap`], in ascending order.
+///
+/// Bits are read 64 at a time and the set bits of each word are found via
+/// [`u64::trailing_zeros`], so that this iterator is `O(len / 64 + k)` where `k` is the
+/// number of set bits. It is useful to convert a mask into indices (e.g. for a `take`).
+#[derive(Debug)]
+pub struct SetPositions<'a> {
+    chunks: BitChunks<'a, u64>,
+    remainder: Option<u64>,
+    // the bits of the current word yet to be yielded
+    word: u64,
+    // the position of the first bit of the current word
+    base: usize,
+    // the position of the first bit of the next word
+    next_base: usize,
+    // the number of set bits yet to be yielded
+    remaining: usize,
+}
+
+impl<'a> SetPositions<'a> {
+    /// Creates a new [`SetPositions`]
+    pub fn new(bitmap: &'a Bitmap) -> Self {
+        let chunks = bitmap.chunks::<u64>();
+        // the bits of the remainder beyond the bitmap's length are undefined
+        let remainder_len = chunks.remainder_len();
+        let remainder = if remainder_len == 0 {
+            0
+        } else {
+            chunks.remainder() & (u64::MAX >> (64 - remainder_len))
+        };
+
+        Self {
+            chunks,
+            remainder: Some(remainder),
+            word: 0,
+            base: 0,
+            next_base: 0,
+            remaining: bitmap.len() - bitmap.unset_bits(),
+        }
+    }
+}
+
+impl Iterator for SetPositions<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            if self.remaining == 0 {
+                return None;
+            }
+            self.word = match self.chunks.next() {
+                Some(word) => word,
+                None => self.remainder.take()?,
+            };
+            self.base = self.next_base;
+            self.next_base += 64;
+        }
+        let position = self.base + self.word.trailing_zeros() as usize;
+        // unset the lowest set bit
+        self.word &= self.word - 1;
+        self.remaining -= 1;
+        Some(position)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for SetPositions<'_> {}
+
+unsafe impl TrustedLen for SetPositions<'_> {}
+
+/// Returns an iterator over the positions of the set bits of `bitmap`, in ascending order.
+/// See [`SetPositions`] for details.
+/// # Example
+/// ```
+/// use arrow2::bitmap::{utils::set_positions, Bitmap};
+///
+/// let bitmap = Bitmap::from([false, true, true, false, true]);
+/// assert_eq!(set_positions(&bitmap).collect::<Vec<_>>(), vec![1, 2, 4]);
+/// ```
+pub fn set_positions(bitmap: &Bitmap) -> SetPositions<'_> {
+    SetPositions::new(bitmap)
+}
@@ -1,7 +1,14 @@
 use std::{iter::FromIterator, ops::Deref, sync::Arc, usize};
 
 use super::Bytes;
+#[cfg(feature = "bytes")]
+use super::BytesAllocator;
 use super::IntoIter;
+#[cfg(feature = "bytes")]
+use crate::{
+    error::{Error, Result},
+    types::NativeType,
+};
 
 /// [`Buffer`] is a contiguous memory region that can be shared across
 /// thread boundaries.
@@ -159,11 +166,17 @@ impl<T> Buffer<T> {
     /// * has not been sliced with an offset
     /// * has not been cloned (i.e. [`Arc`]`::get_mut` yields [`Some`])
     /// * has not been imported from the c data interface (FFI)
+    ///
+    /// Items beyond the length of a [`Buffer`] sliced without an offset are dropped from the
+    /// returned [`Vec`].
     pub fn get_mut(&mut self) -> Option<&mut Vec<T>> {
         if self.offset != 0 {
             None
         } else {
-            Arc::get_mut(&mut self.data).and_then(|b| b.get_vec())
+            let length = self.length;
+            let vec = Arc::get_mut(&mut self.data).and_then(|b| b.get_vec())?;
+            vec.truncate(length);
+            Some(vec)
         }
     }
 
@@ -178,6 +191,69 @@ impl<T> Buffer<T> {
     }
 }
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl Buffer<u8> {
+    /// Creates a [`Buffer`] backed by `bytes` without copying it.
+    ///
+    /// The [`Buffer`] (and its clones and slices) keeps `bytes` alive; its memory is released when
+    /// the last of them is dropped. Since the memory is shared, [`Buffer::get_mut`] returns [`None`].
+    /// # Example
+    /// ```
+    /// use arrow2::buffer::Buffer;
+    ///
+    /// let bytes = bytes::Bytes::from_static(b"hello world");
+    /// let buffer = Buffer::from_bytes_zero_copy(bytes.clone());
+    /// assert_eq!(buffer.as_slice().as_ptr(), bytes.as_ptr());
+    /// assert_eq!(buffer.slice(6, 5).as_slice(), b"world");
+    /// ```
+    pub fn from_bytes_zero_copy(bytes: bytes::Bytes) -> Self {
+        // `u8` has no alignment or size requirements
+        Self::try_from_bytes_zero_copy(bytes).unwrap()
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl<T: NativeType> Buffer<T> {
+    /// Creates a [`Buffer`] backed by `bytes` without copying it, reinterpreting its bytes as `T`
+    /// (in the native endianness).
+    ///
+    /// See [`Buffer::from_bytes_zero_copy`] for the ownership semantics.
+    /// # Errors
+    /// This function errors iff:
+    /// * the start of `bytes` is not aligned to the alignment of `T`
+    /// * the length of `bytes` is not a multiple of the size of `T`
+    pub fn try_from_bytes_zero_copy(bytes: bytes::Bytes) -> Result<Self> {
+        let size = std::mem::size_of::<T>();
+        if bytes.len() % size != 0 {
+            return Err(Error::InvalidArgumentError(format!(
+                "The length of the bytes ({}) must be a multiple of the size of the type ({})",
+                bytes.len(),
+                size
+            )));
+        }
+        if bytes.is_empty() {
+            return Ok(Self::new());
+        }
+        let alignment = std::mem::align_of::<T>();
+        if bytes.as_ptr() as usize % alignment != 0 {
+            return Err(Error::InvalidArgumentError(format!(
+                "The bytes must be aligned to {} bytes to be reinterpreted as {}",
+                alignment,
+                std::any::type_name::<T>()
+            )));
+        }
+
+        let length = bytes.len() / size;
+        let ptr = bytes.as_ptr() as *const T;
+        // Safety: `ptr` is aligned and valid for `length` values of `T` (a plain old data type).
+        // `bytes` is immutable and kept alive by the owner of `data`.
+        let data = unsafe { Bytes::from_foreign(ptr, length, BytesAllocator::Bytes(bytes)) };
+        Ok(Self::from_bytes(data))
+    }
+}
+
 impl<T> From<Vec<T>> for Buffer<T> {
     #[inline]
     fn from(p: Vec<T>) -> Self {
@@ -5,7 +5,16 @@ mod iterator;
 
 use crate::ffi::InternalArrowArray;
 
-pub(crate) type Bytes<T> = foreign_vec::ForeignVec<InternalArrowArray, T>;
+/// The owner of the memory of a [`Buffer`] or [`crate::bitmap::Bitmap`] that was not allocated
+/// by a [`Vec`]. It is dropped, releasing the memory, when the last reference to it is dropped.
+#[allow(dead_code)]
+pub(crate) enum BytesAllocator {
+    InternalArrowArray(InternalArrowArray),
+    #[cfg(feature = "bytes")]
+    Bytes(bytes::Bytes),
+}
+
+pub(crate) type Bytes<T> = foreign_vec::ForeignVec<BytesAllocator, T>;
 pub(super) use iterator::IntoIter;
 
 pub use immutable::Buffer;
@@ -3,6 +3,7 @@
 
 use crate::array::Array;
 use crate::error::{Error, Result};
+use crate::scalar::{new_scalar, Scalar};
 
 /// A vector of trait objects of [`Array`] where every item has
 /// the same length, [`Chunk::len`].
@@ -61,6 +62,15 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
         self.len() == 0
     }
 
+    /// Returns an iterator over the rows of this [`Chunk`].
+    /// Each [`Row`] is a view that does not clone the underlying arrays.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Row<'_, A>> + '_ {
+        (0..self.len()).map(move |index| Row {
+            arrays: &self.arrays,
+            index,
+        })
+    }
+
     /// Consumes [`Chunk`] into its underlying arrays.
     /// The arrays are guaranteed to have the same length
     pub fn into_arrays(self) -> Vec<A> {
@@ -68,6 +78,59 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
     }
 }
 
+#[cfg(feature = "compute_concatenate")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_concatenate")))]
+impl Chunk<std::sync::Arc<dyn Array>> {
+    /// Concatenates `chunks` vertically, column by column, with
+    /// [`concatenate`](crate::compute::concatenate::concatenate) (which merges the values of
+    /// dictionary columns). A single chunk is cloned.
+    /// # Error
+    /// Iff `chunks` is empty, the chunks have a different number of columns, the data types
+    /// of a column differ between chunks, or concatenating a column errors.
+    pub fn concatenate(chunks: &[Self]) -> Result<Self> {
+        let (first, rest) = chunks.split_first().ok_or_else(|| {
+            Error::InvalidArgumentError(
+                "Chunk::concatenate requires at least one chunk".to_string(),
+            )
+        })?;
+        if rest.is_empty() {
+            return Ok(first.clone());
+        }
+
+        for chunk in rest {
+            if chunk.arrays.len() != first.arrays.len() {
+                return Err(Error::InvalidArgumentError(format!(
+                    "Chunk::concatenate requires all chunks to have the same number of columns ({} != {})",
+                    first.arrays.len(),
+                    chunk.arrays.len()
+                )));
+            }
+            let mismatch = first
+                .arrays
+                .iter()
+                .zip(chunk.arrays.iter())
+                .position(|(a, b)| a.data_type() != b.data_type());
+            if let Some(column) = mismatch {
+                return Err(Error::InvalidArgumentError(format!(
+                    "Chunk::concatenate requires the column {} to have the same data type in all chunks",
+                    column
+                )));
+            }
+        }
+
+        let arrays = (0..first.arrays.len())
+            .map(|column| {
+                let arrays = chunks
+                    .iter()
+                    .map(|chunk| chunk.arrays[column].as_ref())
+                    .collect::<Vec<_>>();
+                crate::compute::concatenate::concatenate(&arrays).map(std::sync::Arc::from)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(arrays)
+    }
+}
+
 impl<A: AsRef<dyn Array>> From<Chunk<A>> for Vec<A> {
     fn from(c: Chunk<A>) -> Self {
         c.into_arrays()
@@ -82,3 +145,55 @@ impl<A: AsRef<dyn Array>> std::ops::Deref for Chunk<A> {
         self.arrays()
     }
 }
+
+/// A view over a single row of a [`Chunk`], created by [`Chunk::rows`].
+pub struct Row<'a, A: AsRef<dyn Array>> {
+    arrays: &'a [A],
+    index: usize,
+}
+
+impl<'a, A: AsRef<dyn Array>> Row<'a, A> {
+    /// Returns the index of this row in its [`Chunk`]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of columns of this row
+    pub fn len(&self) -> usize {
+        self.arrays.len()
+    }
+
+    /// Returns whether this row has no columns
+    pub fn is_empty(&self) -> bool {
+        self.arrays.is_empty()
+    }
+
+    /// Returns the value of this row at column `column` as a [`Scalar`].
+    /// # Panic
+    /// Iff `column >= self.len()`
+    pub fn get(&self, column: usize) -> Box<dyn Scalar> {
+        new_scalar(self.arrays[column].as_ref(), self.index)
+    }
+
+    /// Returns an iterator over the values of this row, one [`Scalar`] per column.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Box<dyn Scalar>> + 'a {
+        let index = self.index;
+        self.arrays
+            .iter()
+            .map(move |array| new_scalar(array.as_ref(), index))
+    }
+}
+
+impl<'a, A: AsRef<dyn Array>> Clone for Row<'a, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A: AsRef<dyn Array>> Copy for Row<'a, A> {}
+
+impl<'a, A: AsRef<dyn Array>> std::fmt::Debug for Row<'a, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
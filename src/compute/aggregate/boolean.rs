@@ -0,0 +1,63 @@
+use crate::array::{Array, BooleanArray};
+
+/// Returns whether any of the non-null values of the array is `true`.
+///
+/// Null values are ignored: returns `None` iff the array has no non-null values.
+/// # Implementation
+/// When the array has no nulls, this is `O(1)` via the bitmap's count of unset bits.
+/// Otherwise, it iterates over the values until a non-null `true` is found.
+/// # Example
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::any;
+///
+/// assert_eq!(any(&BooleanArray::from([Some(false), None, Some(true)])), Some(true));
+/// assert_eq!(any(&BooleanArray::from([Some(false), None])), Some(false));
+/// assert_eq!(any(&BooleanArray::from([None, None])), None);
+/// ```
+pub fn any(array: &BooleanArray) -> Option<bool> {
+    if array.null_count() == 0 {
+        let values = array.values();
+        (!values.is_empty()).then(|| values.unset_bits() != values.len())
+    } else {
+        let mut has_values = false;
+        for value in array.iter().flatten() {
+            if value {
+                return Some(true);
+            }
+            has_values = true;
+        }
+        has_values.then(|| false)
+    }
+}
+
+/// Returns whether all of the non-null values of the array are `true`.
+///
+/// Null values are ignored: returns `None` iff the array has no non-null values.
+/// # Implementation
+/// When the array has no nulls, this is `O(1)` via the bitmap's count of unset bits.
+/// Otherwise, it iterates over the values until a non-null `false` is found.
+/// # Example
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::all;
+///
+/// assert_eq!(all(&BooleanArray::from([Some(false), None, Some(true)])), Some(false));
+/// assert_eq!(all(&BooleanArray::from([Some(true), None])), Some(true));
+/// assert_eq!(all(&BooleanArray::from([None, None])), None);
+/// ```
+pub fn all(array: &BooleanArray) -> Option<bool> {
+    if array.null_count() == 0 {
+        let values = array.values();
+        (!values.is_empty()).then(|| values.unset_bits() == 0)
+    } else {
+        let mut has_values = false;
+        for value in array.iter().flatten() {
+            if !value {
+                return Some(false);
+            }
+            has_values = true;
+        }
+        has_values.then(|| true)
+    }
+}
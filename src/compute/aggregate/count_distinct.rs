@@ -0,0 +1,150 @@
+//! Contains [`count_distinct`], the exact number of distinct values of an array.
+use std::hash::Hash;
+
+use ahash::{AHashMap, AHashSet};
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+};
+use crate::compute::hash::hash;
+use crate::compute::take::take;
+use crate::datatypes::PhysicalType;
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+use crate::scalar::{new_scalar, Scalar};
+use crate::types::NativeType;
+
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    use crate::types::{days_ms, months_days_ns};
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        _ => return Err(Error::NotYetImplemented(format!(
+            "`count_distinct` operator does not support primitive `{:?}`",
+            $key_type
+        )))
+    }
+})}
+
+fn distinct_primitive<T: NativeType + Hash + Eq>(array: &PrimitiveArray<T>) -> usize {
+    array.iter().flatten().collect::<AHashSet<_>>().len()
+}
+
+fn distinct_boolean(array: &BooleanArray) -> usize {
+    array.iter().flatten().collect::<AHashSet<_>>().len()
+}
+
+fn distinct_binary<O: Offset>(array: &BinaryArray<O>) -> usize {
+    array.iter().flatten().collect::<AHashSet<_>>().len()
+}
+
+fn distinct_utf8<O: Offset>(array: &Utf8Array<O>) -> usize {
+    array.iter().flatten().collect::<AHashSet<_>>().len()
+}
+
+/// Counts the distinct values of a nested array by their [`hash`], comparing the values of
+/// equal hashes to resolve collisions.
+fn distinct_nested(array: &dyn Array) -> Result<usize> {
+    let hashes = hash(array)?;
+
+    let mut buckets = AHashMap::<u64, Vec<Box<dyn Scalar>>>::new();
+    let mut count = 0;
+    for (index, hash) in hashes.iter().enumerate() {
+        if let Some(hash) = hash {
+            let scalar = new_scalar(array, index);
+            let bucket = buckets.entry(*hash).or_default();
+            if !bucket.iter().any(|x| x.as_ref() == scalar.as_ref()) {
+                bucket.push(scalar);
+                count += 1;
+            }
+        }
+    }
+    Ok(count)
+}
+
+/// Counts the distinct values of a [`DictionaryArray`] amongst the values referenced by its
+/// keys, without decoding it.
+fn distinct_dictionary<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<(usize, bool)> {
+    let values = array.values();
+
+    let mut used = vec![false; values.len()];
+    array.keys_iter().flatten().for_each(|key| used[key] = true);
+
+    let (count, has_nulls) = if used.iter().all(|x| *x) {
+        distinct(values.as_ref())?
+    } else {
+        let indices = used
+            .iter()
+            .enumerate()
+            .filter(|(_, used)| **used)
+            .map(|(index, _)| index as u64)
+            .collect::<Vec<_>>();
+        let values = take(values.as_ref(), &PrimitiveArray::from_vec(indices))?;
+        distinct(values.as_ref())?
+    };
+    Ok((count, has_nulls || array.null_count() > 0))
+}
+
+/// Returns the number of distinct non-null values of `array` and whether it has null values.
+fn distinct(array: &dyn Array) -> Result<(usize, bool)> {
+    use PhysicalType::*;
+    let count = match array.data_type().to_physical_type() {
+        Boolean => distinct_boolean(array.as_any().downcast_ref().unwrap()),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            distinct_primitive::<$T>(array.as_any().downcast_ref().unwrap())
+        }),
+        Binary => distinct_binary::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeBinary => distinct_binary::<i64>(array.as_any().downcast_ref().unwrap()),
+        Utf8 => distinct_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeUtf8 => distinct_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        List | LargeList | FixedSizeList | Struct => distinct_nested(array)?,
+        Dictionary(key_type) => {
+            return match_integer_type!(key_type, |$T| {
+                distinct_dictionary::<$T>(array.as_any().downcast_ref().unwrap())
+            })
+        }
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "`count_distinct` operator does not support type {:?}",
+                t
+            )))
+        }
+    };
+    Ok((count, array.null_count() > 0))
+}
+
+/// Returns the exact number of distinct values of `array`. Null values are counted as one
+/// additional distinct value iff `count_nulls` is true.
+///
+/// Dictionary arrays are counted from the values referenced by their keys, without decoding
+/// them. This kernel uses memory proportional to the number of distinct values; see
+/// [`approx_distinct`](super::approx_distinct) for an estimate in constant memory.
+/// Supports the same types as [`hash`].
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::aggregate::count_distinct;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a"), None]);
+/// assert_eq!(count_distinct(&array, false).unwrap(), 2);
+/// assert_eq!(count_distinct(&array, true).unwrap(), 3);
+/// ```
+/// # Errors
+/// Errors iff the [`hash`] of `array` is not supported.
+pub fn count_distinct(array: &dyn Array, count_nulls: bool) -> Result<usize> {
+    let (count, has_nulls) = distinct(array)?;
+    Ok(count + (count_nulls && has_nulls) as usize)
+}
@@ -0,0 +1,71 @@
+use num_traits::Float;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::types::NativeType;
+
+/// Statistics of an array of floats, computed by [`float_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatStats<T> {
+    /// The number of null values
+    pub null_count: usize,
+    /// The number of non-null `NaN` values
+    pub nan_count: usize,
+    /// The number of non-null positive and negative infinite values
+    pub inf_count: usize,
+    /// The minimum of the non-null, non-`NaN` values, if any
+    pub min: Option<T>,
+    /// The maximum of the non-null, non-`NaN` values, if any
+    pub max: Option<T>,
+}
+
+/// Returns the number of nulls, `NaN`s and infinities, and the minimum and maximum
+/// (ignoring `NaN`s) of `array`, in a single pass over its values and validity.
+/// # Example
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::aggregate::{float_stats, FloatStats};
+///
+/// let array = Float64Array::from([Some(1.0), None, Some(f64::NAN), Some(f64::INFINITY)]);
+/// assert_eq!(
+///     float_stats(&array),
+///     FloatStats {
+///         null_count: 1,
+///         nan_count: 1,
+///         inf_count: 1,
+///         min: Some(1.0),
+///         max: Some(f64::INFINITY),
+///     }
+/// );
+/// ```
+pub fn float_stats<T: NativeType + Float>(array: &PrimitiveArray<T>) -> FloatStats<T> {
+    let mut stats = FloatStats {
+        null_count: array.null_count(),
+        nan_count: 0,
+        inf_count: 0,
+        min: None,
+        max: None,
+    };
+
+    let mut push = |value: T| {
+        if value.is_nan() {
+            stats.nan_count += 1;
+            return;
+        }
+        if value.is_infinite() {
+            stats.inf_count += 1;
+        }
+        stats.min = Some(stats.min.map_or(value, |min: T| Float::min(min, value)));
+        stats.max = Some(stats.max.map_or(value, |max: T| Float::max(max, value)));
+    };
+
+    match array.validity() {
+        Some(validity) if stats.null_count > 0 => array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .for_each(|(value, _)| push(*value)),
+        _ => array.values().iter().for_each(|value| push(*value)),
+    }
+    stats
+}
@@ -0,0 +1,665 @@
+//! Contains [`group_by`], a hash-based grouped aggregation of arrays, and
+//! [`aggregate_by_ranges`], an aggregation of contiguous groups of rows.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use num_traits::AsPrimitive;
+
+use crate::array::{
+    ord::build_compare, Array, BinaryArray, BooleanArray, MutableArray, MutableBinaryArray,
+    MutableUtf8Array, PrimitiveArray, TryPush, Utf8Array,
+};
+use crate::compute::{hash::hash, take::take};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::offset::{Offset, Offsets};
+use crate::types::simd::Simd;
+use crate::types::NativeType;
+
+use super::{
+    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
+    min_string, sum_decimal, SimdOrd,
+};
+
+/// The aggregations supported by [`group_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    /// The sum of the non-null values of each group, with the type of the values.
+    /// Null when a group has no non-null values. Errors with [`Error::Overflow`] when the
+    /// sum of integers or decimals overflows.
+    Sum,
+    /// The minimum of the non-null values of each group, with the type of the values.
+    /// Null when a group has no non-null values. Supports any type that can be compared.
+    Min,
+    /// The maximum of the non-null values of each group, with the type of the values.
+    /// Null when a group has no non-null values. Supports any type that can be compared.
+    Max,
+    /// The number of non-null values of each group, as [`DataType::UInt64`].
+    Count,
+    /// The arithmetic mean of the non-null values of each group, as [`DataType::Float64`].
+    /// Null when a group has no non-null values.
+    Mean,
+    /// The first non-null value of each group. Supports any type supported by `take`.
+    First,
+    /// The last non-null value of each group. Supports any type supported by `take`.
+    Last,
+}
+
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, $aggregation:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => return Err(Error::InvalidArgumentError(format!(
+            "`{:?}` aggregation does not support primitive `{:?}`",
+            $aggregation,
+            $key_type,
+        ))),
+    }
+})}
+
+/// Hash of null keys
+const NULL_HASH: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Returns the rows of each group of `keys`, in the order of their first row.
+fn groups(keys: &[Box<dyn Array>]) -> Result<Vec<Vec<usize>>> {
+    let len = keys[0].len();
+
+    // combined hash of each row
+    let mut hashes = vec![0u64; len];
+    for key in keys {
+        let key_hashes = hash(key.as_ref())?;
+        hashes
+            .iter_mut()
+            .zip(key_hashes.iter())
+            .for_each(|(row_hash, key_hash)| {
+                let key_hash = key_hash.copied().unwrap_or(NULL_HASH);
+                *row_hash = row_hash.rotate_left(5) ^ key_hash;
+            });
+    }
+
+    let comparators = keys
+        .iter()
+        .map(|key| build_compare(key.as_ref(), key.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let rows_equal = |lhs: usize, rhs: usize| {
+        keys.iter().zip(comparators.iter()).all(|(key, compare)| {
+            match (key.is_valid(lhs), key.is_valid(rhs)) {
+                (true, true) => compare(lhs, rhs) == Ordering::Equal,
+                (false, false) => true,
+                _ => false,
+            }
+        })
+    };
+
+    // hash => ids of the groups whose keys have this hash
+    let mut map = HashMap::<u64, Vec<usize>>::new();
+    let mut groups = Vec::<Vec<usize>>::new();
+    for (row, row_hash) in hashes.into_iter().enumerate() {
+        let candidates = map.entry(row_hash).or_default();
+        let group = candidates
+            .iter()
+            .copied()
+            .find(|&group| rows_equal(groups[group][0], row));
+        if let Some(group) = group {
+            groups[group].push(row);
+        } else {
+            candidates.push(groups.len());
+            groups.push(vec![row]);
+        }
+    }
+    Ok(groups)
+}
+
+fn fold_groups<T, A, F>(
+    array: &PrimitiveArray<T>,
+    groups: &[Vec<usize>],
+    data_type: DataType,
+    op: F,
+) -> PrimitiveArray<A>
+where
+    T: NativeType,
+    A: NativeType,
+    F: Fn(&mut dyn Iterator<Item = T>) -> Option<A>,
+{
+    let values = groups
+        .iter()
+        .map(|group| op(&mut group_values(array, group)))
+        .collect::<Vec<_>>();
+    PrimitiveArray::<A>::from(values).to(data_type)
+}
+
+/// The addition of [`Aggregation::Sum`]: checked for integers, IEEE 754 for floats.
+trait CheckedSum: NativeType {
+    fn checked_sum(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! checked_sum_int {
+    ($($type:ty),*) => {$(
+        impl CheckedSum for $type {
+            #[inline]
+            fn checked_sum(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+        }
+    )*};
+}
+
+checked_sum_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+macro_rules! checked_sum_float {
+    ($($type:ty),*) => {$(
+        impl CheckedSum for $type {
+            #[inline]
+            fn checked_sum(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+        }
+    )*};
+}
+
+checked_sum_float!(f32, f64);
+
+/// The sum of `values`, `None` when there are none.
+/// # Errors
+/// Errors iff the sum overflows.
+fn checked_sum<T: CheckedSum>(mut values: impl Iterator<Item = T>) -> Result<Option<T>> {
+    values
+        .next()
+        .map(|first| {
+            values
+                .try_fold(first, |acc, x| acc.checked_sum(x))
+                .ok_or(Error::Overflow)
+        })
+        .transpose()
+}
+
+/// The valid values of `array` in the rows of `group`.
+fn group_values<'a, T: NativeType>(
+    array: &'a PrimitiveArray<T>,
+    group: &'a [usize],
+) -> impl Iterator<Item = T> + 'a {
+    group
+        .iter()
+        .copied()
+        .filter(|&row| array.is_valid(row))
+        .map(|row| array.value(row))
+}
+
+fn sum_groups<T: CheckedSum>(
+    array: &PrimitiveArray<T>,
+    groups: &[Vec<usize>],
+) -> Result<PrimitiveArray<T>> {
+    let values = groups
+        .iter()
+        .map(|group| checked_sum(group_values(array, group)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<T>::from(values).to(array.data_type().clone()))
+}
+
+/// The sum of each group of a decimal array of `precision`.
+/// # Errors
+/// Errors iff the sum of any group does not fit in `precision`, like [`sum_decimal`].
+fn sum_decimal_groups(
+    array: &PrimitiveArray<i128>,
+    groups: &[Vec<usize>],
+    precision: usize,
+) -> Result<PrimitiveArray<i128>> {
+    let max = 10i128.pow(precision as u32) - 1;
+    let values = groups
+        .iter()
+        .map(|group| match checked_sum(group_values(array, group))? {
+            Some(sum) if sum.abs() > max => Err(Error::Overflow),
+            sum => Ok(sum),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<i128>::from(values).to(array.data_type().clone()))
+}
+
+fn min_groups<T>(array: &PrimitiveArray<T>, groups: &[Vec<usize>]) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    fold_groups(array, groups, array.data_type().clone(), |values| {
+        values.reduce(|acc, x| if x < acc { x } else { acc })
+    })
+}
+
+fn max_groups<T>(array: &PrimitiveArray<T>, groups: &[Vec<usize>]) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    fold_groups(array, groups, array.data_type().clone(), |values| {
+        values.reduce(|acc, x| if x > acc { x } else { acc })
+    })
+}
+
+fn mean_groups<T>(array: &PrimitiveArray<T>, groups: &[Vec<usize>]) -> PrimitiveArray<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    fold_groups(array, groups, DataType::Float64, |values| {
+        let (count, sum) = values.fold((0usize, 0.0f64), |(count, sum), x| {
+            (count + 1, sum + x.as_())
+        });
+        (count > 0).then(|| sum / count as f64)
+    })
+}
+
+/// The number of valid rows of each group.
+fn count_groups<G, I>(array: &dyn Array, groups: G) -> PrimitiveArray<u64>
+where
+    G: Iterator<Item = I>,
+    I: Iterator<Item = usize>,
+{
+    let values = groups
+        .map(|rows| rows.filter(|&row| array.is_valid(row)).count() as u64)
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_vec(values)
+}
+
+/// The first or last valid row of each group.
+fn first_last_groups<G, I>(
+    array: &dyn Array,
+    groups: G,
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>>
+where
+    G: Iterator<Item = I>,
+    I: DoubleEndedIterator<Item = usize>,
+{
+    let indices = groups
+        .map(|mut rows| {
+            let row = if aggregation == Aggregation::First {
+                rows.find(|&row| array.is_valid(row))
+            } else {
+                rows.rfind(|&row| array.is_valid(row))
+            };
+            row.map(|row| row as u64)
+        })
+        .collect::<Vec<_>>();
+    take(array, &PrimitiveArray::<u64>::from(indices))
+}
+
+/// Min and max of arrays of any comparable type, by taking the row of the extreme of each group.
+fn extreme_groups<G, I>(
+    array: &dyn Array,
+    groups: G,
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>>
+where
+    G: Iterator<Item = I>,
+    I: Iterator<Item = usize>,
+{
+    let compare = build_compare(array, array)?;
+    // the ordering of a row that replaces the current extreme
+    let replaces = if aggregation == Aggregation::Min {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    let indices = groups
+        .map(|rows| {
+            rows.filter(|&row| array.is_valid(row))
+                .reduce(|acc, row| {
+                    if compare(row, acc) == replaces {
+                        row
+                    } else {
+                        acc
+                    }
+                })
+                .map(|row| row as u64)
+        })
+        .collect::<Vec<_>>();
+    take(array, &PrimitiveArray::<u64>::from(indices))
+}
+
+fn aggregate(
+    array: &dyn Array,
+    groups: &[Vec<usize>],
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>> {
+    let rows = groups.iter().map(|group| group.iter().copied());
+    match aggregation {
+        Aggregation::Count => return Ok(count_groups(array, rows).boxed()),
+        Aggregation::First | Aggregation::Last => {
+            return first_last_groups(array, rows, aggregation)
+        }
+        _ => {}
+    };
+
+    let primitive = match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) => primitive,
+        _ if matches!(aggregation, Aggregation::Min | Aggregation::Max) => {
+            return extreme_groups(array, rows, aggregation)
+        }
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "`{:?}` aggregation does not support type `{:?}`",
+                aggregation,
+                array.data_type(),
+            )))
+        }
+    };
+    if let DataType::Decimal(precision, _) = array.data_type().to_logical_type() {
+        match aggregation {
+            Aggregation::Mean => {
+                return Err(Error::InvalidArgumentError(
+                    "`Mean` aggregation does not support decimals".to_string(),
+                ))
+            }
+            Aggregation::Sum => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap();
+                return sum_decimal_groups(array, groups, *precision).map(|x| x.boxed());
+            }
+            _ => {}
+        }
+    }
+
+    with_match_primitive_type!(primitive, aggregation, |$T| {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+        Ok(match aggregation {
+            Aggregation::Sum => sum_groups(array, groups)?.boxed(),
+            Aggregation::Min => min_groups(array, groups).boxed(),
+            Aggregation::Max => max_groups(array, groups).boxed(),
+            Aggregation::Mean => mean_groups(array, groups).boxed(),
+            _ => unreachable!(),
+        })
+    })
+}
+
+/// Groups the rows of `values` by the rows of `keys` and aggregates each of the `values`
+/// with its corresponding [`Aggregation`].
+///
+/// Returns the unique keys, one array per key, and the aggregated values, one array per value.
+/// Groups are returned in the order of their first row; null keys form their own group.
+/// # Implementation
+/// Rows are bucketed by the hash of their keys in a `HashMap<u64, Vec<usize>>`;
+/// rows with equal hashes are compared to resolve collisions.
+/// This is a reference implementation: query engines are expected to have their own.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, UInt64Array, Utf8Array};
+/// use arrow2::compute::aggregate::{group_by, Aggregation};
+///
+/// let keys = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+/// let values = Int32Array::from_slice([1, 2, 3]).boxed();
+///
+/// let (keys, values) = group_by(
+///     &[keys],
+///     &[values.clone(), values],
+///     &[Aggregation::Sum, Aggregation::Count],
+/// ).unwrap();
+///
+/// assert_eq!(keys[0].as_ref(), &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array);
+/// assert_eq!(values[0].as_ref(), &Int32Array::from_slice([4, 2]) as &dyn Array);
+/// assert_eq!(values[1].as_ref(), &UInt64Array::from_slice([2, 1]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff:
+/// * `keys` is empty or the arrays have different lengths
+/// * `values` and `aggregations` have different lengths
+/// * any of the keys cannot be hashed or compared
+/// * any of the aggregations is not supported for the type of its values
+/// * the sum of any group overflows
+pub fn group_by(
+    keys: &[Box<dyn Array>],
+    values: &[Box<dyn Array>],
+    aggregations: &[Aggregation],
+) -> Result<(Vec<Box<dyn Array>>, Vec<Box<dyn Array>>)> {
+    if keys.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "group_by requires at least one key".to_string(),
+        ));
+    }
+    if values.len() != aggregations.len() {
+        return Err(Error::InvalidArgumentError(
+            "group_by requires one aggregation per value".to_string(),
+        ));
+    }
+    let len = keys[0].len();
+    if keys
+        .iter()
+        .chain(values.iter())
+        .any(|array| array.len() != len)
+    {
+        return Err(Error::InvalidArgumentError(
+            "group_by requires all arrays to have the same length".to_string(),
+        ));
+    }
+
+    let groups = groups(keys)?;
+
+    let first_rows = groups.iter().map(|rows| rows[0] as u64).collect::<Vec<_>>();
+    let first_rows = PrimitiveArray::<u64>::from_vec(first_rows);
+    let keys = keys
+        .iter()
+        .map(|key| take(key.as_ref(), &first_rows))
+        .collect::<Result<Vec<_>>>()?;
+
+    let values = values
+        .iter()
+        .zip(aggregations.iter())
+        .map(|(array, aggregation)| aggregate(array.as_ref(), &groups, *aggregation))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((keys, values))
+}
+
+/// Aggregates each of `ranges` of `array` by applying `op` to the slice of the range.
+fn fold_ranges<T, A, F>(
+    array: &PrimitiveArray<T>,
+    ranges: &[Range<usize>],
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<A>>
+where
+    T: NativeType,
+    A: NativeType,
+    F: Fn(&PrimitiveArray<T>) -> Result<Option<A>>,
+{
+    let values = ranges
+        .iter()
+        .map(|range| op(&array.slice(range.start, range.len())))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<A>::from(values).to(data_type))
+}
+
+fn aggregate_primitive_ranges<T>(
+    array: &PrimitiveArray<T>,
+    ranges: &[Range<usize>],
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>>
+where
+    T: CheckedSum + Simd + AsPrimitive<f64>,
+    T::Simd: SimdOrd<T>,
+{
+    let data_type = array.data_type().clone();
+    Ok(match aggregation {
+        Aggregation::Sum => fold_ranges(array, ranges, data_type, |x| {
+            checked_sum(x.iter().flatten().copied())
+        })?
+        .boxed(),
+        Aggregation::Min => {
+            fold_ranges(array, ranges, data_type, |x| Ok(min_primitive(x)))?.boxed()
+        }
+        Aggregation::Max => {
+            fold_ranges(array, ranges, data_type, |x| Ok(max_primitive(x)))?.boxed()
+        }
+        Aggregation::Mean => fold_ranges(array, ranges, DataType::Float64, |x| {
+            let count = x.len() - x.null_count();
+            let sum = x.iter().flatten().map(|x| x.as_()).sum::<f64>();
+            Ok((count > 0).then(|| sum / count as f64))
+        })?
+        .boxed(),
+        _ => unreachable!(),
+    })
+}
+
+fn aggregate_decimal_ranges(
+    array: &PrimitiveArray<i128>,
+    ranges: &[Range<usize>],
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+    Ok(match aggregation {
+        Aggregation::Sum => fold_ranges(array, ranges, data_type, sum_decimal)?.boxed(),
+        Aggregation::Min => {
+            fold_ranges(array, ranges, data_type, |x| Ok(min_primitive(x)))?.boxed()
+        }
+        Aggregation::Max => {
+            fold_ranges(array, ranges, data_type, |x| Ok(max_primitive(x)))?.boxed()
+        }
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "`{:?}` aggregation does not support decimals",
+                aggregation
+            )))
+        }
+    })
+}
+
+fn extreme_utf8_ranges<O: Offset>(
+    array: &dyn Array,
+    ranges: &[Range<usize>],
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let op = if aggregation == Aggregation::Min {
+        min_string
+    } else {
+        max_string
+    };
+    let mut result =
+        MutableUtf8Array::<O>::try_new(array.data_type().clone(), Offsets::new(), vec![], None)?;
+    for range in ranges {
+        result.try_push(op(&array.slice(range.start, range.len())))?;
+    }
+    Ok(result.as_box())
+}
+
+fn extreme_binary_ranges<O: Offset>(
+    array: &dyn Array,
+    ranges: &[Range<usize>],
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let op = if aggregation == Aggregation::Min {
+        min_binary
+    } else {
+        max_binary
+    };
+    let mut result =
+        MutableBinaryArray::<O>::try_new(array.data_type().clone(), Offsets::new(), vec![], None)?;
+    for range in ranges {
+        result.try_push(op(&array.slice(range.start, range.len())))?;
+    }
+    Ok(result.as_box())
+}
+
+/// Aggregates each of the `ranges` of rows of `values` with `aggregation`, returning one slot
+/// per range.
+///
+/// This is the grouped aggregation of sorted data, whose groups are contiguous, e.g. the
+/// ranges returned by `compute::partition::lexicographical_partition_ranges`.
+/// Each range is aggregated by slicing `values` (`O(1)`) and applying the non-grouped
+/// kernels, e.g. [`min_primitive`] or [`min_string`]. Sums of integers are checked, like
+/// those of [`group_by`].
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::aggregate::{aggregate_by_ranges, Aggregation};
+///
+/// let values = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let result = aggregate_by_ranges(&values, &[0..2, 2..3, 3..4], Aggregation::Sum).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(3), None, Some(4)]) as &dyn Array);
+///
+/// let values = Utf8Array::<i32>::from_slice(["b", "a", "c"]);
+/// let result = aggregate_by_ranges(&values, &[0..2, 2..3], Aggregation::Min).unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from_slice(["a", "c"]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff any of the ranges is out of bounds of `values`, `aggregation`
+/// is not supported for the type of `values` or a sum overflows.
+pub fn aggregate_by_ranges(
+    values: &dyn Array,
+    ranges: &[Range<usize>],
+    aggregation: Aggregation,
+) -> Result<Box<dyn Array>> {
+    if let Some(range) = ranges
+        .iter()
+        .find(|range| range.start > range.end || range.end > values.len())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "aggregate_by_ranges requires ranges within the values (range = {:?}, length = {})",
+            range,
+            values.len()
+        )));
+    }
+
+    let rows = ranges.iter().cloned();
+    use PhysicalType::*;
+    match (values.data_type().to_physical_type(), aggregation) {
+        (_, Aggregation::Count) => Ok(count_groups(values, rows).boxed()),
+        (_, Aggregation::First | Aggregation::Last) => first_last_groups(values, rows, aggregation),
+        (Primitive(primitive), _) => {
+            if let DataType::Decimal(_, _) = values.data_type().to_logical_type() {
+                let values = values
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap();
+                return aggregate_decimal_ranges(values, ranges, aggregation);
+            }
+            with_match_primitive_type!(primitive, aggregation, |$T| {
+                let values = values.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                aggregate_primitive_ranges(values, ranges, aggregation)
+            })
+        }
+        (Utf8, Aggregation::Min | Aggregation::Max) => {
+            extreme_utf8_ranges::<i32>(values, ranges, aggregation)
+        }
+        (LargeUtf8, Aggregation::Min | Aggregation::Max) => {
+            extreme_utf8_ranges::<i64>(values, ranges, aggregation)
+        }
+        (Binary, Aggregation::Min | Aggregation::Max) => {
+            extreme_binary_ranges::<i32>(values, ranges, aggregation)
+        }
+        (LargeBinary, Aggregation::Min | Aggregation::Max) => {
+            extreme_binary_ranges::<i64>(values, ranges, aggregation)
+        }
+        (Boolean, Aggregation::Min | Aggregation::Max) => {
+            let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let op = if aggregation == Aggregation::Min {
+                min_boolean
+            } else {
+                max_boolean
+            };
+            let values = ranges
+                .iter()
+                .map(|range| op(&values.slice(range.start, range.len())));
+            Ok(BooleanArray::from_iter(values).boxed())
+        }
+        (_, Aggregation::Min | Aggregation::Max) => extreme_groups(values, rows, aggregation),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "`{:?}` aggregation does not support type `{:?}`",
+            aggregation,
+            values.data_type(),
+        ))),
+    }
+}
@@ -1,6 +1,8 @@
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::PhysicalType;
+use crate::offset::Offset;
 
 fn validity_size(validity: Option<&Bitmap>) -> usize {
     validity.as_ref().map(|b| b.as_slice().0.len()).unwrap_or(0)
@@ -22,6 +24,32 @@ macro_rules! dyn_binary {
     }};
 }
 
+/// Returns the region of `values` referenced by `offsets`, since the offsets of nested arrays
+/// are sliced, not their values.
+fn sliced_child<O: Offset>(values: &dyn Array, offsets: &[O]) -> Box<dyn Array> {
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    values.slice(start, end - start)
+}
+
+/// Returns the size of `views` and of the data `buffers` they reference. The data buffers are
+/// shared between slices of an array, so they are counted in full.
+fn views_size(views: &[View], buffers: &[Buffer<u8>]) -> usize {
+    std::mem::size_of_val(views) + buffers.iter().map(|x| x.len()).sum::<usize>()
+}
+
+macro_rules! dyn_list {
+    ($array:expr, $ty:ty, $o:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let offsets = array.offsets().buffer();
+        let values = sliced_child(array.values().as_ref(), offsets);
+
+        estimated_bytes_size(values.as_ref())
+            + offsets.len() * std::mem::size_of::<$o>()
+            + validity_size(array.validity())
+    }};
+}
+
 /// Returns the total (heap) allocated size of the array in bytes.
 /// # Implementation
 /// This estimation is the sum of the size of its buffers, validity, including nested arrays.
@@ -30,10 +58,17 @@ macro_rules! dyn_binary {
 ///
 /// When an array is sliced, its allocated size remains constant because the buffer unchanged.
 /// However, this function will yield a smaller number. This is because this function returns
-/// the visible size of the buffer, not its total capacity.
+/// the visible size of the buffer, not its total capacity. The children of sliced nested arrays
+/// (e.g. the values of a [`ListArray`]) only count the region referenced by the slice.
 ///
 /// FFI buffers are included in this estimation.
 pub fn estimated_bytes_size(array: &dyn Array) -> usize {
+    estimated_bytes_size_of(array)
+}
+
+/// [`estimated_bytes_size`] of any [`Array`], so that [`Array::estimated_bytes_size`] can call it
+/// on an unsized `Self`.
+pub(crate) fn estimated_bytes_size_of<A: Array + ?Sized>(array: &A) -> usize {
     use PhysicalType::*;
     match array.data_type().to_physical_type() {
         Null => 0,
@@ -60,22 +95,20 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         LargeBinary => dyn_binary!(array, BinaryArray<i64>, i64),
         Utf8 => dyn_binary!(array, Utf8Array<i32>, i32),
         LargeUtf8 => dyn_binary!(array, Utf8Array<i64>, i64),
-        List => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            estimated_bytes_size(array.values().as_ref())
-                + array.offsets().len() * std::mem::size_of::<i32>()
-                + validity_size(array.validity())
+        BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            views_size(array.views(), array.data_buffers()) + validity_size(array.validity())
         }
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<StringViewArray>().unwrap();
+            views_size(array.views(), array.data_buffers()) + validity_size(array.validity())
+        }
+        List => dyn_list!(array, ListArray<i32>, i32),
         FixedSizeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
         }
-        LargeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            estimated_bytes_size(array.values().as_ref())
-                + array.offsets().len() * std::mem::size_of::<i64>()
-                + validity_size(array.validity())
-        }
+        LargeList => dyn_list!(array, ListArray<i64>, i64),
         Struct => {
             let array = array.as_any().downcast_ref::<StructArray>().unwrap();
             array
@@ -111,8 +144,23 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         }),
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let offsets = array.offsets().len() * std::mem::size_of::<i32>();
-            offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
+            let offsets = array.offsets().buffer();
+            let field = sliced_child(array.field().as_ref(), offsets);
+            offsets.len() * std::mem::size_of::<i32>()
+                + estimated_bytes_size(field.as_ref())
+                + validity_size(array.validity())
+        }
+        RunEndEncoded => {
+            let primitive = values_primitive_type(array.data_type());
+            with_match_primitive_type!(primitive, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<RunEndEncodedArray<$T>>()
+                    .unwrap();
+                // the runs are not sliced
+                array.run_ends().len() * std::mem::size_of::<i32>()
+                    + estimated_bytes_size(array.values())
+            })
         }
     }
 }
@@ -1,10 +1,11 @@
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::arity::unary;
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::offset::Offset;
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
     bitmap::Bitmap,
@@ -155,6 +156,79 @@ where
     reduced.max_element()
 }
 
+fn nonnull_min_max_primitive<T>(values: &[T]) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let chunks = values.chunks_exact(T::Simd::LANES);
+    let remainder = chunks.remainder();
+
+    let (min_vec, max_vec) = chunks.fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min_acc, max_acc), chunk| {
+            let chunk = T::Simd::from_chunk(chunk);
+            (min_acc.min_lane(chunk), max_acc.max_lane(chunk))
+        },
+    );
+
+    let min_vec = min_vec.min_lane(T::Simd::from_incomplete_chunk(remainder, T::Simd::MAX));
+    let max_vec = max_vec.max_lane(T::Simd::from_incomplete_chunk(remainder, T::Simd::MIN));
+
+    (min_vec.min_element(), max_vec.max_element())
+}
+
+fn null_min_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+    I: BitChunkIterExact<<<T as Simd>::Simd as NativeSimd>::Chunk>,
+{
+    let mut chunks = values.chunks_exact(T::Simd::LANES);
+
+    let (min_vec, max_vec) = chunks.by_ref().zip(validity_masks.by_ref()).fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min_acc, max_acc), (chunk, validity_chunk)| {
+            let chunk = T::Simd::from_chunk(chunk);
+            let mask = <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+            let min_chunk = chunk.select(mask, T::Simd::new_min());
+            let mask = <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+            let max_chunk = chunk.select(mask, T::Simd::new_max());
+            (min_acc.min_lane(min_chunk), max_acc.max_lane(max_chunk))
+        },
+    );
+
+    let remainder = chunks.remainder();
+    let validity_chunk = validity_masks.remainder();
+
+    let min_remainder = T::Simd::from_incomplete_chunk(remainder, T::Simd::MAX);
+    let mask = <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+    let min_vec = min_vec.min_lane(min_remainder.select(mask, T::Simd::new_min()));
+
+    let max_remainder = T::Simd::from_incomplete_chunk(remainder, T::Simd::MIN);
+    let mask = <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+    let max_vec = max_vec.max_lane(max_remainder.select(mask, T::Simd::new_max()));
+
+    (min_vec.min_element(), max_vec.max_element())
+}
+
+/// # Panics
+/// iff `values.len() != bitmap.len()` or the operation overflows.
+fn null_min_max_primitive<T>(values: &[T], bitmap: &Bitmap) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let (slice, offset, length) = bitmap.as_slice();
+    if offset == 0 {
+        let validity_masks = BitChunksExact::<<T::Simd as NativeSimd>::Chunk>::new(slice, length);
+        null_min_max_primitive_impl(values, validity_masks)
+    } else {
+        let validity_masks = bitmap.chunks::<<T::Simd as NativeSimd>::Chunk>();
+        null_min_max_primitive_impl(values, validity_masks)
+    }
+}
+
 /// Returns the minimum value in the array, according to the natural order.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value
 pub fn min_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
@@ -199,6 +273,46 @@ where
     })
 }
 
+/// Returns the minimum value in a [`f16`] array, with the same order as [`min_primitive`]
+/// of the values as `f32`.
+pub fn min_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    let array = unary(array, |x| x.to_f32(), DataType::Float32);
+    min_primitive(&array).map(f16::from_f32)
+}
+
+/// Returns the maximum value in a [`f16`] array, with the same order as [`max_primitive`]
+/// of the values as `f32`.
+pub fn max_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    let array = unary(array, |x| x.to_f32(), DataType::Float32);
+    max_primitive(&array).map(f16::from_f32)
+}
+
+/// Returns the minimum and maximum values in the array, according to the natural order,
+/// in a single pass over the values. This is faster than calling [`min_primitive`] and
+/// [`max_primitive`].
+/// Returns `(None, None)` iff the array is empty or all its values are null.
+/// For floating point arrays any NaN values are considered to be greater than any other non-null value
+pub fn min_max_primitive<T>(array: &PrimitiveArray<T>) -> (Option<T>, Option<T>)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let null_count = array.null_count();
+
+    // Includes case array.len() == 0
+    if null_count == array.len() {
+        return (None, None);
+    }
+    let values = array.values();
+
+    let (min, max) = if let Some(validity) = array.validity() {
+        null_min_max_primitive(values, validity)
+    } else {
+        nonnull_min_max_primitive(values)
+    };
+    (Some(min), Some(max))
+}
+
 /// Helper to compute min/max of [`BinaryArray`] and [`Utf8Array`]
 macro_rules! min_max_binary_utf8 {
     ($array: expr, $cmp: expr) => {
@@ -237,12 +351,18 @@ pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
     min_max_binary_utf8!(array, |a, b| a > b)
 }
 
-/// Returns the maximum value in the string array, according to the natural order.
+/// Returns the maximum value in the string array, according to the natural (lexicographic) order.
+///
+/// The result borrows from `array`'s values. Nulls are skipped; returns `None` if the array
+/// is empty or all values are null.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_binary_utf8!(array, |a, b| a < b)
 }
 
-/// Returns the minimum value in the string array, according to the natural order.
+/// Returns the minimum value in the string array, according to the natural (lexicographic) order.
+///
+/// The result borrows from `array`'s values. Nulls are skipped; returns `None` if the array
+/// is empty or all values are null.
 pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_binary_utf8!(array, |a, b| a > b)
 }
@@ -340,6 +460,11 @@ macro_rules! with_match_primitive_type {(
 pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, max_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, max_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -351,7 +476,7 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
             dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, max_binary)
         }
         PhysicalType::LargeBinary => {
-            dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, min_binary)
+            dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, max_binary)
         }
         _ => {
             return Err(Error::InvalidArgumentError(format!(
@@ -368,6 +493,11 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
 pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, min_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, min_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -383,7 +513,7 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
         _ => {
             return Err(Error::InvalidArgumentError(format!(
-                "The `max` operator does not support type `{:?}`",
+                "The `min` operator does not support type `{:?}`",
                 array.data_type(),
             )))
         }
@@ -397,7 +527,17 @@ pub fn can_min(data_type: &DataType) -> bool {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int32
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         use PhysicalType::*;
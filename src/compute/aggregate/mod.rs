@@ -9,6 +9,41 @@ mod min_max;
 #[cfg(feature = "compute_aggregate")]
 pub use min_max::*;
 
+#[cfg(feature = "compute_aggregate")]
+mod moments;
+#[cfg(feature = "compute_aggregate")]
+pub use moments::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod boolean;
+#[cfg(feature = "compute_aggregate")]
+pub use boolean::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod group_by;
+#[cfg(feature = "compute_aggregate")]
+pub use group_by::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod approx_count_distinct;
+#[cfg(feature = "compute_aggregate")]
+pub use approx_count_distinct::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod count_distinct;
+#[cfg(feature = "compute_aggregate")]
+pub use count_distinct::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod quantile;
+#[cfg(feature = "compute_aggregate")]
+pub use quantile::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod float_stats;
+#[cfg(feature = "compute_aggregate")]
+pub use float_stats::*;
+
 mod memory;
 pub use memory::*;
 #[cfg(feature = "compute_aggregate")]
@@ -0,0 +1,117 @@
+use num_traits::AsPrimitive;
+
+use crate::array::PrimitiveArray;
+use crate::types::NativeType;
+
+/// The central moments of a set of values, computed in a single pass using Welford's
+/// online algorithm (extended to the third and fourth moments).
+#[derive(Debug, Default, Clone, Copy)]
+struct Moments {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl Moments {
+    #[inline]
+    fn push(&mut self, value: f64) {
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+}
+
+fn moments<T>(array: &PrimitiveArray<T>) -> Moments
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    let mut moments = Moments::default();
+    array
+        .iter()
+        .flatten()
+        .for_each(|value| moments.push(value.as_()));
+    moments
+}
+
+/// Returns the variance of the non-null values of the array, where `ddof` is the delta degrees
+/// of freedom: the divisor is `N - ddof`, with `N` the number of non-null values.
+/// `ddof = 0` yields the population variance and `ddof = 1` the sample variance.
+///
+/// Returns `None` iff the array has fewer than `ddof + 1` non-null values.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::var;
+///
+/// let array = Int32Array::from([Some(1), None, Some(2), Some(3), Some(4)]);
+/// assert_eq!(var(&array, 0), Some(1.25));
+/// assert_eq!(var(&array, 1), Some(5.0 / 3.0));
+/// ```
+pub fn var<T>(array: &PrimitiveArray<T>, ddof: u8) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    let moments = moments(array);
+    if moments.count <= ddof as usize {
+        return None;
+    }
+    Some(moments.m2 / (moments.count - ddof as usize) as f64)
+}
+
+/// Returns the standard deviation of the non-null values of the array, the square root of
+/// [`var`].
+///
+/// Returns `None` iff the array has fewer than `ddof + 1` non-null values.
+pub fn std_dev<T>(array: &PrimitiveArray<T>, ddof: u8) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    var(array, ddof).map(|x| x.sqrt())
+}
+
+/// Returns the (population) skewness of the non-null values of the array, `m3 / m2^(3/2)`,
+/// with `mi` the `i`-th central moment.
+///
+/// Returns `None` iff the array has no non-null values. The result is `NaN` when all values
+/// are equal.
+pub fn skewness<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    let moments = moments(array);
+    if moments.count == 0 {
+        return None;
+    }
+    let n = moments.count as f64;
+    Some(n.sqrt() * moments.m3 / moments.m2.powf(1.5))
+}
+
+/// Returns the (population) excess kurtosis of the non-null values of the array,
+/// `m4 / m2^2 - 3`, with `mi` the `i`-th central moment.
+///
+/// Returns `None` iff the array has no non-null values. The result is `NaN` when all values
+/// are equal.
+pub fn kurtosis<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    let moments = moments(array);
+    if moments.count == 0 {
+        return None;
+    }
+    let n = moments.count as f64;
+    Some(n * moments.m4 / (moments.m2 * moments.m2) - 3.0)
+}
@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+
+use num_traits::AsPrimitive;
+
+use crate::array::PrimitiveArray;
+use crate::types::NativeType;
+
+/// Declares how [`quantile`] computes a quantile that lies between two values `i < j`,
+/// following NumPy's and Pandas' naming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantileInterpolation {
+    /// `i + (j - i) * fraction`, where `fraction` is the fractional part of the position
+    #[default]
+    Linear,
+    /// `i`
+    Lower,
+    /// `j`
+    Upper,
+    /// `(i + j) / 2`
+    Midpoint,
+    /// `i` or `j`, whichever is nearest (ties are resolved to the even position)
+    Nearest,
+}
+
+/// Total order over `f64` where `NaN` is greater than any other value.
+#[inline]
+fn compare(a: &f64, b: &f64) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// Returns the `q`-th quantile of the non-null values of the array, where `q` is in `[0, 1]`.
+/// Values are ordered by their natural order, with `NaN` greater than any other value.
+///
+/// Returns `None` iff the array has no non-null values.
+/// # Panics
+/// This function panics iff `q` is not in `[0, 1]`.
+/// # Implementation
+/// This function selects the values around the quantile in linear time
+/// (on average) without sorting all values.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::{quantile, QuantileInterpolation};
+///
+/// let array = Int32Array::from([Some(4), None, Some(1), Some(3), Some(2)]);
+/// assert_eq!(quantile(&array, 0.5, QuantileInterpolation::Linear), Some(2.5));
+/// assert_eq!(quantile(&array, 0.5, QuantileInterpolation::Lower), Some(2.0));
+/// ```
+pub fn quantile<T>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    assert!(
+        (0.0..=1.0).contains(&q),
+        "The quantile must be in [0, 1], got {}",
+        q
+    );
+
+    let mut values = array
+        .iter()
+        .flatten()
+        .map(|x| x.as_())
+        .collect::<Vec<f64>>();
+    if values.is_empty() {
+        return None;
+    }
+
+    let position = q * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+
+    let (_, lower_value, greater) = values.select_nth_unstable_by(lower, compare);
+    let lower_value = *lower_value;
+    let upper_value = if upper == lower {
+        lower_value
+    } else {
+        // the values after `lower` are not smaller than it: the next value is their minimum
+        *greater.iter().min_by(|a, b| compare(a, b)).unwrap()
+    };
+
+    Some(match interpolation {
+        QuantileInterpolation::Linear => lower_value + (upper_value - lower_value) * fraction,
+        QuantileInterpolation::Lower => lower_value,
+        QuantileInterpolation::Upper => upper_value,
+        QuantileInterpolation::Midpoint => (lower_value + upper_value) / 2.0,
+        QuantileInterpolation::Nearest => {
+            let use_upper = match fraction.partial_cmp(&0.5) {
+                Some(Ordering::Greater) => true,
+                Some(Ordering::Less) => false,
+                _ => lower % 2 == 1,
+            };
+            if use_upper {
+                upper_value
+            } else {
+                lower_value
+            }
+        }
+    })
+}
+
+/// Returns the median of the non-null values of the array, i.e. its `0.5` [`quantile`]
+/// with [`QuantileInterpolation::Linear`].
+///
+/// Returns `None` iff the array has no non-null values.
+pub fn median<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    quantile(array, 0.5, QuantileInterpolation::Linear)
+}
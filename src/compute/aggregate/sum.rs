@@ -3,11 +3,12 @@ use std::ops::Add;
 use multiversion::multiversion;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::arity::unary;
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::Bitmap,
@@ -104,13 +105,65 @@ where
     }
 }
 
+/// Returns the sum of values in a [`f16`] array. The values are accumulated in `f32`
+/// for accuracy and the result is rounded to the nearest [`f16`].
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn sum_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    let array = unary(array, |x| x.to_f32(), DataType::Float32);
+    sum_primitive(&array).map(f16::from_f32)
+}
+
+/// Returns the sum of values in a decimal array.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values.
+/// # Errors
+/// Errors iff the logical type of `array` is not [`DataType::Decimal`] or the sum does not fit
+/// in the array's precision.
+pub fn sum_decimal(array: &PrimitiveArray<i128>) -> Result<Option<i128>> {
+    let precision = if let DataType::Decimal(precision, _) = array.data_type().to_logical_type() {
+        *precision
+    } else {
+        return Err(Error::InvalidArgumentError(format!(
+            "`sum_decimal` expects a decimal array, got `{:?}`",
+            array.data_type(),
+        )));
+    };
+
+    if array.null_count() == array.len() {
+        return Ok(None);
+    }
+
+    let sum = array
+        .iter()
+        .flatten()
+        .try_fold(0i128, |acc, x| acc.checked_add(*x))
+        .ok_or(Error::Overflow)?;
+
+    let max = 10i128.pow(precision as u32) - 1;
+    if sum.abs() > max {
+        return Err(Error::Overflow);
+    }
+    Ok(Some(sum))
+}
+
 /// Whether [`sum`] supports `data_type`
 pub fn can_sum(data_type: &DataType) -> bool {
     if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int32
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         false
@@ -144,9 +197,22 @@ macro_rules! with_match_primitive_type {(
 /// Returns the sum of all elements in `array` as a [`Scalar`] of the same physical
 /// and logical types as `array`.
 /// # Error
-/// Errors iff the operation is not supported.
+/// Errors iff the operation is not supported or, for decimals, the sum overflows the precision.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        let data_type = array.data_type().clone();
+        let array = array.as_any().downcast_ref().unwrap();
+        return Ok(Box::new(PrimitiveScalar::new(
+            data_type,
+            sum_decimal(array)?,
+        )));
+    }
     Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, sum_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
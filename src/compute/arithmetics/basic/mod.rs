@@ -20,9 +20,9 @@ pub use sub::*;
 
 use std::ops::Neg;
 
-use num_traits::{CheckedNeg, WrappingNeg};
+use num_traits::{CheckedNeg, Signed, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{array::PrimitiveArray, datatypes::DataType, types::NativeType};
 
 use super::super::arity::{unary, unary_checked};
 
@@ -99,3 +99,123 @@ where
 {
     unary(array, |a| a.wrapping_neg(), array.data_type().clone())
 }
+
+/// Returns the absolute value of each value of the array.
+/// # Panic
+/// This function panics in debug mode when the absolute value overflows, such as
+/// for `i32::MIN`. Use [`checked_abs`] to handle this case.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(0), Some(7)]);
+/// let result = abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), Some(0), Some(7)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed,
+{
+    unary(array, |a| a.abs(), array.data_type().clone())
+}
+
+/// Checked absolute value of each value of the array. Values without a positive
+/// counterpart (e.g. `i32::MIN`) are null.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(i8::MIN), Some(7)]);
+/// let result = checked_abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), None, Some(7)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn checked_abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed + CheckedNeg,
+{
+    unary_checked(
+        array,
+        |a| {
+            if a.is_negative() {
+                a.checked_neg()
+            } else {
+                Some(a)
+            }
+        },
+        array.data_type().clone(),
+    )
+}
+
+/// Returns the sign of each value of the array: `-1` for negative values, `1` for
+/// positive values and `0` for zero. `NaN` and `-0.0` have sign `0`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::sign;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6.5), Some(0.0), Some(7.0)]);
+/// let result = sign(&a);
+/// let expected = PrimitiveArray::from([None, Some(-1i8), Some(0), Some(1)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn sign<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<i8>
+where
+    T: NativeType + Signed + PartialOrd,
+{
+    let zero = T::zero();
+    unary(
+        array,
+        |a| {
+            if a > zero {
+                1
+            } else if a < zero {
+                -1
+            } else {
+                0
+            }
+        },
+        DataType::Int8,
+    )
+}
+
+/// Restricts each value of the array to the interval `[min, max]`.
+/// Values that are not comparable with `min` and `max` (e.g. `NaN`) are kept.
+/// # Panic
+/// This function panics iff `min > max`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::clamp;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(3), Some(17)]);
+/// let result = clamp(&a, 0, 10);
+/// let expected = PrimitiveArray::from([None, Some(0), Some(3), Some(10)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn clamp<T>(array: &PrimitiveArray<T>, min: T, max: T) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    assert!(min <= max, "clamp requires min <= max");
+    unary(
+        array,
+        |a| {
+            if a < min {
+                min
+            } else if a > max {
+                max
+            } else {
+                a
+            }
+        },
+        array.data_type().clone(),
+    )
+}
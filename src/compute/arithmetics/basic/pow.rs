@@ -1,9 +1,11 @@
 //! Definition of basic pow operations with primitive arrays
-use num_traits::{checked_pow, CheckedMul, One, Pow};
+use std::ops::Mul;
+
+use num_traits::{CheckedMul, Float, One, Pow};
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{unary, unary_checked},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
 };
 
 use super::NativeArithmetics;
@@ -46,7 +48,116 @@ pub fn checked_powf_scalar<T>(array: &PrimitiveArray<T>, exponent: usize) -> Pri
 where
     T: NativeArithmetics + CheckedMul + One,
 {
-    let op = move |a: T| checked_pow(a, exponent);
+    let op = move |a: T| num_traits::checked_pow(a, exponent);
 
     unary_checked(array, op, array.data_type().clone())
 }
+
+/// Raises an array of floats to the power of `exponent`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::pow_scalar;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(4f64), None]);
+/// let actual = pow_scalar(&a, 0.5);
+/// let expected = Float64Array::from(&[Some(2f64), None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn pow_scalar<T>(base: &PrimitiveArray<T>, exponent: T) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    unary(base, |x| x.powf(exponent), base.data_type().clone())
+}
+
+/// Raises each value of an array of floats to the power of the value in the
+/// same position of `exponent`.
+///
+/// # Panic
+/// This function panics iff the arrays have different lengths.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::pow;
+/// use arrow2::array::Float32Array;
+///
+/// let base = Float32Array::from(&[Some(2f32), Some(9f32), None]);
+/// let exponent = Float32Array::from(&[Some(3f32), None, Some(1f32)]);
+/// let actual = pow(&base, &exponent);
+/// let expected = Float32Array::from(&[Some(8f32), None, None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn pow<T>(base: &PrimitiveArray<T>, exponent: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    binary(base, exponent, base.data_type().clone(), |a, b| a.powf(b))
+}
+
+/// Checked operation of raising each value of an array of primitives to the
+/// power of the value in the same position of `exponent`, via repeated
+/// multiplication. If the result overflows, the validity for that index is
+/// changed to null.
+///
+/// # Panic
+/// This function panics iff the arrays have different lengths.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_pow;
+/// use arrow2::array::{Int8Array, UInt32Array};
+///
+/// let base = Int8Array::from(&[Some(2i8), Some(2i8), None]);
+/// let exponent = UInt32Array::from(&[Some(3u32), Some(8u32), Some(1u32)]);
+/// let actual = checked_pow(&base, &exponent);
+/// let expected = Int8Array::from(&[Some(8i8), None, None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn checked_pow<T>(base: &PrimitiveArray<T>, exponent: &PrimitiveArray<u32>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedMul + One,
+{
+    let op = |a: T, b: u32| num_traits::checked_pow(a, b as usize);
+
+    binary_checked(base, exponent, base.data_type().clone(), op)
+}
+
+/// Squares each value of an array of primitives. Panics if one of the values
+/// overflows.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::square;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(-3i32), None]);
+/// let actual = square(&a);
+/// let expected = Int32Array::from(&[Some(9i32), None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn square<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Mul<Output = T>,
+{
+    unary(array, |x| x * x, array.data_type().clone())
+}
+
+/// Checked operation of squaring each value of an array of primitives. If the
+/// result overflows, the validity for that index is changed to null.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_square;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-3i8), Some(12i8), None]);
+/// let actual = checked_square(&a);
+/// let expected = Int8Array::from(&[Some(9i8), None, None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn checked_square<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedMul,
+{
+    unary_checked(array, |x| x.checked_mul(&x), array.data_type().clone())
+}
@@ -12,14 +12,16 @@ use crate::{
     error::{Error, Result},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{
+    adjusted_precision_scale, align_scales, get_parameters, max_value, number_digits, Rescale,
+};
 
-/// Adds two decimal [`PrimitiveArray`] with the same precision and scale.
-/// # Error
-/// Errors if the precision and scale are different.
+/// Adds two decimal [`PrimitiveArray`]. If the precision and scale are different,
+/// the array with the smallest scale is rescaled to the largest scale and the
+/// result has the precision needed to hold both arrays (up to 38).
 /// # Panic
-/// This function panics iff the added numbers result in a number larger than
-/// the possible number for the precision.
+/// This function panics iff the added numbers, or a rescaled number, result in a
+/// number larger than the possible number for the precision.
 ///
 /// # Examples
 /// ```
@@ -36,10 +38,11 @@ use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
 /// assert_eq!(result, expected);
 /// ```
 pub fn add(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Panic);
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
-    let op = move |a, b| {
+    let op = move |a: i128, b: i128| {
         let res: i128 = a + b;
 
         assert!(
@@ -51,12 +54,12 @@ pub fn add(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveA
         res
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
-/// Saturated addition of two decimal primitive arrays with the same precision
-/// and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the sum is larger than
+/// Saturated addition of two decimal primitive arrays. If the precision and
+/// scale is different, then both arrays are rescaled to the largest scale as
+/// in [`add`]. If the result from the sum is larger than
 /// the possible number with the selected precision then the resulted number in
 /// the arrow array is the maximum number for the selected precision.
 ///
@@ -78,10 +81,11 @@ pub fn saturating_add(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Saturate);
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
-    let op = move |a, b| {
+    let op = move |a: i128, b: i128| {
         let res: i128 = a + b;
 
         if res.abs() > max {
@@ -95,12 +99,12 @@ pub fn saturating_add(
         }
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
-/// Checked addition of two decimal primitive arrays with the same precision
-/// and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the sum is larger than
+/// Checked addition of two decimal primitive arrays. If the precision and
+/// scale is different, then both arrays are rescaled to the largest scale as
+/// in [`add`]. If the result from the sum is larger than
 /// the possible number with the selected precision (overflowing), then the
 /// validity for that index is changed to None
 ///
@@ -119,11 +123,12 @@ pub fn saturating_add(
 /// assert_eq!(result, expected);
 /// ```
 pub fn checked_add(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Null);
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
-    let op = move |a, b| {
-        let result: i128 = a + b;
+    let op = move |a: i128, b: i128| {
+        let result = a.checked_add(b)?;
 
         if result.abs() > max {
             None
@@ -132,7 +137,7 @@ pub fn checked_add(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
         }
     };
 
-    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+    binary_checked(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
 // Implementation of ArrayAdd trait for PrimitiveArrays
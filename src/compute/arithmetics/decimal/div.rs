@@ -13,13 +13,17 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{
+    adjusted_precision_scale, align_scales, get_parameters, max_value, number_digits, parameters,
+    Rescale, MAX_PRECISION,
+};
 
-/// Divide two decimal primitive arrays with the same precision and scale. If
-/// the precision and scale is different, then an InvalidArgumentError is
-/// returned. This function panics if the dividend is divided by 0 or None.
-/// This function also panics if the division produces a number larger
-/// than the possible number for the array precision.
+/// Divide two decimal primitive arrays. If the precision and scale is
+/// different, then both arrays are rescaled to the largest scale, which is also
+/// the scale of the result. Use [`checked_div_with_scale`] to select the
+/// precision and scale of the result. This function panics if the dividend is divided by 0 or None.
+/// This function also panics if the division, or the rescaling, produces a number
+/// larger than the possible number for the array precision.
 ///
 /// # Examples
 /// ```
@@ -36,6 +40,7 @@ use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
 /// assert_eq!(result, expected);
 /// ```
 pub fn div(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Panic);
     let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let scale = 10i128.pow(scale as u32);
@@ -64,7 +69,7 @@ pub fn div(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveA
         res
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
 /// Multiply a decimal [`PrimitiveArray`] with a [`PrimitiveScalar`] with the same precision and scale. If
@@ -110,9 +115,9 @@ pub fn div_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> Pr
     unary(lhs, op, lhs.data_type().clone())
 }
 
-/// Saturated division of two decimal primitive arrays with the same
-/// precision and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the division is
+/// Saturated division of two decimal primitive arrays. If the precision and
+/// scale is different, then both arrays are rescaled to the largest scale as in
+/// [`div`]. If the result from the division is
 /// larger than the possible number with the selected precision then the
 /// resulted number in the arrow array is the maximum number for the selected
 /// precision. The function panics if divided by zero.
@@ -135,6 +140,7 @@ pub fn saturating_div(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Saturate);
     let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let scale = 10i128.pow(scale as u32);
@@ -158,13 +164,13 @@ pub fn saturating_div(
         }
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
-/// Checked division of two decimal primitive arrays with the same precision
-/// and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the divisor is zero, then the
-/// validity for that index is changed to None
+/// Checked division of two decimal primitive arrays. If the precision and
+/// scale is different, then both arrays are rescaled to the largest scale as in
+/// [`div`]. If the divisor is zero, then the validity for that index is
+/// changed to None
 ///
 /// # Examples
 /// ```
@@ -181,13 +187,14 @@ pub fn saturating_div(
 /// assert_eq!(result, expected);
 /// ```
 pub fn checked_div(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Null);
     let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let scale = 10i128.pow(scale as u32);
     let max = max_value(precision);
 
     let op = move |a: i128, b: i128| {
-        let numeral: i128 = a * scale;
+        let numeral = a.checked_mul(scale)?;
 
         match numeral.checked_div(b) {
             Some(res) => match res {
@@ -198,7 +205,88 @@ pub fn checked_div(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
         }
     };
 
-    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+    binary_checked(&lhs, &rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked division of two decimal primitive arrays of any precision and scale,
+/// returning an array with the given `precision` and `scale`. If the divisor is
+/// zero or the result does not fit in `precision`, then the validity for that
+/// index is changed to None.
+///
+/// # Errors
+/// Errors if `precision` is larger than 38, if `scale` is larger than
+/// `precision` or if the arrays are not decimals.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::checked_div_with_scale;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// // 1.00 / 3.0 = 0.3333
+/// let a = PrimitiveArray::from([Some(1_00i128), Some(1_00i128)]).to(DataType::Decimal(5, 2));
+/// let b = PrimitiveArray::from([Some(3_0i128), Some(0i128)]).to(DataType::Decimal(3, 1));
+///
+/// let result = checked_div_with_scale(&a, &b, 10, 4).unwrap();
+/// let expected = PrimitiveArray::from([Some(3333i128), None]).to(DataType::Decimal(10, 4));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_div_with_scale(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    precision: usize,
+    scale: usize,
+) -> Result<PrimitiveArray<i128>> {
+    if precision > MAX_PRECISION || scale > precision {
+        return Err(Error::InvalidArgumentError(format!(
+            "Decimal({}, {}) is not a valid decimal type",
+            precision, scale
+        )));
+    }
+    if !matches!(lhs.data_type().to_logical_type(), DataType::Decimal(_, _))
+        || !matches!(rhs.data_type().to_logical_type(), DataType::Decimal(_, _))
+    {
+        return Err(Error::InvalidArgumentError(
+            "Incorrect data type for the array".to_string(),
+        ));
+    }
+    let (_, lhs_s) = parameters(lhs.data_type());
+    let (_, rhs_s) = parameters(rhs.data_type());
+
+    // (a / 10^lhs_s) / (b / 10^rhs_s) = x / 10^scale, i.e.
+    // x = a * 10^(scale + rhs_s - lhs_s) / b
+    let exponent = (scale + rhs_s) as i64 - lhs_s as i64;
+    let factor = 10i128
+        .checked_pow(exponent.unsigned_abs() as u32)
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "The scale {} overflows the division of the arrays",
+                scale
+            ))
+        })?;
+
+    let max = max_value(precision);
+    let op = move |a: i128, b: i128| {
+        let numeral = if exponent >= 0 {
+            a.checked_mul(factor)?
+        } else {
+            a / factor
+        };
+        let res = numeral.checked_div(b)?;
+        if res.abs() > max {
+            None
+        } else {
+            Some(res)
+        }
+    };
+
+    Ok(binary_checked(
+        lhs,
+        rhs,
+        DataType::Decimal(precision, scale),
+        op,
+    ))
 }
 
 // Implementation of ArrayDiv trait for PrimitiveArrays
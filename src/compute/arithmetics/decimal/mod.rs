@@ -12,9 +12,14 @@ pub use mul::*;
 mod sub;
 pub use sub::*;
 
+use crate::array::PrimitiveArray;
+use crate::compute::arity::{unary, unary_checked};
 use crate::datatypes::DataType;
 use crate::error::{Error, Result};
 
+/// Maximum precision of a decimal represented by an `i128`
+const MAX_PRECISION: usize = 38;
+
 /// Maximum value that can exist with a selected precision
 #[inline]
 fn max_value(precision: usize) -> i128 {
@@ -51,6 +56,99 @@ fn get_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize)> {
     }
 }
 
+/// Returns the precision and scale of a decimal [`DataType`]
+fn parameters(data_type: &DataType) -> (usize, usize) {
+    if let DataType::Decimal(p, s) = data_type.to_logical_type() {
+        (*p, *s)
+    } else {
+        unreachable!()
+    }
+}
+
+/// How [`align_scales`] handles values that overflow when rescaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rescale {
+    /// Panics on overflow.
+    Panic,
+    /// Replaces the value by the largest (or smallest) value of the precision.
+    Saturate,
+    /// Replaces the value by a null.
+    Null,
+}
+
+/// Rescales `lhs` and `rhs` to a common precision and scale. The scale of the
+/// result is the largest of both scales and the precision is large enough to hold
+/// the digits before and after the point of both arrays, up to 38 digits.
+/// Values that overflow when rescaled are handled according to `overflow`.
+/// # Panic
+/// This function panics iff a value overflows and `overflow` is [`Rescale::Panic`].
+fn align_scales(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    overflow: Rescale,
+) -> (PrimitiveArray<i128>, PrimitiveArray<i128>) {
+    let (lhs_p, lhs_s) = parameters(lhs.data_type());
+    let (rhs_p, rhs_s) = parameters(rhs.data_type());
+    if lhs_p == rhs_p && lhs_s == rhs_s {
+        return (lhs.clone(), rhs.clone());
+    }
+
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let precision = res_p.min(MAX_PRECISION);
+    let data_type = DataType::Decimal(precision, res_s);
+    let max = max_value(precision);
+
+    let rescale = |array: &PrimitiveArray<i128>, diff: usize| {
+        if diff == 0 {
+            return array.clone().to(data_type.clone());
+        }
+        let shift = 10i128.pow(diff as u32);
+        let rescaled = move |x: i128| x.checked_mul(shift).filter(|x| x.abs() <= max);
+        match overflow {
+            Rescale::Panic => unary(
+                array,
+                |x| {
+                    rescaled(x).unwrap_or_else(|| {
+                        panic!(
+                            "Overflow in rescaling presented for precision {}",
+                            precision
+                        )
+                    })
+                },
+                data_type.clone(),
+            ),
+            Rescale::Saturate => unary(
+                array,
+                |x| rescaled(x).unwrap_or(if x > 0 { max } else { -max }),
+                data_type.clone(),
+            ),
+            Rescale::Null => unary_checked(array, rescaled, data_type.clone()),
+        }
+    };
+
+    if lhs_s > rhs_s {
+        (rescale(lhs, 0), rescale(rhs, diff))
+    } else {
+        (rescale(lhs, diff), rescale(rhs, 0))
+    }
+}
+
+/// Returns the precision and scale of the product of two decimals of types `lhs`
+/// and `rhs`, together with the divisor that must be applied to the product of the
+/// underlying integers to obtain it.
+///
+/// The precision and scale of the result are the ones of [`align_scales`]: the largest
+/// scale and a precision large enough to hold the digits before and after the point of
+/// both types, up to 38 digits.
+fn mul_parameters(lhs: &DataType, rhs: &DataType) -> (usize, usize, i128) {
+    let (lhs_p, lhs_s) = parameters(lhs);
+    let (rhs_p, rhs_s) = parameters(rhs);
+
+    let (precision, scale, _) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let divisor = 10i128.pow((lhs_s + rhs_s - scale) as u32);
+    (precision.min(MAX_PRECISION), scale, divisor)
+}
+
 /// Returns the adjusted precision and scale for the lhs and rhs precision and
 /// scale
 fn adjusted_precision_scale(
@@ -116,4 +214,19 @@ mod tests {
         // 11122.1211 -> 9, 4  ->   5        4
         assert_eq!((9, 4, 2), adjusted_precision_scale(5, 4, 7, 2))
     }
+
+    #[test]
+    fn test_mul_parameters() {
+        let a = DataType::Decimal(5, 2);
+        let b = DataType::Decimal(7, 3);
+        assert_eq!((5, 2, 100), mul_parameters(&a, &a));
+        assert_eq!((7, 3, 100), mul_parameters(&a, &b));
+
+        let c = DataType::Decimal(6, 2);
+        assert_eq!((6, 2, 100), mul_parameters(&a, &c));
+
+        let d = DataType::Decimal(38, 30);
+        let e = DataType::Decimal(30, 30);
+        assert_eq!((38, 30, 10i128.pow(30)), mul_parameters(&d, &e));
+    }
 }
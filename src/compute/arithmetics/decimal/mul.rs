@@ -13,12 +13,13 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, get_parameters, max_value, mul_parameters, number_digits};
 
-/// Multiply two decimal primitive arrays with the same precision and scale. If
-/// the precision and scale is different, then an InvalidArgumentError is
-/// returned. This function panics if the multiplied numbers result in a number
-/// larger than the possible number for the selected precision.
+/// Multiply two decimal primitive arrays. The scale of the result is the largest
+/// of both scales and its precision is large enough to hold the digits before and
+/// after the point of both arrays (up to 38), as in [`add`](fn@super::add). This function
+/// panics if the multiplied numbers result in a number larger than the possible number
+/// for the selected precision.
 ///
 /// # Examples
 /// ```
@@ -35,9 +36,8 @@ use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
 /// assert_eq!(result, expected);
 /// ```
 pub fn mul(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
-    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+    let (precision, scale, divisor) = mul_parameters(lhs.data_type(), rhs.data_type());
 
-    let scale = 10i128.pow(scale as u32);
     let max = max_value(precision);
 
     let op = move |a: i128, b: i128| {
@@ -54,7 +54,7 @@ pub fn mul(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveA
         //   222.222 -->      222222
         // --------          -------
         // 24691.308 <-- 24691308642
-        let res = res / scale;
+        let res = res / divisor;
 
         assert!(
             res.abs() <= max,
@@ -65,7 +65,7 @@ pub fn mul(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveA
         res
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(lhs, rhs, DataType::Decimal(precision, scale), op)
 }
 
 /// Multiply a decimal [`PrimitiveArray`] with a [`PrimitiveScalar`] with the same precision and scale. If
@@ -114,9 +114,8 @@ pub fn mul_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> Pr
     unary(lhs, op, lhs.data_type().clone())
 }
 
-/// Saturated multiplication of two decimal primitive arrays with the same
-/// precision and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the multiplication is
+/// Saturated multiplication of two decimal primitive arrays. The precision and
+/// scale of the result are the ones of [`mul`]. If the result from the multiplication is
 /// larger than the possible number with the selected precision then the
 /// resulted number in the arrow array is the maximum number for the selected
 /// precision.
@@ -139,14 +138,13 @@ pub fn saturating_mul(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> PrimitiveArray<i128> {
-    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+    let (precision, scale, divisor) = mul_parameters(lhs.data_type(), rhs.data_type());
 
-    let scale = 10i128.pow(scale as u32);
     let max = max_value(precision);
 
     let op = move |a: i128, b: i128| match a.checked_mul(b) {
         Some(res) => {
-            let res = res / scale;
+            let res = res / divisor;
 
             match res {
                 res if res.abs() > max => {
@@ -162,12 +160,11 @@ pub fn saturating_mul(
         None => max,
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(lhs, rhs, DataType::Decimal(precision, scale), op)
 }
 
-/// Checked multiplication of two decimal primitive arrays with the same
-/// precision and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the mul is larger than
+/// Checked multiplication of two decimal primitive arrays. The precision and
+/// scale of the result are the ones of [`mul`]. If the result from the mul is larger than
 /// the possible number with the selected precision (overflowing), then the
 /// validity for that index is changed to None
 ///
@@ -186,14 +183,13 @@ pub fn saturating_mul(
 /// assert_eq!(result, expected);
 /// ```
 pub fn checked_mul(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
-    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+    let (precision, scale, divisor) = mul_parameters(lhs.data_type(), rhs.data_type());
 
-    let scale = 10i128.pow(scale as u32);
     let max = max_value(precision);
 
     let op = move |a: i128, b: i128| match a.checked_mul(b) {
         Some(res) => {
-            let res = res / scale;
+            let res = res / divisor;
 
             match res {
                 res if res.abs() > max => None,
@@ -203,7 +199,7 @@ pub fn checked_mul(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
         None => None,
     };
 
-    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+    binary_checked(lhs, rhs, DataType::Decimal(precision, scale), op)
 }
 
 // Implementation of ArrayMul trait for PrimitiveArrays
@@ -11,12 +11,15 @@ use crate::{
     error::{Error, Result},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{
+    adjusted_precision_scale, align_scales, get_parameters, max_value, number_digits, Rescale,
+};
 
-/// Subtract two decimal primitive arrays with the same precision and scale. If
-/// the precision and scale is different, then an InvalidArgumentError is
-/// returned. This function panics if the subtracted numbers result in a number
-/// smaller than the possible number for the selected precision.
+/// Subtract two decimal primitive arrays. If the precision and scale is
+/// different, then the array with the smallest scale is rescaled to the largest
+/// scale and the result has the precision needed to hold both arrays (up to
+/// 38). This function panics if the subtracted numbers, or a rescaled number,
+/// result in a number outside the possible numbers for the selected precision.
 ///
 /// # Examples
 /// ```
@@ -33,11 +36,12 @@ use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
 /// assert_eq!(result, expected);
 /// ```
 pub fn sub(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Panic);
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
 
-    let op = move |a, b| {
+    let op = move |a: i128, b: i128| {
         let res: i128 = a - b;
 
         assert!(
@@ -49,12 +53,12 @@ pub fn sub(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveA
         res
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
-/// Saturated subtraction of two decimal primitive arrays with the same
-/// precision and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the sum is smaller
+/// Saturated subtraction of two decimal primitive arrays. If the precision and
+/// scale is different, then both arrays are rescaled to the largest scale as in
+/// [`sub`]. If the result from the sum is smaller
 /// than the possible number with the selected precision then the resulted
 /// number in the arrow array is the minimum number for the selected precision.
 ///
@@ -76,11 +80,12 @@ pub fn saturating_sub(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Saturate);
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
 
-    let op = move |a, b| {
+    let op = move |a: i128, b: i128| {
         let res: i128 = a - b;
 
         match res {
@@ -95,7 +100,7 @@ pub fn saturating_sub(
         }
     };
 
-    binary(lhs, rhs, lhs.data_type().clone(), op)
+    binary(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
 // Implementation of ArraySub trait for PrimitiveArrays
@@ -118,9 +123,9 @@ impl ArraySaturatingSub<PrimitiveArray<i128>> for PrimitiveArray<i128> {
         saturating_sub(self, rhs)
     }
 }
-/// Checked subtract of two decimal primitive arrays with the same precision
-/// and scale. If the precision and scale is different, then an
-/// InvalidArgumentError is returned. If the result from the sub is larger than
+/// Checked subtract of two decimal primitive arrays. If the precision and
+/// scale is different, then both arrays are rescaled to the largest scale as in
+/// [`sub`]. If the result from the sub is larger than
 /// the possible number with the selected precision (overflowing), then the
 /// validity for that index is changed to None
 ///
@@ -139,12 +144,13 @@ impl ArraySaturatingSub<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 /// assert_eq!(result, expected);
 /// ```
 pub fn checked_sub(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let (lhs, rhs) = align_scales(lhs, rhs, Rescale::Null);
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
 
-    let op = move |a, b| {
-        let res: i128 = a - b;
+    let op = move |a: i128, b: i128| {
+        let res = a.checked_sub(b)?;
 
         match res {
             res if res.abs() > max => None,
@@ -152,7 +158,7 @@ pub fn checked_sub(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
         }
     };
 
-    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+    binary_checked(&lhs, &rhs, lhs.data_type().clone(), op)
 }
 
 /// Adaptive subtract of two decimal primitive arrays with different precision
@@ -0,0 +1,129 @@
+//! Defines the addition and subtraction kernels for [`PrimitiveArray`] representing
+//! 256-bit decimals. The [`Decimal256`](crate::datatypes::DataType::Decimal256) type
+//! specifies the precision (up to 76) and scale of the values, and both arrays must
+//! have the same precision and scale.
+use crate::{
+    array::PrimitiveArray,
+    compute::arity::{binary, binary_checked},
+    datatypes::DataType,
+    types::i256,
+};
+
+/// Returns the precision of two [`DataType::Decimal256`] with the same precision and scale.
+/// # Panic
+/// This function panics iff the precision or scale of `lhs` and `rhs` differ.
+fn get_precision(lhs: &DataType, rhs: &DataType) -> usize {
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (DataType::Decimal256(lhs_p, lhs_s), DataType::Decimal256(rhs_p, rhs_s)) => {
+            assert!(
+                lhs_p == rhs_p && lhs_s == rhs_s,
+                "Arrays must have the same precision and scale"
+            );
+            *lhs_p
+        }
+        _ => panic!("internal error: i256 is always a decimal"),
+    }
+}
+
+/// Maximum value that can exist with a selected precision. Precisions that exceed
+/// the digits of an `i256` are bounded by [`ethnum::I256::MAX`].
+#[inline]
+fn max_value(precision: usize) -> ethnum::I256 {
+    ethnum::I256::new(10)
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(ethnum::I256::MAX)
+}
+
+/// Adds two [`PrimitiveArray<i256>`] of the same precision and scale.
+/// # Panic
+/// This function panics iff the arrays have a different precision or scale, or
+/// the added numbers result in a number larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from(1)), None, Some(i256::from(-2))]).to(DataType::Decimal256(50, 2));
+/// let b = PrimitiveArray::from([Some(i256::from(1)), None, Some(i256::from(1))]).to(DataType::Decimal256(50, 2));
+///
+/// let result = add(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from(2)), None, Some(i256::from(-1))]).to(DataType::Decimal256(50, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let precision = get_precision(lhs.data_type(), rhs.data_type());
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res =
+            a.0.checked_add(b.0)
+                .filter(|res| *res >= -max && *res <= max)
+                .unwrap_or_else(|| {
+                    panic!("Overflow in addition presented for precision {}", precision)
+                });
+        i256(res)
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked addition of two [`PrimitiveArray<i256>`] of the same precision and scale.
+/// Values whose sum does not fit in the precision (overflowing) are null.
+/// # Panic
+/// This function panics iff the arrays have a different precision or scale.
+pub fn checked_add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let precision = get_precision(lhs.data_type(), rhs.data_type());
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        a.0.checked_add(b.0)
+            .filter(|res| *res >= -max && *res <= max)
+            .map(i256)
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Subtracts two [`PrimitiveArray<i256>`] of the same precision and scale.
+/// # Panic
+/// This function panics iff the arrays have a different precision or scale, or
+/// the subtracted numbers result in a number smaller than the possible number for
+/// the precision.
+pub fn sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let precision = get_precision(lhs.data_type(), rhs.data_type());
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res =
+            a.0.checked_sub(b.0)
+                .filter(|res| *res >= -max && *res <= max)
+                .unwrap_or_else(|| {
+                    panic!("Overflow in subtract presented for precision {}", precision)
+                });
+        i256(res)
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked subtraction of two [`PrimitiveArray<i256>`] of the same precision and scale.
+/// Values whose difference does not fit in the precision (overflowing) are null.
+/// # Panic
+/// This function panics iff the arrays have a different precision or scale.
+pub fn checked_sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let precision = get_precision(lhs.data_type(), rhs.data_type());
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        a.0.checked_sub(b.0)
+            .filter(|res| *res >= -max && *res <= max)
+            .map(i256)
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
@@ -14,6 +14,7 @@
 #[forbid(unsafe_code)]
 pub mod basic;
 pub mod decimal;
+pub mod decimal256;
 pub mod time;
 
 use crate::{
@@ -37,7 +38,7 @@ fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> Pr
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, decimal256 = $op_decimal256:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -62,6 +63,13 @@ macro_rules! arith {
             }
             )?
             $ (
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(decimal256::$op_decimal256(lhs, rhs)) as Box<dyn Array>
+            }
+            )?
+            $ (
             (Time32(TimeUnit::Second), Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
             | (Date32, Duration(_)) => {
@@ -202,6 +210,8 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         lhs,
         rhs,
         add,
+        decimal = add,
+        decimal256 = add,
         duration = add_duration,
         interval = add_interval
     )
@@ -248,6 +258,9 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
             | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+    ) || matches!(
+        (lhs, rhs),
+        (Decimal256(lhs_p, lhs_s), Decimal256(rhs_p, rhs_s)) if lhs_p == rhs_p && lhs_s == rhs_s
     )
 }
 
@@ -263,6 +276,7 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         rhs,
         sub,
         decimal = sub,
+        decimal256 = sub,
         duration = subtract_duration,
         timestamp = subtract_timestamps
     )
@@ -309,6 +323,9 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
             | (Timestamp(_, None), Timestamp(_, None))
+    ) || matches!(
+        (lhs, rhs),
+        (Decimal256(lhs_p, lhs_s), Decimal256(rhs_p, rhs_s)) if lhs_p == rhs_p && lhs_s == rhs_s
     )
 }
 
@@ -15,7 +15,10 @@ use num_traits::AsPrimitive;
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{binary, unary},
+    compute::{
+        arity::{binary, unary},
+        utils::check_same_len,
+    },
     datatypes::{DataType, TimeUnit},
     error::{Error, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -261,9 +264,11 @@ pub fn subtract_timestamps(
     // Both timestamps have a Timeunit enum in its data type.
     // This enum is used to adjust the scale between the timestamps.
     match (lhs.data_type(), rhs.data_type()) {
-        // Naive timestamp comparison. It doesn't take into account timezones
-        // from the Timestamp timeunit.
-        (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) => {
+        // Timestamps are stored in UTC, so the difference between two timestamps
+        // in the same timezone does not depend on the timezone itself.
+        (DataType::Timestamp(timeunit_a, tz_a), DataType::Timestamp(timeunit_b, tz_b))
+            if tz_a == tz_b =>
+        {
             // Closure for the binary operation. The closure contains the scale
             // required to calculate the difference between the timestamps.
             let scale = temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b);
@@ -272,7 +277,8 @@ pub fn subtract_timestamps(
             Ok(binary(lhs, rhs, DataType::Duration(*timeunit_a), op))
         }
         _ => Err(Error::InvalidArgumentError(
-            "Incorrect data type for the arguments".to_string(),
+            "subtract_timestamps requires both arguments to be timestamps with the same timezone"
+                .to_string(),
         )),
     }
 }
@@ -282,26 +288,26 @@ pub fn sub_timestamps_scalar(
     lhs: &PrimitiveArray<i64>,
     rhs: &PrimitiveScalar<i64>,
 ) -> Result<PrimitiveArray<i64>> {
-    let (scale, timeunit_a) =
-        if let (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) =
-            (lhs.data_type(), rhs.data_type())
+    let (scale, timeunit_a) = match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Timestamp(timeunit_a, tz_a), DataType::Timestamp(timeunit_b, tz_b))
+            if tz_a == tz_b =>
         {
             (
                 temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b),
                 timeunit_a,
             )
-        } else {
-            return Err(Error::InvalidArgumentError(
-                "sub_timestamps_scalar requires both arguments to be timestamps without timezone"
-                    .to_string(),
-            ));
-        };
+        }
+        _ => return Err(Error::InvalidArgumentError(
+            "sub_timestamps_scalar requires both arguments to be timestamps with the same timezone"
+                .to_string(),
+        )),
+    };
 
     let rhs = if let Some(value) = *rhs.value() {
         value
     } else {
         return Ok(PrimitiveArray::<i64>::new_null(
-            lhs.data_type().clone(),
+            DataType::Duration(*timeunit_a),
             lhs.len(),
         ));
     };
@@ -312,10 +318,19 @@ pub fn sub_timestamps_scalar(
 }
 
 /// Adds an interval to a [`DataType::Timestamp`].
+///
+/// Months are added in calendar time and clamped to the last day of the resulting month
+/// (e.g. `2021-01-31 + 1 month = 2021-02-28`). For timestamps with a timezone, months and days
+/// are added in the local time of the timezone, so that adding a day across a daylight saving
+/// time transition preserves the local time of the day.
+/// # Errors
+/// This function errors if `timestamp` is not a [`DataType::Timestamp`], its timezone
+/// cannot be parsed or the arrays have different lengths.
 pub fn add_interval(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveArray<months_days_ns>,
 ) -> Result<PrimitiveArray<i64>> {
+    check_same_len(timestamp, interval)?;
     match timestamp.data_type().to_logical_type() {
         DataType::Timestamp(time_unit, Some(timezone_str)) => {
             let time_unit = *time_unit;
@@ -432,3 +447,31 @@ pub fn add_interval_scalar(
         )),
     }
 }
+
+/// Subtracts an interval from a [`DataType::Timestamp`].
+///
+/// This is equivalent to [`add_interval`] with the negated interval.
+/// # Errors
+/// This function errors if `timestamp` is not a [`DataType::Timestamp`], its timezone
+/// cannot be parsed or the arrays have different lengths.
+pub fn sub_interval(
+    timestamp: &PrimitiveArray<i64>,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> Result<PrimitiveArray<i64>> {
+    let interval = unary(interval, |x| -x, interval.data_type().clone());
+    add_interval(timestamp, &interval)
+}
+
+/// Subtracts an interval from a [`DataType::Timestamp`].
+///
+/// This is equivalent to [`add_interval_scalar`] with the negated interval.
+/// # Errors
+/// This function errors if `timestamp` is not a [`DataType::Timestamp`] or its timezone
+/// cannot be parsed.
+pub fn sub_interval_scalar(
+    timestamp: &PrimitiveArray<i64>,
+    interval: &PrimitiveScalar<months_days_ns>,
+) -> Result<PrimitiveArray<i64>> {
+    let interval = PrimitiveScalar::new(interval.data_type().clone(), interval.value().map(|x| -x));
+    add_interval_scalar(timestamp, &interval)
+}
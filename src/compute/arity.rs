@@ -30,6 +30,22 @@ where
     PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
 }
 
+/// Applies an unary and infallible function to a [`PrimitiveArray`], consuming it and
+/// writing the results to its values in place when they are not shared.
+///
+/// # Implementation
+/// When the values of `array` are shared (e.g. `array` was cloned or sliced), this allocates
+/// a new region, like [`unary`]. See [`super::arity_assign::unary`].
+#[inline]
+pub fn unary_mut<T, F>(mut array: PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T) -> T,
+{
+    super::arity_assign::unary(&mut array, op);
+    array
+}
+
 /// Version of unary that checks for errors in the closure used to create the
 /// buffer
 pub fn try_unary<I, F, O>(
@@ -168,6 +184,35 @@ where
     PrimitiveArray::<T>::new(data_type, values, validity)
 }
 
+/// Applies a binary and infallible function to two [`PrimitiveArray`]s, consuming them and
+/// writing the results in place to the values of whichever of them is not shared (`lhs`
+/// first). The result has the [`DataType`] of `lhs`.
+///
+/// # Implementation
+/// When the values of both arrays are shared (e.g. they were cloned or sliced), this
+/// allocates a new region, like [`binary`]. See [`super::arity_assign::binary`].
+/// # Panics
+/// This function panics iff the arrays have a different length.
+#[inline]
+pub fn binary_mut<T, F>(
+    mut lhs: PrimitiveArray<T>,
+    mut rhs: PrimitiveArray<T>,
+    op: F,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    if lhs.get_mut_values().is_none() && rhs.get_mut_values().is_some() {
+        let data_type = lhs.data_type().clone();
+        super::arity_assign::binary(&mut rhs, &lhs, |r, l| op(l, r));
+        rhs.to(data_type)
+    } else {
+        super::arity_assign::binary(&mut lhs, &rhs, op);
+        lhs
+    }
+}
+
 /// Version of binary that checks for errors in the closure used to create the
 /// buffer
 pub fn try_binary<T, D, F>(
@@ -1,8 +1,12 @@
-//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
+//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`], the [`popcount`]
+//! kernel and the aggregations [`bit_and_agg`], [`bit_or_agg`] and [`bit_xor_agg`].
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use crate::array::PrimitiveArray;
+use num_traits::PrimInt;
+
+use crate::array::{Array, PrimitiveArray};
 use crate::compute::arity::{binary, unary};
+use crate::datatypes::DataType;
 use crate::types::NativeType;
 
 /// Performs `OR` operation on two [`PrimitiveArray`]s.
@@ -73,3 +77,49 @@ where
 {
     unary(lhs, |a| a & *rhs, lhs.data_type().clone())
 }
+
+/// Returns a new [`PrimitiveArray`] with the number of ones in the binary representation
+/// of each value.
+pub fn popcount<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<u32>
+where
+    T: NativeType + PrimInt,
+{
+    unary(array, |a| a.count_ones(), DataType::UInt32)
+}
+
+/// Reduces the non-null values of `array` with `op`, or returns `None` if there are none.
+fn reduce<T, F>(array: &PrimitiveArray<T>, op: F) -> Option<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    if array.null_count() == 0 {
+        array.values().iter().copied().reduce(op)
+    } else {
+        array.iter().flatten().copied().reduce(op)
+    }
+}
+
+/// Returns the `AND` of all non-null values of `array`, or `None` if there are none.
+pub fn bit_and_agg<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + BitAnd<Output = T>,
+{
+    reduce(array, |a, b| a & b)
+}
+
+/// Returns the `OR` of all non-null values of `array`, or `None` if there are none.
+pub fn bit_or_agg<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + BitOr<Output = T>,
+{
+    reduce(array, |a, b| a | b)
+}
+
+/// Returns the `XOR` of all non-null values of `array`, or `None` if there are none.
+pub fn bit_xor_agg<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + BitXor<Output = T>,
+{
+    reduce(array, |a, b| a ^ b)
+}
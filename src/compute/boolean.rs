@@ -2,8 +2,10 @@
 use crate::array::{Array, BooleanArray};
 use crate::bitmap::{Bitmap, MutableBitmap};
 use crate::datatypes::DataType;
-use crate::scalar::BooleanScalar;
+use crate::error::{Error, Result};
+use crate::scalar::{BooleanScalar, Scalar};
 
+use super::boolean_kleene;
 use super::utils::combine_validities;
 
 fn assert_lengths(lhs: &BooleanArray, rhs: &BooleanArray) {
@@ -251,3 +253,143 @@ pub fn all(array: &BooleanArray) -> bool {
         vals.unset_bits() == 0
     }
 }
+
+/// How the dynamically typed boolean kernels, such as [`and_dyn`], handle nulls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NullLogic {
+    /// A null operand makes the result null, like [`and`] and [`or`]; [`any`] and [`all`]
+    /// ignore nulls and treat them as `false` respectively (default).
+    #[default]
+    Propagate,
+    /// SQL's three-valued logic of [`boolean_kleene`], e.g. `false AND null` is `false`.
+    Kleene,
+}
+
+fn as_boolean(array: &dyn Array) -> Result<&BooleanArray> {
+    array
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "Boolean kernels require arrays of type Boolean, not {:?}",
+                array.data_type()
+            ))
+        })
+}
+
+fn as_boolean_scalar(scalar: &dyn Scalar) -> Result<&BooleanScalar> {
+    scalar
+        .as_any()
+        .downcast_ref::<BooleanScalar>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "Boolean kernels require scalars of type Boolean, not {:?}",
+                scalar.data_type()
+            ))
+        })
+}
+
+/// Performs `AND` on two [`Array`]s of type [`DataType::Boolean`] with the null handling of
+/// `logic`.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean::{and_dyn, NullLogic};
+///
+/// let a = BooleanArray::from(&[Some(false), Some(true), None]);
+/// let b = BooleanArray::from(&[None, None, Some(false)]);
+/// let result = and_dyn(&a, &b, NullLogic::Kleene).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(false), None, Some(false)]));
+/// ```
+/// # Errors
+/// This function errors iff either array is not a [`BooleanArray`].
+/// # Panics
+/// This function panics iff the arrays have different lengths.
+pub fn and_dyn(lhs: &dyn Array, rhs: &dyn Array, logic: NullLogic) -> Result<BooleanArray> {
+    let (lhs, rhs) = (as_boolean(lhs)?, as_boolean(rhs)?);
+    Ok(match logic {
+        NullLogic::Propagate => and(lhs, rhs),
+        NullLogic::Kleene => boolean_kleene::and(lhs, rhs),
+    })
+}
+
+/// Performs `OR` on two [`Array`]s of type [`DataType::Boolean`] with the null handling of
+/// `logic`.
+/// # Errors
+/// This function errors iff either array is not a [`BooleanArray`].
+/// # Panics
+/// This function panics iff the arrays have different lengths.
+pub fn or_dyn(lhs: &dyn Array, rhs: &dyn Array, logic: NullLogic) -> Result<BooleanArray> {
+    let (lhs, rhs) = (as_boolean(lhs)?, as_boolean(rhs)?);
+    Ok(match logic {
+        NullLogic::Propagate => or(lhs, rhs),
+        NullLogic::Kleene => boolean_kleene::or(lhs, rhs),
+    })
+}
+
+/// Performs `AND` on an [`Array`] and a [`Scalar`] of type [`DataType::Boolean`] with the
+/// null handling of `logic`.
+/// # Errors
+/// This function errors iff `array` is not a [`BooleanArray`] or `scalar` is not a
+/// [`BooleanScalar`].
+pub fn and_scalar_dyn(
+    array: &dyn Array,
+    scalar: &dyn Scalar,
+    logic: NullLogic,
+) -> Result<BooleanArray> {
+    let (array, scalar) = (as_boolean(array)?, as_boolean_scalar(scalar)?);
+    Ok(match logic {
+        NullLogic::Propagate => and_scalar(array, scalar),
+        NullLogic::Kleene => boolean_kleene::and_scalar(array, scalar),
+    })
+}
+
+/// Performs `OR` on an [`Array`] and a [`Scalar`] of type [`DataType::Boolean`] with the
+/// null handling of `logic`.
+/// # Errors
+/// This function errors iff `array` is not a [`BooleanArray`] or `scalar` is not a
+/// [`BooleanScalar`].
+pub fn or_scalar_dyn(
+    array: &dyn Array,
+    scalar: &dyn Scalar,
+    logic: NullLogic,
+) -> Result<BooleanArray> {
+    let (array, scalar) = (as_boolean(array)?, as_boolean_scalar(scalar)?);
+    Ok(match logic {
+        NullLogic::Propagate => or_scalar(array, scalar),
+        NullLogic::Kleene => boolean_kleene::or_scalar(array, scalar),
+    })
+}
+
+/// Returns whether any of the values of an [`Array`] of type [`DataType::Boolean`] is
+/// `true`, with the null handling of `logic`: [`any`] or [`boolean_kleene::any_kleene`].
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean::{any_dyn, NullLogic};
+///
+/// let array = BooleanArray::from(&[Some(false), None]);
+/// assert_eq!(any_dyn(&array, NullLogic::Propagate).unwrap(), Some(false));
+/// assert_eq!(any_dyn(&array, NullLogic::Kleene).unwrap(), None);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a [`BooleanArray`].
+pub fn any_dyn(array: &dyn Array, logic: NullLogic) -> Result<Option<bool>> {
+    let array = as_boolean(array)?;
+    Ok(match logic {
+        NullLogic::Propagate => Some(any(array)),
+        NullLogic::Kleene => boolean_kleene::any_kleene(array),
+    })
+}
+
+/// Returns whether all values of an [`Array`] of type [`DataType::Boolean`] are `true`,
+/// with the null handling of `logic`: [`all`] or [`boolean_kleene::all_kleene`].
+/// # Errors
+/// This function errors iff `array` is not a [`BooleanArray`].
+pub fn all_dyn(array: &dyn Array, logic: NullLogic) -> Result<Option<bool>> {
+    let array = as_boolean(array)?;
+    Ok(match logic {
+        NullLogic::Propagate => Some(all(array)),
+        NullLogic::Kleene => boolean_kleene::all_kleene(array),
+    })
+}
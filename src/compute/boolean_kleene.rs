@@ -234,24 +234,77 @@ pub fn and_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray
     }
 }
 
-/// Returns whether any of the values in the array is `true`
+/// Returns whether any of the values in the array is `true`.
+///
+/// Nulls are ignored; see [`any_kleene`] for the Kleene logic version.
 pub fn any(array: &BooleanArray) -> bool {
-    if array.is_empty() {
-        false
-    } else if array.validity().is_some() {
-        array.into_iter().any(|v| v == Some(true))
+    any_kleene(array) == Some(true)
+}
+
+/// Returns whether all values in the array are `true`.
+///
+/// Returns `false` if the array is empty or has any null; see [`all_kleene`] for the
+/// Kleene logic version.
+pub fn all(array: &BooleanArray) -> bool {
+    !array.is_empty() && all_kleene(array) == Some(true)
+}
+
+/// Returns whether any of the values in the array is `true` with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics),
+/// i.e. `Some(true)` if any value is `true`, `None` if otherwise any value is null and
+/// `Some(false)` otherwise (including when the array is empty).
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::any_kleene;
+///
+/// assert_eq!(any_kleene(&BooleanArray::from(&[Some(false), Some(true), None])), Some(true));
+/// assert_eq!(any_kleene(&BooleanArray::from(&[Some(false), None])), None);
+/// assert_eq!(any_kleene(&BooleanArray::from(&[Some(false), Some(false)])), Some(false));
+/// ```
+pub fn any_kleene(array: &BooleanArray) -> Option<bool> {
+    let (valid_true, null_count) = count_valid_true(array);
+    if valid_true > 0 {
+        Some(true)
+    } else if null_count > 0 {
+        None
     } else {
-        let vals = array.values();
-        vals.unset_bits() != vals.len()
+        Some(false)
     }
 }
 
-/// Returns whether all values in the array are `true`
-pub fn all(array: &BooleanArray) -> bool {
-    if array.is_empty() || array.null_count() > 0 {
-        false
+/// Returns whether all values in the array are `true` with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics),
+/// i.e. `Some(false)` if any value is `false`, `None` if otherwise any value is null and
+/// `Some(true)` otherwise (including when the array is empty).
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::all_kleene;
+///
+/// assert_eq!(all_kleene(&BooleanArray::from(&[Some(false), Some(true), None])), Some(false));
+/// assert_eq!(all_kleene(&BooleanArray::from(&[Some(true), None])), None);
+/// assert_eq!(all_kleene(&BooleanArray::from(&[Some(true), Some(true)])), Some(true));
+/// ```
+pub fn all_kleene(array: &BooleanArray) -> Option<bool> {
+    let (valid_true, null_count) = count_valid_true(array);
+    if valid_true + null_count < array.len() {
+        Some(false)
+    } else if null_count > 0 {
+        None
     } else {
-        let vals = array.values();
-        vals.unset_bits() == 0
+        Some(true)
+    }
+}
+
+/// Returns the number of non-null `true` values and the number of nulls of `array`.
+fn count_valid_true(array: &BooleanArray) -> (usize, usize) {
+    let values = array.values();
+    match array.validity() {
+        Some(validity) if validity.unset_bits() > 0 => {
+            let valid_true = values.len() - (values & validity).unset_bits();
+            (valid_true, validity.unset_bits())
+        }
+        _ => (values.len() - values.unset_bits(), 0),
     }
 }
@@ -1,4 +1,5 @@
-use crate::error::Result;
+use crate::bitmap::Bitmap;
+use crate::error::{Error, Result};
 use crate::offset::Offset;
 use crate::{array::*, datatypes::DataType, types::NativeType};
 
@@ -56,6 +57,60 @@ pub fn binary_to_large_utf8(
     Utf8Array::<i64>::try_new(to_data_type, offsets, values, from.validity().cloned())
 }
 
+/// Returns whether each of the values of `array` is valid utf8. Validity is preserved.
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, BooleanArray};
+/// use arrow2::compute::cast::is_utf8_valid;
+///
+/// let array = BinaryArray::<i32>::from([Some(&b"a"[..]), None, Some(&[0xffu8][..])]);
+/// let expected = BooleanArray::from([Some(true), None, Some(false)]);
+/// assert_eq!(is_utf8_valid(&array), expected);
+/// ```
+pub fn is_utf8_valid<O: Offset>(array: &BinaryArray<O>) -> BooleanArray {
+    let values = array
+        .values_iter()
+        .map(|x| std::str::from_utf8(x).is_ok())
+        .collect::<Bitmap>();
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Converts a [`BinaryArray`] to a [`Utf8Array`] of the same offset type.
+/// Unlike [`binary_to_utf8`], values in null slots are not required to be valid utf8.
+/// # Errors
+/// This function errors iff a non-null value is not valid utf8, reporting the index of the
+/// first such value and the position of its first invalid byte.
+pub fn try_cast_binary_to_utf8<O: Offset>(array: &BinaryArray<O>) -> Result<Utf8Array<O>> {
+    let mut null_slots_are_utf8 = true;
+    for (index, value) in array.values_iter().enumerate() {
+        if let Err(error) = std::str::from_utf8(value) {
+            if array.is_valid(index) {
+                return Err(Error::InvalidArgumentError(format!(
+                    "The value at index {} is not valid utf8: invalid byte at position {}",
+                    index,
+                    error.valid_up_to()
+                )));
+            }
+            null_slots_are_utf8 = false;
+        }
+    }
+
+    if null_slots_are_utf8 {
+        let data_type = if O::IS_LARGE {
+            DataType::LargeUtf8
+        } else {
+            DataType::Utf8
+        };
+        binary_to_utf8(array, data_type)
+    } else {
+        // the values of null slots can't be re-used
+        let iter = array
+            .iter()
+            .map(|x| x.map(|x| std::str::from_utf8(x).unwrap()));
+        Ok(Utf8Array::<O>::from_trusted_len_iter(iter))
+    }
+}
+
 /// Casts a [`BinaryArray`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
 pub fn partial_binary_to_primitive<O: Offset, T>(
     from: &BinaryArray<O>,
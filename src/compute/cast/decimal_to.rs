@@ -1,9 +1,11 @@
 use num_traits::{AsPrimitive, Float, NumCast};
 
 use crate::error::Result;
-use crate::types::NativeType;
+use crate::types::{i256, NativeType};
 use crate::{array::*, datatypes::DataType};
 
+use super::{check_cast, CastOptions};
+
 #[inline]
 fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
     from: &PrimitiveArray<i128>,
@@ -74,9 +76,149 @@ pub(super) fn decimal_to_decimal_dyn(
     from: &dyn Array,
     to_precision: usize,
     to_scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_decimal(from, to_precision, to_scale)))
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(
+        from,
+        Box::new(decimal_to_decimal(array, to_precision, to_scale)),
+        options,
+    )
+}
+
+/// Returns the precision and scale of a [`DataType::Decimal`] or [`DataType::Decimal256`]
+fn decimal_parameters(data_type: &DataType) -> (usize, usize) {
+    match data_type.to_logical_type() {
+        DataType::Decimal(p, s) | DataType::Decimal256(p, s) => (*p, *s),
+        _ => panic!("internal error: the array must be a decimal"),
+    }
+}
+
+/// Returns a function that rescales a 256-bit decimal from `from_scale` to `to_scale`,
+/// returning `None` when the result does not fit in `to_precision` digits.
+fn rescale_i256(
+    from_scale: usize,
+    to_precision: usize,
+    to_scale: usize,
+) -> impl Fn(ethnum::I256) -> Option<ethnum::I256> {
+    let ten = ethnum::I256::new(10);
+    // a precision of 77 digits or more is never reached by an `i256`
+    let max = ten
+        .checked_pow(to_precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(ethnum::I256::MAX);
+    let (factor, upscale) = if from_scale > to_scale {
+        (ten.checked_pow((from_scale - to_scale) as u32), false)
+    } else {
+        (ten.checked_pow((to_scale - from_scale) as u32), true)
+    };
+    move |x| {
+        let x = match (factor, upscale) {
+            (Some(factor), true) => x.checked_mul(factor)?,
+            (Some(factor), false) => x / factor,
+            // the factor itself overflows: only zero can be rescaled
+            (None, true) => (x == 0).then_some(x)?,
+            (None, false) => ethnum::I256::ZERO,
+        };
+        (x >= -max && x <= max).then_some(x)
+    }
+}
+
+/// Returns a [`PrimitiveArray<i256>`] of [`DataType::Decimal256`] with the casted values.
+/// Values are `None` on overflow
+pub fn decimal_to_decimal256(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let (_, from_scale) = decimal_parameters(from.data_type());
+    let op = rescale_i256(from_scale, to_precision, to_scale);
+
+    let values = from
+        .iter()
+        .map(|x| x.and_then(|x| op(ethnum::I256::new(*x)).map(i256)));
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(DataType::Decimal256(to_precision, to_scale))
+}
+
+pub(super) fn decimal_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(
+        from,
+        Box::new(decimal_to_decimal256(array, to_precision, to_scale)),
+        options,
+    )
+}
+
+/// Returns a [`PrimitiveArray<i128>`] of [`DataType::Decimal`] with the casted values.
+/// Values are `None` on overflow
+pub fn decimal256_to_decimal(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i128> {
+    let (_, from_scale) = decimal_parameters(from.data_type());
+    let op = rescale_i256(from_scale, to_precision, to_scale);
+
+    let values = from
+        .iter()
+        .map(|x| x.and_then(|x| op(x.0).and_then(|x| i128::try_from(x).ok())));
+    PrimitiveArray::<i128>::from_trusted_len_iter(values)
+        .to(DataType::Decimal(to_precision, to_scale))
+}
+
+pub(super) fn decimal256_to_decimal_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(
+        from,
+        Box::new(decimal256_to_decimal(array, to_precision, to_scale)),
+        options,
+    )
+}
+
+/// Returns a [`PrimitiveArray<i256>`] of [`DataType::Decimal256`] with the casted values.
+/// Values are `None` on overflow
+pub fn decimal256_to_decimal256(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let (from_precision, from_scale) = decimal_parameters(from.data_type());
+    if to_scale == from_scale && to_precision >= from_precision {
+        // fast path
+        return from
+            .clone()
+            .to(DataType::Decimal256(to_precision, to_scale));
+    }
+    let op = rescale_i256(from_scale, to_precision, to_scale);
+
+    let values = from.iter().map(|x| x.and_then(|x| op(x.0).map(i256)));
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(DataType::Decimal256(to_precision, to_scale))
+}
+
+pub(super) fn decimal256_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(
+        from,
+        Box::new(decimal256_to_decimal256(array, to_precision, to_scale)),
+        options,
+    )
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
@@ -127,10 +269,13 @@ where
     PrimitiveArray::from_trusted_len_iter(values)
 }
 
-pub(super) fn decimal_to_integer_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+pub(super) fn decimal_to_integer_dyn<T>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
 where
     T: NativeType + NumCast,
 {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_integer::<T>(from)))
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(from, Box::new(decimal_to_integer::<T>(array)), options)
 }
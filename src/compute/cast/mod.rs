@@ -16,13 +16,16 @@ pub use utf8_to::*;
 
 use crate::{
     array::*,
+    bitmap::MutableBitmap,
+    compute::take,
     datatypes::*,
     error::{Error, Result},
     offset::{Offset, Offsets},
+    types::NativeType,
 };
 
 /// options defining how Cast kernels behave
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug)]
 pub struct CastOptions {
     /// default to false
     /// whether an overflowing cast should be converted to `None` (default), or be wrapped (i.e. `256i16 as u8 = 0` vectorized).
@@ -31,6 +34,22 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to true
+    /// whether a value that cannot be cast (e.g. an unparsable string, an overflowing number,
+    /// or a `NaN` cast to an integer) should be converted to `None` (default), or make the cast
+    /// return an error. When `false`, casting a timestamp to a coarser unit also errors
+    /// if it would truncate a value.
+    pub safe: bool,
+}
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        Self {
+            wrapped: false,
+            partial: false,
+            safe: true,
+        }
+    }
 }
 
 impl CastOptions {
@@ -41,6 +60,26 @@ impl CastOptions {
     }
 }
 
+/// Returns `to` unchanged when `options.safe` is set or when every non-null value of `from`
+/// was successfully cast, i.e. when `to` has no more nulls than `from`.
+/// # Errors
+/// This function errors when `options.safe` is `false` and a value of `from` could not be cast.
+fn check_cast(
+    from: &dyn Array,
+    to: Box<dyn Array>,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    if !options.safe && to.null_count() > from.null_count() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Casting from {:?} to {:?} failed for {} value(s)",
+            from.data_type(),
+            to.data_type(),
+            to.null_count() - from.null_count()
+        )));
+    }
+    Ok(to)
+}
+
 /// Returns true if this type is numeric: (UInt*, Unit*, or Float*).
 fn is_numeric(t: &DataType) -> bool {
     use DataType::*;
@@ -81,30 +120,52 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 
     match (from_type, to_type) {
         (Null, _) | (_, Null) => true,
+        (Struct(fields_from), Struct(fields_to)) => fields_to.iter().all(|field_to| {
+            fields_from
+                .iter()
+                .find(|field_from| field_from.name == field_to.name)
+                .map(|field_from| can_cast_types(&field_from.data_type, &field_to.data_type))
+                .unwrap_or(field_to.is_nullable)
+        }),
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (FixedSizeList(list_from, _), List(list_to)) => {
             can_cast_types(&list_from.data_type, &list_to.data_type)
         }
-        (List(list_from), FixedSizeList(list_to, _)) => {
+        (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
             can_cast_types(&list_from.data_type, &list_to.data_type)
         }
-        (List(list_from), List(list_to)) => {
+        (List(list_from) | LargeList(list_from), List(list_to) | LargeList(list_to)) => {
             can_cast_types(&list_from.data_type, &list_to.data_type)
         }
-        (LargeList(list_from), LargeList(list_to)) => {
-            can_cast_types(&list_from.data_type, &list_to.data_type)
-        }
-        (List(list_from), LargeList(list_to)) if list_from == list_to => true,
-        (LargeList(list_from), List(list_to)) if list_from == list_to => true,
+        (Map(map_from, _), Map(map_to, _)) => match (&map_from.data_type, &map_to.data_type) {
+            (Struct(entries_from), Struct(entries_to))
+                if entries_from.len() == 2 && entries_to.len() == 2 =>
+            {
+                entries_from
+                    .iter()
+                    .zip(entries_to.iter())
+                    .all(|(from, to)| can_cast_types(&from.data_type, &to.data_type))
+            }
+            _ => false,
+        },
         (_, List(list_to)) => can_cast_types(from_type, &list_to.data_type),
+        (RunEndEncoded(_, values), _) => can_cast_types(&values.data_type, to_type),
+        (_, RunEndEncoded(run_ends, values)) => {
+            run_ends.data_type == Int32
+                && matches!(
+                    values.data_type.to_physical_type(),
+                    PhysicalType::Primitive(_)
+                )
+                && can_cast_types(from_type, &values.data_type)
+        }
         (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
         }
         (Dictionary(_, value_type, _), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type, _)) => can_cast_types(from_type, value_type),
 
-        (_, Boolean) => is_numeric(from_type),
+        (_, Boolean) => is_numeric(from_type) || matches!(from_type, Utf8 | LargeUtf8),
         (Boolean, _) => {
             is_numeric(to_type)
                 || to_type == &Utf8
@@ -231,6 +292,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int64, Decimal(_, _)) => true,
 
         (Float16, Float32) => true,
+        (Float16, Float64) => true,
 
         (Float32, UInt8) => true,
         (Float32, UInt16) => true,
@@ -241,6 +303,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float32, Int32) => true,
         (Float32, Int64) => true,
         (Float32, Float64) => true,
+        (Float32, Float16) => true,
         (Float32, Decimal(_, _)) => true,
 
         (Float64, UInt8) => true,
@@ -252,6 +315,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float64, Int32) => true,
         (Float64, Int64) => true,
         (Float64, Float32) => true,
+        (Float64, Float16) => true,
         (Float64, Decimal(_, _)) => true,
 
         (
@@ -266,8 +330,10 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Int64
             | Float32
             | Float64
-            | Decimal(_, _),
+            | Decimal(_, _)
+            | Decimal256(_, _),
         ) => true,
+        (Decimal256(_, _), Decimal(_, _) | Decimal256(_, _)) => true,
         // end numeric casts
 
         // temporal casts
@@ -323,26 +389,44 @@ fn cast_list<O: Offset>(
     ))
 }
 
-fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListArray<i64> {
+fn cast_list_to_large_list(
+    array: &ListArray<i32>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<i64>> {
     let offsets = array.offsets().into();
+    let new_values = cast(
+        array.values().as_ref(),
+        ListArray::<i64>::get_child_type(to_type),
+        options,
+    )?;
 
-    ListArray::<i64>::new(
+    Ok(ListArray::<i64>::new(
         to_type.clone(),
         offsets,
-        array.values().clone(),
+        new_values,
         array.validity().cloned(),
-    )
+    ))
 }
 
-fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i32> {
-    let offsets = array.offsets().try_into().expect("Conver me to error");
+fn cast_large_to_list(
+    array: &ListArray<i64>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<i32>> {
+    let offsets = array.offsets().try_into()?;
+    let new_values = cast(
+        array.values().as_ref(),
+        ListArray::<i32>::get_child_type(to_type),
+        options,
+    )?;
 
-    ListArray::<i32>::new(
+    Ok(ListArray::<i32>::new(
         to_type.clone(),
         offsets,
-        array.values().clone(),
+        new_values,
         array.validity().cloned(),
-    )
+    ))
 }
 
 fn cast_fixed_size_list_to_list(
@@ -370,31 +454,117 @@ fn cast_fixed_size_list_to_list(
     ))
 }
 
-fn cast_list_to_fixed_size_list(
-    list: &ListArray<i32>,
+/// Casts a [`ListArray`] to a [`FixedSizeListArray`] of `size` items.
+/// Valid slots whose length is different from `size` are null when `options.safe` is set,
+/// and error otherwise.
+fn cast_list_to_fixed_size_list<O: Offset>(
+    list: &ListArray<O>,
     inner: &Field,
     size: usize,
     options: CastOptions,
 ) -> Result<FixedSizeListArray> {
-    let offsets = list.offsets().buffer().iter();
-    let expected = (0..list.len()).map(|ix| (ix * size) as i32);
-
-    match offsets
-        .zip(expected)
-        .find(|(actual, expected)| *actual != expected)
-    {
-        Some(_) => Err(Error::NotYetImplemented(
-            "incompatible offsets in source list".to_string(),
-        )),
-        None => {
-            let new_values = cast(list.values().as_ref(), inner.data_type(), options)?;
-            Ok(FixedSizeListArray::new(
-                DataType::FixedSizeList(Box::new(inner.clone()), size),
-                new_values,
-                list.validity().cloned(),
-            ))
+    let offsets = list.offsets().buffer();
+    let first = offsets[0].to_usize();
+    let is_contiguous = offsets
+        .iter()
+        .enumerate()
+        .all(|(ix, offset)| offset.to_usize() == first + ix * size);
+
+    let (values, validity) = if is_contiguous {
+        // fast path: every slot (including null slots) has `size` items
+        (
+            list.values().slice(first, list.len() * size),
+            list.validity().cloned(),
+        )
+    } else {
+        let mut indices = MutablePrimitiveArray::<i64>::with_capacity(list.len() * size);
+        let mut validity = MutableBitmap::with_capacity(list.len());
+        for index in 0..list.len() {
+            let (start, end) = list.offsets().start_end(index);
+            let is_valid = list.is_valid(index);
+            if is_valid && end - start == size {
+                (start..end).for_each(|x| indices.push(Some(x as i64)));
+                validity.push(true);
+            } else {
+                if is_valid && !options.safe {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "Cannot cast list to FixedSizeList({}): slot {} has {} items",
+                        size,
+                        index,
+                        end - start
+                    )));
+                }
+                indices.extend_constant(size, None);
+                validity.push(false);
+            }
         }
-    }
+        let indices: PrimitiveArray<i64> = indices.into();
+        (
+            take::take(list.values().as_ref(), &indices)?,
+            Some(validity.into()),
+        )
+    };
+
+    let new_values = cast(values.as_ref(), inner.data_type(), options)?;
+    FixedSizeListArray::try_new(
+        DataType::FixedSizeList(Box::new(inner.clone()), size),
+        new_values,
+        validity,
+    )
+}
+
+/// Casts the keys and values of a [`MapArray`] to the entries of `to_type`, by position.
+fn cast_map(array: &MapArray, to_type: &DataType, options: CastOptions) -> Result<MapArray> {
+    let entries_type = MapArray::try_get_field(to_type)?.data_type();
+    let entries = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    let values = entries
+        .values()
+        .iter()
+        .zip(StructArray::try_get_fields(entries_type)?.iter())
+        .map(|(values, field_to)| cast(values.as_ref(), field_to.data_type(), options))
+        .collect::<Result<Vec<_>>>()?;
+    let entries = StructArray::try_new(entries_type.clone(), values, entries.validity().cloned())?;
+
+    MapArray::try_new(
+        to_type.clone(),
+        array.offsets().clone(),
+        entries.boxed(),
+        array.validity().cloned(),
+    )
+}
+
+/// Casts a [`StructArray`] to another struct type by matching its fields by name.
+/// Fields of `to_type` that do not exist in `array` must be nullable and are filled with nulls.
+fn cast_struct(
+    array: &StructArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<StructArray> {
+    let values = StructArray::get_fields(to_type)
+        .iter()
+        .map(|field_to| {
+            match array
+                .fields()
+                .iter()
+                .position(|field_from| field_from.name == field_to.name)
+            {
+                Some(index) => cast(array.values()[index].as_ref(), field_to.data_type(), options),
+                None if field_to.is_nullable => {
+                    Ok(new_null_array(field_to.data_type().clone(), array.len()))
+                }
+                None => Err(Error::InvalidArgumentError(format!(
+                    "Cannot cast struct: the non-nullable field \"{}\" does not exist in the source",
+                    field_to.name
+                ))),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    StructArray::try_new(to_type.clone(), values, array.validity().cloned())
 }
 
 /// Cast `array` to the provided data type and return a new [`Array`] with
@@ -402,23 +572,33 @@ fn cast_list_to_fixed_size_list(
 ///
 /// Behavior:
 /// * PrimitiveArray to PrimitiveArray: overflowing cast will be None
+/// * Values that cannot be cast (e.g. unparsable strings, overflowing numbers, `NaN` to integers)
+///   are None when [`CastOptions::safe`] is set (default), and make the cast error otherwise
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Utf8 to boolean: `"true"`, `"t"`, `"1"` and `"yes"` return `true`, `"false"`, `"f"`, `"0"`
+///   and `"no"` return `false` (case-insensitive); empty strings are None and other strings
+///   are None (or error when [`CastOptions::safe`] is not set). See [`utf8_to_boolean`].
 /// * List to List: the underlying data type is cast
 /// * Fixed Size List to List: the underlying data type is cast
-/// * List to Fixed Size List: the offsets are checked for valid order, then the
-///   underlying type is cast.
+/// * List to Fixed Size List: slots whose length differs from the fixed size are None
+///   (or error when [`CastOptions::safe`] is not set), then the underlying type is cast.
+/// * List to Large List and vice-versa: the offsets are converted and the underlying type is cast
+/// * Struct to Struct: fields are matched by name and cast; missing nullable fields are None
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
+/// * RunEndEncoded to any type: the runs are decoded (see [`ree_decode`]) and cast
+/// * Any type to RunEndEncoded: the array is cast to the type of the values and encoded
+///   (see [`ree_encode`])
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Temporal to Utf8 with a custom strftime format: see [`cast_with_format`]
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray` from other types
 /// * List to primitive
-/// * Utf8 to boolean
 /// * Interval and duration
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
@@ -432,13 +612,23 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
     let as_options = options.with_wrapped(true);
     match (from_type, to_type) {
         (Null, _) | (_, Null) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Struct(_), Struct(_)) => {
+            cast_struct(array.as_any().downcast_ref().unwrap(), to_type, options).map(|x| x.boxed())
+        }
         (Struct(_), _) => Err(Error::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
         (_, Struct(_)) => Err(Error::NotYetImplemented(
             "Cannot cast to struct from other types".to_string(),
         )),
-        (List(_), FixedSizeList(inner, size)) => cast_list_to_fixed_size_list(
+        (List(_), FixedSizeList(inner, size)) => cast_list_to_fixed_size_list::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            inner.as_ref(),
+            *size,
+            options,
+        )
+        .map(|x| x.boxed()),
+        (LargeList(_), FixedSizeList(inner, size)) => cast_list_to_fixed_size_list::<i64>(
             array.as_any().downcast_ref().unwrap(),
             inner.as_ref(),
             *size,
@@ -457,11 +647,16 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             cast_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
                 .map(|x| x.boxed())
         }
-        (List(lhs), LargeList(rhs)) if lhs == rhs => {
-            Ok(cast_list_to_large_list(array.as_any().downcast_ref().unwrap(), to_type).boxed())
+        (List(_), LargeList(_)) => {
+            cast_list_to_large_list(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| x.boxed())
+        }
+        (LargeList(_), List(_)) => {
+            cast_large_to_list(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| x.boxed())
         }
-        (LargeList(lhs), List(rhs)) if lhs == rhs => {
-            Ok(cast_large_to_list(array.as_any().downcast_ref().unwrap(), to_type).boxed())
+        (Map(_, _), Map(_, _)) => {
+            cast_map(array.as_any().downcast_ref().unwrap(), to_type, options).map(|x| x.boxed())
         }
 
         (_, List(to)) => {
@@ -477,6 +672,21 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Ok(Box::new(list_array))
         }
 
+        (RunEndEncoded(..), _) => {
+            let primitive = values_primitive_type(from_type);
+            let array = with_match_primitive_type!(primitive, |$T| {
+                ree_decode::<$T>(array.as_any().downcast_ref().unwrap()).boxed()
+            });
+            cast(array.as_ref(), to_type, options)
+        }
+        (_, RunEndEncoded(_, values)) => {
+            let primitive = try_values_primitive_type(to_type)?;
+            let array = cast(array, &values.data_type, options)?;
+            with_match_primitive_type!(primitive, |$T| {
+                cast_to_run_end_encoded::<$T>(array.as_ref(), to_type)
+            })
+        }
+
         (Dictionary(index_type, ..), _) => match_integer_type!(index_type, |$T| {
             dictionary_cast_dyn::<$T>(array, to_type, options)
         }),
@@ -494,6 +704,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
+            Utf8 => utf8_to_boolean_dyn::<i32>(array, options),
+            LargeUtf8 => utf8_to_boolean_dyn::<i64>(array, options),
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -531,8 +743,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
-            Date32 => utf8_to_date32_dyn::<i32>(array),
-            Date64 => utf8_to_date64_dyn::<i32>(array),
+            Date32 => utf8_to_date32_dyn::<i32>(array, options),
+            Date64 => utf8_to_date64_dyn::<i32>(array, options),
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
@@ -541,9 +753,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 to_type.clone(),
             )
             .boxed()),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_naive_timestamp_ns_dyn::<i32>(array, options)
+            }
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
+                utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone(), options)
             }
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -561,17 +775,19 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
-            Date32 => utf8_to_date32_dyn::<i64>(array),
-            Date64 => utf8_to_date64_dyn::<i64>(array),
+            Date32 => utf8_to_date32_dyn::<i64>(array, options),
+            Date64 => utf8_to_date64_dyn::<i64>(array, options),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap()).map(|x| x.boxed()),
             LargeBinary => Ok(utf8_to_binary::<i64>(
                 array.as_any().downcast_ref().unwrap(),
                 to_type.clone(),
             )
             .boxed()),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_naive_timestamp_ns_dyn::<i64>(array, options)
+            }
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
+                utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone(), options)
             }
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -739,7 +955,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type, options),
         (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, as_options),
         (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, as_options),
-        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s),
+        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s, options),
 
         (UInt16, UInt8) => primitive_to_primitive_dyn::<u16, u8>(array, to_type, options),
         (UInt16, UInt32) => primitive_to_primitive_dyn::<u16, u32>(array, to_type, as_options),
@@ -750,7 +966,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type, options),
         (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, as_options),
         (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, as_options),
-        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s),
+        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s, options),
 
         (UInt32, UInt8) => primitive_to_primitive_dyn::<u32, u8>(array, to_type, options),
         (UInt32, UInt16) => primitive_to_primitive_dyn::<u32, u16>(array, to_type, options),
@@ -761,7 +977,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type, options),
         (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, as_options),
         (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, as_options),
-        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s),
+        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s, options),
 
         (UInt64, UInt8) => primitive_to_primitive_dyn::<u64, u8>(array, to_type, options),
         (UInt64, UInt16) => primitive_to_primitive_dyn::<u64, u16>(array, to_type, options),
@@ -772,7 +988,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type, options),
         (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, as_options),
         (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, as_options),
-        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s),
+        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s, options),
 
         (Int8, UInt8) => primitive_to_primitive_dyn::<i8, u8>(array, to_type, options),
         (Int8, UInt16) => primitive_to_primitive_dyn::<i8, u16>(array, to_type, options),
@@ -783,7 +999,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type, as_options),
         (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, as_options),
         (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, as_options),
-        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s),
+        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s, options),
 
         (Int16, UInt8) => primitive_to_primitive_dyn::<i16, u8>(array, to_type, options),
         (Int16, UInt16) => primitive_to_primitive_dyn::<i16, u16>(array, to_type, options),
@@ -794,7 +1010,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type, as_options),
         (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, as_options),
         (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, as_options),
-        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s),
+        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s, options),
 
         (Int32, UInt8) => primitive_to_primitive_dyn::<i32, u8>(array, to_type, options),
         (Int32, UInt16) => primitive_to_primitive_dyn::<i32, u16>(array, to_type, options),
@@ -805,7 +1021,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type, as_options),
         (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, as_options),
         (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, as_options),
-        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s),
+        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s, options),
 
         (Int64, UInt8) => primitive_to_primitive_dyn::<i64, u8>(array, to_type, options),
         (Int64, UInt16) => primitive_to_primitive_dyn::<i64, u16>(array, to_type, options),
@@ -816,12 +1032,24 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type, options),
         (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
         (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, as_options),
-        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s),
+        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s, options),
 
         (Float16, Float32) => {
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         }
+        (Float16, Float64) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f16_to_f64(from).boxed())
+        }
+        (Float32, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f32_to_f16(from).boxed())
+        }
+        (Float64, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f64_to_f16(from).boxed())
+        }
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
@@ -832,7 +1060,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float32, Int32) => primitive_to_primitive_dyn::<f32, i32>(array, to_type, options),
         (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type, options),
         (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type, as_options),
-        (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s),
+        (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s, options),
 
         (Float64, UInt8) => primitive_to_primitive_dyn::<f64, u8>(array, to_type, options),
         (Float64, UInt16) => primitive_to_primitive_dyn::<f64, u16>(array, to_type, options),
@@ -843,19 +1071,30 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type, options),
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
-        (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s),
-
-        (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array),
-        (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array),
-        (Decimal(_, _), UInt32) => decimal_to_integer_dyn::<u32>(array),
-        (Decimal(_, _), UInt64) => decimal_to_integer_dyn::<u64>(array),
-        (Decimal(_, _), Int8) => decimal_to_integer_dyn::<i8>(array),
-        (Decimal(_, _), Int16) => decimal_to_integer_dyn::<i16>(array),
-        (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array),
-        (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array),
+        (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s, options),
+
+        (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array, options),
+        (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array, options),
+        (Decimal(_, _), UInt32) => decimal_to_integer_dyn::<u32>(array, options),
+        (Decimal(_, _), UInt64) => decimal_to_integer_dyn::<u64>(array, options),
+        (Decimal(_, _), Int8) => decimal_to_integer_dyn::<i8>(array, options),
+        (Decimal(_, _), Int16) => decimal_to_integer_dyn::<i16>(array, options),
+        (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array, options),
+        (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array, options),
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
-        (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+        (Decimal(_, _), Decimal(to_p, to_s)) => {
+            decimal_to_decimal_dyn(array, *to_p, *to_s, options)
+        }
+        (Decimal(_, _), Decimal256(to_p, to_s)) => {
+            decimal_to_decimal256_dyn(array, *to_p, *to_s, options)
+        }
+        (Decimal256(_, _), Decimal(to_p, to_s)) => {
+            decimal256_to_decimal_dyn(array, *to_p, *to_s, options)
+        }
+        (Decimal256(_, _), Decimal256(to_p, to_s)) => {
+            decimal256_to_decimal256_dyn(array, *to_p, *to_s, options)
+        }
         // end numeric casts
 
         // temporal casts
@@ -903,7 +1142,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
-            primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
+            timestamp_to_timestamp_dyn(array, *from_unit, *to_unit, tz, options)
         }
         (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
         (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
@@ -925,6 +1164,44 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
     }
 }
 
+/// Casts `array` of a temporal type (`Date32`, `Date64`, `Time32`, `Time64` or `Timestamp`)
+/// to `to_type`, [`DataType::Utf8`] or [`DataType::LargeUtf8`], formatting every value with
+/// the strftime `format` (e.g. `"%m/%d/%Y"`). See [`temporal_to_utf8_with_format`].
+/// # Errors
+/// This function errors iff `format` is not a valid strftime format, it cannot be applied
+/// to the values of `array`, or the cast is not supported.
+pub fn cast_with_format(
+    array: &dyn Array,
+    to_type: &DataType,
+    format: &str,
+) -> Result<Box<dyn Array>> {
+    match to_type {
+        DataType::Utf8 => temporal_to_utf8_with_format::<i32>(array, format).map(|x| x.boxed()),
+        DataType::LargeUtf8 => {
+            temporal_to_utf8_with_format::<i64>(array, format).map(|x| x.boxed())
+        }
+        _ => Err(Error::NotYetImplemented(format!(
+            "Casting from {:?} to {:?} with a format is not supported",
+            array.data_type(),
+            to_type,
+        ))),
+    }
+}
+
+/// Encodes a [`PrimitiveArray`] into a [`RunEndEncodedArray`] of type `to_type`.
+fn cast_to_run_end_encoded<T: NativeType>(
+    array: &dyn Array,
+    to_type: &DataType,
+) -> Result<Box<dyn Array>> {
+    let encoded = ree_encode::<T>(array.as_any().downcast_ref().unwrap());
+    RunEndEncodedArray::try_new(
+        to_type.clone(),
+        encoded.run_ends().clone(),
+        encoded.values().clone(),
+    )
+    .map(|x| x.boxed())
+}
+
 /// Attempts to encode an array into an `ArrayDictionary` with index
 /// type K and value (dictionary) type value_type
 ///
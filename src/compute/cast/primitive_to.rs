@@ -1,9 +1,10 @@
+use std::fmt::Write;
 use std::hash::Hash;
 
 use num_traits::{AsPrimitive, Float, ToPrimitive};
 
 use crate::datatypes::IntervalUnit;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::offset::{Offset, Offsets};
 use crate::types::{days_ms, f16, months_days_ns};
 use crate::{
@@ -15,7 +16,7 @@ use crate::{
     types::NativeType,
 };
 
-use super::CastOptions;
+use super::{check_cast, CastOptions};
 
 /// Returns a [`BinaryArray`] where every element is the binary representation of the number.
 pub fn primitive_to_binary<T: NativeType + lexical_core::ToLexical, O: Offset>(
@@ -139,11 +140,15 @@ where
     I: NativeType + num_traits::NumCast + num_traits::AsPrimitive<O>,
     O: NativeType + num_traits::NumCast,
 {
-    let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
+    let array = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
     if options.wrapped {
-        Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)))
+        Ok(Box::new(primitive_as_primitive::<I, O>(array, to_type)))
     } else {
-        Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
+        check_cast(
+            from,
+            Box::new(primitive_to_primitive::<I, O>(array, to_type)),
+            options,
+        )
     }
 }
 
@@ -195,15 +200,23 @@ pub(super) fn integer_to_decimal_dyn<T>(
     from: &dyn Array,
     precision: usize,
     scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
     T: NativeType + AsPrimitive<i128>,
 {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(
+        from,
+        Box::new(integer_to_decimal::<T>(array, precision, scale)),
+        options,
+    )
 }
 
-/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+/// Returns a [`PrimitiveArray<i128>`] with the casted values, rounded to the nearest value
+/// representable with `to_scale` (ties away from zero).
+/// Values are `None` when they do not fit in `to_precision` digits and for `NaN` and
+/// infinite values.
 pub fn float_to_decimal<T>(
     from: &PrimitiveArray<T>,
     to_precision: usize,
@@ -211,20 +224,19 @@ pub fn float_to_decimal<T>(
 ) -> PrimitiveArray<i128>
 where
     T: NativeType + Float + ToPrimitive,
-    f64: AsPrimitive<T>,
 {
     // 1.2 => 12
-    let multiplier: T = (10_f64).powi(to_scale as i32).as_();
+    let multiplier = 10_f64.powi(to_scale as i32);
 
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+    let max_for_precision = 10_i128
+        .checked_pow(to_precision as u32)
+        .map_or(i128::MAX, |x| x - 1);
 
     let values = from.iter().map(|x| {
         x.and_then(|x| {
-            let x = (*x * multiplier).to_i128().unwrap();
-            if x > max_for_precision || x < min_for_precision {
+            // `NaN`, infinite and out-of-range values are `None`
+            let x = (x.to_f64()? * multiplier).round().to_i128()?;
+            if x.unsigned_abs() > max_for_precision as u128 {
                 None
             } else {
                 Some(x)
@@ -240,13 +252,31 @@ pub(super) fn float_to_decimal_dyn<T>(
     from: &dyn Array,
     precision: usize,
     scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
     T: NativeType + Float + ToPrimitive,
-    f64: AsPrimitive<T>,
 {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(float_to_decimal::<T>(from, precision, scale)))
+    let from = from.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let to = float_to_decimal::<T>(from, precision, scale);
+
+    if !options.safe {
+        // `NaN` and infinite values are null regardless of `options`: only overflows fail
+        let overflows = from
+            .iter()
+            .zip(to.iter())
+            .filter(|(from, to)| from.map_or(false, |x| x.is_finite()) && to.is_none())
+            .count();
+        if overflows > 0 {
+            return Err(Error::InvalidArgumentError(format!(
+                "Casting from {:?} to {:?} failed for {} value(s)",
+                from.data_type(),
+                to.data_type(),
+                overflows
+            )));
+        }
+    }
+    Ok(Box::new(to))
 }
 
 /// Cast [`PrimitiveArray`] as a [`PrimitiveArray`]
@@ -401,7 +431,8 @@ pub fn time64_to_time32(
     unary(from, |x| (x / divisor) as i32, DataType::Time32(to_unit))
 }
 
-/// Conversion of timestamp
+/// Conversion of timestamp. Values are truncated when converting to a coarser unit
+/// and `None` on overflow when converting to a finer unit.
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
     from_unit: TimeUnit,
@@ -415,10 +446,40 @@ pub fn timestamp_to_timestamp(
     if from_size >= to_size {
         unary(from, |x| (x / (from_size / to_size)), to_type)
     } else {
-        unary(from, |x| (x * (to_size / from_size)), to_type)
+        let factor = to_size / from_size;
+        let iter = from.iter().map(|x| x.and_then(|x| x.checked_mul(factor)));
+        PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(to_type)
     }
 }
 
+pub(super) fn timestamp_to_timestamp_dyn(
+    from: &dyn Array,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    tz: &Option<String>,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = from.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    if !options.safe && from_size > to_size {
+        let divisor = from_size / to_size;
+        if array.iter().flatten().any(|x| x % divisor != 0) {
+            return Err(Error::InvalidArgumentError(format!(
+                "Casting timestamps from {:?} to {:?} would truncate values",
+                from_unit, to_unit
+            )));
+        }
+    }
+
+    check_cast(
+        from,
+        Box::new(timestamp_to_timestamp(array, from_unit, to_unit, tz)),
+        options,
+    )
+}
+
 fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
     from: &PrimitiveArray<i64>,
     time_unit: TimeUnit,
@@ -555,6 +616,153 @@ pub fn naive_timestamp_to_utf8<O: Offset>(
     }
 }
 
+/// Formats every non-null value of `from` with `op` into a [`Utf8Array`].
+/// # Errors
+/// This function errors iff `op` errors, e.g. when the format requires a field
+/// (such as `%H`) that the value does not have.
+fn temporal_to_utf8_impl<O, T, F>(from: &PrimitiveArray<T>, op: F) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    T: NativeType,
+    F: Fn(T, &mut String) -> std::fmt::Result,
+{
+    let mut buffer = String::new();
+    let mut array = MutableUtf8Array::<O>::with_capacity(from.len());
+    for x in from.iter() {
+        if let Some(x) = x {
+            buffer.clear();
+            op(*x, &mut buffer).map_err(|_| {
+                Error::InvalidArgumentError(format!(
+                    "the format cannot be applied to values of type {:?}",
+                    from.data_type()
+                ))
+            })?;
+            array.try_push(Some(buffer.as_str()))?;
+        } else {
+            array.push_null();
+        }
+    }
+    Ok(array.into())
+}
+
+fn timestamp_to_utf8_with_format_impl<O: Offset, T: chrono::TimeZone>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    items: &[chrono::format::Item],
+) -> Result<Utf8Array<O>>
+where
+    T::Offset: std::fmt::Display,
+{
+    temporal_to_utf8_impl(from, |x, buffer| {
+        let datetime = timestamp_to_datetime(x, time_unit, &timezone);
+        write!(buffer, "{}", datetime.format_with_items(items.iter()))
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+fn chrono_tz_timestamp_to_utf8_with_format<O: Offset>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    items: &[chrono::format::Item],
+) -> Result<Utf8Array<O>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    timestamp_to_utf8_with_format_impl::<O, chrono_tz::Tz>(from, time_unit, timezone, items)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_timestamp_to_utf8_with_format<O: Offset>(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: &[chrono::format::Item],
+) -> Result<Utf8Array<O>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Returns a [`Utf8Array`] where every element is the temporal value of `from` formatted
+/// with the strftime `format` (e.g. `"%m/%d/%Y"`). Timestamps with a timezone are
+/// formatted in that timezone.
+/// # Errors
+/// This function errors iff:
+/// * `format` is not a valid strftime format
+/// * `format` requires a field that the values do not have (e.g. `%H` on a date)
+/// * the logical type of `from` is not `Date32`, `Date64`, `Time32`, `Time64` or `Timestamp`
+pub fn temporal_to_utf8_with_format<O: Offset>(
+    from: &dyn Array,
+    format: &str,
+) -> Result<Utf8Array<O>> {
+    let items = parse_format(format)?;
+    let items = items.as_slice();
+
+    match from.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let from = from.as_any().downcast_ref().unwrap();
+            temporal_to_utf8_impl(from, |x, buffer| {
+                write!(
+                    buffer,
+                    "{}",
+                    date32_to_date(x).format_with_items(items.iter())
+                )
+            })
+        }
+        DataType::Date64 => {
+            let from = from.as_any().downcast_ref().unwrap();
+            temporal_to_utf8_impl(from, |x, buffer| {
+                write!(
+                    buffer,
+                    "{}",
+                    date64_to_datetime(x).format_with_items(items.iter())
+                )
+            })
+        }
+        DataType::Time32(time_unit) => {
+            let from = from.as_any().downcast_ref().unwrap();
+            let to_time = match time_unit {
+                TimeUnit::Second => time32s_to_time,
+                _ => time32ms_to_time,
+            };
+            temporal_to_utf8_impl(from, |x, buffer| {
+                write!(buffer, "{}", to_time(x).format_with_items(items.iter()))
+            })
+        }
+        DataType::Time64(time_unit) => {
+            let from = from.as_any().downcast_ref().unwrap();
+            let to_time = match time_unit {
+                TimeUnit::Microsecond => time64us_to_time,
+                _ => time64ns_to_time,
+            };
+            temporal_to_utf8_impl(from, |x, buffer| {
+                write!(buffer, "{}", to_time(x).format_with_items(items.iter()))
+            })
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let from = from.as_any().downcast_ref().unwrap();
+            temporal_to_utf8_impl(from, |x, buffer| {
+                let datetime = timestamp_to_naive_datetime(x, *time_unit);
+                write!(buffer, "{}", datetime.format_with_items(items.iter()))
+            })
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let from = from.as_any().downcast_ref().unwrap();
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                timestamp_to_utf8_with_format_impl(from, *time_unit, timezone, items)
+            } else {
+                chrono_tz_timestamp_to_utf8_with_format(from, *time_unit, timezone_str, items)
+            }
+        }
+        data_type => Err(Error::InvalidArgumentError(format!(
+            "Formatting values of type {:?} is not supported",
+            data_type
+        ))),
+    }
+}
+
 #[inline]
 fn days_ms_to_months_days_ns_scalar(from: days_ms) -> months_days_ns {
     months_days_ns::new(0, from.days(), from.milliseconds() as i64 * 1000)
@@ -587,3 +795,18 @@ pub fn months_to_months_days_ns(from: &PrimitiveArray<i32>) -> PrimitiveArray<mo
 pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), DataType::Float32)
 }
+
+/// Casts f16 into f64
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f64(), DataType::Float64)
+}
+
+/// Casts f32 into f16, rounding to the nearest f16
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, DataType::Float16)
+}
+
+/// Casts f64 into f16, rounding to the nearest f16
+pub fn f64_to_f16(from: &PrimitiveArray<f64>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f64, DataType::Float16)
+}
@@ -3,7 +3,7 @@ use chrono::Datelike;
 use crate::{
     array::*,
     datatypes::DataType,
-    error::Result,
+    error::{Error, Result},
     offset::Offset,
     temporal_conversions::{
         utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
@@ -12,7 +12,7 @@ use crate::{
     types::NativeType,
 };
 
-use super::CastOptions;
+use super::{check_cast, CastOptions};
 
 const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
@@ -51,14 +51,72 @@ pub(super) fn utf8_to_primitive_dyn<O: Offset, T>(
 where
     T: NativeType + lexical_core::FromLexical,
 {
-    let from = from.as_any().downcast_ref().unwrap();
+    let from: &Utf8Array<O> = from.as_any().downcast_ref().unwrap();
     if options.partial {
         Ok(Box::new(partial_utf8_to_primitive::<O, T>(from, to)))
     } else {
-        Ok(Box::new(utf8_to_primitive::<O, T>(from, to)))
+        check_cast(from, Box::new(utf8_to_primitive::<O, T>(from, to)), options)
     }
 }
 
+/// Tokens cast to `true` by [`utf8_to_boolean`], compared case-insensitively
+const TRUE_TOKENS: [&str; 4] = ["true", "t", "1", "yes"];
+/// Tokens cast to `false` by [`utf8_to_boolean`], compared case-insensitively
+const FALSE_TOKENS: [&str; 4] = ["false", "f", "0", "no"];
+
+fn parse_boolean(value: &str) -> Option<bool> {
+    if TRUE_TOKENS
+        .iter()
+        .any(|token| value.eq_ignore_ascii_case(token))
+    {
+        Some(true)
+    } else if FALSE_TOKENS
+        .iter()
+        .any(|token| value.eq_ignore_ascii_case(token))
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Casts a [`Utf8Array`] to a [`BooleanArray`], making any uncastable value a Null.
+///
+/// The following tokens are recognized, case-insensitively:
+///
+/// | value   | tokens                          |
+/// |---------|---------------------------------|
+/// | `true`  | `"true"`, `"t"`, `"1"`, `"yes"` |
+/// | `false` | `"false"`, `"f"`, `"0"`, `"no"` |
+///
+/// Empty strings and any other token are Null.
+pub fn utf8_to_boolean<O: Offset>(from: &Utf8Array<O>) -> BooleanArray {
+    let iter = from.iter().map(|x| x.and_then(parse_boolean));
+    BooleanArray::from_trusted_len_iter(iter)
+}
+
+pub(super) fn utf8_to_boolean_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from: &Utf8Array<O> = from.as_any().downcast_ref().unwrap();
+    if !options.safe {
+        // empty strings are null and thus not an error
+        if let Some(value) = from
+            .iter()
+            .flatten()
+            .find(|x| !x.is_empty() && parse_boolean(x).is_none())
+        {
+            return Err(Error::InvalidArgumentError(format!(
+                "Casting from {:?} to Boolean failed: \"{}\" is not a boolean",
+                from.data_type(),
+                value
+            )));
+        }
+    }
+    Ok(Box::new(utf8_to_boolean(from)))
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
@@ -71,9 +129,12 @@ pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
 }
 
-pub(super) fn utf8_to_date32_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date32::<O>(from)))
+pub(super) fn utf8_to_date32_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(from, Box::new(utf8_to_date32::<O>(array)), options)
 }
 
 /// Casts a [`Utf8Array`] to a Date64 primitive, making any uncastable value a Null.
@@ -88,9 +149,12 @@ pub fn utf8_to_date64<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Date64)
 }
 
-pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date64::<O>(from)))
+pub(super) fn utf8_to_date64_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(from, Box::new(utf8_to_date64::<O>(array)), options)
 }
 
 pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
@@ -115,9 +179,14 @@ pub fn utf8_to_dictionary<O: Offset, K: DictionaryKey>(
 
 pub(super) fn utf8_to_naive_timestamp_ns_dyn<O: Offset>(
     from: &dyn Array,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_naive_timestamp_ns::<O>(from)))
+    let array = from.as_any().downcast_ref().unwrap();
+    check_cast(
+        from,
+        Box::new(utf8_to_naive_timestamp_ns::<O>(array)),
+        options,
+    )
 }
 
 /// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
@@ -128,11 +197,11 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveAr
 pub(super) fn utf8_to_timestamp_ns_dyn<O: Offset>(
     from: &dyn Array,
     timezone: String,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    utf8_to_timestamp_ns::<O>(from, timezone)
-        .map(Box::new)
-        .map(|x| x as Box<dyn Array>)
+    let array = from.as_any().downcast_ref().unwrap();
+    let to = utf8_to_timestamp_ns::<O>(array, timezone)?;
+    check_cast(from, Box::new(to), options)
 }
 
 /// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
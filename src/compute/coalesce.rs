@@ -0,0 +1,73 @@
+//! Contains the operator [`coalesce`], the first non-null value across arrays (SQL's
+//! `COALESCE`).
+use crate::array::{growable::make_growable, Array};
+use crate::error::{Error, Result};
+
+/// Returns, for each slot, the value of the first of `arrays` that is not null in that slot,
+/// or null if all of them are null.
+///
+/// [`DictionaryArray`](crate::array::DictionaryArray)s with different values are supported:
+/// the values of the result are the concatenation of the values of `arrays`.
+/// # Example
+/// ```rust
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::coalesce::coalesce;
+///
+/// let a = Int32Array::from([Some(1), None, None, None]);
+/// let b = Int32Array::from([Some(10), Some(20), None, None]);
+/// let c = Int32Array::from([None, Some(200), Some(300), None]);
+///
+/// let result = coalesce(&[&a, &b, &c]).unwrap();
+/// assert_eq!(Int32Array::from([Some(1), Some(20), Some(300), None]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty or its arrays have different lengths or data
+/// types.
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("coalesce requires at least one array".to_string())
+    })?;
+    if let Some(array) = arrays
+        .iter()
+        .find(|array| array.data_type() != first.data_type())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "coalesce requires all arrays to have the same data type ({:?} != {:?})",
+            first.data_type(),
+            array.data_type()
+        )));
+    }
+    let length = first.len();
+    if let Some(array) = arrays.iter().find(|array| array.len() != length) {
+        return Err(Error::InvalidArgumentError(format!(
+            "coalesce requires all arrays to have the same length ({} != {})",
+            length,
+            array.len()
+        )));
+    }
+
+    if first.null_count() == 0 || arrays.len() == 1 {
+        return Ok(first.to_boxed());
+    }
+
+    // the index of the first array that is valid at `row`; the last array (null) otherwise
+    let source = |row: usize| {
+        arrays
+            .iter()
+            .position(|array| array.is_valid(row))
+            .unwrap_or(arrays.len() - 1)
+    };
+
+    let mut growable = make_growable(arrays, true, length);
+    // extend in runs of consecutive slots taken from the same array
+    let mut start = 0;
+    while start < length {
+        let index = source(start);
+        let end = (start + 1..length)
+            .find(|&row| source(row) != index)
+            .unwrap_or(length);
+        growable.extend(index, start, end - start);
+        start = end;
+    }
+    Ok(growable.as_box())
+}
@@ -57,7 +57,7 @@ mod simd;
 pub use simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
 use super::take::take_boolean;
-use crate::bitmap::{binary, Bitmap};
+use crate::bitmap::{binary, Bitmap, MutableBitmap};
 use crate::compute;
 pub(crate) use primitive::{
     compare_values_op as primitive_compare_values_op,
@@ -69,7 +69,7 @@ macro_rules! match_eq_ord {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::i256;
+    use crate::types::{f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -83,7 +83,7 @@ macro_rules! match_eq_ord {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
-        Float16 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -281,6 +281,70 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
 }
 
+// `==` and `!=` against a dictionary are performed on its keys, see [`dictionary_eq_scalar`]
+macro_rules! dictionary_scalar_fast_path {
+    (eq_scalar, $lhs:expr, $rhs:expr) => {
+        dictionary_eq_scalar($lhs, $rhs, true)
+    };
+    (neq_scalar, $lhs:expr, $rhs:expr) => {
+        dictionary_eq_scalar($lhs, $rhs, false)
+    };
+    ($op:tt, $lhs:expr, $rhs:expr) => {
+        None
+    };
+}
+
+/// Compares the keys of `lhs` against the key of `rhs` in the dictionary values,
+/// without decoding the keys. Returns `None` when this is not possible, i.e. when
+/// the values contain nulls or contain `rhs` more than once.
+fn dictionary_eq_scalar<K>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Scalar,
+    equal: bool,
+) -> Option<BooleanArray>
+where
+    K: DictionaryKey + Simd8,
+    K::Simd: Simd8PartialEq,
+{
+    let values = lhs.values();
+    if values.null_count() > 0 {
+        return None;
+    }
+    let matches = eq_scalar(values.as_ref(), rhs);
+    let mut positions = matches
+        .values()
+        .iter()
+        .enumerate()
+        .filter(|(_, is_match)| *is_match)
+        .map(|(position, _)| position);
+    let position = positions.next();
+    if positions.next().is_some() {
+        return None;
+    }
+
+    let keys = lhs.keys();
+    let result = match position {
+        Some(position) => {
+            let key = K::try_from(position).ok()?;
+            if equal {
+                primitive::eq_scalar(keys, key)
+            } else {
+                primitive::neq_scalar(keys, key)
+            }
+        }
+        None => {
+            // `rhs` is not in the dictionary: no key is equal to it
+            let values = if equal {
+                Bitmap::new_zeroed(keys.len())
+            } else {
+                MutableBitmap::from_len_set(keys.len()).into()
+            };
+            BooleanArray::new(DataType::Boolean, values, keys.validity().cloned())
+        }
+    };
+    Some(result)
+}
+
 macro_rules! compare_scalar {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
         let lhs = $lhs;
@@ -329,9 +393,17 @@ macro_rules! compare_scalar {
             Dictionary(key_type) => {
                 match_integer_type!(key_type, |$T| {
                     let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
-                    let values = $op(lhs.values().as_ref(), rhs);
-
-                    take_boolean(&values, lhs.keys())
+                    let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+                    // validity checked above
+                    let rhs = rhs.value().unwrap().as_ref();
+
+                    match dictionary_scalar_fast_path!($op, lhs, rhs) {
+                        Some(result) => result,
+                        None => {
+                            let values = $op(lhs.values().as_ref(), rhs);
+                            take_boolean(&values, lhs.keys())
+                        }
+                    }
                 })
             }
             _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
@@ -477,11 +549,13 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Binary
             | DataType::LargeBinary
     )
@@ -492,8 +566,7 @@ fn can_partial_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
         || matches!(
             data_type.to_logical_type(),
-            DataType::Float16
-                | DataType::Interval(IntervalUnit::DayTime)
+            DataType::Interval(IntervalUnit::DayTime)
                 | DataType::Interval(IntervalUnit::MonthDayNano)
         )
 }
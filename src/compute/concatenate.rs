@@ -13,10 +13,31 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::array::{growable::make_growable, Array};
+use crate::array::{
+    growable::{make_growable, merge_dictionary_values, remap_key},
+    Array, BinaryArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, IntegerType, PhysicalType};
 use crate::error::{Error, Result};
+use crate::offset::{Offset, Offsets, OffsetsBuffer};
+use crate::types::NativeType;
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
+///
+/// Primitive, binary and utf8 arrays are concatenated by copying their buffers directly.
+/// [`DictionaryArray`]s whose values are primitive, binary or utf8 are concatenated by merging
+/// their values, so that the values of the result are unique, and remapping their keys
+/// accordingly (values are compared by their bytes, so e.g. different `NaN`s are not merged).
+/// Dictionaries whose data type is marked as sorted have their values concatenated instead.
+/// When the merged values do not fit in the keys, these are promoted to the smallest larger
+/// integer type of the same signedness that fits them.
+/// # Errors
+/// This function errors iff `arrays` is empty, its arrays have different data types, or the
+/// result overflows the offsets of the data type.
+/// # Panics
+/// This function panics iff the merged values of dictionaries nested in other types do not
+/// fit in their keys, since the type of nested keys is not promoted.
 pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
     if arrays.is_empty() {
         return Err(Error::InvalidArgumentError(
@@ -33,6 +54,23 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
+    use PhysicalType::*;
+    match arrays[0].data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Ok(concatenate_primitive::<$T>(arrays).boxed())
+        }),
+        Binary => concatenate_binary::<i32>(arrays),
+        LargeBinary => concatenate_binary::<i64>(arrays),
+        Utf8 => concatenate_utf8::<i32>(arrays),
+        LargeUtf8 => concatenate_utf8::<i64>(arrays),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            concatenate_dictionaries::<$T>(arrays)
+        }),
+        _ => Ok(concatenate_growable(arrays)),
+    }
+}
+
+fn concatenate_growable(arrays: &[&dyn Array]) -> Box<dyn Array> {
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
     let capacity = lengths.iter().sum();
 
@@ -42,5 +80,259 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         mutable.extend(i, 0, *len)
     }
 
-    Ok(mutable.as_box())
+    mutable.as_box()
+}
+
+/// Concatenates the validities of `arrays`, or `None` when all their slots are valid.
+fn concatenate_validities(arrays: &[&dyn Array]) -> Option<Bitmap> {
+    if arrays.iter().all(|array| array.null_count() == 0) {
+        return None;
+    }
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+    let mut validity = MutableBitmap::with_capacity(capacity);
+    for array in arrays {
+        match array.validity() {
+            Some(bitmap) => validity.extend_from_bitmap(bitmap),
+            None => validity.extend_constant(array.len(), true),
+        }
+    }
+    Some(validity.into())
+}
+
+fn concatenate_primitive<T: NativeType>(arrays: &[&dyn Array]) -> PrimitiveArray<T> {
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+    let mut values = Vec::<T>::with_capacity(capacity);
+    for array in arrays {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        values.extend_from_slice(array.values());
+    }
+
+    PrimitiveArray::new(
+        arrays[0].data_type().clone(),
+        values.into(),
+        concatenate_validities(arrays),
+    )
+}
+
+/// Concatenates the (possibly sliced) `offsets` and `values` of binary-like arrays.
+fn concatenate_offsets_values<'a, O: Offset, I>(arrays: I) -> Result<(Offsets<O>, Vec<u8>)>
+where
+    I: Iterator<Item = (&'a OffsetsBuffer<O>, &'a [u8])> + Clone,
+{
+    let values_range =
+        |offsets: &OffsetsBuffer<O>| offsets.buffer()[0].to_usize()..offsets.last().to_usize();
+
+    let (length, values_length) = arrays
+        .clone()
+        .fold((0, 0), |(length, values), (offsets, _)| {
+            (length + offsets.len(), values + values_range(offsets).len())
+        });
+
+    let mut new_offsets = Offsets::<O>::with_capacity(length);
+    let mut new_values = Vec::<u8>::with_capacity(values_length);
+    for (offsets, values) in arrays {
+        new_offsets.try_extend_from_slice(offsets, 0, offsets.len())?;
+        new_values.extend_from_slice(&values[values_range(offsets)]);
+    }
+    Ok((new_offsets, new_values))
+}
+
+fn concatenate_binary<O: Offset>(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let iter = arrays.iter().map(|array| {
+        let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+        (array.offsets(), array.values().as_slice())
+    });
+    let (offsets, values) = concatenate_offsets_values(iter)?;
+
+    Ok(BinaryArray::<O>::new(
+        arrays[0].data_type().clone(),
+        offsets.into(),
+        values.into(),
+        concatenate_validities(arrays),
+    )
+    .boxed())
+}
+
+fn concatenate_utf8<O: Offset>(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let iter = arrays.iter().map(|array| {
+        let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+        (array.offsets(), array.values().as_slice())
+    });
+    let (offsets, values) = concatenate_offsets_values(iter)?;
+
+    // Safety: the values are concatenations of valid utf8 values
+    Ok(unsafe {
+        Utf8Array::<O>::new_unchecked(
+            arrays[0].data_type().clone(),
+            offsets.into(),
+            values.into(),
+            concatenate_validities(arrays),
+        )
+    }
+    .boxed())
+}
+
+/// Returns the smallest key type of the same signedness as `key_type`, and at least as
+/// large, that can index `length` values (or the largest one).
+fn promote_key_type(key_type: IntegerType, length: usize) -> IntegerType {
+    use IntegerType::*;
+    let candidates = if matches!(key_type, Int8 | Int16 | Int32 | Int64) {
+        [Int8, Int16, Int32, Int64]
+    } else {
+        [UInt8, UInt16, UInt32, UInt64]
+    };
+    let max_key = length.saturating_sub(1);
+    candidates
+        .into_iter()
+        .skip_while(|candidate| *candidate != key_type)
+        .find(|candidate| match_integer_type!(candidate, |$T| $T::try_from(max_key).is_ok()))
+        .unwrap_or(candidates[3])
+}
+
+/// Returns the keys of `arrays` remapped to their merged values with `remaps`.
+fn remap_keys<K: DictionaryKey, P: DictionaryKey>(
+    arrays: &[&DictionaryArray<K>],
+    remaps: &[Vec<usize>],
+) -> Result<Vec<P>> {
+    arrays
+        .iter()
+        .zip(remaps)
+        .flat_map(|(array, remap)| {
+            array
+                .keys()
+                .values()
+                .iter()
+                .map(move |key| P::try_from(remap_key(*key, remap)).map_err(|_| Error::Overflow))
+        })
+        .collect()
+}
+
+fn concatenate_dictionaries<K: DictionaryKey>(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let (value_type, is_sorted) = match arrays[0].data_type().to_logical_type() {
+        DataType::Dictionary(_, value_type, is_sorted) => (value_type.clone(), *is_sorted),
+        _ => unreachable!(),
+    };
+    if arrays.len() == 1 {
+        return Ok(arrays[0].to_boxed());
+    }
+    let dictionaries = arrays
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap())
+        .collect::<Vec<_>>();
+    let values = dictionaries
+        .iter()
+        .map(|array| array.values().as_ref())
+        .collect::<Vec<_>>();
+    let (values, remaps) = merge_dictionary_values(&values, is_sorted);
+
+    let key_type = promote_key_type(K::KEY_TYPE, values.len());
+    let data_type = if key_type == K::KEY_TYPE {
+        arrays[0].data_type().clone()
+    } else {
+        DataType::Dictionary(key_type, value_type, is_sorted)
+    };
+    let validity = concatenate_validities(arrays);
+
+    match_integer_type!(key_type, |$P| {
+        let keys = remap_keys::<K, $P>(&dictionaries, &remaps)?;
+        let keys = PrimitiveArray::<$P>::new($P::PRIMITIVE.into(), keys.into(), validity);
+        DictionaryArray::try_new(data_type, keys, values).map(|array| array.boxed())
+    })
+}
+
+/// How [`concat_str`] handles null values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NullHandling {
+    /// A row is null if any of its values is null (SQL's `CONCAT`).
+    Propagate,
+    /// Null values are skipped, together with their separator (SQL's `CONCAT_WS`). A row
+    /// whose values are all null is an empty string.
+    Skip,
+}
+
+/// Concatenates the strings of `arrays` row-wise, separated by `separator`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::concatenate::{concat_str, NullHandling};
+///
+/// let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+/// let b = Utf8Array::<i32>::from([Some("x"), None, None]);
+///
+/// let result = concat_str(&[&a, &b], "-", NullHandling::Propagate).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("a-x"), None, None]));
+///
+/// let result = concat_str(&[&a, &b], "-", NullHandling::Skip).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("a-x"), Some("b"), Some("")]));
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty, its arrays have different lengths, or the
+/// result overflows `O`.
+pub fn concat_str<O: Offset>(
+    arrays: &[&Utf8Array<O>],
+    separator: &str,
+    null_handling: NullHandling,
+) -> Result<Utf8Array<O>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("concat_str requires at least one array".to_string())
+    })?;
+    let length = first.len();
+    if let Some(array) = arrays.iter().find(|array| array.len() != length) {
+        return Err(Error::InvalidArgumentError(format!(
+            "concat_str requires all arrays to have the same length ({} != {})",
+            length,
+            array.len()
+        )));
+    }
+
+    let validity = match null_handling {
+        NullHandling::Propagate => arrays.iter().filter_map(|array| array.validity()).fold(
+            None,
+            |acc: Option<Bitmap>, validity| {
+                Some(acc.map_or_else(|| validity.clone(), |acc| &acc & validity))
+            },
+        ),
+        NullHandling::Skip => None,
+    };
+    let is_valid = |row: usize| validity.as_ref().map_or(true, |v| v.get_bit(row));
+    // the values concatenated on a valid row
+    let row_values = |row: usize| {
+        arrays
+            .iter()
+            .filter(move |array| array.is_valid(row))
+            .map(move |array| array.value(row))
+    };
+
+    // first pass: the offsets, so that the values are allocated once
+    let lengths = (0..length).map(|row| {
+        if !is_valid(row) {
+            return 0;
+        }
+        let (count, bytes) = row_values(row).fold((0usize, 0), |(count, bytes), value| {
+            (count + 1, bytes + value.len())
+        });
+        bytes + separator.len() * count.saturating_sub(1)
+    });
+    let offsets = Offsets::<O>::try_from_lengths(lengths)?;
+
+    let mut values = Vec::<u8>::with_capacity(offsets.last().to_usize());
+    for row in (0..length).filter(|row| is_valid(*row)) {
+        for (i, value) in row_values(row).enumerate() {
+            if i > 0 {
+                values.extend_from_slice(separator.as_bytes());
+            }
+            values.extend_from_slice(value.as_bytes());
+        }
+    }
+    debug_assert_eq!(values.len(), offsets.last().to_usize());
+
+    // Safety: concatenations of utf8 are utf8 and the offsets were computed from their lengths
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            first.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            validity,
+        )
+    })
 }
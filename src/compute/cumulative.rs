@@ -0,0 +1,151 @@
+//! Contains the cumulative operators [`cumprod`], [`cummax`] and [`cummin`].
+//!
+//! The cumulative sum is `compute::window::cumsum` (or `compute::window::try_cumsum` to error
+//! on overflow).
+use std::cmp::Ordering;
+
+use crate::{
+    array::PrimitiveArray,
+    error::{Error, Result},
+    types::NativeType,
+};
+
+/// The arithmetic used by [`cumprod`]: checked for integers, and IEEE 754 for floats (which
+/// never overflow).
+pub trait Cumulative: NativeType + PartialOrd {
+    /// Returns `self * rhs`, or [`None`] on overflow.
+    fn try_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! cumulative_integer {
+    ($type:ty) => {
+        impl Cumulative for $type {
+            #[inline]
+            fn try_mul(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+        }
+    };
+}
+
+cumulative_integer!(i8);
+cumulative_integer!(i16);
+cumulative_integer!(i32);
+cumulative_integer!(i64);
+cumulative_integer!(u8);
+cumulative_integer!(u16);
+cumulative_integer!(u32);
+cumulative_integer!(u64);
+
+macro_rules! cumulative_float {
+    ($type:ty) => {
+        impl Cumulative for $type {
+            #[inline]
+            fn try_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+        }
+    };
+}
+
+cumulative_float!(f32);
+cumulative_float!(f64);
+
+/// Returns the running aggregate of the non-null values of `array` with `op`. Null slots
+/// remain null and do not change the aggregate.
+fn cumulative<T, F>(array: &PrimitiveArray<T>, op: F) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut accumulator: Option<T> = None;
+    let values = array
+        .iter()
+        .map(|value| {
+            if let Some(value) = value {
+                let value = match accumulator {
+                    Some(accumulator) => op(accumulator, *value).ok_or(Error::Overflow)?,
+                    None => *value,
+                };
+                accumulator = Some(value);
+            }
+            Ok(accumulator.unwrap_or_default())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PrimitiveArray::new(
+        array.data_type().clone(),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Returns the cumulative product of `array`, i.e. the product of all non-null values up to
+/// and including each slot. Null slots remain null and do not reset the product.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cumprod;
+///
+/// let array = Int32Array::from([Some(2), None, Some(3), Some(4)]);
+/// let result = cumprod(&array).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(2), None, Some(6), Some(24)]));
+/// ```
+/// # Errors
+/// This function errors with [`Error::Overflow`] iff an integer product overflows.
+pub fn cumprod<T: Cumulative>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>> {
+    cumulative(array, T::try_mul)
+}
+
+/// Returns the value replacing the running extreme `accumulator` by `value` iff `value` is
+/// `ordering` relative to it. `NaN`s are ignored unless all values so far are `NaN`.
+#[inline]
+#[allow(clippy::eq_op)]
+fn extreme<T: PartialOrd>(accumulator: T, value: T, ordering: Ordering) -> T {
+    let is_nan = accumulator != accumulator;
+    if is_nan || value.partial_cmp(&accumulator) == Some(ordering) {
+        value
+    } else {
+        accumulator
+    }
+}
+
+/// Returns the cumulative maximum of `array`, i.e. the maximum of all non-null values up to
+/// and including each slot. Null slots remain null and do not reset the maximum.
+///
+/// `NaN`s are ignored unless all values up to a slot are `NaN`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cummax;
+///
+/// let array = Int32Array::from([Some(2), None, Some(1), Some(4)]);
+/// let result = cummax(&array);
+/// assert_eq!(result, Int32Array::from([Some(2), None, Some(2), Some(4)]));
+/// ```
+pub fn cummax<T: NativeType + PartialOrd>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, |accumulator, value| {
+        Some(extreme(accumulator, value, Ordering::Greater))
+    })
+    .unwrap()
+}
+
+/// Returns the cumulative minimum of `array`, i.e. the minimum of all non-null values up to
+/// and including each slot. Null slots remain null and do not reset the minimum.
+///
+/// `NaN`s are ignored unless all values up to a slot are `NaN`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cummin;
+///
+/// let array = Int32Array::from([Some(2), None, Some(3), Some(1)]);
+/// let result = cummin(&array);
+/// assert_eq!(result, Int32Array::from([Some(2), None, Some(2), Some(1)]));
+/// ```
+pub fn cummin<T: NativeType + PartialOrd>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, |accumulator, value| {
+        Some(extreme(accumulator, value, Ordering::Less))
+    })
+    .unwrap()
+}
@@ -4,7 +4,8 @@ use crate::bitmap::utils::{BitChunk, BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::offset::{Offset, Offsets, OffsetsBuffer};
 use crate::types::simd::{NativeSimd, Simd};
 use crate::types::BitChunkOnes;
 use crate::{array::*, types::NativeType};
@@ -185,6 +186,106 @@ fn filter_primitive<T: NativeType + Simd>(
     filter_nonnull_primitive(array, mask.values())
 }
 
+/// Masks where the number of selected (or discarded) values is smaller than `1 / SPARSE_RATIO`
+/// of the length are applied by copying runs of selected values. Other masks are applied
+/// by selecting values one by one.
+const SPARSE_RATIO: usize = 10;
+
+/// Returns whether `mask` is sufficiently sparse or dense for runs of selected values to
+/// be copied instead of selecting values one by one.
+fn prefer_slices(mask: &Bitmap) -> bool {
+    let unset_bits = mask.unset_bits();
+    let set_bits = mask.len() - unset_bits;
+    set_bits.min(unset_bits) * SPARSE_RATIO < mask.len()
+}
+
+/// Filters `array` by copying contiguous runs of selected values.
+fn filter_slices(array: &dyn Array, mask: &Bitmap) -> Box<dyn Array> {
+    let iter = SlicesIterator::new(mask);
+    let mut mutable = make_growable(&[array], false, iter.slots());
+    iter.for_each(|(start, len)| mutable.extend(0, start, len));
+    mutable.as_box()
+}
+
+/// Filters the offsets, values and validity of a binary-like array one value at a time.
+fn filter_binary_values<O: Offset>(
+    offsets: &OffsetsBuffer<O>,
+    values: &[u8],
+    validity: Option<&Bitmap>,
+    mask: &Bitmap,
+) -> (OffsetsBuffer<O>, Vec<u8>, Option<Bitmap>) {
+    assert_eq!(offsets.len(), mask.len());
+    let filter_count = mask.len() - mask.unset_bits();
+
+    let mut new_offsets = Offsets::<O>::with_capacity(filter_count);
+    let mut new_values = Vec::<u8>::new();
+    let mut new_validity = validity.map(|_| MutableBitmap::with_capacity(filter_count));
+
+    mask.iter()
+        .enumerate()
+        .filter(|(_, is_selected)| *is_selected)
+        .for_each(|(index, _)| {
+            let (start, end) = offsets.start_end(index);
+            new_values.extend_from_slice(&values[start..end]);
+            // the new offsets are bounded by the existing ones and thus do not overflow
+            new_offsets.try_push_usize(end - start).unwrap();
+            if let (Some(new_validity), Some(validity)) = (new_validity.as_mut(), validity) {
+                new_validity.push(validity.get_bit(index));
+            }
+        });
+
+    (
+        new_offsets.into(),
+        new_values,
+        new_validity.map(|x| x.into()),
+    )
+}
+
+fn filter_utf8<O: Offset>(array: &Utf8Array<O>, mask: &Bitmap) -> Utf8Array<O> {
+    let (offsets, values, validity) =
+        filter_binary_values(array.offsets(), array.values(), array.validity(), mask);
+    // safety - every value is a complete value of `array`, which is valid utf8
+    unsafe {
+        Utf8Array::<O>::new_unchecked(array.data_type().clone(), offsets, values.into(), validity)
+    }
+}
+
+fn filter_binary<O: Offset>(array: &BinaryArray<O>, mask: &Bitmap) -> BinaryArray<O> {
+    let (offsets, values, validity) =
+        filter_binary_values(array.offsets(), array.values(), array.validity(), mask);
+    BinaryArray::<O>::new(array.data_type().clone(), offsets, values.into(), validity)
+}
+
+/// Filters a binary-like array, picking between copying runs of values and selecting
+/// values one by one based on the density of the mask.
+fn filter_binary_like<A, F>(array: &A, mask: &Bitmap, op: F) -> Box<dyn Array>
+where
+    A: Array + 'static,
+    F: Fn(&A, &Bitmap) -> A,
+{
+    if prefer_slices(mask) {
+        filter_slices(array, mask)
+    } else {
+        Box::new(op(array, mask))
+    }
+}
+
+fn filter_dictionary<K: DictionaryKey + Simd>(
+    array: &DictionaryArray<K>,
+    mask: &BooleanArray,
+) -> DictionaryArray<K> {
+    let keys = filter_primitive::<K>(array.keys(), mask);
+    // safety - this operation takes a subset of keys and thus preserves the dictionary's invariant
+    unsafe {
+        DictionaryArray::<K>::try_new_unchecked(
+            array.data_type().clone(),
+            keys,
+            array.values().clone(),
+        )
+        .unwrap()
+    }
+}
+
 fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize)]) {
     chunks
         .iter()
@@ -194,8 +295,14 @@ fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize
 /// Returns a prepared function optimized to filter multiple arrays.
 /// Creating this function requires time, but using it is faster than [filter] when the
 /// same filter needs to be applied to multiple arrays (e.g. a multiple columns).
+///
+/// As in [`filter`], the nulls of `filter` are interpreted as `false`.
 pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
-    let iter = SlicesIterator::new(filter.values());
+    let values = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+    let iter = SlicesIterator::new(&values);
     let filter_count = iter.slots();
     let chunks = iter.collect::<Vec<_>>();
 
@@ -224,6 +331,23 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
                 let array: Utf8Array<i64> = growable.into();
                 Box::new(array)
             }
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let mut growable =
+                    growable::GrowablePrimitive::<$T>::new(vec![array.keys()], false, filter_count);
+                filter_growable(&mut growable, &chunks);
+                let keys: PrimitiveArray<$T> = growable.into();
+                // safety - this operation takes a subset of keys and thus preserves the dictionary's invariant
+                let array = unsafe {
+                    DictionaryArray::<$T>::try_new_unchecked(
+                        array.data_type().clone(),
+                        keys,
+                        array.values().clone(),
+                    )
+                    .unwrap()
+                };
+                Box::new(array)
+            }),
             _ => {
                 let mut mutable = make_growable(&[array], false, filter_count);
                 chunks
@@ -280,33 +404,85 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<$T>(array, filter)))
         }),
-        _ => {
-            let iter = SlicesIterator::new(filter.values());
-            let mut mutable = make_growable(&[array], false, iter.slots());
-            iter.for_each(|(start, len)| mutable.extend(0, start, len));
-            Ok(mutable.as_box())
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_dictionary::<$T>(array, filter)))
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(filter_binary_like(
+                array,
+                filter.values(),
+                filter_utf8::<i32>,
+            ))
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(filter_binary_like(
+                array,
+                filter.values(),
+                filter_utf8::<i64>,
+            ))
         }
+        Binary => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(filter_binary_like(
+                array,
+                filter.values(),
+                filter_binary::<i32>,
+            ))
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(filter_binary_like(
+                array,
+                filter.values(),
+                filter_binary::<i64>,
+            ))
+        }
+        // nested arrays copy runs of selected values, since each value may contain many items
+        _ => Ok(filter_slices(array, filter.values())),
     }
 }
 
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
-/// This is a convenience function: filter multiple columns is embarassingly parallel.
+/// The filter is analyzed once and applied to every column, with the same semantics as [`filter`].
+/// # Errors
+/// This function errors iff the columns do not have the same length as the filter.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
     columns: &Chunk<A>,
     filter_values: &BooleanArray,
 ) -> Result<Chunk<Box<dyn Array>>> {
-    let arrays = columns.arrays();
+    // The validities may be masking out `true` bits, making the filter operation
+    // based on the values incorrect
+    if let Some(validities) = filter_values.validity() {
+        let values = filter_values.values() & validities;
+        let filter_values = BooleanArray::new(DataType::Boolean, values, None);
+        return filter_chunk(columns, &filter_values);
+    }
 
-    let num_colums = arrays.len();
+    let arrays = columns.arrays();
+    if !arrays.is_empty() && columns.len() != filter_values.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The filter must have the same length as the chunk ({} != {})",
+            filter_values.len(),
+            columns.len()
+        )));
+    }
 
-    let filtered_arrays = match num_colums {
-        1 => {
-            vec![filter(columns.arrays()[0].as_ref(), filter_values)?]
-        }
-        _ => {
-            let filter = build_filter(filter_values)?;
-            arrays.iter().map(|a| filter(a.as_ref())).collect()
-        }
+    let false_count = filter_values.values().unset_bits();
+    let filtered_arrays = if false_count == 0 {
+        arrays.iter().map(|a| a.as_ref().to_boxed()).collect()
+    } else if false_count == filter_values.len() {
+        arrays
+            .iter()
+            .map(|a| new_empty_array(a.as_ref().data_type().clone()))
+            .collect()
+    } else if arrays.len() == 1 {
+        vec![filter(arrays[0].as_ref(), filter_values)?]
+    } else {
+        let filter = build_filter(filter_values)?;
+        arrays.iter().map(|a| filter(a.as_ref())).collect()
     };
     Chunk::try_new(filtered_arrays)
 }
@@ -0,0 +1,333 @@
+//! Contains the operator [`format_array`], formatting the values of an array to strings with a
+//! printf-style format (e.g. SQL's `TO_CHAR`).
+use crate::{
+    array::{Array, BooleanArray, PrimitiveArray, Utf8Array},
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::Offset,
+};
+
+/// The conversion of a format specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conversion {
+    /// `%d` or `%i`
+    Decimal,
+    /// `%x` or `%X`
+    Hex { upper: bool },
+    /// `%o`
+    Octal,
+    /// `%f` or `%F`
+    Float,
+    /// `%e` or `%E`
+    Exponent { upper: bool },
+    /// `%s`
+    String,
+}
+
+/// A parsed printf-style format with a single conversion.
+#[derive(Debug, Clone)]
+struct Format {
+    prefix: String,
+    suffix: String,
+    left: bool,
+    zero: bool,
+    plus: bool,
+    space: bool,
+    width: usize,
+    precision: Option<usize>,
+    conversion: Conversion,
+}
+
+fn unescape(text: &str) -> Result<String> {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' && chars.next() != Some('%') {
+            return Err(Error::InvalidArgumentError(
+                "A format must contain exactly one conversion (use `%%` for `%`)".to_string(),
+            ));
+        }
+        result.push(c);
+    }
+    Ok(result)
+}
+
+fn parse_number(chars: &mut std::iter::Peekable<std::str::CharIndices>) -> Option<usize> {
+    let mut number = None;
+    while let Some(digit) = chars.peek().and_then(|(_, c)| c.to_digit(10)) {
+        number = Some(number.unwrap_or(0usize).saturating_mul(10) + digit as usize);
+        chars.next();
+    }
+    number
+}
+
+impl Format {
+    fn try_new(format: &str) -> Result<Self> {
+        // find the first `%` that is not an escaped `%%`
+        let mut start = None;
+        let mut chars = format.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c == '%' {
+                if chars.peek().map(|(_, c)| *c) == Some('%') {
+                    chars.next();
+                } else {
+                    start = Some(i);
+                    break;
+                }
+            }
+        }
+        let start = start.ok_or_else(|| {
+            Error::InvalidArgumentError(format!("The format \"{}\" has no conversion", format))
+        })?;
+
+        let (mut left, mut zero, mut plus, mut space) = (false, false, false, false);
+        while let Some((_, c)) = chars.peek() {
+            match c {
+                '-' => left = true,
+                '0' => zero = true,
+                '+' => plus = true,
+                ' ' => space = true,
+                _ => break,
+            }
+            chars.next();
+        }
+        let width = parse_number(&mut chars).unwrap_or(0);
+        let precision = if chars.peek().map(|(_, c)| *c) == Some('.') {
+            chars.next();
+            Some(parse_number(&mut chars).unwrap_or(0))
+        } else {
+            None
+        };
+
+        let (end, conversion) = match chars.next() {
+            Some((i, 'd' | 'i')) => (i, Conversion::Decimal),
+            Some((i, 'x')) => (i, Conversion::Hex { upper: false }),
+            Some((i, 'X')) => (i, Conversion::Hex { upper: true }),
+            Some((i, 'o')) => (i, Conversion::Octal),
+            Some((i, 'f' | 'F')) => (i, Conversion::Float),
+            Some((i, 'e')) => (i, Conversion::Exponent { upper: false }),
+            Some((i, 'E')) => (i, Conversion::Exponent { upper: true }),
+            Some((i, 's')) => (i, Conversion::String),
+            other => {
+                return Err(Error::InvalidArgumentError(format!(
+                    "The format \"{}\" has an invalid conversion {:?}",
+                    format,
+                    other.map(|(_, c)| c)
+                )))
+            }
+        };
+
+        Ok(Self {
+            prefix: unescape(&format[..start])?,
+            suffix: unescape(&format[end + 1..])?,
+            left,
+            zero,
+            plus,
+            space,
+            width,
+            precision,
+            conversion,
+        })
+    }
+
+    /// Writes `sign` and `body` padded to the width, with the prefix and suffix.
+    fn finish(&self, negative: bool, body: &str, numeric: bool) -> String {
+        let sign = if negative {
+            "-"
+        } else if self.plus && numeric {
+            "+"
+        } else if self.space && numeric {
+            " "
+        } else {
+            ""
+        };
+        let length = sign.len() + body.chars().count();
+        let padding = self.width.saturating_sub(length);
+
+        let mut result =
+            String::with_capacity(self.prefix.len() + self.suffix.len() + length + padding);
+        result.push_str(&self.prefix);
+        if self.left {
+            result.push_str(sign);
+            result.push_str(body);
+            result.extend(std::iter::repeat(' ').take(padding));
+        } else if self.zero && numeric {
+            result.push_str(sign);
+            result.extend(std::iter::repeat('0').take(padding));
+            result.push_str(body);
+        } else {
+            result.extend(std::iter::repeat(' ').take(padding));
+            result.push_str(sign);
+            result.push_str(body);
+        }
+        result.push_str(&self.suffix);
+        result
+    }
+
+    /// Formats an integer of `bits` bits.
+    fn integer(&self, value: i128, bits: u32) -> String {
+        match self.conversion {
+            Conversion::Decimal => {
+                let mut body = value.unsigned_abs().to_string();
+                if let Some(precision) = self.precision {
+                    // the precision is the minimum number of digits
+                    if body.len() < precision {
+                        body.insert_str(0, &"0".repeat(precision - body.len()));
+                    }
+                }
+                self.finish(value < 0, &body, true)
+            }
+            Conversion::Hex { .. } | Conversion::Octal => {
+                // negative values are written in two's complement of their width
+                let value = (value as u128) & (u128::MAX >> (128 - bits));
+                let body = match self.conversion {
+                    Conversion::Hex { upper: true } => format!("{:X}", value),
+                    Conversion::Hex { upper: false } => format!("{:x}", value),
+                    _ => format!("{:o}", value),
+                };
+                self.finish(false, &body, true)
+            }
+            Conversion::Float | Conversion::Exponent { .. } => self.float(value as f64),
+            Conversion::String => self.string(&value.to_string()),
+        }
+    }
+
+    fn float(&self, value: f64) -> String {
+        if self.conversion == Conversion::String {
+            return self.string(&value.to_string());
+        }
+        let precision = self.precision.unwrap_or(6);
+        let negative = value.is_sign_negative() && !value.is_nan();
+        let value = value.abs();
+        let body = match self.conversion {
+            Conversion::Exponent { upper } if value.is_finite() => {
+                // Rust writes `1.5e2`, printf writes `1.5e+02`
+                let body = format!("{:.*e}", precision, value);
+                let (mantissa, exponent) = body.split_once('e').unwrap();
+                let exponent = exponent.parse::<i32>().unwrap();
+                let e = if upper { 'E' } else { 'e' };
+                let sign = if exponent < 0 { '-' } else { '+' };
+                format!("{}{}{}{:02}", mantissa, e, sign, exponent.abs())
+            }
+            _ => format!("{:.*}", precision, value),
+        };
+        self.finish(negative, &body, true)
+    }
+
+    fn string(&self, value: &str) -> String {
+        match self.precision {
+            // the precision is the maximum number of characters
+            Some(precision) => {
+                let end = value
+                    .char_indices()
+                    .nth(precision)
+                    .map(|(i, _)| i)
+                    .unwrap_or(value.len());
+                self.finish(false, &value[..end], false)
+            }
+            None => self.finish(false, value, false),
+        }
+    }
+}
+
+fn format_values<I: Iterator<Item = String>>(values: I, array: &dyn Array) -> Utf8Array<i32> {
+    Utf8Array::<i32>::from_iter_values(values).with_validity(array.validity().cloned())
+}
+
+macro_rules! format_integer {
+    ($array:expr, $format:expr, $ty:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        let values = array
+            .values_iter()
+            .map(|x| $format.integer(*x as i128, <$ty>::BITS));
+        format_values(values, array)
+    }};
+}
+
+macro_rules! format_float {
+    ($array:expr, $format:expr, $ty:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        let values = array.values_iter().map(|x| $format.float(*x as f64));
+        format_values(values, array)
+    }};
+}
+
+fn format_utf8<O: Offset>(array: &Utf8Array<O>, format: &Format) -> Utf8Array<i32> {
+    format_values(array.values_iter().map(|x| format.string(x)), array)
+}
+
+/// Formats each of the values of `array` according to a printf-style `format`, such as
+/// `"%d"`, `"%05.2f"` or `"value: %e"`.
+///
+/// The format must contain exactly one conversion `%[flags][width][.precision]conversion`,
+/// and may contain any other text, where `%%` writes `%`. Supported flags are `-` (left
+/// alignment), `0` (zero padding), `+` (always write the sign) and ` ` (a space for positive
+/// numbers). Supported conversions are:
+/// * `d`, `i`: integers, in decimal (the precision is the minimum number of digits)
+/// * `x`, `X`, `o`: integers, in hexadecimal or octal (negative values in two's complement)
+/// * `f`, `F`: integers and floats, with `precision` decimals (6 by default)
+/// * `e`, `E`: integers and floats, in scientific notation with `precision` decimals
+/// * `s`: integers, floats, booleans and strings (the precision is the maximum length)
+///
+/// Null values remain null.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Utf8Array};
+/// use arrow2::compute::format::format_array;
+///
+/// let array = Float64Array::from([Some(3.14159), None, Some(-2.5)]);
+/// let result = format_array(&array, "%08.2f").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("00003.14"), None, Some("-0002.50")]));
+///
+/// let result = format_array(&array, "%.3e").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("3.142e+00"), None, Some("-2.500e+00")]));
+/// ```
+/// # Errors
+/// This function errors iff the format is invalid or its conversion does not support the
+/// [`DataType`] of `array`.
+pub fn format_array(array: &dyn Array, format: &str) -> Result<Utf8Array<i32>> {
+    let format = Format::try_new(format)?;
+
+    use DataType::*;
+    Ok(match (array.data_type(), format.conversion) {
+        (Int8, _) => format_integer!(array, format, i8),
+        (Int16, _) => format_integer!(array, format, i16),
+        (Int32, _) => format_integer!(array, format, i32),
+        (Int64, _) => format_integer!(array, format, i64),
+        (UInt8, _) => format_integer!(array, format, u8),
+        (UInt16, _) => format_integer!(array, format, u16),
+        (UInt32, _) => format_integer!(array, format, u32),
+        (UInt64, _) => format_integer!(array, format, u64),
+        (Float32, Conversion::Float | Conversion::Exponent { .. } | Conversion::String) => {
+            format_float!(array, format, f32)
+        }
+        (Float64, Conversion::Float | Conversion::Exponent { .. } | Conversion::String) => {
+            format_float!(array, format, f64)
+        }
+        (Boolean, Conversion::String) => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let values = array
+                .values_iter()
+                .map(|x| format.string(if x { "true" } else { "false" }));
+            format_values(values, array)
+        }
+        (Utf8, Conversion::String) => {
+            format_utf8::<i32>(array.as_any().downcast_ref().unwrap(), &format)
+        }
+        (LargeUtf8, Conversion::String) => {
+            format_utf8::<i64>(array.as_any().downcast_ref().unwrap(), &format)
+        }
+        (data_type, conversion) => {
+            return Err(Error::InvalidArgumentError(format!(
+                "The conversion {:?} of the format does not support the data type {:?}",
+                conversion, data_type
+            )))
+        }
+    })
+}
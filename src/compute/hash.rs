@@ -1,4 +1,5 @@
-//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators.
+//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators, and [`hash_columns`],
+//! the hash of rows of multiple arrays.
 // multiversion does not copy documentation, causing a false positive
 #![allow(missing_docs)]
 use ahash::RandomState;
@@ -12,7 +13,11 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeListArray,
+        ListArray, PrimitiveArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{Error, Result},
     offset::Offset,
@@ -21,6 +26,29 @@ use crate::{
 
 use super::arity::unary;
 
+/// The hash of null values when they are part of a list, struct or row.
+const NULL_HASH: u64 = 0x2545_f491_4f6c_dd1d;
+/// The initial hash of lists, structs and rows, before combining the hashes of their values.
+const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Mixes `hash` into `seed`, using boost's `hash_combine` with a 64-bit constant:
+/// `seed ^ (hash + 0x9e3779b97f4a7c15 + (seed << 6) + (seed >> 2))`.
+#[inline]
+fn combine(seed: u64, hash: u64) -> u64 {
+    seed ^ hash
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(seed << 6)
+        .wrapping_add(seed >> 2)
+}
+
+/// Element-wise hash of `array` where null values hash to [`NULL_HASH`].
+fn hash_or_null(array: &dyn Array) -> Result<Vec<u64>> {
+    Ok(hash(array)?
+        .iter()
+        .map(|x| x.copied().unwrap_or(NULL_HASH))
+        .collect())
+}
+
 #[multiversion]
 #[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
 /// Element-wise hash of a [`PrimitiveArray`]. Validity is preserved.
@@ -72,6 +100,155 @@ pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
     PrimitiveArray::<u64>::new(DataType::UInt64, values, array.validity().cloned())
 }
 
+/// Element-wise hash of a [`ListArray`], combining the hashes of the values of each list.
+/// Validity is preserved.
+/// # Errors
+/// This function errors iff the values' [`DataType`] is not supported by [`hash`].
+pub fn hash_list<O: Offset>(array: &ListArray<O>) -> Result<PrimitiveArray<u64>> {
+    let values = hash_or_null(array.values().as_ref())?;
+
+    let hashes = array
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|w| {
+            values[w[0].to_usize()..w[1].to_usize()]
+                .iter()
+                .fold(SEED, |seed, hash| combine(seed, *hash))
+        })
+        .collect::<Vec<_>>();
+
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        hashes.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Element-wise hash of a [`FixedSizeListArray`], combining the hashes of the values of each
+/// list. Validity is preserved.
+/// # Errors
+/// This function errors iff the values' [`DataType`] is not supported by [`hash`].
+pub fn hash_fixed_size_list(array: &FixedSizeListArray) -> Result<PrimitiveArray<u64>> {
+    let values = hash_or_null(array.values().as_ref())?;
+    let size = array.size();
+
+    let hashes = (0..array.len())
+        .map(|i| {
+            values[i * size..(i + 1) * size]
+                .iter()
+                .fold(SEED, |seed, hash| combine(seed, *hash))
+        })
+        .collect::<Vec<_>>();
+
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        hashes.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Element-wise hash of a [`StructArray`], combining the hashes of its fields in order.
+/// Validity is preserved.
+/// # Errors
+/// This function errors iff any of the fields' [`DataType`] is not supported by [`hash`].
+pub fn hash_struct(array: &StructArray) -> Result<PrimitiveArray<u64>> {
+    let hashes = combine_rows(array.values().iter().map(|x| x.as_ref()), array.len())?;
+
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        hashes.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Element-wise hash of a [`DictionaryArray`], equal to the hash of its decoded values.
+/// A slot is null when its key or its value is null.
+/// # Errors
+/// This function errors iff the values' [`DataType`] is not supported by [`hash`].
+pub fn hash_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+) -> Result<PrimitiveArray<u64>> {
+    let values = hash(array.values().as_ref())?;
+
+    let hashes = array
+        .keys_values_iter()
+        .map(|key| values.value(key))
+        .collect::<Vec<_>>();
+
+    let validity = if array.null_count() == 0 && values.null_count() == 0 {
+        None
+    } else {
+        Some(
+            array
+                .keys_iter()
+                .map(|key| key.map_or(false, |key| values.is_valid(key)))
+                .collect::<Bitmap>(),
+        )
+    };
+
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        hashes.into(),
+        validity,
+    ))
+}
+
+/// Combines the element-wise hashes of `arrays`, each of length `length`.
+fn combine_rows<'a, I: Iterator<Item = &'a dyn Array>>(
+    arrays: I,
+    length: usize,
+) -> Result<Vec<u64>> {
+    let mut hashes = vec![SEED; length];
+    for array in arrays {
+        if array.len() != length {
+            return Err(Error::InvalidArgumentError(
+                "Arrays must have the same length to hash their rows".to_string(),
+            ));
+        }
+        hashes
+            .iter_mut()
+            .zip(hash_or_null(array)?)
+            .for_each(|(seed, hash)| *seed = combine(*seed, hash));
+    }
+    Ok(hashes)
+}
+
+/// Returns the hash of each row of `arrays`, e.g. to use as keys of a hash join or group by.
+///
+/// The hash of a row is computed by mixing the [`hash`] of each of its values, in order,
+/// into a fixed seed via `seed ^ (hash + 0x9e3779b97f4a7c15 + (seed << 6) + (seed >> 2))`.
+/// Null values hash to a fixed value, so that equal rows (including nulls) have equal hashes.
+/// The result has no nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::hash::hash_columns;
+///
+/// let a = Int32Array::from([Some(1), None, Some(1)]);
+/// let b = Utf8Array::<i32>::from([Some("a"), None, Some("a")]);
+/// let hashes = hash_columns(&[&a, &b]).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_eq!(hashes.null_count(), 0);
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or any of
+/// their [`DataType`]s is not supported by [`hash`].
+pub fn hash_columns(arrays: &[&dyn Array]) -> Result<PrimitiveArray<u64>> {
+    let length = arrays
+        .first()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("hash_columns requires at least one array".to_string())
+        })?
+        .len();
+    let hashes = combine_rows(arrays.iter().copied(), length)?;
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        hashes.into(),
+        None,
+    ))
+}
+
 macro_rules! with_match_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
@@ -102,7 +279,9 @@ macro_rules! with_match_primitive_type {(
 /// * Boolean types
 /// * All primitive types except `Float32` and `Float64`
 /// * `[Large]Utf8`;
-/// * `[Large]Binary`.
+/// * `[Large]Binary`;
+/// * `[Large]List`, `FixedSizeList` and `Struct` of supported types;
+/// * `Dictionary` of supported types, hashed as its decoded values.
 /// # Errors
 /// This function errors whenever it does not support the specific `DataType`.
 pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
@@ -116,6 +295,13 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
         Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
         LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        List => hash_list::<i32>(array.as_any().downcast_ref().unwrap())?,
+        LargeList => hash_list::<i64>(array.as_any().downcast_ref().unwrap())?,
+        FixedSizeList => hash_fixed_size_list(array.as_any().downcast_ref().unwrap())?,
+        Struct => hash_struct(array.as_any().downcast_ref().unwrap())?,
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            hash_dictionary::<$T>(array.as_any().downcast_ref().unwrap())?
+        }),
         t => {
             return Err(Error::NotYetImplemented(format!(
                 "Hash not implemented for type {:?}",
@@ -139,23 +325,30 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
 /// assert_eq!(can_hash(&data_type), false);
 /// ```
 pub fn can_hash(data_type: &DataType) -> bool {
-    matches!(
-        data_type.to_physical_type(),
-        PhysicalType::Boolean
-            | PhysicalType::Primitive(PrimitiveType::Int8)
-            | PhysicalType::Primitive(PrimitiveType::Int16)
-            | PhysicalType::Primitive(PrimitiveType::Int32)
-            | PhysicalType::Primitive(PrimitiveType::Int64)
-            | PhysicalType::Primitive(PrimitiveType::Int128)
-            | PhysicalType::Primitive(PrimitiveType::DaysMs)
-            | PhysicalType::Primitive(PrimitiveType::MonthDayNano)
-            | PhysicalType::Primitive(PrimitiveType::UInt8)
-            | PhysicalType::Primitive(PrimitiveType::UInt16)
-            | PhysicalType::Primitive(PrimitiveType::UInt32)
-            | PhysicalType::Primitive(PrimitiveType::UInt64)
-            | PhysicalType::Binary
-            | PhysicalType::LargeBinary
-            | PhysicalType::Utf8
-            | PhysicalType::LargeUtf8
-    )
+    match data_type.to_logical_type() {
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            can_hash(field.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().all(|f| can_hash(f.data_type())),
+        DataType::Dictionary(_, values, _) => can_hash(values),
+        _ => matches!(
+            data_type.to_physical_type(),
+            PhysicalType::Boolean
+                | PhysicalType::Primitive(PrimitiveType::Int8)
+                | PhysicalType::Primitive(PrimitiveType::Int16)
+                | PhysicalType::Primitive(PrimitiveType::Int32)
+                | PhysicalType::Primitive(PrimitiveType::Int64)
+                | PhysicalType::Primitive(PrimitiveType::Int128)
+                | PhysicalType::Primitive(PrimitiveType::DaysMs)
+                | PhysicalType::Primitive(PrimitiveType::MonthDayNano)
+                | PhysicalType::Primitive(PrimitiveType::UInt8)
+                | PhysicalType::Primitive(PrimitiveType::UInt16)
+                | PhysicalType::Primitive(PrimitiveType::UInt32)
+                | PhysicalType::Primitive(PrimitiveType::UInt64)
+                | PhysicalType::Binary
+                | PhysicalType::LargeBinary
+                | PhysicalType::Utf8
+                | PhysicalType::LargeUtf8
+        ),
+    }
 }
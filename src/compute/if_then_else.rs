@@ -1,10 +1,16 @@
-//! Contains the operator [`if_then_else`].
-use crate::array::{growable, Array, BooleanArray};
+//! Contains the operators [`if_then_else`] and [`zip`].
+use crate::array::{growable, Array, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray};
 use crate::bitmap::utils::SlicesIterator;
+use crate::datatypes::DataType;
 use crate::error::{Error, Result};
 
 /// Returns the values from `lhs` if the predicate is `true` or from the `rhs` if the predicate is false
 /// Returns `None` if the predicate is `None`.
+///
+/// [`DictionaryArray`]s with different values are supported: their values are merged as
+/// when they are concatenated (see `compute::concatenate::concatenate`), so that the values
+/// of the result are those of `lhs` and `rhs`, without duplicates when they are primitive,
+/// binary or utf8, in order of first appearance.
 /// # Example
 /// ```rust
 /// # use arrow2::error::Result;
@@ -28,48 +34,196 @@ pub fn if_then_else(
     lhs: &dyn Array,
     rhs: &dyn Array,
 ) -> Result<Box<dyn Array>> {
-    if lhs.data_type() != rhs.data_type() {
+    check_types(lhs, rhs)?;
+    if (lhs.len() != rhs.len()) | (lhs.len() != predicate.len()) {
         return Err(Error::InvalidArgumentError(format!(
-            "If then else requires the arguments to have the same datatypes ({:?} != {:?})",
-            lhs.data_type(),
-            rhs.data_type()
+            "If then else requires all arguments to have the same length (predicate = {}, lhs = {}, rhs = {})",
+            predicate.len(),
+            lhs.len(),
+            rhs.len()
         )));
     }
-    if (lhs.len() != rhs.len()) | (lhs.len() != predicate.len()) {
+
+    zip_impl(predicate, lhs, rhs)
+}
+
+/// Like [`if_then_else`], but where `lhs` and `rhs` can also be scalars, represented as
+/// arrays of length 1, whose value is used for every slot of `predicate`.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::if_then_else::zip;
+/// use arrow2::array::{Int32Array, BooleanArray};
+///
+/// # fn main() -> Result<()> {
+/// let lhs = Int32Array::from_slice(&[1, 2, 3]);
+/// let rhs = Int32Array::from_slice(&[0]);
+/// let predicate = BooleanArray::from(&[Some(true), None, Some(false)]);
+/// let result = zip(&predicate, &lhs, &rhs)?;
+///
+/// let expected = Int32Array::from(&[Some(1), None, Some(0)]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `lhs` and `rhs` have different data types or any of them has a
+/// length different from `predicate` and from 1.
+pub fn zip(predicate: &BooleanArray, lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    check_types(lhs, rhs)?;
+    let is_valid_len = |len: usize| len == predicate.len() || len == 1;
+    if !is_valid_len(lhs.len()) || !is_valid_len(rhs.len()) {
         return Err(Error::InvalidArgumentError(format!(
-            "If then else requires all arguments to have the same length (predicate = {}, lhs = {}, rhs = {})",
+            "zip requires each argument to be a scalar or to have the predicate's length (predicate = {}, lhs = {}, rhs = {})",
             predicate.len(),
             lhs.len(),
             rhs.len()
         )));
     }
 
-    let result = if predicate.null_count() > 0 {
-        let mut growable = growable::make_growable(&[lhs, rhs], true, lhs.len());
+    zip_impl(predicate, lhs, rhs)
+}
+
+fn check_types(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "If then else requires the arguments to have the same datatypes ({:?} != {:?})",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    Ok(())
+}
+
+fn zip_impl(predicate: &BooleanArray, lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    if let DataType::Dictionary(key_type, _, _) = lhs.data_type().to_logical_type() {
+        match_integer_type!(key_type, |$T| {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            zip_dictionary::<$T>(predicate, lhs, rhs)
+        })
+    } else {
+        Ok(zip_growable(predicate, lhs, rhs))
+    }
+}
+
+/// Extends `growable` from the array at `index`, repeating its single value if it is a scalar.
+#[inline]
+fn extend(
+    growable: &mut dyn growable::Growable<'_>,
+    is_scalar: bool,
+    index: usize,
+    start: usize,
+    len: usize,
+) {
+    if is_scalar {
+        (0..len).for_each(|_| growable.extend(index, 0, 1))
+    } else {
+        growable.extend(index, start, len)
+    }
+}
+
+fn zip_growable(predicate: &BooleanArray, lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    let length = predicate.len();
+    // scalars (arrays of length 1) are repeated for every slot
+    let lhs_scalar = lhs.len() == 1 && length != 1;
+    let rhs_scalar = rhs.len() == 1 && length != 1;
+
+    if predicate.null_count() > 0 {
+        let mut growable = growable::make_growable(&[lhs, rhs], true, length);
         for (i, v) in predicate.iter().enumerate() {
             match v {
-                Some(v) => growable.extend(!v as usize, i, 1),
+                Some(true) => extend(&mut *growable, lhs_scalar, 0, i, 1),
+                Some(false) => extend(&mut *growable, rhs_scalar, 1, i, 1),
                 None => growable.extend_validity(1),
             }
         }
         growable.as_box()
     } else {
-        let mut growable = growable::make_growable(&[lhs, rhs], false, lhs.len());
+        let mut growable = growable::make_growable(&[lhs, rhs], false, length);
         let mut start_falsy = 0;
         let mut total_len = 0;
         for (start, len) in SlicesIterator::new(predicate.values()) {
             if start != start_falsy {
-                growable.extend(1, start_falsy, start - start_falsy);
+                extend(
+                    &mut *growable,
+                    rhs_scalar,
+                    1,
+                    start_falsy,
+                    start - start_falsy,
+                );
                 total_len += start - start_falsy;
             };
-            growable.extend(0, start, len);
+            extend(&mut *growable, lhs_scalar, 0, start, len);
             total_len += len;
             start_falsy = start + len;
         }
-        if total_len != lhs.len() {
-            growable.extend(1, total_len, lhs.len() - total_len);
+        if total_len != length {
+            extend(&mut *growable, rhs_scalar, 1, total_len, length - total_len);
         }
         growable.as_box()
+    }
+}
+
+fn zip_dictionary<K: DictionaryKey>(
+    predicate: &BooleanArray,
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> Result<Box<dyn Array>> {
+    let (values, lhs_keys, rhs_keys) = unify_dictionaries(lhs, rhs)?;
+
+    let keys = zip_growable(predicate, &lhs_keys, &rhs_keys);
+    let keys = keys
+        .as_any()
+        .downcast_ref::<PrimitiveArray<K>>()
+        .unwrap()
+        .clone();
+
+    DictionaryArray::try_new(lhs.data_type().clone(), keys, values).map(|x| x.boxed())
+}
+
+/// Returns the values of `lhs` and `rhs` merged with [`growable::merge_dictionary_values`],
+/// and the keys of `lhs` and `rhs` remapped to them.
+fn unify_dictionaries<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> Result<(Box<dyn Array>, PrimitiveArray<K>, PrimitiveArray<K>)> {
+    if lhs.values() == rhs.values() {
+        return Ok((lhs.values().clone(), lhs.keys().clone(), rhs.keys().clone()));
+    }
+
+    let is_sorted = match lhs.data_type().to_logical_type() {
+        DataType::Dictionary(_, _, is_sorted) => *is_sorted,
+        _ => unreachable!(),
     };
-    Ok(result)
+    let (values, remaps) = growable::merge_dictionary_values(
+        &[lhs.values().as_ref(), rhs.values().as_ref()],
+        is_sorted,
+    );
+
+    let remap_keys = |keys: &PrimitiveArray<K>, remap: &[usize]| {
+        let values = keys
+            .values()
+            .iter()
+            .map(|key| {
+                K::try_from(growable::remap_key(*key, remap)).map_err(|_| {
+                    Error::InvalidArgumentError(
+                        "The unified dictionary values do not fit in the key type".to_string(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok::<_, Error>(PrimitiveArray::<K>::new(
+            keys.data_type().clone(),
+            values.into(),
+            keys.validity().cloned(),
+        ))
+    };
+
+    Ok((
+        values,
+        remap_keys(lhs.keys(), &remaps[0])?,
+        remap_keys(rhs.keys(), &remaps[1])?,
+    ))
 }
@@ -64,6 +64,51 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
+fn char_length_string<O: Offset + NativeType>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    let values = array
+        .values_iter()
+        .map(|value| O::from_as_usize(value.chars().count()))
+        .collect::<Vec<_>>();
+
+    let data_type = if O::IS_LARGE {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+
+    PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
+}
+
+/// Returns an array of integers with the number of characters (Unicode scalar values) on each
+/// string of the array. Contrarily to [`length`], multi-byte characters count as one.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::length::char_length;
+///
+/// let array = Utf8Array::<i32>::from([Some("héllo"), None, Some("💖")]);
+/// let result = char_length(&array).unwrap();
+/// assert_eq!(Int32Array::from([Some(5), None, Some(1)]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff the data type of `array` is not supported (see [`can_length`]).
+pub fn char_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(Box::new(char_length_string::<i32>(array)))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(Box::new(char_length_string::<i64>(array)))
+        }
+        _ => Err(Error::InvalidArgumentError(format!(
+            "char_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform length operation
 ///
 /// # Examples
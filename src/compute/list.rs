@@ -0,0 +1,724 @@
+//! Contains operators on [`ListArray`]s, such as [`list_element`], [`list_slice`],
+//! [`list_contains`], [`list_contains_any`], [`list_contains_array`], [`explode`],
+//! [`list_flatten`] and aggregations such as [`list_sum`].
+use std::cmp::Ordering;
+use std::ops::Add;
+
+use num_traits::AsPrimitive;
+
+use crate::{
+    array::{growable::make_growable, Array, BooleanArray, ListArray, PrimitiveArray, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    scalar::Scalar,
+    types::NativeType,
+};
+
+use super::comparison::{can_eq, can_eq_scalar, eq, eq_scalar};
+use super::take::take;
+use super::utils::combine_validities;
+
+/// Returns the element at `index` of each list of `list`, where negative indices count from
+/// the end of the list (`-1` is the last element).
+/// Null lists, and lists without an element at `index`, yield nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{
+///     Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend,
+/// };
+/// use arrow2::compute::list::list_element;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_element(&list, -2).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), None, None]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff [`take`] does not support the values' [`DataType`].
+pub fn list_element<O: Offset>(list: &ListArray<O>, index: i64) -> Result<Box<dyn Array>> {
+    let offsets = list.offsets();
+    let indices = (0..list.len())
+        .map(|row| {
+            if !list.is_valid(row) {
+                return None;
+            }
+            let (start, end) = offsets.start_end(row);
+            let length = (end - start) as i64;
+            let index = if index < 0 { length + index } else { index };
+            (0..length).contains(&index).then(|| start as i64 + index)
+        })
+        .collect::<PrimitiveArray<i64>>();
+    take(list.values().as_ref(), &indices)
+}
+
+/// Resolves `index` of a list of `length` elements to a position in `0..=length`, where
+/// negative indices count from the end.
+#[inline]
+fn resolve_index(index: i64, length: usize) -> usize {
+    if index >= 0 {
+        usize::try_from(index).map_or(length, |index| index.min(length))
+    } else {
+        usize::try_from(index.unsigned_abs()).map_or(0, |index| length.saturating_sub(index))
+    }
+}
+
+/// Returns the sublist `start..stop` of each list of `list` (`stop` defaults to the end of
+/// the list), with Python semantics: negative indices count from the end of the list and
+/// indices beyond the list are clamped to it. Null lists remain null.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_slice;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(1), Some(2), Some(3)]), None, Some(vec![Some(4)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_slice(&list, -2, None);
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend([Some(vec![Some(2), Some(3)]), None, Some(vec![Some(4)])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result, expected);
+/// ```
+pub fn list_slice<O: Offset>(list: &ListArray<O>, start: i64, stop: Option<i64>) -> ListArray<O> {
+    let offsets = list.offsets();
+    let values = list.values().as_ref();
+
+    let mut growable = make_growable(&[values], true, values.len());
+    let mut new_offsets = Offsets::<O>::with_capacity(list.len());
+    for row in 0..list.len() {
+        let mut length = 0;
+        if list.is_valid(row) {
+            let (row_start, row_end) = offsets.start_end(row);
+            let row_length = row_end - row_start;
+            let start = resolve_index(start, row_length);
+            let stop = stop.map_or(row_length, |stop| resolve_index(stop, row_length));
+            length = stop.saturating_sub(start);
+            growable.extend(0, row_start + start, length);
+        }
+        new_offsets
+            .try_push_usize(length)
+            .expect("A slice is never larger than the original - overflow never happens");
+    }
+
+    ListArray::new(
+        list.data_type().clone(),
+        new_offsets.into(),
+        growable.as_box(),
+        list.validity().cloned(),
+    )
+}
+
+/// Returns whether each list of `list` contains `value`.
+/// Null values within lists are never equal to `value`. Null lists yield nulls, and all
+/// results are null when `value` is null.
+///
+/// To compare each list with a different value, use [`list_contains_array`] or, for lists of
+/// primitives, [`list_contains_any`]. [`list_contains_str`] is a shorthand for lists of strings.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, ListArray, MutableListArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::list::list_contains;
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// list.try_extend([Some(vec![Some("a"), Some("b")]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_contains(&list, &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+/// ```
+/// # Errors
+/// This function errors iff `value` has a different [`DataType`] from the lists' values, or
+/// equality is not supported for it.
+pub fn list_contains<O: Offset>(list: &ListArray<O>, value: &dyn Scalar) -> Result<BooleanArray> {
+    let values = list.values();
+    if values.data_type() != value.data_type() {
+        return Err(Error::InvalidArgumentError(
+            "list_contains requires the value to be of the same logical type as the lists' values"
+                .to_string(),
+        ));
+    }
+    if !can_eq_scalar(values.data_type()) {
+        return Err(Error::NotYetImplemented(format!(
+            "list_contains is not supported for values of type {:?}",
+            values.data_type()
+        )));
+    }
+    if !value.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, list.len()));
+    }
+
+    // nulls are not equal to anything
+    let equal = eq_scalar(values.as_ref(), value);
+    let equal = match equal.validity() {
+        Some(validity) => equal.values() & validity,
+        None => equal.values().clone(),
+    };
+
+    let offsets = list.offsets();
+    let contains = (0..list.len())
+        .map(|row| {
+            let (start, end) = offsets.start_end(row);
+            equal.null_count_range(start, end - start) < end - start
+        })
+        .collect::<Bitmap>();
+    Ok(BooleanArray::new(
+        DataType::Boolean,
+        contains,
+        list.validity().cloned(),
+    ))
+}
+
+/// Returns, for each row `i`, whether the `i`-th list of `list` contains `search[i]`.
+/// Null values within lists are never equal to the searched value. Null lists and null
+/// searched values yield nulls.
+///
+/// This is a typed version of [`crate::compute::contains::contains`] for lists of primitives.
+/// # Example
+/// ```
+/// use arrow2::array::{
+///     BooleanArray, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend,
+/// };
+/// use arrow2::compute::list::list_contains_any;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// let data = [Some(vec![Some(1), Some(2)]), Some(vec![Some(3)]), None, Some(vec![Some(4)])];
+/// list.try_extend(data).unwrap();
+/// let list: ListArray<i32> = list.into();
+/// let search = Int32Array::from([Some(2), Some(2), Some(1), None]);
+///
+/// let result = list_contains_any(&list, &search).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff `list` and `search` have different lengths or the lists' values
+/// are not a [`PrimitiveArray<T>`] of `search`'s [`DataType`].
+pub fn list_contains_any<O: Offset, T: NativeType + PartialEq>(
+    list: &ListArray<O>,
+    search: &PrimitiveArray<T>,
+) -> Result<BooleanArray> {
+    if list.len() != search.len() {
+        return Err(Error::InvalidArgumentError(
+            "list_contains_any requires arrays of the same length".to_string(),
+        ));
+    }
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .filter(|values| values.data_type() == search.data_type())
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(
+                "list_contains_any requires the lists' values to be of the searched values' type"
+                    .to_string(),
+            )
+        })?;
+
+    let validity = combine_validities(list.validity(), search.validity());
+    let searched = search.values();
+    Ok(contains_where(list, validity, |row, index| {
+        values.is_valid(index) && values.value(index) == searched[row]
+    }))
+}
+
+/// Returns, for each row `i`, whether the `i`-th list of `list` contains `search[i]`, for
+/// searched values of any type supported by [`eq`].
+/// Null values within lists are never equal to the searched value. Null lists and null
+/// searched values yield nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{
+///     BooleanArray, ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array,
+/// };
+/// use arrow2::compute::list::list_contains_array;
+///
+/// let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// let data = [Some(vec![Some("a"), Some("b")]), Some(vec![Some("c")]), None];
+/// list.try_extend(data).unwrap();
+/// let list: ListArray<i32> = list.into();
+/// let search = Utf8Array::<i32>::from([Some("b"), Some("b"), Some("a")]);
+///
+/// let result = list_contains_array(&list, &search).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+/// ```
+/// # Errors
+/// This function errors iff `list` and `search` have different lengths, `search` has a
+/// different [`DataType`] from the lists' values, or equality is not supported for it.
+pub fn list_contains_array<O: Offset>(
+    list: &ListArray<O>,
+    search: &dyn Array,
+) -> Result<BooleanArray> {
+    if list.len() != search.len() {
+        return Err(Error::InvalidArgumentError(
+            "list_contains_array requires arrays of the same length".to_string(),
+        ));
+    }
+    let values = list.values();
+    if values.data_type() != search.data_type() {
+        return Err(Error::InvalidArgumentError(
+            "list_contains_array requires the searched values to be of the lists' values' type"
+                .to_string(),
+        ));
+    }
+    if !can_eq(values.data_type()) {
+        return Err(Error::NotYetImplemented(format!(
+            "list_contains_array is not supported for values of type {:?}",
+            values.data_type()
+        )));
+    }
+
+    // repeat the searched value of each row once per value of its list
+    let offsets = list.offsets();
+    let first = offsets.buffer()[0].to_usize();
+    let last = offsets.last().to_usize();
+    let rows = offsets
+        .lengths()
+        .enumerate()
+        .flat_map(|(row, length)| std::iter::repeat(row as u64).take(length))
+        .collect::<Vec<_>>();
+    let rows = PrimitiveArray::<u64>::from_vec(rows);
+    let searched = take(search, &rows)?;
+
+    // nulls are not equal to anything
+    let equal = eq(
+        values.slice(first, last - first).as_ref(),
+        searched.as_ref(),
+    );
+    let equal = match equal.validity() {
+        Some(validity) => equal.values() & validity,
+        None => equal.values().clone(),
+    };
+
+    let validity = combine_validities(list.validity(), search.validity());
+    Ok(contains_where(list, validity, |_, index| {
+        equal.get_bit(index - first)
+    }))
+}
+
+/// Returns whether each list of `list`, whose values are strings, contains `value`.
+/// Null values within lists are never equal to `value`, and null lists yield nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, ListArray, MutableListArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::list::list_contains_str;
+///
+/// let mut list = MutableListArray::<i32, MutableUtf8Array<i64>>::new();
+/// list.try_extend([Some(vec![Some("a"), None]), None, Some(vec![Some("b")])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_contains_str(&list, "a").unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+/// ```
+/// # Errors
+/// This function errors iff the lists' values are not of type [`DataType::Utf8`] or
+/// [`DataType::LargeUtf8`].
+pub fn list_contains_str<O: Offset>(list: &ListArray<O>, value: &str) -> Result<BooleanArray> {
+    match list.values().data_type().to_logical_type() {
+        DataType::Utf8 => Ok(utf8_contains::<O, i32>(list, value)),
+        DataType::LargeUtf8 => Ok(utf8_contains::<O, i64>(list, value)),
+        other => Err(Error::InvalidArgumentError(format!(
+            "list_contains_str requires lists of strings, got values of type {:?}",
+            other
+        ))),
+    }
+}
+
+fn utf8_contains<O: Offset, V: Offset>(list: &ListArray<O>, value: &str) -> BooleanArray {
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<V>>()
+        .unwrap();
+    contains_where(list, list.validity().cloned(), |_, index| {
+        values.is_valid(index) && values.value(index) == value
+    })
+}
+
+/// Returns whether any value of each list of `list` satisfies `predicate(row, index)`, where
+/// `index` is the position of the value in the lists' values.
+fn contains_where<O: Offset, F: Fn(usize, usize) -> bool>(
+    list: &ListArray<O>,
+    validity: Option<Bitmap>,
+    predicate: F,
+) -> BooleanArray {
+    let offsets = list.offsets();
+    let contains = (0..list.len())
+        .map(|row| {
+            let (start, end) = offsets.start_end(row);
+            (start..end).any(|index| predicate(row, index))
+        })
+        .collect::<Bitmap>();
+    BooleanArray::new(DataType::Boolean, contains, validity)
+}
+
+/// Flattens `list` into the values of its (non-null) lists, in order, together with the index
+/// of the row of `list` that each value belongs to, so that other columns can be joined back.
+/// Null and empty lists contribute no values.
+/// # Example
+/// ```
+/// use arrow2::array::{
+///     Array, Int64Array, ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array,
+/// };
+/// use arrow2::compute::list::explode;
+///
+/// let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// list.try_extend([
+///     Some(vec![Some("a"), Some("b")]),
+///     None,
+///     Some(vec![]),
+///     Some(vec![Some("c")]),
+/// ])
+/// .unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let (values, rows) = explode(&list).unwrap();
+/// assert_eq!(values.as_ref(), &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array);
+/// assert_eq!(rows, Int64Array::from_slice([0, 0, 3]));
+/// ```
+/// # Errors
+/// This function errors iff [`take`] does not support the values' [`DataType`].
+pub fn explode<O: Offset>(list: &ListArray<O>) -> Result<(Box<dyn Array>, PrimitiveArray<i64>)> {
+    let offsets = list.offsets();
+    let rows = (0..list.len())
+        .filter(|row| list.is_valid(*row))
+        .flat_map(|row| {
+            let (start, end) = offsets.start_end(row);
+            std::iter::repeat(row as i64).take(end - start)
+        })
+        .collect::<Vec<_>>();
+    let rows = PrimitiveArray::<i64>::from_vec(rows);
+
+    if list.null_count() == 0 {
+        // the values of all lists are contiguous
+        let start = offsets.buffer()[0].to_usize();
+        let length = offsets.last().to_usize() - start;
+        return Ok((list.values().slice(start, length), rows));
+    }
+
+    let indices = (0..list.len())
+        .filter(|row| list.is_valid(*row))
+        .flat_map(|row| {
+            let (start, end) = offsets.start_end(row);
+            start as i64..end as i64
+        })
+        .collect::<Vec<_>>();
+    let values = take(
+        list.values().as_ref(),
+        &PrimitiveArray::<i64>::from_vec(indices),
+    )?;
+    Ok((values, rows))
+}
+
+/// Removes one level of nesting of `array`, a list of lists (`LIST<LIST<T>>`), returning a
+/// list (`LIST<T>`) where each list is the concatenation of its inner lists.
+///
+/// Null lists remain null. Null inner lists of a valid list become a null value in the
+/// concatenation.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray};
+/// use arrow2::compute::list::list_flatten;
+/// use arrow2::datatypes::DataType;
+///
+/// // [[[1, 2], [3]], null, [[], [4]]]
+/// let values = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+/// let inner_type = ListArray::<i32>::default_datatype(DataType::Int32);
+/// let inner = ListArray::<i32>::new(
+///     inner_type.clone(),
+///     vec![0, 2, 3, 3, 4].try_into().unwrap(),
+///     values,
+///     None,
+/// );
+/// let outer = ListArray::<i32>::new(
+///     ListArray::<i32>::default_datatype(inner_type.clone()),
+///     vec![0, 2, 2, 4].try_into().unwrap(),
+///     inner.boxed(),
+///     Some([true, false, true].into()),
+/// );
+///
+/// // [[1, 2, 3], null, [4]]
+/// let result = list_flatten(&outer).unwrap();
+/// assert_eq!(result.data_type(), &inner_type);
+/// assert_eq!(result.value(0).as_ref(), &Int32Array::from_slice([1, 2, 3]) as &dyn Array);
+/// assert!(result.is_null(1));
+/// assert_eq!(result.value(2).as_ref(), &Int32Array::from_slice([4]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff the values of `array` are not a [`ListArray<O>`].
+pub fn list_flatten<O: Offset>(array: &ListArray<O>) -> Result<ListArray<O>> {
+    let inner = array
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<O>>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "list_flatten requires a list of lists with the same offsets, but its values are {:?}",
+                array.values().data_type()
+            ))
+        })?;
+    let offsets = array.offsets();
+    let inner_offsets = inner.offsets();
+
+    let start = offsets.buffer()[0].to_usize();
+    let end = offsets.last().to_usize();
+    let inner_nulls = inner
+        .validity()
+        .map(|validity| validity.null_count_range(start, end - start))
+        .unwrap_or(0);
+
+    if inner_nulls == 0 {
+        // the offsets of the inner lists of each list are contiguous: compose them
+        let new_offsets = offsets
+            .buffer()
+            .iter()
+            .map(|offset| inner_offsets.buffer()[offset.to_usize()])
+            .collect::<Vec<_>>();
+        return Ok(ListArray::new(
+            inner.data_type().clone(),
+            Offsets::try_from(new_offsets)?.into(),
+            inner.values().clone(),
+            array.validity().cloned(),
+        ));
+    }
+
+    let values = inner.values().as_ref();
+    let mut growable = make_growable(&[values], true, values.len());
+    let mut new_offsets = Offsets::<O>::with_capacity(array.len());
+    for row in 0..array.len() {
+        let mut length = 0;
+        if array.is_valid(row) {
+            let (start, end) = offsets.start_end(row);
+            for inner_row in start..end {
+                if inner.is_valid(inner_row) {
+                    let (start, end) = inner_offsets.start_end(inner_row);
+                    growable.extend(0, start, end - start);
+                    length += end - start;
+                } else {
+                    growable.extend_validity(1);
+                    length += 1;
+                }
+            }
+        }
+        new_offsets.try_push_usize(length)?;
+    }
+
+    Ok(ListArray::new(
+        inner.data_type().clone(),
+        new_offsets.into(),
+        growable.as_box(),
+        array.validity().cloned(),
+    ))
+}
+
+fn list_flatten_all_impl<O: Offset>(array: &ListArray<O>) -> Result<Box<dyn Array>> {
+    let mut array = array.clone();
+    while array.values().as_any().is::<ListArray<O>>() {
+        array = list_flatten(&array)?;
+    }
+    Ok(array.boxed())
+}
+
+/// Applies [`list_flatten`] to `array` until its values are no longer a list of the same
+/// offsets (e.g. `LIST<LIST<LIST<T>>>` becomes `LIST<T>`).
+/// # Errors
+/// This function errors iff `array` is not a [`ListArray`].
+pub fn list_flatten_all(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::List(_) => list_flatten_all_impl::<i32>(array.as_any().downcast_ref().unwrap()),
+        DataType::LargeList(_) => {
+            list_flatten_all_impl::<i64>(array.as_any().downcast_ref().unwrap())
+        }
+        data_type => Err(Error::InvalidArgumentError(format!(
+            "list_flatten_all requires a list, but the array is {:?}",
+            data_type
+        ))),
+    }
+}
+
+/// Folds the valid values of each list of `list`, whose values must be a [`PrimitiveArray<T>`],
+/// with `fold` and maps the result with `finish`. Null lists, and lists without valid values,
+/// yield nulls.
+fn list_aggregate<'a, O, T, A, R, F, G>(
+    list: &'a ListArray<O>,
+    name: &str,
+    fold: F,
+    finish: G,
+) -> Result<(Vec<Option<R>>, &'a DataType)>
+where
+    O: Offset,
+    T: NativeType,
+    F: Fn(Option<A>, T) -> A,
+    G: Fn(A) -> R,
+{
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "{} does not support lists of {:?}",
+                name,
+                list.values().data_type()
+            ))
+        })?;
+    let offsets = list.offsets();
+
+    let result = (0..list.len())
+        .map(|row| {
+            if !list.is_valid(row) {
+                return None;
+            }
+            let (start, end) = offsets.start_end(row);
+            (start..end)
+                .filter(|i| values.is_valid(*i))
+                .map(|i| values.value(i))
+                .fold(None, |state, value| Some(fold(state, value)))
+                .map(&finish)
+        })
+        .collect();
+    Ok((result, values.data_type()))
+}
+
+/// Returns the sum of the non-null values of each list of `list`. Null lists, empty lists and
+/// lists whose values are all null yield nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_sum;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(1), None, Some(2)]), None, Some(vec![None])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_sum::<i32, i32>(&list).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(3), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `list` are not a [`PrimitiveArray<T>`].
+pub fn list_sum<O, T>(list: &ListArray<O>) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + Add<Output = T>,
+{
+    let (values, data_type) = list_aggregate(
+        list,
+        "list_sum",
+        |sum: Option<T>, value: T| sum.map_or(value, |sum| sum + value),
+        |sum| sum,
+    )?;
+    Ok(PrimitiveArray::<T>::from(values).to(data_type.clone()))
+}
+
+/// Returns the arithmetic mean of the non-null values of each list of `list`. Null lists,
+/// empty lists and lists whose values are all null yield nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_mean;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(1), None, Some(2)]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_mean::<i32, i32>(&list).unwrap();
+/// assert_eq!(result, Float64Array::from([Some(1.5), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `list` are not a [`PrimitiveArray<T>`].
+pub fn list_mean<O, T>(list: &ListArray<O>) -> Result<PrimitiveArray<f64>>
+where
+    O: Offset,
+    T: NativeType + AsPrimitive<f64>,
+{
+    let (values, _) = list_aggregate(
+        list,
+        "list_mean",
+        |state: Option<(f64, usize)>, value: T| {
+            let (sum, count) = state.unwrap_or((0.0, 0));
+            (sum + value.as_(), count + 1)
+        },
+        |(sum, count)| sum / count as f64,
+    )?;
+    Ok(PrimitiveArray::<f64>::from(values))
+}
+
+/// Returns `value` if it is `ordering` relative to `extreme` (or if `extreme` is `NaN`), and
+/// `extreme` otherwise, so that `NaN`s are ignored unless all values are `NaN`.
+#[inline]
+#[allow(clippy::eq_op)]
+fn extreme<T: PartialOrd>(extreme: Option<T>, value: T, ordering: Ordering) -> T {
+    match extreme {
+        Some(extreme) if extreme == extreme && value.partial_cmp(&extreme) != Some(ordering) => {
+            extreme
+        }
+        _ => value,
+    }
+}
+
+/// Returns the minimum of the non-null values of each list of `list`. Null lists, empty lists
+/// and lists whose values are all null yield nulls. `NaN`s are ignored unless all values of
+/// the list are `NaN`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_min;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(3), None, Some(2)]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_min::<i32, i32>(&list).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(2), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `list` are not a [`PrimitiveArray<T>`].
+pub fn list_min<O, T>(list: &ListArray<O>) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + PartialOrd,
+{
+    let (values, data_type) = list_aggregate(
+        list,
+        "list_min",
+        |min: Option<T>, value: T| extreme(min, value, Ordering::Less),
+        |min| min,
+    )?;
+    Ok(PrimitiveArray::<T>::from(values).to(data_type.clone()))
+}
+
+/// Returns the maximum of the non-null values of each list of `list`. Null lists, empty lists
+/// and lists whose values are all null yield nulls. `NaN`s are ignored unless all values of
+/// the list are `NaN`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_max;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(3), None, Some(2)]), None, Some(vec![None])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_max::<i32, i32>(&list).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(3), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `list` are not a [`PrimitiveArray<T>`].
+pub fn list_max<O, T>(list: &ListArray<O>) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + PartialOrd,
+{
+    let (values, data_type) = list_aggregate(
+        list,
+        "list_max",
+        |max: Option<T>, value: T| extreme(max, value, Ordering::Greater),
+        |max| max,
+    )?;
+    Ok(PrimitiveArray::<T>::from(values).to(data_type.clone()))
+}
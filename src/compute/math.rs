@@ -0,0 +1,123 @@
+//! Defines logarithmic, exponential and root kernels for float [`PrimitiveArray`]s.
+//!
+//! Some of these functions are undefined for some inputs (e.g. the logarithm of a negative
+//! number). [`InvalidMode`] declares whether such values are returned as `NaN`
+//! (like the standard library does) or as nulls.
+use num_traits::Float;
+
+use crate::{
+    array::PrimitiveArray,
+    compute::arity::{unary, unary_checked},
+    types::NativeType,
+};
+
+/// Declares how the kernels of this module handle values whose result is `NaN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvalidMode {
+    /// `NaN` results are kept as `NaN` (default)
+    #[default]
+    PropagateNaN,
+    /// `NaN` results are null
+    ProduceNullOnInvalid,
+}
+
+#[inline]
+fn apply<T, F>(array: &PrimitiveArray<T>, op: F, mode: InvalidMode) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+    F: Fn(T) -> T,
+{
+    match mode {
+        InvalidMode::PropagateNaN => unary(array, op, array.data_type().clone()),
+        InvalidMode::ProduceNullOnInvalid => unary_checked(
+            array,
+            |x| {
+                let result = op(x);
+                if result.is_nan() {
+                    None
+                } else {
+                    Some(result)
+                }
+            },
+            array.data_type().clone(),
+        ),
+    }
+}
+
+/// Returns the natural logarithm of each value of `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::math::{ln, InvalidMode};
+///
+/// let array = Float64Array::from([Some(1.0), Some(-1.0), None]);
+///
+/// let result = ln(&array, InvalidMode::ProduceNullOnInvalid);
+/// assert_eq!(result, Float64Array::from([Some(0.0), None, None]));
+///
+/// let result = ln(&array, InvalidMode::PropagateNaN);
+/// assert!(result.value(1).is_nan());
+/// ```
+pub fn ln<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.ln(), mode)
+}
+
+/// Returns the base 2 logarithm of each value of `array`.
+pub fn log2<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.log2(), mode)
+}
+
+/// Returns the base 10 logarithm of each value of `array`.
+pub fn log10<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.log10(), mode)
+}
+
+/// Returns the logarithm with respect to `base` of each value of `array`.
+pub fn log<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    base: T,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.log(base), mode)
+}
+
+/// Returns `e^x` for each value `x` of `array`.
+pub fn exp<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.exp(), mode)
+}
+
+/// Returns `2^x` for each value `x` of `array`.
+pub fn exp2<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.exp2(), mode)
+}
+
+/// Returns the square root of each value of `array`.
+pub fn sqrt<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.sqrt(), mode)
+}
+
+/// Returns the cubic root of each value of `array`.
+pub fn cbrt<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    mode: InvalidMode,
+) -> PrimitiveArray<T> {
+    apply(array, |x| x.cbrt(), mode)
+}
@@ -275,7 +275,8 @@ where
     }
 
     fn next_left(&mut self) {
-        match self.lhs.next() {
+        // empty slices have no rows to compare
+        match self.lhs.by_ref().find(|slice| slice.2 > 0) {
             Some(slice) => {
                 self.left = Some((*slice, slice.1));
                 self.current_start = slice.1;
@@ -285,7 +286,8 @@ where
     }
 
     fn next_right(&mut self) {
-        match self.rhs.next() {
+        // empty slices have no rows to compare
+        match self.rhs.by_ref().find(|slice| slice.2 > 0) {
             Some(slice) => {
                 self.right = Some((*slice, slice.1));
                 self.current_start = slice.1;
@@ -330,6 +332,8 @@ where
             // first call of `next`
             self.next_left();
             self.next_right();
+            // when one side is empty, there is nothing to compare and we are done starting
+            self.has_started = self.left.is_none() || self.right.is_none();
         }
 
         match (self.left, self.right) {
@@ -11,8 +11,6 @@
 //! Some dynamically-typed operators have an auxiliary function, `can_*`, that returns
 //! true if the operator can be applied to the particular `DataType`.
 
-#[cfg(any(feature = "compute_aggregate", feature = "io_parquet"))]
-#[cfg_attr(docsrs, doc(cfg(feature = "compute_aggregate")))]
 pub mod aggregate;
 #[cfg(feature = "compute_arithmetics")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_arithmetics")))]
@@ -31,6 +29,9 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_coalesce")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_coalesce")))]
+pub mod coalesce;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -40,9 +41,15 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_cumulative")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cumulative")))]
+pub mod cumulative;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_format")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_format")))]
+pub mod format;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
@@ -58,6 +65,12 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
+#[cfg(feature = "compute_math")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_math")))]
+pub mod math;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
@@ -67,12 +80,18 @@ pub mod nullif;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
+#[cfg(feature = "compute_rank")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rank")))]
+pub mod rank;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_struct_utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_struct_utils")))]
+pub mod struct_utils;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
@@ -1,10 +1,12 @@
 //! Contains the operator [`nullif`].
-use crate::array::PrimitiveArray;
+use crate::array::{equal, PrimitiveArray};
 use crate::bitmap::Bitmap;
 use crate::compute::comparison::{
-    primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8, Simd8PartialEq,
+    can_eq, eq, primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8,
+    Simd8PartialEq,
 };
 use crate::datatypes::DataType;
+use crate::error::{Error, Result};
 use crate::scalar::PrimitiveScalar;
 use crate::scalar::Scalar;
 use crate::{array::Array, types::NativeType};
@@ -12,7 +14,7 @@ use crate::{array::Array, types::NativeType};
 use super::utils::combine_validities;
 
 /// Returns an array whose validity is null iff `lhs == rhs` or `lhs` is null.
-/// This has the same semantics as postgres - the validity of the rhs is ignored.
+/// This has the same semantics as postgres - a null `rhs` is never equal to `lhs`.
 /// # Panic
 /// This function panics iff
 /// * The arguments do not have the same logical type
@@ -37,10 +39,15 @@ where
     T: NativeType + Simd8,
     T::Simd: Simd8PartialEq,
 {
-    let equal = primitive_compare_values_op(lhs.values(), rhs.values(), |lhs, rhs| lhs.neq(rhs));
-    let equal: Option<Bitmap> = equal.into();
+    let not_equal =
+        primitive_compare_values_op(lhs.values(), rhs.values(), |lhs, rhs| lhs.neq(rhs));
+    let not_equal: Bitmap = not_equal.into();
+    let not_equal = match rhs.validity() {
+        Some(rhs_validity) => &not_equal | &!rhs_validity,
+        None => not_equal,
+    };
 
-    let validity = combine_validities(lhs.validity(), equal.as_ref());
+    let validity = combine_validities(lhs.validity(), Some(&not_equal));
 
     PrimitiveArray::<T>::new(lhs.data_type().clone(), lhs.values().clone(), validity)
 }
@@ -79,14 +86,11 @@ where
 }
 
 /// Returns an [`Array`] with the same type as `lhs` and whose validity
-/// is null iff either `lhs == rhs` or `lhs` is null.
+/// is null iff either `lhs == rhs` or `lhs` is null (SQL's `NULLIF`).
 ///
-/// This has the same semantics as postgres - the validity of the rhs is ignored.
+/// This has the same semantics as postgres - a null `rhs` is never equal to `lhs`.
 /// # Panics
-/// This function panics iff
-/// * The arguments do not have the same logical type
-/// * The arguments do not have the same length
-/// * The physical type is not supported for this operation (use [`can_nullif`] to check)
+/// This function panics iff [`try_nullif`] errors.
 /// # Example
 /// ```rust
 /// # use arrow2::array::Int32Array;
@@ -103,18 +107,84 @@ where
 /// # }
 /// ```
 pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    assert_eq!(lhs.data_type(), rhs.data_type());
-    assert_eq!(lhs.len(), rhs.len());
+    try_nullif(lhs, rhs).unwrap()
+}
+
+/// Returns an [`Array`] with the same type as `lhs` and whose validity
+/// is null iff either `lhs == rhs` or `lhs` is null (SQL's `NULLIF`).
+///
+/// This has the same semantics as postgres - a null `rhs` is never equal to `lhs`.
+/// All types but unions are supported: types without an [`eq`] kernel (e.g. nested types)
+/// are compared slot by slot with [`equal`](crate::array::equal).
+/// # Example
+/// ```rust
+/// # use arrow2::array::Utf8Array;
+/// # use arrow2::compute::nullif::try_nullif;
+/// # fn main() {
+/// let lhs = Utf8Array::<i32>::from([None, None, Some("a"), Some("a"), Some("a")]);
+/// let rhs = Utf8Array::<i32>::from([None, Some("a"), None, Some("a"), Some("b")]);
+/// let result = try_nullif(&lhs, &rhs).unwrap();
+///
+/// let expected = Utf8Array::<i32>::from([None, None, Some("a"), None, Some("a")]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff the arguments do not have the same data type or length, or are
+/// unions.
+pub fn try_nullif(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "nullif requires arguments with the same data type ({:?} != {:?})",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    if lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "nullif requires arguments with the same length ({} != {})",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
 
     use crate::datatypes::PhysicalType::*;
     match lhs.data_type().to_physical_type() {
+        Null => Ok(lhs.to_boxed()),
+        Union => Err(Error::NotYetImplemented(
+            "nullif is not implemented for unions, which have no validity".to_string(),
+        )),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
-            Box::new(primitive_nullif::<$T>(
+            Ok(Box::new(primitive_nullif::<$T>(
                 lhs.as_any().downcast_ref().unwrap(),
                 rhs.as_any().downcast_ref().unwrap(),
-            ))
+            )))
         }),
-        other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
+        _ => {
+            let equal: Bitmap = if can_eq(lhs.data_type()) {
+                // null iff either is null
+                let equal = eq(lhs, rhs);
+                match equal.validity() {
+                    Some(validity) => equal.values() & validity,
+                    None => equal.values().clone(),
+                }
+            } else {
+                // null iff either is null, like `eq`
+                (0..lhs.len())
+                    .map(|i| {
+                        lhs.is_valid(i)
+                            && rhs.is_valid(i)
+                            && equal(lhs.slice(i, 1).as_ref(), rhs.slice(i, 1).as_ref())
+                    })
+                    .collect()
+            };
+            let validity = match lhs.validity() {
+                Some(validity) => validity & &!&equal,
+                None => !&equal,
+            };
+            Ok(lhs.with_validity(Some(validity)))
+        }
     }
 }
 
@@ -158,7 +228,7 @@ pub fn nullif_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
     }
 }
 
-/// Returns whether [`nullif`] and [`nullif_scalar`] is implemented for the datatypes.
+/// Returns whether [`nullif_scalar`] is implemented for the datatypes.
 pub fn can_nullif(lhs: &DataType, rhs: &DataType) -> bool {
     if lhs != rhs {
         return false;
@@ -0,0 +1,235 @@
+//! Contains the operators [`rank`] and [`average_rank`], the ranks of the values of an array
+//! (e.g. SQL's `RANK()`, `DENSE_RANK()` and `ROW_NUMBER()`), and their counterparts over
+//! any sortable [`Array`], [`rank_with_options`] and [`average_rank_with_options`].
+use std::cmp::Ordering;
+
+use crate::array::{ord::build_compare, Array, PrimitiveArray};
+use crate::compute::sort::SortOptions;
+use crate::datatypes::DataType;
+use crate::error::Result;
+use crate::types::NativeType;
+
+/// How [`rank`] ranks tied values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    /// The average of the ranks of the ties, rounded down (see [`average_rank`] for the
+    /// fractional average).
+    Average,
+    /// The lowest rank of the ties, leaving gaps after them (SQL's `RANK()`).
+    Min,
+    /// The highest rank of the ties.
+    Max,
+    /// The lowest rank of the ties, without gaps after them (SQL's `DENSE_RANK()`).
+    Dense,
+    /// Distinct ranks in order of appearance (SQL's `ROW_NUMBER()`).
+    #[doc(alias = "First")]
+    Ordinal,
+}
+
+/// Order of values where `NaN`s are equal to each other and greater than any other value.
+#[inline]
+#[allow(clippy::eq_op)]
+fn total_order<T: PartialOrd>(lhs: &T, rhs: &T) -> Ordering {
+    lhs.partial_cmp(rhs)
+        .unwrap_or_else(|| (lhs != lhs).cmp(&(rhs != rhs)))
+}
+
+/// Assigns `rank(start, end, dense, position)` to every slot in `indices`, sorted such that
+/// ties (according to `eq`) are contiguous, where `start..end` are the 0-based positions of the
+/// ties of the slot at `position`, the `dense`-th distinct value.
+fn assign_ranks<R, E, F>(len: usize, indices: &[usize], eq: E, rank: F) -> Vec<R>
+where
+    R: NativeType,
+    E: Fn(usize, usize) -> bool,
+    F: Fn(usize, usize, usize, usize) -> R,
+{
+    let mut ranks = vec![R::default(); len];
+    let mut start = 0;
+    let mut dense = 0;
+    while start < indices.len() {
+        let first = indices[start];
+        let end = start
+            + indices[start..]
+                .iter()
+                .take_while(|&&i| eq(first, i))
+                .count();
+        dense += 1;
+        for (position, &index) in indices.iter().enumerate().take(end).skip(start) {
+            ranks[index] = rank(start, end, dense, position);
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Assigns `rank(start, end, dense, position)` to every valid slot of `array`, where the
+/// valid values are sorted (stable).
+fn assign_primitive_ranks<T, R, F>(array: &PrimitiveArray<T>, descending: bool, rank: F) -> Vec<R>
+where
+    T: NativeType + PartialOrd,
+    R: NativeType,
+    F: Fn(usize, usize, usize, usize) -> R,
+{
+    let values = array.values();
+    let mut indices = (0..array.len())
+        .filter(|&i| array.is_valid(i))
+        .collect::<Vec<_>>();
+    if descending {
+        indices.sort_by(|&a, &b| total_order(&values[b], &values[a]));
+    } else {
+        indices.sort_by(|&a, &b| total_order(&values[a], &values[b]));
+    }
+
+    let eq = |a: usize, b: usize| total_order(&values[a], &values[b]) == Ordering::Equal;
+    assign_ranks(array.len(), &indices, eq, rank)
+}
+
+/// Assigns `rank(start, end, dense, position)` to every slot of `array`, where the slots are
+/// sorted (stable) according to `options` and null values are tied with each other.
+fn assign_dyn_ranks<R, F>(array: &dyn Array, options: &SortOptions, rank: F) -> Result<Vec<R>>
+where
+    R: NativeType,
+    F: Fn(usize, usize, usize, usize) -> R,
+{
+    let cmp = build_compare(array, array)?;
+
+    let (mut indices, nulls): (Vec<_>, Vec<_>) = (0..array.len()).partition(|&i| array.is_valid(i));
+    if options.descending {
+        indices.sort_by(|&a, &b| cmp(b, a));
+    } else {
+        indices.sort_by(|&a, &b| cmp(a, b));
+    }
+    let indices = if options.nulls_first {
+        nulls.into_iter().chain(indices).collect::<Vec<_>>()
+    } else {
+        indices.into_iter().chain(nulls).collect::<Vec<_>>()
+    };
+
+    let eq = |a: usize, b: usize| match (array.is_valid(a), array.is_valid(b)) {
+        (true, true) => cmp(a, b) == Ordering::Equal,
+        (false, false) => true,
+        _ => false,
+    };
+    Ok(assign_ranks(array.len(), &indices, eq, rank))
+}
+
+/// The rank of the tie at `position` amongst the ties `start..end` according to `method`.
+#[inline]
+fn method_rank(method: RankMethod, start: usize, end: usize, dense: usize, position: usize) -> u32 {
+    (match method {
+        RankMethod::Average => (start + 1 + end) / 2,
+        RankMethod::Min => start + 1,
+        RankMethod::Max => end,
+        RankMethod::Dense => dense,
+        RankMethod::Ordinal => position + 1,
+    }) as u32
+}
+
+/// Returns the 1-based rank of each value of `array` according to `method`, in ascending or
+/// `descending` order. Null values have null ranks.
+///
+/// `NaN`s are ranked as equal to each other and greater than any other value.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::rank::{rank, RankMethod};
+///
+/// let array = Int32Array::from([Some(10), Some(20), None, Some(10), Some(30)]);
+///
+/// let result = rank(&array, RankMethod::Min, false);
+/// assert_eq!(result, UInt32Array::from([Some(1), Some(3), None, Some(1), Some(4)]));
+///
+/// let result = rank(&array, RankMethod::Max, false);
+/// assert_eq!(result, UInt32Array::from([Some(2), Some(3), None, Some(2), Some(4)]));
+///
+/// let result = rank(&array, RankMethod::Dense, false);
+/// assert_eq!(result, UInt32Array::from([Some(1), Some(2), None, Some(1), Some(3)]));
+///
+/// let result = rank(&array, RankMethod::Ordinal, true);
+/// assert_eq!(result, UInt32Array::from([Some(3), Some(2), None, Some(4), Some(1)]));
+/// ```
+pub fn rank<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    method: RankMethod,
+    descending: bool,
+) -> PrimitiveArray<u32> {
+    let ranks = assign_primitive_ranks(array, descending, |start, end, dense, position| {
+        method_rank(method, start, end, dense, position)
+    });
+    PrimitiveArray::new(DataType::UInt32, ranks.into(), array.validity().cloned())
+}
+
+/// Returns the 1-based rank of each value of `array` in ascending or `descending` order,
+/// where tied values share the average of their ranks. Null values have null ranks.
+///
+/// `NaN`s are ranked as equal to each other and greater than any other value.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Int32Array};
+/// use arrow2::compute::rank::average_rank;
+///
+/// let array = Int32Array::from([Some(10), Some(20), None, Some(10)]);
+///
+/// let result = average_rank(&array, false);
+/// assert_eq!(result, Float64Array::from([Some(1.5), Some(3.0), None, Some(1.5)]));
+/// ```
+pub fn average_rank<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    descending: bool,
+) -> PrimitiveArray<f64> {
+    let ranks = assign_primitive_ranks(array, descending, |start, end, _, _| {
+        (start + 1 + end) as f64 / 2.0
+    });
+    PrimitiveArray::new(DataType::Float64, ranks.into(), array.validity().cloned())
+}
+
+/// Returns the 1-based rank of each slot of `array` according to `method`, in the order of
+/// `options`.
+///
+/// Unlike [`rank`], null values are ranked too: they are tied with each other and ranked before
+/// or after every valid value according to `options.nulls_first`. Supports every type with a
+/// natural order (see [`build_compare`]); floats are ranked by their IEEE 754 total order.
+/// # Example
+/// ```
+/// use arrow2::array::{UInt32Array, Utf8Array};
+/// use arrow2::compute::rank::{rank_with_options, RankMethod};
+/// use arrow2::compute::sort::SortOptions;
+///
+/// let array = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("b")]);
+/// let options = SortOptions {
+///     descending: false,
+///     nulls_first: true,
+/// };
+///
+/// let result = rank_with_options(&array, RankMethod::Min, &options).unwrap();
+/// assert_eq!(result, UInt32Array::from_slice([3, 1, 2, 3]));
+/// ```
+/// # Errors
+/// Errors iff the type of `array` has no natural order.
+pub fn rank_with_options(
+    array: &dyn Array,
+    method: RankMethod,
+    options: &SortOptions,
+) -> Result<PrimitiveArray<u32>> {
+    let ranks = assign_dyn_ranks(array, options, |start, end, dense, position| {
+        method_rank(method, start, end, dense, position)
+    })?;
+    Ok(PrimitiveArray::new(DataType::UInt32, ranks.into(), None))
+}
+
+/// Returns the 1-based rank of each slot of `array` in the order of `options`, where tied
+/// values share the average of their ranks.
+///
+/// Null values are tied with each other and ranked according to `options.nulls_first`, as in
+/// [`rank_with_options`].
+/// # Errors
+/// Errors iff the type of `array` has no natural order.
+pub fn average_rank_with_options(
+    array: &dyn Array,
+    options: &SortOptions,
+) -> Result<PrimitiveArray<f64>> {
+    let ranks = assign_dyn_ranks(array, options, |start, end, _, _| {
+        (start + 1 + end) as f64 / 2.0
+    })?;
+    Ok(PrimitiveArray::new(DataType::Float64, ranks.into(), None))
+}
@@ -1,11 +1,17 @@
-//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`],
+//! the regex capturing operators [`regexp_match`], [`regexp_extract`] and [`regexp_extract_all`]
+//! the regex splitting operator [`regexp_split`] and the regex replacing operator
+//! [`regexp_replace`].
 
 use ahash::AHashMap;
 use regex::Regex;
 
-use crate::array::{BooleanArray, Utf8Array};
-use crate::bitmap::Bitmap;
-use crate::datatypes::DataType;
+use crate::array::{
+    BooleanArray, ListArray, MutableArray, MutableListArray, MutableUtf8Array, StructArray,
+    TryPush, Utf8Array,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, Field};
 use crate::error::{Error, Result};
 use crate::offset::Offset;
 
@@ -82,3 +88,288 @@ fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
     let values = Bitmap::from_trusted_len_iter(iterator);
     BooleanArray::new(DataType::Boolean, values, validity)
 }
+
+/// Returns the capture groups of `pattern` on each of the values of `array` as a [`ListArray`].
+///
+/// For each row, the list contains one item per capture group of `pattern`; when `pattern` has
+/// no capture groups, the list contains the whole match.
+/// Rows that are null or do not match `pattern` are null lists; optional groups that did
+/// not participate in the match are null items.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::regex_match::regexp_match;
+///
+/// let strings = Utf8Array::<i32>::from([Some("a1"), Some("b"), None]);
+///
+/// let result = regexp_match(&strings, r"([a-z])(\d)?").unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend([
+///     Some(vec![Some("a"), Some("1")]),
+///     Some(vec![Some("b"), None]),
+///     None,
+/// ]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff `pattern` is not a valid regex.
+pub fn regexp_match<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> Result<ListArray<O>> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+    // group 0 is the whole match; it is only returned when there are no other groups
+    let skip = usize::from(regex.captures_len() > 1);
+
+    let mut result = MutableListArray::<O, MutableUtf8Array<O>>::with_capacity(array.len());
+    for value in array.iter() {
+        let captures = value.and_then(|value| regex.captures(value));
+        let groups = captures.as_ref().map(|captures| {
+            captures
+                .iter()
+                .skip(skip)
+                .map(|group| group.map(|group| group.as_str()))
+        });
+        result.try_push(groups)?;
+    }
+    Ok(result.into())
+}
+
+/// Splits each of the values of `array` on the matches of `pattern` into a [`ListArray`] of
+/// its parts.
+///
+/// When `limit` is set, each value is split at most `limit` times, with the last part
+/// containing the remainder of the value.
+/// Empty values are empty lists and null values are null lists.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::regex_match::regexp_split;
+///
+/// let strings = Utf8Array::<i32>::from([Some("a1b22c"), None]);
+///
+/// let result = regexp_split(&strings, r"\d+", None).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend([
+///     Some(vec![Some("a"), Some("b"), Some("c")]),
+///     None,
+/// ]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff `pattern` is not a valid regex.
+pub fn regexp_split<O: Offset>(
+    array: &Utf8Array<O>,
+    pattern: &str,
+    limit: Option<usize>,
+) -> Result<ListArray<O>> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+
+    let mut result = MutableListArray::<O, MutableUtf8Array<O>>::with_capacity(array.len());
+    for value in array.iter() {
+        let parts = value.map(|value| {
+            let parts: Box<dyn Iterator<Item = &str> + '_> = if value.is_empty() {
+                Box::new(std::iter::empty())
+            } else if let Some(limit) = limit {
+                Box::new(regex.splitn(value, limit.saturating_add(1)))
+            } else {
+                Box::new(regex.split(value))
+            };
+            parts.map(Some)
+        });
+        result.try_push(parts)?;
+    }
+    Ok(result.into())
+}
+
+/// Replaces the non-overlapping matches of `pattern` in each of the values of `array` by
+/// `replacement`, from left to right.
+///
+/// `replacement` may reference the capture groups of `pattern` by index (e.g. `$1`) or by
+/// name (e.g. `$name`), as in [`Regex::replace`]. When `limit` is set, at most `limit` matches
+/// are replaced in each value.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::regexp_replace;
+///
+/// let strings = Utf8Array::<i32>::from([Some("2022-01-31"), None, Some("abc")]);
+///
+/// let result = regexp_replace(&strings, r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", None).unwrap();
+/// let expected = Utf8Array::<i32>::from([Some("31/01/2022"), None, Some("abc")]);
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff `pattern` is not a valid regex.
+pub fn regexp_replace<O: Offset>(
+    array: &Utf8Array<O>,
+    pattern: &str,
+    replacement: &str,
+    limit: Option<usize>,
+) -> Result<Utf8Array<O>> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+
+    // `Regex::replacen` replaces all matches when its limit is 0
+    let limit = match limit {
+        Some(0) => return Ok(array.clone()),
+        Some(limit) => limit,
+        None => 0,
+    };
+
+    let iter = array
+        .iter()
+        .map(|value| value.map(|value| regex.replacen(value, limit, replacement)));
+    Ok(Utf8Array::<O>::from_trusted_len_iter(iter))
+}
+
+/// Returns the index and name of each group returned by the extract kernels: the capture
+/// groups of `regex`, or the whole match when `regex` has no capture groups.
+/// Unnamed groups are named after their index.
+fn extract_groups(regex: &Regex) -> Vec<(usize, String)> {
+    if regex.captures_len() > 1 {
+        regex
+            .capture_names()
+            .enumerate()
+            .skip(1)
+            .map(|(index, name)| {
+                let name = name.map_or_else(|| index.to_string(), |name| name.to_string());
+                (index, name)
+            })
+            .collect()
+    } else {
+        vec![(0, "0".to_string())]
+    }
+}
+
+/// Extracts the capture groups of the first match of `pattern` on each of the values of `array`
+/// into a [`StructArray`] with one [`Utf8Array`] per group.
+///
+/// Fields are named after the named groups of `pattern` and after the group index otherwise;
+/// when `pattern` has no capture groups, the single field `"0"` contains the whole match.
+/// Rows that are null or do not match `pattern` are null; optional groups that did not
+/// participate in the match are null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::regex_match::regexp_extract;
+///
+/// let strings = Utf8Array::<i32>::from([Some("a1"), Some("-"), None]);
+///
+/// let result = regexp_extract(&strings, r"(?P<letter>[a-z])(\d)").unwrap();
+///
+/// assert_eq!(result.fields()[0].name, "letter");
+/// assert_eq!(result.fields()[1].name, "2");
+/// assert_eq!(
+///     result.values()[0].as_ref(),
+///     &Utf8Array::<i32>::from([Some("a"), None, None]) as &dyn Array
+/// );
+/// assert_eq!(
+///     result.values()[1].as_ref(),
+///     &Utf8Array::<i32>::from([Some("1"), None, None]) as &dyn Array
+/// );
+/// assert_eq!(result.null_count(), 2);
+/// ```
+/// # Error
+/// Errors iff `pattern` is not a valid regex.
+pub fn regexp_extract<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> Result<StructArray> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+    let groups = extract_groups(&regex);
+
+    let mut values = groups
+        .iter()
+        .map(|_| MutableUtf8Array::<O>::with_capacity(array.len()))
+        .collect::<Vec<_>>();
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    for value in array.iter() {
+        let captures = value.and_then(|value| regex.captures(value));
+        validity.push(captures.is_some());
+        for ((index, _), values) in groups.iter().zip(values.iter_mut()) {
+            let group = captures
+                .as_ref()
+                .and_then(|captures| captures.get(*index))
+                .map(|group| group.as_str());
+            values.push(group);
+        }
+    }
+
+    let (fields, values) = groups
+        .into_iter()
+        .zip(values)
+        .map(|((_, name), mut values)| {
+            let values = values.as_box();
+            (Field::new(name, values.data_type().clone(), true), values)
+        })
+        .unzip();
+    Ok(StructArray::new(
+        DataType::Struct(fields),
+        values,
+        validity.into(),
+    ))
+}
+
+/// Extracts the capture groups of every match of `pattern` on each of the values of `array`
+/// into a [`StructArray`] with one [`ListArray`] of [`Utf8Array`] per group.
+///
+/// Fields are named as in [`regexp_extract`]. For each row, the list of a group contains
+/// one item per match of `pattern`, in order; rows that do not match `pattern` are empty
+/// lists and null rows are null.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::regex_match::regexp_extract_all;
+///
+/// let strings = Utf8Array::<i32>::from([Some("a1b2"), Some("-"), None]);
+///
+/// let result = regexp_extract_all(&strings, r"([a-z])\d").unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend([
+///     Some(vec![Some("a"), Some("b")]),
+///     Some(vec![]),
+///     None,
+/// ]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.values()[0].as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+/// # Error
+/// Errors iff `pattern` is not a valid regex.
+pub fn regexp_extract_all<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> Result<StructArray> {
+    let regex = Regex::new(pattern)
+        .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+    let groups = extract_groups(&regex);
+
+    let mut values = groups
+        .iter()
+        .map(|_| MutableListArray::<O, MutableUtf8Array<O>>::with_capacity(array.len()))
+        .collect::<Vec<_>>();
+    for value in array.iter() {
+        let captures = value.map(|value| regex.captures_iter(value).collect::<Vec<_>>());
+        for ((index, _), values) in groups.iter().zip(values.iter_mut()) {
+            let items = captures.as_ref().map(|captures| {
+                captures
+                    .iter()
+                    .map(|captures| captures.get(*index).map(|group| group.as_str()))
+            });
+            values.try_push(items)?;
+        }
+    }
+
+    let (fields, values) = groups
+        .into_iter()
+        .zip(values)
+        .map(|((_, name), mut values)| {
+            let values = values.as_box();
+            (Field::new(name, values.data_type().clone(), true), values)
+        })
+        .unzip();
+    Ok(StructArray::new(
+        DataType::Struct(fields),
+        values,
+        array.validity().cloned(),
+    ))
+}
@@ -1,10 +1,13 @@
 use std::cmp::Ordering;
 
+use num_traits::AsPrimitive;
+
 use crate::compute::take;
+use crate::datatypes::{PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::{
     array::{ord, Array, PrimitiveArray},
-    types::Index,
+    types::{Index, NativeType},
 };
 
 use super::{sort_to_indices, SortOptions};
@@ -22,8 +25,8 @@ pub struct SortColumn<'a> {
 }
 
 /// Sort a list of [`Array`] using [`SortOptions`] provided for each array.
-/// # Implementaqtion
-/// The sort is stable and lexicographical on values.
+/// # Implementation
+/// The sort is lexicographical on values, and stable when `limit` is `None`.
 ///
 /// Returns an [`Error`] if any of the array type is either unsupported by
 /// `lexsort_to_indices` or `take`.
@@ -169,6 +172,14 @@ pub fn lexsort_to_indices_impl<I: Index>(
         ));
     };
 
+    if limit.is_none()
+        && columns
+            .iter()
+            .any(|column| is_radix_sortable(column.values))
+    {
+        return lexsort_to_indices_lsd(columns, build_compare_fn);
+    }
+
     // map arrays to comparators
     let comparators = columns
         .iter()
@@ -208,9 +219,119 @@ pub fn lexsort_to_indices_impl<I: Index>(
         values.truncate(limit);
         values.shrink_to_fit();
     } else {
-        values.sort_unstable_by(lex_comparator);
+        values.sort_by(lex_comparator);
+    }
+
+    let data_type = I::PRIMITIVE.into();
+    Ok(PrimitiveArray::<I>::new(data_type, values.into(), None))
+}
+
+fn is_radix_sortable(array: &dyn Array) -> bool {
+    use PrimitiveType::*;
+    matches!(
+        array.data_type().to_physical_type(),
+        PhysicalType::Primitive(Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64)
+    )
+}
+
+/// Returns the keys of an integer `array` as unsigned integers whose order on its first
+/// `size_of::<T>()` bytes is the order of the values.
+fn integer_keys<T: NativeType + AsPrimitive<u64>>(
+    array: &dyn Array,
+    signed: bool,
+) -> (Vec<u64>, usize) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let bytes = std::mem::size_of::<T>();
+    // flipping the sign bit maps the two's complement order to the unsigned order
+    let sign = if signed { 1u64 << (bytes * 8 - 1) } else { 0 };
+    let keys = array.values().iter().map(|x| x.as_() ^ sign).collect();
+    (keys, bytes)
+}
+
+/// Stable LSD radix sort of `indices` by `keys[index]`, considering the first `bytes` bytes
+/// of each key.
+fn radix_sort(indices: &mut Vec<usize>, keys: &[u64], bytes: usize) {
+    let mut buffer = vec![0; indices.len()];
+    for byte in 0..bytes {
+        let shift = byte * 8;
+        let digit = |index: usize| ((keys[index] >> shift) & 0xff) as usize;
+
+        let mut offsets = [0usize; 256];
+        indices.iter().for_each(|index| offsets[digit(*index)] += 1);
+        if offsets.contains(&indices.len()) {
+            // all keys share this digit
+            continue;
+        }
+        // counts => offsets of each digit's bucket
+        let mut sum = 0;
+        for offset in offsets.iter_mut() {
+            let count = *offset;
+            *offset = sum;
+            sum += count;
+        }
+        for index in indices.iter() {
+            let offset = &mut offsets[digit(*index)];
+            buffer[*offset] = *index;
+            *offset += 1;
+        }
+        std::mem::swap(indices, &mut buffer);
+    }
+}
+
+/// Sorts `columns` by stable-sorting the indices by each column, from the last to the first.
+/// Integer columns are radix sorted; other columns are sorted by comparison.
+fn lexsort_to_indices_lsd<I: Index>(
+    columns: &[SortColumn],
+    build_compare_fn: &dyn Fn(&dyn Array, &dyn Array) -> Result<DynComparator>,
+) -> Result<PrimitiveArray<I>> {
+    let row_count = columns[0].values.len();
+    // errors early on indices that do not fit in `I`
+    I::range(0, row_count).ok_or(Error::Overflow)?;
+
+    let mut indices = (0..row_count).collect::<Vec<_>>();
+    for column in columns.iter().rev() {
+        let options = column.options.unwrap_or_default();
+        let array = column.values;
+        if is_radix_sortable(array) {
+            use PrimitiveType::*;
+            let (mut keys, bytes) = match array.data_type().to_physical_type() {
+                PhysicalType::Primitive(Int8) => integer_keys::<i8>(array, true),
+                PhysicalType::Primitive(Int16) => integer_keys::<i16>(array, true),
+                PhysicalType::Primitive(Int32) => integer_keys::<i32>(array, true),
+                PhysicalType::Primitive(Int64) => integer_keys::<i64>(array, true),
+                PhysicalType::Primitive(UInt8) => integer_keys::<u8>(array, false),
+                PhysicalType::Primitive(UInt16) => integer_keys::<u16>(array, false),
+                PhysicalType::Primitive(UInt32) => integer_keys::<u32>(array, false),
+                PhysicalType::Primitive(UInt64) => integer_keys::<u64>(array, false),
+                _ => unreachable!(),
+            };
+            if options.descending {
+                keys.iter_mut().for_each(|key| *key = !*key);
+            }
+            radix_sort(&mut indices, &keys, bytes);
+
+            // nulls are equal to each other: a stable partition places them
+            if let Some(validity) = array.validity() {
+                let (valid, null): (Vec<_>, Vec<_>) = indices
+                    .iter()
+                    .copied()
+                    .partition(|index| validity.get_bit(*index));
+                indices = if options.nulls_first {
+                    null.into_iter().chain(valid).collect()
+                } else {
+                    valid.into_iter().chain(null).collect()
+                };
+            }
+        } else {
+            let comparator = build_compare_impl(array, options, build_compare_fn)?;
+            indices.sort_by(|a, b| comparator(*a, *b));
+        }
     }
 
+    let values = indices
+        .into_iter()
+        .map(I::from_as_usize)
+        .collect::<Vec<_>>();
     let data_type = I::PRIMITIVE.into();
     Ok(PrimitiveArray::<I>::new(data_type, values.into(), None))
 }
@@ -0,0 +1,97 @@
+//! Contains [`struct_pack`] and [`struct_unpack`], to build [`StructArray`]s from named arrays
+//! and back.
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    array::{Array, StructArray},
+    datatypes::{DataType, Field},
+    error::{Error, Result},
+};
+
+/// Returns a [`StructArray`] whose fields are the named `fields`, in order.
+///
+/// All fields are nullable and the returned array has no validity (all its slots are valid).
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::struct_utils::struct_pack;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let array = struct_pack(vec![
+///     ("a", Int32Array::from_slice([1, 2]).boxed()),
+///     ("b", Utf8Array::<i32>::from_slice(["x", "y"]).boxed()),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(
+///     array.data_type(),
+///     &DataType::Struct(vec![
+///         Field::new("a", DataType::Int32, true),
+///         Field::new("b", DataType::Utf8, true),
+///     ])
+/// );
+/// ```
+/// # Errors
+/// This function errors iff `fields` is empty, contains duplicated names, or its arrays have
+/// different lengths.
+pub fn struct_pack(fields: Vec<(&str, Box<dyn Array>)>) -> Result<StructArray> {
+    let length = fields
+        .first()
+        .map(|(_, array)| array.len())
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("struct_pack requires at least one field".to_string())
+        })?;
+
+    let mut names = HashSet::with_capacity(fields.len());
+    for (name, array) in &fields {
+        if !names.insert(*name) {
+            return Err(Error::InvalidArgumentError(format!(
+                "struct_pack requires unique names, but \"{}\" is duplicated",
+                name
+            )));
+        }
+        if array.len() != length {
+            return Err(Error::InvalidArgumentError(format!(
+                "struct_pack requires arrays of the same length, but \"{}\" has length {} instead of {}",
+                name,
+                array.len(),
+                length
+            )));
+        }
+    }
+
+    let (fields, values): (Vec<_>, Vec<_>) = fields
+        .into_iter()
+        .map(|(name, array)| (Field::new(name, array.data_type().clone(), true), array))
+        .unzip();
+    StructArray::try_new(DataType::Struct(fields), values, None)
+}
+
+/// Returns the values of each field of `array` by the field's name.
+///
+/// The values are the struct's children as-is: the validity of `array` is not applied to them.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::struct_utils::{struct_pack, struct_unpack};
+///
+/// let a = Int32Array::from_slice([1, 2]);
+/// let array = struct_pack(vec![
+///     ("a", a.clone().boxed()),
+///     ("b", Utf8Array::<i32>::from_slice(["x", "y"]).boxed()),
+/// ])
+/// .unwrap();
+///
+/// let fields = struct_unpack(&array);
+/// assert_eq!(fields.len(), 2);
+/// assert_eq!(fields["a"], &a as &dyn Array);
+/// ```
+pub fn struct_unpack(array: &StructArray) -> HashMap<String, &dyn Array> {
+    array
+        .fields()
+        .iter()
+        .zip(array.values())
+        .map(|(field, values)| (field.name.clone(), values.as_ref()))
+        .collect()
+}
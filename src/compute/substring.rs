@@ -24,44 +24,37 @@ use crate::{
     offset::{Offset, Offsets},
 };
 
-fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>) -> Utf8Array<O> {
-    let length = length.map(|v| v.to_usize());
-
-    let iter = array.values_iter().map(|str_val| {
-        // compute where we should start slicing this entry.
-        let start = if start >= O::zero() {
-            start.to_usize()
+fn utf8_substring_chars<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: &Option<u64>,
+) -> Utf8Array<O> {
+    let length = length.map(|length| usize::try_from(length).unwrap_or(usize::MAX));
+
+    let iter = array.values_iter().map(|value| {
+        // the byte position of the `start`-th char, clamped to the string
+        let start = if start >= 0 {
+            let start = usize::try_from(start).unwrap_or(usize::MAX);
+            value
+                .char_indices()
+                .nth(start)
+                .map(|(idx, _)| idx)
+                .unwrap_or(value.len())
         } else {
-            let start = (O::zero() - start).to_usize();
-            str_val
+            let start = usize::try_from(start.unsigned_abs()).unwrap_or(usize::MAX);
+            value
                 .char_indices()
                 .rev()
-                .nth(start)
-                .map(|(idx, _)| idx + 1)
+                .nth(start - 1)
+                .map(|(idx, _)| idx)
                 .unwrap_or(0)
         };
+        let value = &value[start..];
 
-        let mut iter_chars = str_val.char_indices();
-        if let Some((start_idx, _)) = iter_chars.nth(start) {
-            // length of the str
-            let len_end = str_val.len() - start_idx;
-
-            // length to slice
-            let length = length.unwrap_or(len_end);
-
-            if length == 0 {
-                return "";
-            }
-            // compute
-            let end_idx = iter_chars
-                .nth(length.saturating_sub(1))
-                .map(|(idx, _)| idx)
-                .unwrap_or(str_val.len());
-
-            &str_val[start_idx..end_idx]
-        } else {
-            ""
-        }
+        let end = length
+            .and_then(|length| value.char_indices().nth(length).map(|(idx, _)| idx))
+            .unwrap_or(value.len());
+        &value[..end]
     });
 
     let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
@@ -117,6 +110,7 @@ fn binary_substring<O: Offset>(
 
 /// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
 /// `start` can be negative, in which case the start counts from the end of the string.
+/// Binary arrays are sliced by bytes and \[Large\]Utf8 arrays by characters (see [`utf8_substring`]).
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<Box<dyn Array>> {
     match array.data_type() {
@@ -136,24 +130,58 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
             start,
             &length.map(|e| e as i64),
         ))),
-        DataType::LargeUtf8 => Ok(Box::new(utf8_substring(
+        DataType::LargeUtf8 | DataType::Utf8 => utf8_substring(array, start, length),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "substring does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns a [`Utf8Array`] with the substring of each string of `array` starting at the
+/// `start`-th character and with at most `length` characters, where characters are Unicode
+/// scalar values (so that multi-byte characters are never split).
+///
+/// A negative `start` counts from the end of the string. Positions beyond the string are
+/// clamped to it, so this never errors due to positions. Note that a grapheme composed of
+/// multiple scalar values (e.g. a letter followed by a combining accent) counts as multiple
+/// characters.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::substring::utf8_substring;
+///
+/// let array = Utf8Array::<i32>::from([Some("héllo"), None, Some("💖ab")]);
+/// let result = utf8_substring(&array, -3, &Some(2)).unwrap();
+/// let expected = Utf8Array::<i32>::from([Some("ll"), None, Some("💖a")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]Utf8 array.
+pub fn utf8_substring(
+    array: &dyn Array,
+    start: i64,
+    length: &Option<u64>,
+) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::LargeUtf8 => Ok(Box::new(utf8_substring_chars(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
             start,
-            &length.map(|e| e as i64),
+            length,
         ))),
-        DataType::Utf8 => Ok(Box::new(utf8_substring(
+        DataType::Utf8 => Ok(Box::new(utf8_substring_chars(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
                 .expect("A string is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start,
+            length,
         ))),
         _ => Err(Error::InvalidArgumentError(format!(
-            "substring does not support type {:?}",
+            "utf8_substring does not support type {:?}",
             array.data_type()
         ))),
     }
@@ -0,0 +1,45 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::{
+    array::{growable::make_growable, Array, FixedSizeListArray, PrimitiveArray},
+    error::Result,
+};
+
+use super::{check_bounds, structure::take_validity, Index};
+
+/// `take` implementation for [`FixedSizeListArray`]s.
+/// # Implementation
+/// The `size` values of each taken list are copied as a single range of the inner array.
+pub fn take<O: Index>(
+    values: &FixedSizeListArray,
+    indices: &PrimitiveArray<O>,
+) -> Result<FixedSizeListArray> {
+    check_bounds(values.len(), indices)?;
+    let size = FixedSizeListArray::get_child_and_size(values.data_type()).1;
+
+    let inner = values.values().as_ref();
+    let mut growable = make_growable(&[inner], true, indices.len() * size);
+    for index in indices.iter() {
+        match index {
+            Some(index) => growable.extend(0, index.to_usize() * size, size),
+            None => growable.extend_validity(size),
+        }
+    }
+
+    let validity = take_validity(values.validity(), indices)?;
+    FixedSizeListArray::try_new(values.data_type().clone(), growable.as_box(), validity)
+}
@@ -0,0 +1,53 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::{
+    array::{growable::make_growable, Array, MapArray, PrimitiveArray},
+    error::Result,
+    offset::Offsets,
+};
+
+use super::{check_bounds, structure::take_validity, Index};
+
+/// `take` implementation for [`MapArray`]s.
+/// # Implementation
+/// Like lists, the entries of each taken map are copied as a single range of the inner array.
+pub fn take<O: Index>(values: &MapArray, indices: &PrimitiveArray<O>) -> Result<MapArray> {
+    check_bounds(values.len(), indices)?;
+
+    let field = values.field().as_ref();
+    let mut offsets = Offsets::<i32>::with_capacity(indices.len());
+    let mut growable = make_growable(&[field], false, 0);
+    for index in indices.iter() {
+        let length = match index {
+            Some(index) => {
+                let (start, end) = values.offsets().start_end(index.to_usize());
+                growable.extend(0, start, end - start);
+                end - start
+            }
+            None => 0,
+        };
+        offsets.try_push_usize(length)?;
+    }
+
+    let validity = take_validity(values.validity(), indices)?;
+    MapArray::try_new(
+        values.data_type().clone(),
+        offsets.into(),
+        growable.as_box(),
+        validity,
+    )
+}
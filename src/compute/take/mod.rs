@@ -20,17 +20,20 @@
 use crate::{
     array::{new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::DataType,
-    error::Result,
+    error::{Error, Result},
     types::Index,
 };
 
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_list;
 mod generic_binary;
 mod list;
+mod map;
 mod primitive;
 mod structure;
+mod union;
 mod utf8;
 
 pub(crate) use boolean::take as take_boolean;
@@ -90,6 +93,18 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(list::take::<i64, O>(array, indices)))
         }
+        FixedSizeList => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_list::take::<O>(array, indices)?))
+        }
+        Map => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(map::take::<O>(array, indices)?))
+        }
+        Union => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(union::take::<O>(array, indices)?))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
@@ -135,6 +150,24 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::Struct(_)
             | DataType::List(_)
             | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+            | DataType::Union(_, _, _)
             | DataType::Dictionary(..)
     )
 }
+
+/// Errors iff any of the non-null `indices` is out of bounds of an array of length `len`.
+fn check_bounds<O: Index>(len: usize, indices: &PrimitiveArray<O>) -> Result<()> {
+    indices.iter().flatten().try_for_each(|index| {
+        let index = index.to_usize();
+        if index >= len {
+            Err(Error::InvalidArgumentError(format!(
+                "Out-of-bounds index {} in take of an array of length {}",
+                index, len
+            )))
+        } else {
+            Ok(())
+        }
+    })
+}
@@ -24,20 +24,17 @@ use crate::{
 use super::Index;
 
 #[inline]
-fn take_validity<I: Index>(
+pub(super) fn take_validity<I: Index>(
     validity: Option<&Bitmap>,
     indices: &PrimitiveArray<I>,
 ) -> Result<Option<Bitmap>> {
     let indices_validity = indices.validity();
     match (validity, indices_validity) {
         (None, _) => Ok(indices_validity.cloned()),
-        (Some(validity), None) => {
-            let iter = indices.values().iter().map(|index| {
-                let index = index.to_usize();
-                validity.get_bit(index)
-            });
-            Ok(MutableBitmap::from_trusted_len_iter(iter).into())
-        }
+        (Some(validity), None) => Ok(crate::bitmap::take_validity(
+            Some(validity),
+            indices.values(),
+        )),
         (Some(validity), _) => {
             let iter = indices.iter().map(|x| match x {
                 Some(index) => {
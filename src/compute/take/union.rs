@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::{
+    array::{Array, PrimitiveArray, UnionArray},
+    datatypes::DataType,
+    error::Result,
+};
+
+use super::{check_bounds, Index};
+
+/// `take` implementation for sparse and dense [`UnionArray`]s.
+/// Null indices are taken as a null slot of the first field (with values, for dense unions).
+pub fn take<O: Index>(values: &UnionArray, indices: &PrimitiveArray<O>) -> Result<UnionArray> {
+    check_bounds(values.len(), indices)?;
+    let data_type = values.data_type().clone();
+    if values.len() == 0 {
+        // all indices are null
+        return Ok(UnionArray::new_null(data_type, indices.len()));
+    }
+
+    let (ids, is_sparse) = match data_type.to_logical_type() {
+        DataType::Union(_, ids, mode) => (ids.as_ref(), mode.is_sparse()),
+        _ => unreachable!(),
+    };
+    let type_id = |field: usize| ids.map(|ids| ids[field] as i8).unwrap_or(field as i8);
+
+    if is_sparse {
+        // every field has a slot for each row: take the same slots from all of them
+        let slots = indices
+            .iter()
+            .map(|index| index.map(|index| values.index(index.to_usize()).1 as u64))
+            .collect::<Vec<_>>();
+        let slots = PrimitiveArray::<u64>::from(slots);
+        let types = indices
+            .iter()
+            .map(|index| type_id(index.map_or(0, |index| values.index(index.to_usize()).0)))
+            .collect::<Vec<_>>();
+
+        let fields = values
+            .fields()
+            .iter()
+            .map(|field| super::take(field.as_ref(), &slots))
+            .collect::<Result<_>>()?;
+        UnionArray::try_new(data_type, types.into(), fields, None)
+    } else {
+        // a non-empty dense union has at least one field with values
+        let null_field = values
+            .fields()
+            .iter()
+            .position(|field| !field.is_empty())
+            .unwrap();
+
+        let mut types = Vec::with_capacity(indices.len());
+        let mut offsets = Vec::with_capacity(indices.len());
+        let mut slots = vec![vec![]; values.fields().len()];
+        for index in indices.iter() {
+            let (field, slot) = match index {
+                Some(index) => {
+                    let (field, slot) = values.index(index.to_usize());
+                    (field, Some(slot as i32))
+                }
+                None => (null_field, None),
+            };
+            types.push(type_id(field));
+            offsets.push(slots[field].len() as i32);
+            slots[field].push(slot);
+        }
+
+        let fields = values
+            .fields()
+            .iter()
+            .zip(slots)
+            .map(|(field, slots)| super::take(field.as_ref(), &PrimitiveArray::<i32>::from(slots)))
+            .collect::<Result<_>>()?;
+        UnionArray::try_new(data_type, types.into(), fields, Some(offsets.into()))
+    }
+}
@@ -49,6 +49,37 @@ trait U32IsoWeek: Datelike {
 impl U32IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> U32IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that returns the ISO year of a date, and its quarter
+trait DateParts: Datelike {
+    fn i32_iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
+
+    fn u32_quarter(&self) -> u32 {
+        self.month0() / 3 + 1
+    }
+}
+
+impl DateParts for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> DateParts for chrono::DateTime<T> {}
+
+// Create and implement a trait that returns the seconds since the unix epoch
+trait I64Epoch {
+    fn i64_epoch(&self) -> i64;
+}
+
+impl I64Epoch for chrono::NaiveDateTime {
+    fn i64_epoch(&self) -> i64 {
+        self.and_utc().timestamp()
+    }
+}
+
+impl<T: chrono::TimeZone> I64Epoch for chrono::DateTime<T> {
+    fn i64_epoch(&self) -> i64 {
+        self.timestamp()
+    }
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -109,6 +140,62 @@ pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// Extracts the ISO year of a temporal array as [`PrimitiveArray<i32>`], the year that the
+/// [`iso_week`] belongs to (e.g. `2024` for `2024-12-30`).
+/// Use [`can_iso_year`] to check if this operation is supported for the target [`DataType`]
+pub fn iso_year(array: &dyn Array) -> Result<PrimitiveArray<i32>> {
+    date_like!(i32_iso_year, array, DataType::Int32)
+}
+
+/// Extracts the quarters of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 4.
+/// Use [`can_quarter`] to check if this operation is supported for the target [`DataType`]
+pub fn quarter(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(u32_quarter, array, DataType::UInt32)
+}
+
+/// Extracts the days of the year of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 366 (Last day depends on the year).
+/// Use [`can_day_of_year`] to check if this operation is supported for the target [`DataType`]
+pub fn day_of_year(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(ordinal, array, DataType::UInt32)
+}
+
+/// Extracts the days of the week of a temporal array as [`PrimitiveArray<u32>`], counting
+/// from `week_start`: `week_start` is 1, the following day is 2, ..., and the day before
+/// `week_start` is 7. [`weekday`] is equivalent to a `week_start` of [`chrono::Weekday::Mon`].
+/// Use [`can_day_of_week`] to check if this operation is supported for the target [`DataType`]
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::temporal::day_of_week;
+/// use arrow2::datatypes::DataType;
+/// use chrono::Weekday;
+///
+/// // 1970-01-01 (a Thursday) and 1970-01-04 (a Sunday)
+/// let array = Int32Array::from([Some(0), None, Some(3)]).to(DataType::Date32);
+///
+/// let result = day_of_week(&array, Weekday::Sun).unwrap();
+/// assert_eq!(result, UInt32Array::from([Some(5), None, Some(1)]));
+/// ```
+pub fn day_of_week(array: &dyn Array, week_start: chrono::Weekday) -> Result<PrimitiveArray<u32>> {
+    let start = week_start.number_from_monday();
+    let weekdays = weekday(array)?;
+    Ok(unary(
+        &weekdays,
+        |x| (x + 7 - start) % 7 + 1,
+        DataType::UInt32,
+    ))
+}
+
+/// Extracts the number of seconds since the unix epoch (`1970-01-01T00:00:00Z`) of a temporal
+/// array as [`PrimitiveArray<i64>`], rounded towards negative infinity.
+/// Timestamps without a timezone are interpreted as UTC.
+/// Use [`can_epoch`] to check if this operation is supported for the target [`DataType`]
+pub fn epoch(array: &dyn Array) -> Result<PrimitiveArray<i64>> {
+    date_like!(i64_epoch, array, DataType::Int64)
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -353,6 +440,31 @@ pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform ISO year operation
+pub fn can_iso_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform quarter operation
+pub fn can_quarter(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform day of year operation
+pub fn can_day_of_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform day of week operation
+pub fn can_day_of_week(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform epoch operation
+pub fn can_epoch(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
@@ -401,3 +513,252 @@ fn can_time(data_type: &DataType) -> bool {
             | DataType::Timestamp(_, _)
     )
 }
+
+/// The granularity that [`truncate`] and [`round`] round timestamps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// Whole seconds
+    Second,
+    /// Whole minutes
+    Minute,
+    /// Whole hours
+    Hour,
+    /// Midnight of the day
+    Day,
+    /// Midnight of the Monday of the week
+    Week,
+    /// Midnight of the first day of the month
+    Month,
+    /// Midnight of the first day of the year
+    Year,
+}
+
+impl Granularity {
+    /// The number of seconds of the granularity, for granularities shorter than a day.
+    fn seconds(&self) -> Option<i64> {
+        match self {
+            Self::Second => Some(1),
+            Self::Minute => Some(60),
+            Self::Hour => Some(3600),
+            Self::Day | Self::Week | Self::Month | Self::Year => None,
+        }
+    }
+
+    /// The local date that starts the period of `date`.
+    fn start(&self, date: chrono::NaiveDate) -> chrono::NaiveDate {
+        match self {
+            Self::Week => {
+                date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            Self::Month => date.with_day(1).unwrap(),
+            Self::Year => date.with_ordinal(1).unwrap(),
+            _ => date,
+        }
+    }
+
+    /// The local date that starts the period after the one starting at `start`.
+    fn next(&self, start: chrono::NaiveDate) -> chrono::NaiveDate {
+        match self {
+            Self::Week => start + chrono::Duration::days(7),
+            Self::Month if start.month() == 12 => {
+                chrono::NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap()
+            }
+            Self::Month => start.with_month(start.month() + 1).unwrap(),
+            Self::Year => chrono::NaiveDate::from_ymd_opt(start.year() + 1, 1, 1).unwrap(),
+            _ => start + chrono::Duration::days(1),
+        }
+    }
+}
+
+/// Truncates each timestamp of `array` to the start of its [`Granularity`], e.g. to midnight
+/// with [`Granularity::Day`] (SQL's `date_trunc`).
+///
+/// Granularities of a day or coarser are applied in the local (wall-clock) time of the
+/// timezone of `array`, so that e.g. days start at local midnight, also on days of daylight
+/// saving time transitions. Timestamps without a timezone are treated as UTC. When a local
+/// midnight does not exist (it falls on a gap), the period starts at the end of the gap; when
+/// it is ambiguous, the earliest instant is used.
+/// Finer granularities are applied to the timestamps as absolute time.
+///
+/// The result has the same [`DataType`] as `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::{truncate, Granularity};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// // 2021-05-24T18:25:30+01:00
+/// let array = Int64Array::from([Some(1621877130), None]).to(data_type.clone());
+///
+/// let result = truncate(&array, Granularity::Day).unwrap();
+/// // 2021-05-24T00:00:00+01:00
+/// assert_eq!(result, Int64Array::from([Some(1621810800), None]).to(data_type.clone()));
+///
+/// let result = truncate(&array, Granularity::Hour).unwrap();
+/// assert_eq!(result, Int64Array::from([Some(1621875600), None]).to(data_type));
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a timestamp, or its timezone cannot be parsed.
+pub fn truncate(
+    array: &PrimitiveArray<i64>,
+    granularity: Granularity,
+) -> Result<PrimitiveArray<i64>> {
+    round_impl(array, granularity, false)
+}
+
+/// Rounds each timestamp of `array` to the nearest start of a period of [`Granularity`]
+/// (halfway values are rounded up). Periods are the same as in [`truncate`].
+///
+/// The result has the same [`DataType`] as `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::{round, Granularity};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+/// // 1970-01-01T00:00:29.999 and 1970-01-01T00:00:30
+/// let array = Int64Array::from([Some(29_999), Some(30_000), None]).to(data_type.clone());
+///
+/// let result = round(&array, Granularity::Minute).unwrap();
+/// assert_eq!(result, Int64Array::from([Some(0), Some(60_000), None]).to(data_type));
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a timestamp, or its timezone cannot be parsed.
+pub fn round(array: &PrimitiveArray<i64>, granularity: Granularity) -> Result<PrimitiveArray<i64>> {
+    round_impl(array, granularity, true)
+}
+
+fn round_impl(
+    array: &PrimitiveArray<i64>,
+    granularity: Granularity,
+    nearest: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let (time_unit, timezone) = match array.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, timezone) => (*time_unit, timezone.as_ref()),
+        dt => {
+            return Err(Error::NotYetImplemented(format!(
+                "\"{}\" does not support type {:?}",
+                if nearest { "round" } else { "truncate" },
+                dt
+            )))
+        }
+    };
+
+    if let Some(seconds) = granularity.seconds() {
+        let size = seconds
+            * match time_unit {
+                TimeUnit::Second => 1,
+                TimeUnit::Millisecond => MILLISECONDS,
+                TimeUnit::Microsecond => MICROSECONDS,
+                TimeUnit::Nanosecond => NANOSECONDS,
+            };
+        let offset = if nearest { size / 2 } else { 0 };
+        return Ok(unary(
+            array,
+            |x| (x + offset).div_euclid(size) * size,
+            array.data_type().clone(),
+        ));
+    }
+
+    match timezone {
+        None => Ok(round_calendar(
+            array,
+            time_unit,
+            &chrono::Utc,
+            granularity,
+            nearest,
+        )),
+        Some(timezone_str) => {
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                Ok(round_calendar(
+                    array,
+                    time_unit,
+                    &timezone,
+                    granularity,
+                    nearest,
+                ))
+            } else {
+                round_calendar_tz(array, time_unit, timezone_str, granularity, nearest)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+fn round_calendar_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    granularity: Granularity,
+    nearest: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(round_calendar(
+        array,
+        time_unit,
+        &timezone,
+        granularity,
+        nearest,
+    ))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn round_calendar_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: Granularity,
+    _: bool,
+) -> Result<PrimitiveArray<i64>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Returns the (utc) instant of the local midnight of `date` in `timezone`.
+fn local_midnight<T: chrono::TimeZone>(
+    timezone: &T,
+    date: chrono::NaiveDate,
+) -> chrono::NaiveDateTime {
+    use chrono::{LocalResult, Offset};
+
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+    match timezone.from_local_datetime(&midnight) {
+        LocalResult::Single(datetime) => datetime.naive_utc(),
+        LocalResult::Ambiguous(earliest, _) => earliest.naive_utc(),
+        LocalResult::None => {
+            // midnight is in a gap: it is reached with the offset before the gap, at its end
+            let before = midnight - chrono::Duration::days(1);
+            let offset = timezone.offset_from_utc_datetime(&before).fix();
+            midnight - offset
+        }
+    }
+}
+
+fn round_calendar<T: chrono::TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: &T,
+    granularity: Granularity,
+    nearest: bool,
+) -> PrimitiveArray<i64> {
+    let op = |x| {
+        let datetime = timestamp_to_datetime(x, time_unit, timezone);
+        let start = granularity.start(datetime.naive_local().date());
+        let floor = datetime_to_timestamp(local_midnight(timezone, start), time_unit);
+        if !nearest {
+            return floor;
+        }
+        let ceil =
+            datetime_to_timestamp(local_midnight(timezone, granularity.next(start)), time_unit);
+        if x - floor >= ceil - x {
+            ceil
+        } else {
+            floor
+        }
+    };
+    unary(array, op, array.data_type().clone())
+}
@@ -1,10 +1,10 @@
 //! Defines common maps to a [`Utf8Array`]
 
 use crate::{
-    array::{Array, Utf8Array},
+    array::{Array, ListArray, MutableListArray, MutableUtf8Array, TryPush, Utf8Array},
     datatypes::DataType,
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, Offsets},
 };
 
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
@@ -15,6 +15,68 @@ pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>)
     new.with_validity(array.validity().cloned())
 }
 
+/// Like [`utf8_apply`], but for functions returning a sub-slice of each value (e.g. trimming),
+/// which are written to the new array without intermediate allocations.
+fn utf8_apply_slice<O: Offset, F: Fn(&str) -> &str>(f: F, array: &Utf8Array<O>) -> Utf8Array<O> {
+    let iter = array.values_iter().map(f);
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Splits each of the values of `array` on `delimiter` into a [`ListArray`] of its parts.
+///
+/// When `limit` is set, each value is split at most `limit` times, with the last part
+/// containing the remainder of the value.
+/// Empty values are empty lists and null values are null lists.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::utf8::split;
+///
+/// let array = Utf8Array::<i32>::from([Some("a,b,c"), Some(""), None]);
+///
+/// let result = split(&array, ",", Some(1)).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend([
+///     Some(vec![Some("a"), Some("b,c")]),
+///     Some(vec![]),
+///     None,
+/// ]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff `delimiter` is empty.
+pub fn split<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    limit: Option<usize>,
+) -> Result<ListArray<O>> {
+    if delimiter.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "split requires a non-empty delimiter".to_string(),
+        ));
+    }
+
+    let mut result = MutableListArray::<O, MutableUtf8Array<O>>::with_capacity(array.len());
+    for value in array.iter() {
+        let parts = value.map(|value| {
+            let parts: Box<dyn Iterator<Item = &str> + '_> = if value.is_empty() {
+                Box::new(std::iter::empty())
+            } else if let Some(limit) = limit {
+                Box::new(value.splitn(limit.saturating_add(1), delimiter))
+            } else {
+                Box::new(value.split(delimiter))
+            };
+            parts.map(Some)
+        });
+        result.try_push(parts)?;
+    }
+    Ok(result.into())
+}
+
 /// Returns a new `Array` where each of each of the elements is upper-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
@@ -98,3 +160,264 @@ pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
 pub fn can_lower(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
+
+fn initcap_str(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut previous_is_alphanumeric = false;
+    for c in value.chars() {
+        if previous_is_alphanumeric {
+            result.extend(c.to_lowercase());
+        } else {
+            result.extend(c.to_uppercase());
+        }
+        previous_is_alphanumeric = c.is_alphanumeric();
+    }
+    result
+}
+
+/// Returns a new `Array` where the first letter of each word of each of the elements is
+/// upper-cased and the remaining letters are lower-cased.
+/// Words are sequences of alphanumeric characters.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::initcap;
+///
+/// let array = Utf8Array::<i32>::from([Some("hello wORLD"), None, Some("élan-vital")]);
+/// let result = initcap(&array).unwrap();
+///
+/// let expected = Utf8Array::<i32>::from([Some("Hello World"), None, Some("Élan-Vital")]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+pub fn initcap(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
+            initcap_str,
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .expect("A large string is expected"),
+        ))),
+        DataType::Utf8 => Ok(Box::new(utf8_apply(
+            initcap_str,
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .expect("A string is expected"),
+        ))),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "initcap does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Checks if an array of type `datatype` can perform initcap operation
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::utf8::can_initcap;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_initcap(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_initcap(&data_type), false);
+/// ```
+pub fn can_initcap(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+/// Removes the leading and trailing `characters` (whitespace when `None`) of each of the
+/// values of `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::trim;
+///
+/// let array = Utf8Array::<i32>::from([Some("  a b "), None, Some("xxaxx")]);
+/// assert_eq!(trim(&array, None), Utf8Array::<i32>::from([Some("a b"), None, Some("xxaxx")]));
+/// assert_eq!(trim(&array, Some("x ")), Utf8Array::<i32>::from([Some("a b"), None, Some("a")]));
+/// ```
+pub fn trim<O: Offset>(array: &Utf8Array<O>, characters: Option<&str>) -> Utf8Array<O> {
+    match characters {
+        Some(characters) => {
+            utf8_apply_slice(|x| x.trim_matches(|c: char| characters.contains(c)), array)
+        }
+        None => utf8_apply_slice(str::trim, array),
+    }
+}
+
+/// Removes the leading `characters` (whitespace when `None`) of each of the values of `array`.
+pub fn ltrim<O: Offset>(array: &Utf8Array<O>, characters: Option<&str>) -> Utf8Array<O> {
+    match characters {
+        Some(characters) => utf8_apply_slice(
+            |x| x.trim_start_matches(|c: char| characters.contains(c)),
+            array,
+        ),
+        None => utf8_apply_slice(str::trim_start, array),
+    }
+}
+
+/// Removes the trailing `characters` (whitespace when `None`) of each of the values of `array`.
+pub fn rtrim<O: Offset>(array: &Utf8Array<O>, characters: Option<&str>) -> Utf8Array<O> {
+    match characters {
+        Some(characters) => utf8_apply_slice(
+            |x| x.trim_end_matches(|c: char| characters.contains(c)),
+            array,
+        ),
+        None => utf8_apply_slice(str::trim_end, array),
+    }
+}
+
+/// Writes `value` padded with `fill` up to `length` characters (or truncated to `length`
+/// characters) to `buffer`.
+fn pad_value(buffer: &mut Vec<u8>, value: &str, length: usize, fill: &str, left: bool) {
+    let count = value.chars().count();
+    if count >= length {
+        // values longer than `length` are truncated
+        let end = value
+            .char_indices()
+            .nth(length)
+            .map(|(index, _)| index)
+            .unwrap_or(value.len());
+        buffer.extend_from_slice(&value.as_bytes()[..end]);
+        return;
+    }
+
+    let mut encoded = [0u8; 4];
+    let mut write_padding = |buffer: &mut Vec<u8>| {
+        fill.chars()
+            .cycle()
+            .take(length - count)
+            .for_each(|c| buffer.extend_from_slice(c.encode_utf8(&mut encoded).as_bytes()))
+    };
+    if left {
+        write_padding(buffer);
+        buffer.extend_from_slice(value.as_bytes());
+    } else {
+        buffer.extend_from_slice(value.as_bytes());
+        write_padding(buffer);
+    }
+}
+
+/// Writes `write(buffer, value)` for every valid value of `array` into a single buffer,
+/// without intermediate allocations.
+/// # Errors
+/// Errors iff the written values do not fit in the offsets of `O`.
+fn utf8_write<O: Offset, F: Fn(&mut Vec<u8>, &str)>(
+    array: &Utf8Array<O>,
+    write: F,
+) -> Result<Utf8Array<O>> {
+    let mut values = Vec::<u8>::with_capacity(array.values().len());
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+    for (index, value) in array.values_iter().enumerate() {
+        let start = values.len();
+        if array.is_valid(index) {
+            write(&mut values, value);
+        }
+        offsets.try_push_usize(values.len() - start)?;
+    }
+
+    // Safety: every value is a concatenation of valid utf8 strings
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+fn pad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: &str, left: bool) -> Utf8Array<O> {
+    utf8_write(array, |buffer, value| {
+        pad_value(buffer, value, length, fill, left)
+    })
+    .expect("the padded values to fit in the offset type")
+}
+
+/// Pads each of the values of `array` on the left with `fill`, repeated as needed, up to
+/// `length` characters. Values longer than `length` characters are truncated to `length`
+/// characters; values are not padded when `fill` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::lpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("1"), None, Some("12345")]);
+/// let expected = Utf8Array::<i32>::from([Some("xyx1"), None, Some("1234")]);
+/// assert_eq!(lpad(&array, 4, "xy"), expected);
+/// ```
+/// # Panics
+/// Panics iff the padded values do not fit in the offsets of `O`.
+pub fn lpad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: &str) -> Utf8Array<O> {
+    pad(array, length, fill, true)
+}
+
+/// Pads each of the values of `array` on the right with `fill`, repeated as needed, up to
+/// `length` characters. Values longer than `length` characters are truncated to `length`
+/// characters; values are not padded when `fill` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::rpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("ñ"), None, Some("ñandú")]);
+/// let expected = Utf8Array::<i32>::from([Some("ñ.."), None, Some("ñan")]);
+/// assert_eq!(rpad(&array, 3, "."), expected);
+/// ```
+/// # Panics
+/// Panics iff the padded values do not fit in the offsets of `O`.
+pub fn rpad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: &str) -> Utf8Array<O> {
+    pad(array, length, fill, false)
+}
+
+/// Writes `value` to `buffer` with (at most `limit`) non-overlapping occurrences of `from`,
+/// from left to right, replaced by `to`.
+fn replace_value(buffer: &mut Vec<u8>, value: &str, from: &str, to: &str, limit: usize) {
+    let mut last = 0;
+    for (start, _) in value.match_indices(from).take(limit) {
+        buffer.extend_from_slice(&value.as_bytes()[last..start]);
+        buffer.extend_from_slice(to.as_bytes());
+        last = start + from.len();
+    }
+    buffer.extend_from_slice(&value.as_bytes()[last..]);
+}
+
+/// Replaces the non-overlapping occurrences of `from` in each of the values of `array` by `to`,
+/// from left to right.
+///
+/// When `limit` is set, at most `limit` occurrences are replaced in each value.
+/// Values are returned unchanged when `from` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::replace;
+///
+/// let array = Utf8Array::<i32>::from([Some("a-b-c"), None, Some("aaa")]);
+///
+/// let expected = Utf8Array::<i32>::from([Some("a+b+c"), None, Some("aaa")]);
+/// assert_eq!(replace(&array, "-", "+", None).unwrap(), expected);
+///
+/// let expected = Utf8Array::<i32>::from([Some("a-b-c"), None, Some("ba")]);
+/// assert_eq!(replace(&array, "aa", "b", Some(1)).unwrap(), expected);
+/// ```
+/// # Errors
+/// Errors iff the replaced values do not fit in the offsets of `O`.
+pub fn replace<O: Offset>(
+    array: &Utf8Array<O>,
+    from: &str,
+    to: &str,
+    limit: Option<usize>,
+) -> Result<Utf8Array<O>> {
+    if from.is_empty() {
+        return Ok(array.clone());
+    }
+    let limit = limit.unwrap_or(usize::MAX);
+    utf8_write(array, |buffer, value| {
+        replace_value(buffer, value, from, to, limit)
+    })
+}
@@ -15,19 +15,34 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing and cumulative sums
 
 use crate::compute::concatenate::concatenate;
-use num_traits::{abs, clamp};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{
+        new_null_array, values_primitive_type, Array, BinaryArray, BinaryViewArray, BooleanArray,
+        DictionaryArray, DictionaryKey, PrimitiveArray, RunEndEncodedArray, StringViewArray,
+        Utf8Array,
+    },
+    bitmap::MutableBitmap,
+    chunk::Chunk,
+    datatypes::DataType,
     error::{Error, Result},
+    offset::Offset,
+    scalar::{
+        scalar_data_type, BinaryScalar, BooleanScalar, DictionaryScalar, PrimitiveScalar, Scalar,
+        Utf8Scalar,
+    },
+    types::NativeType,
 };
 
-/// Shifts array by defined number of items (to left or right)
+/// Shifts array by defined number of items (to left or right), filling the vacated slots
+/// with nulls.
 /// A positive value for `offset` shifts the array to the right
 /// a negative value shifts the array to the left.
+/// When the absolute offset is equal to or larger than the length of the array, the result is
+/// entirely null. Dictionary arrays remain dictionary-encoded.
 /// # Examples
 /// ```
 /// use arrow2::array::Int32Array;
@@ -39,27 +54,477 @@ use crate::{
 /// assert_eq!(expected, result.as_ref());
 /// ```
 pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
-    if abs(offset) as usize > array.len() {
-        return Err(Error::InvalidArgumentError(format!(
-            "Shift's absolute offset must be smaller or equal to the arrays length. Offset is {}, length is {}",
-            abs(offset), array.len()
-        )));
-    }
-
-    // Compute slice
-    let slice_offset = clamp(-offset, 0, array.len() as i64) as usize;
-    let length = array.len() - abs(offset) as usize;
-    let slice = array.slice(slice_offset, length);
+    shift_impl(array, offset, None)
+}
 
-    // Generate array with remaining `null` items
-    let nulls = abs(offset) as usize;
+/// Shifts array by defined number of items (to left or right) like [`shift`], filling the
+/// vacated slots with `fill`.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::shift_with_fill;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let fill = PrimitiveScalar::from(Some(0i32));
+/// let result = shift_with_fill(&array, 2, &fill).unwrap();
+/// let expected = Int32Array::from(&[Some(0), Some(0), Some(1)]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff the [`DataType`] of `fill` is different from the one of the
+/// array's scalars (see [`new_scalar`](crate::scalar::new_scalar)) or filling arrays of this [`DataType`] is not supported (only nulls are supported for nested
+/// types).
+pub fn shift_with_fill(
+    array: &dyn Array,
+    offset: i64,
+    fill: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    shift_impl(array, offset, Some(fill))
+}
 
-    let null_array = new_null_array(array.data_type().clone(), nulls);
+fn shift_impl(array: &dyn Array, offset: i64, fill: Option<&dyn Scalar>) -> Result<Box<dyn Array>> {
+    let shift = (offset.unsigned_abs() as usize).min(array.len());
+    let fill = fill_array(array.data_type(), fill, shift)?;
+    if shift == array.len() {
+        return Ok(fill);
+    }
 
-    // Concatenate both arrays, add nulls after if shift > 0 else before
+    let length = array.len() - shift;
     if offset > 0 {
-        concatenate(&[null_array.as_ref(), slice.as_ref()])
+        let slice = array.slice(0, length);
+        concatenate(&[fill.as_ref(), slice.as_ref()])
     } else {
-        concatenate(&[slice.as_ref(), null_array.as_ref()])
+        let slice = array.slice(shift, length);
+        concatenate(&[slice.as_ref(), fill.as_ref()])
+    }
+}
+
+/// Returns an array of `length` repetitions of `fill`, or of nulls when `fill` is `None`.
+fn fill_array(
+    data_type: &DataType,
+    fill: Option<&dyn Scalar>,
+    length: usize,
+) -> Result<Box<dyn Array>> {
+    let fill = match fill {
+        Some(fill) if fill.data_type() != scalar_data_type(data_type) => {
+            return Err(Error::InvalidArgumentError(
+                "The fill value must have the same data type as the array".to_string(),
+            ))
+        }
+        Some(fill) if fill.is_valid() && length > 0 => fill,
+        _ => return Ok(new_null_array(data_type.clone(), length)),
+    };
+
+    use crate::datatypes::PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Boolean => {
+            let value = fill
+                .as_any()
+                .downcast_ref::<BooleanScalar>()
+                .unwrap()
+                .value();
+            let values = if value == Some(true) {
+                MutableBitmap::from_len_set(length)
+            } else {
+                MutableBitmap::from_len_zeroed(length)
+            };
+            Box::new(BooleanArray::new(data_type.clone(), values.into(), None))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let fill = fill.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let values = vec![fill.value().unwrap(); length];
+            Box::new(PrimitiveArray::<$T>::new(data_type.clone(), values.into(), None))
+        }),
+        Utf8 => fill_utf8::<i32>(fill, length).boxed(),
+        LargeUtf8 => fill_utf8::<i64>(fill, length).boxed(),
+        Binary => fill_binary::<i32>(fill, length).boxed(),
+        LargeBinary => fill_binary::<i64>(fill, length).boxed(),
+        Utf8View => {
+            let array: StringViewArray = fill_utf8::<i32>(fill, length).into();
+            array.boxed()
+        }
+        BinaryView => {
+            let array: BinaryViewArray = fill_binary::<i32>(fill, length).into();
+            array.boxed()
+        }
+        RunEndEncoded => with_match_primitive_type!(values_primitive_type(data_type), |$T| {
+            let fill = fill.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let values = PrimitiveArray::<$T>::new(
+                fill.data_type().clone(),
+                vec![fill.value().unwrap()].into(),
+                None,
+            );
+            let run_ends = vec![i32::try_from(length).map_err(|_| Error::Overflow)?];
+            Box::new(RunEndEncodedArray::<$T>::try_new(
+                data_type.clone(),
+                run_ends.into(),
+                values,
+            )?)
+        }),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let fill = fill.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+            let values = fill_array(
+                DictionaryArray::<$T>::try_get_child(data_type)?,
+                fill.value().map(|x| x.as_ref()),
+                1,
+            )?;
+            let keys = PrimitiveArray::<$T>::new(
+                $T::KEY_TYPE.into(),
+                vec![<$T>::default(); length].into(),
+                None,
+            );
+            Box::new(DictionaryArray::<$T>::try_new(data_type.clone(), keys, values)?)
+        }),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "Filling arrays of physical type {:?} with a non-null value",
+                other
+            )))
+        }
+    })
+}
+
+fn fill_utf8<O: Offset>(fill: &dyn Scalar, length: usize) -> Utf8Array<O> {
+    let value = fill
+        .as_any()
+        .downcast_ref::<Utf8Scalar<O>>()
+        .unwrap()
+        .value();
+    Utf8Array::<O>::from_trusted_len_values_iter(std::iter::repeat(value.unwrap()).take(length))
+}
+
+fn fill_binary<O: Offset>(fill: &dyn Scalar, length: usize) -> BinaryArray<O> {
+    let value = fill
+        .as_any()
+        .downcast_ref::<BinaryScalar<O>>()
+        .unwrap()
+        .value();
+    BinaryArray::<O>::from_trusted_len_values_iter(std::iter::repeat(value.unwrap()).take(length))
+}
+
+/// Returns the `column` of `chunk` shifted `offset` rows up, i.e. each row holds the value
+/// `offset` rows after it (SQL's `LEAD`), filling the last rows with `fill` or nulls.
+/// # Errors
+/// This function errors iff `column` is out of bounds or [`shift_with_fill`] errors.
+pub fn lead<A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    column: usize,
+    offset: usize,
+    fill: Option<&dyn Scalar>,
+) -> Result<Box<dyn Array>> {
+    shift_impl(chunk_column(chunk, column)?, -(offset as i64), fill)
+}
+
+/// Returns the `column` of `chunk` shifted `offset` rows down, i.e. each row holds the value
+/// `offset` rows before it (SQL's `LAG`), filling the first rows with `fill` or nulls.
+/// # Errors
+/// This function errors iff `column` is out of bounds or [`shift_with_fill`] errors.
+pub fn lag<A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    column: usize,
+    offset: usize,
+    fill: Option<&dyn Scalar>,
+) -> Result<Box<dyn Array>> {
+    shift_impl(chunk_column(chunk, column)?, offset as i64, fill)
+}
+
+fn chunk_column<A: AsRef<dyn Array>>(chunk: &Chunk<A>, column: usize) -> Result<&dyn Array> {
+    chunk
+        .arrays()
+        .get(column)
+        .map(|array| array.as_ref())
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "Column {} is out of bounds for a chunk of {} columns",
+                column,
+                chunk.arrays().len()
+            ))
+        })
+}
+
+/// Types supported by [`cumsum`] and [`try_cumsum`].
+pub trait CumSum: NativeType {
+    /// Writes the inclusive prefix sums of `values` to `out`, of the same length.
+    fn cumsum(values: &[Self], out: &mut [Self]);
+
+    /// Writes the inclusive prefix sums of `values` to `out`, of the same length, returning
+    /// `false` iff an integer sum overflows.
+    fn checked_cumsum(values: &[Self], out: &mut [Self]) -> bool;
+}
+
+/// Sequential inclusive prefix sum of `values` starting from `acc`.
+#[inline]
+fn scan<T: Copy, F: Fn(T, T) -> T>(values: &[T], out: &mut [T], mut acc: T, add: F) {
+    values.iter().zip(out.iter_mut()).for_each(|(value, out)| {
+        acc = add(acc, *value);
+        *out = acc;
+    })
+}
+
+/// Sequential inclusive prefix sum of `values` starting from `acc`, returning `false` iff
+/// `add` overflows.
+#[inline]
+fn checked_scan<T: Copy, F: Fn(T, T) -> Option<T>>(
+    values: &[T],
+    out: &mut [T],
+    mut acc: T,
+    add: F,
+) -> bool {
+    for (value, out) in values.iter().zip(out.iter_mut()) {
+        match add(acc, *value) {
+            Some(sum) => acc = sum,
+            None => return false,
+        }
+        *out = acc;
+    }
+    true
+}
+
+macro_rules! cumsum_sequential {
+    ($add:expr, $checked_add:expr, $($type:ty),*) => {$(
+        impl CumSum for $type {
+            #[inline]
+            fn cumsum(values: &[Self], out: &mut [Self]) {
+                scan(values, out, Self::default(), $add)
+            }
+
+            #[inline]
+            fn checked_cumsum(values: &[Self], out: &mut [Self]) -> bool {
+                checked_scan(values, out, Self::default(), $checked_add)
+            }
+        }
+    )*};
+}
+
+cumsum_sequential!(
+    |a: Self, b: Self| a.wrapping_add(b),
+    |a: Self, b: Self| a.checked_add(b),
+    i8,
+    i16,
+    u8,
+    u16,
+    u32,
+    u64
+);
+cumsum_sequential!(
+    |a: Self, b: Self| a + b,
+    |a: Self, b: Self| Some(a + b),
+    f32
+);
+
+macro_rules! cumsum_avx2 {
+    ($type:ty, $avx2:ident, $add:expr) => {
+        impl CumSum for $type {
+            #[inline]
+            fn cumsum(values: &[Self], out: &mut [Self]) {
+                #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+                avx2::$avx2(values, out);
+                #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+                scan(values, out, Self::default(), $add);
+            }
+
+            #[inline]
+            fn checked_cumsum(values: &[Self], out: &mut [Self]) -> bool {
+                // no prefix sum overflows when the sum of the absolute values fits, in which
+                // case the (SIMD) wrapping sum is used
+                let bound = values.iter().map(|x| (*x as i128).abs()).sum::<i128>();
+                if bound <= Self::MAX as i128 {
+                    Self::cumsum(values, out);
+                    true
+                } else {
+                    checked_scan(values, out, Self::default(), |a: Self, b: Self| {
+                        a.checked_add(b)
+                    })
+                }
+            }
+        }
+    };
+}
+
+cumsum_avx2!(i32, cumsum_i32, |a: Self, b: Self| a.wrapping_add(b));
+cumsum_avx2!(i64, cumsum_i64, |a: Self, b: Self| a.wrapping_add(b));
+
+impl CumSum for f64 {
+    #[inline]
+    fn cumsum(values: &[Self], out: &mut [Self]) {
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+        avx2::cumsum_f64(values, out);
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+        scan(values, out, Self::default(), |a: Self, b: Self| a + b);
+    }
+
+    #[inline]
+    fn checked_cumsum(values: &[Self], out: &mut [Self]) -> bool {
+        // floats never overflow
+        Self::cumsum(values, out);
+        true
+    }
+}
+
+/// Prefix sums over 256-bit lanes using the Hillis-Steele scan: each lane is added to itself
+/// shifted by 1, 2, (4) elements, and the running total of previous lanes is then broadcast
+/// and added to it.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+#[allow(unused_unsafe)]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    use super::scan;
+
+    pub fn cumsum_i32(values: &[i32], out: &mut [i32]) {
+        let mut carry = 0i32;
+        let chunks = values.chunks_exact(8);
+        let remainder = chunks.remainder();
+        let mut out_chunks = out.chunks_exact_mut(8);
+        for (chunk, out) in chunks.zip(&mut out_chunks) {
+            // safety: `chunk` and `out` have 8 elements and avx2 is enabled
+            unsafe {
+                let zero = _mm256_setzero_si256();
+                let mut x = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+                let shifted =
+                    _mm256_permutevar8x32_epi32(x, _mm256_setr_epi32(0, 0, 1, 2, 3, 4, 5, 6));
+                x = _mm256_add_epi32(x, _mm256_blend_epi32(shifted, zero, 0b0000_0001));
+                let shifted =
+                    _mm256_permutevar8x32_epi32(x, _mm256_setr_epi32(0, 0, 0, 1, 2, 3, 4, 5));
+                x = _mm256_add_epi32(x, _mm256_blend_epi32(shifted, zero, 0b0000_0011));
+                let shifted =
+                    _mm256_permutevar8x32_epi32(x, _mm256_setr_epi32(0, 0, 0, 0, 0, 1, 2, 3));
+                x = _mm256_add_epi32(x, _mm256_blend_epi32(shifted, zero, 0b0000_1111));
+                x = _mm256_add_epi32(x, _mm256_set1_epi32(carry));
+                _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, x);
+            }
+            carry = out[7];
+        }
+        scan(
+            remainder,
+            out_chunks.into_remainder(),
+            carry,
+            i32::wrapping_add,
+        )
+    }
+
+    pub fn cumsum_i64(values: &[i64], out: &mut [i64]) {
+        let mut carry = 0i64;
+        let chunks = values.chunks_exact(4);
+        let remainder = chunks.remainder();
+        let mut out_chunks = out.chunks_exact_mut(4);
+        for (chunk, out) in chunks.zip(&mut out_chunks) {
+            // safety: `chunk` and `out` have 4 elements and avx2 is enabled
+            unsafe {
+                let zero = _mm256_setzero_si256();
+                let mut x = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+                let shifted = _mm256_permute4x64_epi64(x, 0b10_01_00_00);
+                x = _mm256_add_epi64(x, _mm256_blend_epi32(shifted, zero, 0b0000_0011));
+                let shifted = _mm256_permute4x64_epi64(x, 0b01_00_00_00);
+                x = _mm256_add_epi64(x, _mm256_blend_epi32(shifted, zero, 0b0000_1111));
+                x = _mm256_add_epi64(x, _mm256_set1_epi64x(carry));
+                _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, x);
+            }
+            carry = out[3];
+        }
+        scan(
+            remainder,
+            out_chunks.into_remainder(),
+            carry,
+            i64::wrapping_add,
+        )
+    }
+
+    pub fn cumsum_f64(values: &[f64], out: &mut [f64]) {
+        let mut carry = 0f64;
+        let chunks = values.chunks_exact(4);
+        let remainder = chunks.remainder();
+        let mut out_chunks = out.chunks_exact_mut(4);
+        for (chunk, out) in chunks.zip(&mut out_chunks) {
+            // safety: `chunk` and `out` have 4 elements and avx2 is enabled
+            unsafe {
+                let zero = _mm256_setzero_pd();
+                let mut x = _mm256_loadu_pd(chunk.as_ptr());
+                let shifted = _mm256_permute4x64_pd(x, 0b10_01_00_00);
+                x = _mm256_add_pd(x, _mm256_blend_pd(shifted, zero, 0b0001));
+                let shifted = _mm256_permute4x64_pd(x, 0b01_00_00_00);
+                x = _mm256_add_pd(x, _mm256_blend_pd(shifted, zero, 0b0011));
+                x = _mm256_add_pd(x, _mm256_set1_pd(carry));
+                _mm256_storeu_pd(out.as_mut_ptr(), x);
+            }
+            carry = out[3];
+        }
+        scan(remainder, out_chunks.into_remainder(), carry, |a, b| a + b)
+    }
+}
+
+/// Returns the cumulative sum of `array`, i.e. the sum of all non-null values up to and
+/// including each slot. Null slots remain null and do not contribute to the sum.
+///
+/// Integer sums wrap around on overflow, see [`try_cumsum`] to error instead. `i32`, `i64` and `f64` are computed with AVX2
+/// when the crate is compiled with `target_feature = "avx2"`, in which case the sums of
+/// `f64` may differ from a sequential sum by rounding.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::cumsum;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+/// let result = cumsum(&array);
+/// assert_eq!(result, Int32Array::from(&[Some(1), None, Some(4), Some(8)]));
+/// ```
+pub fn cumsum<T: CumSum>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    let mut out = vec![T::default(); array.len()];
+    with_valid_values(array, |values| T::cumsum(values, &mut out));
+    PrimitiveArray::new(
+        array.data_type().clone(),
+        out.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Returns the cumulative sum of `array` like [`cumsum`], erroring instead of wrapping around
+/// when an integer sum overflows.
+///
+/// Arrays whose sum of absolute values fits in `T` use the same (AVX2) path as [`cumsum`].
+/// # Example
+/// ```
+/// use arrow2::array::Int8Array;
+/// use arrow2::compute::window::try_cumsum;
+///
+/// let array = Int8Array::from(&[Some(100), None, Some(20)]);
+/// let result = try_cumsum(&array).unwrap();
+/// assert_eq!(result, Int8Array::from(&[Some(100), None, Some(120)]));
+///
+/// let array = Int8Array::from_slice([100, 20, 10]);
+/// assert!(try_cumsum(&array).is_err());
+/// ```
+/// # Errors
+/// This function errors with [`Error::Overflow`] iff an integer sum overflows.
+pub fn try_cumsum<T: CumSum>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>> {
+    let mut out = vec![T::default(); array.len()];
+    if !with_valid_values(array, |values| T::checked_cumsum(values, &mut out)) {
+        return Err(Error::Overflow);
+    }
+    Ok(PrimitiveArray::new(
+        array.data_type().clone(),
+        out.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Calls `op` with the values of `array`, where the values of null slots are replaced by
+/// zero so that they do not contribute to sums.
+fn with_valid_values<T: NativeType, R, F: FnOnce(&[T]) -> R>(
+    array: &PrimitiveArray<T>,
+    op: F,
+) -> R {
+    match array.validity() {
+        Some(validity) if validity.unset_bits() > 0 => {
+            let values = array
+                .values()
+                .iter()
+                .zip(validity.iter())
+                .map(|(value, is_valid)| if is_valid { *value } else { T::default() })
+                .collect::<Vec<_>>();
+            op(&values)
+        }
+        _ => op(array.values()),
     }
 }
@@ -99,6 +99,12 @@ pub enum DataType {
     Utf8,
     /// A variable-length UTF-8 encoded string whose offsets are represented as [`i64`].
     LargeUtf8,
+    /// Opaque binary data of variable length stored as 16-byte views, which hold values of up
+    /// to 12 bytes inline and reference longer values in shared data buffers.
+    BinaryView,
+    /// A variable-length UTF-8 encoded string stored as 16-byte views, which hold strings of up
+    /// to 12 bytes inline and reference longer strings in shared data buffers.
+    Utf8View,
     /// A list of some logical data type whose offsets are represented as [`i32`].
     List(Box<Field>),
     /// A list of some logical data type with a fixed number of elements.
@@ -136,6 +142,11 @@ pub enum DataType {
     /// for Map can make Map an alias for List. The "layout" attribute for the Map
     /// field must have the same contents as a List.
     Map(Box<Field>, bool),
+    /// A run-end encoded array (`run_ends`, `values`), where the `i`-th run of equal slots has
+    /// the value `values[i]` and ends at the logical slot `run_ends[i]` (exclusive).
+    ///
+    /// The `run_ends` field must be a non-nullable [`DataType::Int32`].
+    RunEndEncoded(Box<Field>, Box<Field>),
     /// A dictionary encoded array (`key_type`, `value_type`), where
     /// each array element is an index of `key_type` into an
     /// associated dictionary of `value_type`.
@@ -252,12 +263,15 @@ impl DataType {
             LargeBinary => PhysicalType::LargeBinary,
             Utf8 => PhysicalType::Utf8,
             LargeUtf8 => PhysicalType::LargeUtf8,
+            BinaryView => PhysicalType::BinaryView,
+            Utf8View => PhysicalType::Utf8View,
             List(_) => PhysicalType::List,
             FixedSizeList(_, _) => PhysicalType::FixedSizeList,
             LargeList(_) => PhysicalType::LargeList,
             Struct(_) => PhysicalType::Struct,
             Union(_, _, _) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
+            RunEndEncoded(_, _) => PhysicalType::RunEndEncoded,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
             Extension(_, key, _) => key.to_physical_type(),
         }
@@ -25,6 +25,10 @@ pub enum PhysicalType {
     Utf8,
     /// A variable-length string in Unicode with UFT-8 encoding and 64-bit offsets.
     LargeUtf8,
+    /// Opaque binary data of variable length stored as views.
+    BinaryView,
+    /// A variable-length string in Unicode with UTF-8 encoding stored as views.
+    Utf8View,
     /// A list of some data type with variable length.
     List,
     /// A list of some data type with fixed length.
@@ -37,6 +41,8 @@ pub enum PhysicalType {
     Union,
     /// A nested type.
     Map,
+    /// Runs of equal values of some data type.
+    RunEndEncoded,
     /// A dictionary encoded array by `IntegerType`.
     Dictionary(IntegerType),
 }
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use crate::{
     array::*,
     bitmap::{utils::bytes_for, Bitmap},
-    buffer::{Buffer, Bytes},
+    buffer::{Buffer, Bytes, BytesAllocator},
     datatypes::{DataType, PhysicalType},
     error::{Error, Result},
     ffi::schema::get_child,
@@ -27,6 +27,11 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         }),
         Utf8 => Box::new(Utf8Array::<i32>::try_from_ffi(array)?),
         LargeUtf8 => Box::new(Utf8Array::<i64>::try_from_ffi(array)?),
+        BinaryView | Utf8View => {
+            return Err(Error::NotYetImplemented(
+                "Importing view arrays from the C data interface".to_string(),
+            ))
+        }
         Binary => Box::new(BinaryArray::<i32>::try_from_ffi(array)?),
         LargeBinary => Box::new(BinaryArray::<i64>::try_from_ffi(array)?),
         FixedSizeBinary => Box::new(FixedSizeBinaryArray::try_from_ffi(array)?),
@@ -41,6 +46,12 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         }
         Union => Box::new(UnionArray::try_from_ffi(array)?),
         Map => Box::new(MapArray::try_from_ffi(array)?),
+        RunEndEncoded => {
+            let primitive = try_values_primitive_type(array.data_type())?;
+            with_match_primitive_type!(primitive, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::try_from_ffi(array)?)
+            })
+        }
     })
 }
 
@@ -86,6 +97,25 @@ struct PrivateData {
     buffers_ptr: Box<[*const std::os::raw::c_void]>,
     children_ptr: Box<[*mut ArrowArray]>,
     dictionary_ptr: Option<*mut ArrowArray>,
+    variadic_buffer_sizes: Option<Box<[i64]>>,
+}
+
+/// Returns the sizes of the data buffers of `array` iff it is a view array.
+fn variadic_buffer_sizes(array: &dyn Array) -> Option<Box<[i64]>> {
+    let buffers = match array.data_type().to_physical_type() {
+        PhysicalType::BinaryView => array
+            .as_any()
+            .downcast_ref::<BinaryViewArray>()
+            .unwrap()
+            .data_buffers(),
+        PhysicalType::Utf8View => array
+            .as_any()
+            .downcast_ref::<StringViewArray>()
+            .unwrap()
+            .data_buffers(),
+        _ => return None,
+    };
+    Some(buffers.iter().map(|buffer| buffer.len() as i64).collect())
 }
 
 impl ArrowArray {
@@ -94,9 +124,15 @@ impl ArrowArray {
     /// This method releases `buffers`. Consumers of this struct *must* call `release` before
     /// releasing this struct, or contents in `buffers` leak.
     pub(crate) fn new(array: Box<dyn Array>) -> Self {
-        let (offset, buffers, children, dictionary) =
+        let (offset, mut buffers, children, dictionary) =
             offset_buffers_children_dictionary(array.as_ref());
 
+        // view arrays export the sizes of their data buffers as their last buffer
+        let variadic_buffer_sizes = variadic_buffer_sizes(array.as_ref());
+        if let Some(sizes) = variadic_buffer_sizes.as_ref() {
+            buffers.push(Some(sizes.as_ptr().cast::<u8>()));
+        }
+
         let buffers_ptr = buffers
             .iter()
             .map(|maybe_buffer| match maybe_buffer {
@@ -123,6 +159,7 @@ impl ArrowArray {
             buffers_ptr,
             children_ptr,
             dictionary_ptr,
+            variadic_buffer_sizes,
         });
 
         Self {
@@ -237,7 +274,7 @@ unsafe fn create_buffer<T: NativeType>(
 
     let len = buffer_len(array, data_type, index)?;
     let offset = buffer_offset(array, data_type, index);
-    let bytes = Bytes::from_foreign(ptr, len, owner);
+    let bytes = Bytes::from_foreign(ptr, len, BytesAllocator::InternalArrowArray(owner));
 
     Ok(Buffer::from_bytes(bytes).slice(offset, len - offset))
 }
@@ -258,7 +295,7 @@ unsafe fn create_bitmap(
     let len: usize = array.length.try_into().expect("length to fit in `usize`");
     let offset: usize = array.offset.try_into().expect("Offset to fit in `usize`");
     let bytes_len = bytes_for(offset + len);
-    let bytes = Bytes::from_foreign(ptr, bytes_len, owner);
+    let bytes = Bytes::from_foreign(ptr, bytes_len, BytesAllocator::InternalArrowArray(owner));
 
     Ok(Bitmap::from_bytes(bytes, offset + len).slice(offset, len))
 }
@@ -24,12 +24,16 @@ pub fn align_to_c_data_interface(array: Box<dyn Array>) -> Box<dyn Array> {
         FixedSizeBinary => ffi_dyn!(array, FixedSizeBinaryArray),
         Utf8 => ffi_dyn!(array, Utf8Array::<i32>),
         LargeUtf8 => ffi_dyn!(array, Utf8Array::<i64>),
+        BinaryView => ffi_dyn!(array, BinaryViewArray),
+        Utf8View => ffi_dyn!(array, StringViewArray),
         List => ffi_dyn!(array, ListArray::<i32>),
         LargeList => ffi_dyn!(array, ListArray::<i64>),
         FixedSizeList => ffi_dyn!(array, FixedSizeListArray),
         Struct => ffi_dyn!(array, StructArray),
         Union => ffi_dyn!(array, UnionArray),
         Map => ffi_dyn!(array, MapArray),
+        // run-end encoded arrays are always exported with their offset
+        RunEndEncoded => array,
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 ffi_dyn!(array, DictionaryArray<$T>)
@@ -1,6 +1,7 @@
 use std::{collections::BTreeMap, convert::TryInto, ffi::CStr, ffi::CString, ptr};
 
 use crate::{
+    array::try_values_primitive_type,
     datatypes::{
         DataType, Extension, Field, IntegerType, IntervalUnit, Metadata, TimeUnit, UnionMode,
     },
@@ -51,6 +52,10 @@ fn schema_children(data_type: &DataType, flags: &mut i64) -> Box<[*mut ArrowSche
             .iter()
             .map(|field| Box::into_raw(Box::new(ArrowSchema::new(field))))
             .collect::<Box<[_]>>(),
+        DataType::RunEndEncoded(run_ends, values) => Box::new([
+            Box::into_raw(Box::new(ArrowSchema::new(run_ends.as_ref()))),
+            Box::into_raw(Box::new(ArrowSchema::new(values.as_ref()))),
+        ]),
         DataType::Extension(_, inner, _) => schema_children(inner, flags),
         _ => Box::new([]),
     }
@@ -246,6 +251,8 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
         "Z" => DataType::LargeBinary,
         "u" => DataType::Utf8,
         "U" => DataType::LargeUtf8,
+        "vz" => DataType::BinaryView,
+        "vu" => DataType::Utf8View,
         "tdD" => DataType::Date32,
         "tdm" => DataType::Date64,
         "tts" => DataType::Time32(TimeUnit::Second),
@@ -272,6 +279,14 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
             let is_sorted = (schema.flags & 4) != 0;
             DataType::Map(Box::new(to_field(child)?), is_sorted)
         }
+        "+r" => {
+            let run_ends = to_field(schema.child(0))?;
+            let values = to_field(schema.child(1))?;
+            let data_type = DataType::RunEndEncoded(Box::new(run_ends), Box::new(values));
+            // only run-end encoded arrays of primitive values are supported
+            try_values_primitive_type(&data_type)?;
+            data_type
+        }
         "+s" => {
             let children = (0..schema.n_children as usize)
                 .map(|x| to_field(schema.child(x)))
@@ -403,6 +418,8 @@ fn to_format(data_type: &DataType) -> String {
         DataType::LargeBinary => "Z".to_string(),
         DataType::Utf8 => "u".to_string(),
         DataType::LargeUtf8 => "U".to_string(),
+        DataType::BinaryView => "vz".to_string(),
+        DataType::Utf8View => "vu".to_string(),
         DataType::Date32 => "tdD".to_string(),
         DataType::Date64 => "tdm".to_string(),
         DataType::Time32(TimeUnit::Second) => "tts".to_string(),
@@ -458,6 +475,7 @@ fn to_format(data_type: &DataType) -> String {
             r
         }
         DataType::Map(_, _) => "+m".to_string(),
+        DataType::RunEndEncoded(_, _) => "+r".to_string(),
         DataType::Dictionary(index, _, _) => to_format(&(*index).into()),
         DataType::Extension(_, inner, _) => to_format(inner.as_ref()),
     }
@@ -469,6 +487,8 @@ pub(super) fn get_child(data_type: &DataType, index: usize) -> Result<DataType>
         (0, DataType::FixedSizeList(field, _)) => Ok(field.data_type().clone()),
         (0, DataType::LargeList(field)) => Ok(field.data_type().clone()),
         (0, DataType::Map(field, _)) => Ok(field.data_type().clone()),
+        (0, DataType::RunEndEncoded(run_ends, _)) => Ok(run_ends.data_type().clone()),
+        (1, DataType::RunEndEncoded(_, values)) => Ok(values.data_type().clone()),
         (index, DataType::Struct(fields)) => Ok(fields[index].data_type().clone()),
         (index, DataType::Union(fields, _, _)) => Ok(fields[index].data_type().clone()),
         (index, DataType::Extension(_, subtype, _)) => get_child(subtype, index),
@@ -566,6 +586,8 @@ mod tests {
             DataType::LargeUtf8,
             DataType::Binary,
             DataType::LargeBinary,
+            DataType::BinaryView,
+            DataType::Utf8View,
             DataType::FixedSizeBinary(2),
             DataType::List(Box::new(Field::new("example", DataType::Boolean, false))),
             DataType::FixedSizeList(Box::new(Field::new("example", DataType::Boolean, false)), 2),
@@ -579,6 +601,10 @@ mod tests {
                 ),
             ]),
             DataType::Map(Box::new(Field::new("a", DataType::Int64, true)), true),
+            DataType::RunEndEncoded(
+                Box::new(Field::new("run_ends", DataType::Int32, false)),
+                Box::new(Field::new("values", DataType::Float64, true)),
+            ),
             DataType::Union(
                 vec![
                     Field::new("a", DataType::Int64, true),
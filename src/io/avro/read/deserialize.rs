@@ -51,6 +51,14 @@ fn make_mutable(
                     capacity,
                 )) as Box<dyn MutableArray>
             }
+            DataType::Map(inner, _) => {
+                let values = make_mutable(inner.data_type(), None, 0)?;
+                Box::new(DynMutableMapArray::new_from(
+                    values,
+                    data_type.clone(),
+                    capacity,
+                )) as Box<dyn MutableArray>
+            }
             DataType::FixedSizeBinary(size) => {
                 Box::new(MutableFixedSizeBinaryArray::with_capacity(*size, capacity))
                     as Box<dyn MutableArray>
@@ -81,6 +89,27 @@ fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     }
 }
 
+/// Returns the value field of a map's `entries` field
+fn map_value_field(entries: &Field) -> &Field {
+    if let DataType::Struct(fields) = entries.data_type() {
+        &fields[1]
+    } else {
+        unreachable!()
+    }
+}
+
+/// Returns the schema of the values of an avro map, possibly wrapped in a nullable union
+fn map_value_schema(avro_field: &AvroSchema) -> &AvroSchema {
+    match avro_field {
+        AvroSchema::Map(inner) => inner.as_ref(),
+        AvroSchema::Union(u) => match &u.as_slice() {
+            &[AvroSchema::Map(inner), _] | &[_, AvroSchema::Map(inner)] => inner.as_ref(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 fn deserialize_item<'a>(
     array: &mut dyn MutableArray,
     is_nullable: bool,
@@ -149,6 +178,47 @@ fn deserialize_value<'a>(
             }
             array.try_push_valid()?;
         }
+        DataType::Map(inner, _) => {
+            let is_nullable = map_value_field(inner).is_nullable;
+            let avro_inner = map_value_schema(avro_field);
+
+            let array = array
+                .as_mut_any()
+                .downcast_mut::<DynMutableMapArray>()
+                .unwrap();
+            // Maps are encoded as a series of blocks, like arrays.
+            loop {
+                let len = util::zigzag_i64(&mut block)?;
+                let len = if len < 0 {
+                    // the count is followed by the block size in bytes, which we do not need
+                    let _ = util::zigzag_i64(&mut block)?;
+
+                    -len
+                } else {
+                    len
+                };
+
+                // A block with count zero indicates the end of the map.
+                if len == 0 {
+                    break;
+                }
+
+                // Each item is a string key followed by a value encoded per the map's value schema.
+                let entries = array
+                    .mut_values()
+                    .as_mut_any()
+                    .downcast_mut::<DynMutableStructArray>()
+                    .unwrap();
+                for _ in 0..len {
+                    block =
+                        deserialize_value(entries.mut_values(0), &AvroSchema::String(None), block)?;
+                    block =
+                        deserialize_item(entries.mut_values(1), is_nullable, avro_inner, block)?;
+                    entries.try_push_valid()?;
+                }
+            }
+            array.try_push_valid()?;
+        }
         DataType::Struct(inner_fields) => {
             let fields = match avro_field {
                 AvroSchema::Record(Record { fields, .. }) => fields,
@@ -386,6 +456,48 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                 }
             }
         }
+        DataType::Map(inner, _) => {
+            let value_field = map_value_field(inner);
+            let avro_inner = map_value_schema(avro_field);
+
+            loop {
+                let len = util::zigzag_i64(&mut block)?;
+                let (len, bytes) = if len < 0 {
+                    let bytes = util::zigzag_i64(&mut block)?;
+
+                    (-len, Some(bytes))
+                } else {
+                    (len, None)
+                };
+
+                let bytes: Option<usize> = bytes
+                    .map(|bytes| {
+                        bytes
+                            .try_into()
+                            .map_err(|_| Error::oos("Avro block size negative or too large"))
+                    })
+                    .transpose()?;
+
+                if len == 0 {
+                    break;
+                }
+
+                if let Some(bytes) = bytes {
+                    block = &block[bytes..];
+                } else {
+                    for _ in 0..len {
+                        let len: usize =
+                            util::zigzag_i64(&mut block)?.try_into().map_err(|_| {
+                                Error::ExternalFormat(
+                                    "Avro format contains a non-usize number of bytes".to_string(),
+                                )
+                            })?;
+                        block = &block[len..];
+                        block = skip_item(value_field, avro_inner, block)?;
+                    }
+                }
+            }
+        }
         DataType::Struct(inner_fields) => {
             let fields = match avro_field {
                 AvroSchema::Record(Record { fields, .. }) => fields,
@@ -117,6 +117,126 @@ impl<O: Offset> MutableArray for DynMutableListArray<O> {
     }
 }
 
+/// Auxiliary struct
+#[derive(Debug)]
+pub struct DynMutableMapArray {
+    data_type: DataType,
+    offsets: Offsets<i32>,
+    values: Box<dyn MutableArray>,
+    validity: Option<MutableBitmap>,
+}
+
+impl DynMutableMapArray {
+    pub fn new_from(values: Box<dyn MutableArray>, data_type: DataType, capacity: usize) -> Self {
+        assert_eq!(values.len(), 0);
+        MapArray::get_field(&data_type);
+        Self {
+            data_type,
+            offsets: Offsets::<i32>::with_capacity(capacity),
+            values,
+            validity: None,
+        }
+    }
+
+    /// The entries (a struct of keys and values)
+    pub fn mut_values(&mut self) -> &mut dyn MutableArray {
+        self.values.as_mut()
+    }
+
+    #[inline]
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let total_length = self.values.len();
+        let offset = *self.offsets.last() as usize;
+        let length = total_length.checked_sub(offset).ok_or(Error::Overflow)?;
+
+        self.offsets.try_push_usize(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+}
+
+impl MutableArray for DynMutableMapArray {
+    fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        MapArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            self.values.as_box(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+        .boxed()
+    }
+
+    fn as_arc(&mut self) -> std::sync::Arc<dyn Array> {
+        MapArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            self.values.as_box(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+        .arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(validity) = &mut self.validity {
+            validity.reserve(additional)
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct FixedItemsUtf8Dictionary {
     data_type: DataType,
@@ -82,7 +82,19 @@ fn schema_to_field(schema: &AvroSchema, name: Option<&str>, props: Metadata) ->
             Some("item"), // default name for list items
             Metadata::default(),
         )?)),
-        AvroSchema::Map(_) => todo!("Avro maps are mapped to MapArrays"),
+        AvroSchema::Map(value_schema) => {
+            // Avro map keys are always strings
+            let key = Field::new("key", DataType::Utf8, false);
+            let value = schema_to_field(value_schema, Some("value"), Metadata::default())?;
+            DataType::Map(
+                Box::new(Field::new(
+                    "entries",
+                    DataType::Struct(vec![key, value]),
+                    false,
+                )),
+                false,
+            )
+        }
         AvroSchema::Union(schemas) => {
             // If there are only two variants and one of them is null, set the other type as the field data type
             let has_nullable = schemas.iter().any(|x| x == &AvroSchema::Null);
@@ -63,6 +63,23 @@ fn _type_to_schema(data_type: &DataType, name_counter: &mut i32) -> Result<AvroS
         DataType::LargeList(inner) | DataType::List(inner) => AvroSchema::Array(Box::new(
             type_to_schema(&inner.data_type, inner.is_nullable, name_counter)?,
         )),
+        DataType::Map(inner, _) => match inner.data_type() {
+            DataType::Struct(fields)
+                if fields.len() == 2
+                    && matches!(fields[0].data_type(), DataType::Utf8 | DataType::LargeUtf8) =>
+            {
+                AvroSchema::Map(Box::new(type_to_schema(
+                    &fields[1].data_type,
+                    fields[1].is_nullable,
+                    name_counter,
+                )?))
+            }
+            _ => {
+                return Err(Error::NotYetImplemented(
+                    "write a map with non-utf8 keys to avro".to_string(),
+                ))
+            }
+        },
         DataType::Struct(fields) => AvroSchema::Record(Record::new(
             _get_field_name(name_counter),
             fields
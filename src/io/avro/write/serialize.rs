@@ -108,6 +108,10 @@ fn list_required<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) ->
         lengths,
         move |length, buf| {
             encode::zigzag_encode(length, buf).unwrap();
+            if length == 0 {
+                // an empty array is encoded as a single zero-count block
+                return;
+            }
             let mut rows = 0;
             while let Some(item) = inner.next() {
                 buf.extend_from_slice(item);
@@ -137,6 +141,10 @@ fn list_optional<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) ->
             if let Some(length) = length {
                 buf.push(IS_VALID);
                 encode::zigzag_encode(length, buf).unwrap();
+                if length == 0 {
+                    // an empty array is encoded as a single zero-count block
+                    return;
+                }
                 let mut rows = 0;
                 while let Some(item) = inner.next() {
                     buf.extend_from_slice(item);
@@ -154,6 +162,87 @@ fn list_optional<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) ->
     ))
 }
 
+fn map_required<'a>(array: &'a MapArray, schema: &AvroSchema) -> BoxSerializer<'a> {
+    let mut inner = map_entries_serializer(array, schema);
+    let lengths = array
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|w| (w[1] - w[0]) as i64);
+
+    Box::new(BufStreamingIterator::new(
+        lengths,
+        move |length, buf| {
+            encode::zigzag_encode(length, buf).unwrap();
+            if length > 0 {
+                for _ in 0..length {
+                    buf.extend_from_slice(inner.next().unwrap());
+                }
+                encode::zigzag_encode(0, buf).unwrap();
+            }
+        },
+        vec![],
+    ))
+}
+
+fn map_optional<'a>(array: &'a MapArray, schema: &AvroSchema) -> BoxSerializer<'a> {
+    let mut inner = map_entries_serializer(array, schema);
+    let lengths = array
+        .offsets()
+        .buffer()
+        .windows(2)
+        .enumerate()
+        .map(move |(i, w)| ((w[1] - w[0]) as i64, array.is_valid(i)));
+
+    Box::new(BufStreamingIterator::new(
+        lengths,
+        move |(length, is_valid), buf| {
+            if is_valid {
+                buf.push(IS_VALID);
+                encode::zigzag_encode(length, buf).unwrap();
+                if length > 0 {
+                    for _ in 0..length {
+                        buf.extend_from_slice(inner.next().unwrap());
+                    }
+                    encode::zigzag_encode(0, buf).unwrap();
+                }
+            } else {
+                buf.push(IS_NULL);
+                // skip the entries of the null slot
+                for _ in 0..length {
+                    let _ = inner.next().unwrap();
+                }
+            }
+        },
+        vec![],
+    ))
+}
+
+/// Returns a serializer of the entries of `array`, each encoded as its key followed by its value
+fn map_entries_serializer<'a>(array: &'a MapArray, schema: &AvroSchema) -> BoxSerializer<'a> {
+    let entries = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    let keys = entries.values()[0].as_ref();
+    let mut keys = match keys.data_type().to_physical_type() {
+        PhysicalType::Utf8 => utf8_required::<i32>(keys.as_any().downcast_ref().unwrap()),
+        PhysicalType::LargeUtf8 => utf8_required::<i64>(keys.as_any().downcast_ref().unwrap()),
+        _ => unreachable!(),
+    };
+    let mut values = new_serializer(entries.values()[1].as_ref(), schema);
+
+    Box::new(BufStreamingIterator::new(
+        0..entries.len(),
+        move |_, buf| {
+            buf.extend_from_slice(keys.next().unwrap());
+            buf.extend_from_slice(values.next().unwrap());
+        },
+        vec![],
+    ))
+}
+
 fn struct_required<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer<'a> {
     let schemas = schema.fields.iter().map(|x| &x.schema);
     let mut inner = array
@@ -483,6 +572,17 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
             };
             list_optional::<i64>(array.as_any().downcast_ref().unwrap(), schema)
         }
+        (PhysicalType::Map, AvroSchema::Map(schema)) => {
+            map_required(array.as_any().downcast_ref().unwrap(), schema.as_ref())
+        }
+        (PhysicalType::Map, AvroSchema::Union(inner)) => {
+            let schema = if let AvroSchema::Map(schema) = &inner[1] {
+                schema.as_ref()
+            } else {
+                unreachable!("The schema declaration does not match the deserialization")
+            };
+            map_optional(array.as_any().downcast_ref().unwrap(), schema)
+        }
         (PhysicalType::Struct, AvroSchema::Record(inner)) => {
             struct_required(array.as_any().downcast_ref().unwrap(), inner)
         }
@@ -505,6 +605,15 @@ pub fn can_serialize(data_type: &DataType) -> bool {
         List(inner) => return can_serialize(&inner.data_type),
         LargeList(inner) => return can_serialize(&inner.data_type),
         Struct(inner) => return inner.iter().all(|inner| can_serialize(&inner.data_type)),
+        Map(inner, _) => {
+            return match inner.data_type() {
+                Struct(fields) if fields.len() == 2 => {
+                    matches!(fields[0].data_type(), Utf8 | LargeUtf8)
+                        && can_serialize(&fields[1].data_type)
+                }
+                _ => false,
+            }
+        }
         _ => {}
     };
 
@@ -2,7 +2,7 @@ use lexical_core::ToLexical;
 
 use crate::datatypes::IntegerType;
 use crate::temporal_conversions;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::util::lexical_to_bytes_mut;
 use crate::{
     array::{
@@ -409,6 +409,21 @@ pub fn new_serializer<'a>(
                 options.timestamp_format.as_ref().map(|x| x.as_ref()),
             )
         }
+        DataType::Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            Box::new(BufStreamingIterator::new(
+                array.iter(),
+                |x, buf| {
+                    if let Some(x) = x {
+                        lexical_to_bytes_mut(x.to_f32(), buf)
+                    }
+                },
+                vec![],
+            ))
+        }
         DataType::Float32 => {
             dyn_primitive!(f32, array)
         }
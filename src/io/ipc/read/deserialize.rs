@@ -6,7 +6,7 @@ use arrow_format::ipc::MetadataVersion;
 
 use crate::array::*;
 use crate::datatypes::{DataType, Field, PhysicalType};
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::io::ipc::IpcField;
 
 use super::{array::*, Dictionaries};
@@ -226,6 +226,12 @@ pub fn read<R: Read + Seek>(
             scratch,
         )
         .map(|x| x.boxed()),
+        RunEndEncoded => Err(Error::NotYetImplemented(
+            "Reading RunEndEncoded arrays from IPC".to_string(),
+        )),
+        BinaryView | Utf8View => Err(Error::NotYetImplemented(
+            "Reading view arrays from IPC".to_string(),
+        )),
     }
 }
 
@@ -249,5 +255,11 @@ pub fn skip(
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
         Union => skip_union(field_nodes, data_type, buffers),
         Map => skip_map(field_nodes, data_type, buffers),
+        RunEndEncoded => Err(Error::NotYetImplemented(
+            "Skipping RunEndEncoded arrays from IPC".to_string(),
+        )),
+        BinaryView | Utf8View => Err(Error::NotYetImplemented(
+            "Skipping view arrays from IPC".to_string(),
+        )),
     }
 }
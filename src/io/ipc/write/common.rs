@@ -10,7 +10,7 @@ use crate::io::ipc::endianess::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
 
 use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::{try_write, write_dictionary};
 
 /// Compression codec
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -61,7 +61,7 @@ fn encode_dictionary(
                     array,
                     options,
                     is_native_little_endian(),
-                ));
+                )?);
             };
             Ok(())
         }),
@@ -168,6 +168,9 @@ fn encode_dictionary(
                 encoded_dictionaries,
             )
         }
+        // the values of run-end encoded arrays are primitive
+        RunEndEncoded => Ok(()),
+        BinaryView | Utf8View => Ok(()),
     }
 }
 
@@ -189,7 +192,7 @@ pub fn encode_chunk(
         )?;
     }
 
-    let encoded_message = chunk_to_bytes(chunk, options);
+    let encoded_message = chunk_to_bytes(chunk, options)?;
 
     Ok((encoded_dictionaries, encoded_message))
 }
@@ -213,13 +216,13 @@ fn serialize_compression(
 
 /// Write [`Chunk`] into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the batch's data
-fn chunk_to_bytes(chunk: &Chunk<Box<dyn Array>>, options: &WriteOptions) -> EncodedData {
+fn chunk_to_bytes(chunk: &Chunk<Box<dyn Array>>, options: &WriteOptions) -> Result<EncodedData> {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
     let mut offset = 0;
     for array in chunk.arrays() {
-        write(
+        try_write(
             array.as_ref(),
             &mut buffers,
             &mut arrow_data,
@@ -227,7 +230,7 @@ fn chunk_to_bytes(chunk: &Chunk<Box<dyn Array>>, options: &WriteOptions) -> Enco
             &mut offset,
             is_native_little_endian(),
             options.compression,
-        )
+        )?;
     }
 
     let compression = serialize_compression(options.compression);
@@ -249,10 +252,10 @@ fn chunk_to_bytes(chunk: &Chunk<Box<dyn Array>>, options: &WriteOptions) -> Enco
     let mut builder = Builder::new();
     let ipc_message = builder.finish(&message, None);
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: ipc_message.to_vec(),
         arrow_data,
-    }
+    })
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
@@ -262,7 +265,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     options: &WriteOptions,
     is_little_endian: bool,
-) -> EncodedData {
+) -> Result<EncodedData> {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
@@ -276,7 +279,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
         is_little_endian,
         options.compression,
         false,
-    );
+    )?;
 
     let compression = serialize_compression(options.compression);
 
@@ -301,10 +304,10 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut builder = Builder::new();
     let ipc_message = builder.finish(&message, None);
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: ipc_message.to_vec(),
         arrow_data,
-    }
+    })
 }
 
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
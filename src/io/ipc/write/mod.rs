@@ -6,9 +6,9 @@ mod stream;
 pub(crate) mod writer;
 
 pub use common::{Compression, Record, WriteOptions};
-pub use schema::schema_to_bytes;
-pub use serialize::write;
+pub use schema::{schema_to_bytes, try_schema_to_bytes};
 pub(self) use serialize::write_dictionary;
+pub use serialize::{try_write, write};
 pub use stream::StreamWriter;
 pub use writer::FileWriter;
 
@@ -37,6 +37,13 @@ fn default_ipc_field(data_type: &DataType, current_id: &mut i64) -> IpcField {
             dictionary_id: None,
         },
         // multiple children => recurse
+        RunEndEncoded(run_ends, values) => IpcField {
+            fields: vec![
+                default_ipc_field(run_ends.data_type(), current_id),
+                default_ipc_field(values.data_type(), current_id),
+            ],
+            dictionary_id: None,
+        },
         Union(fields, ..) | Struct(fields) => IpcField {
             fields: fields
                 .iter()
@@ -5,6 +5,7 @@ use crate::{
     array::*,
     bitmap::Bitmap,
     datatypes::PhysicalType,
+    error::{Error, Result},
     offset::{Offset, OffsetsBuffer},
     trusted_len::TrustedLen,
     types::NativeType,
@@ -186,7 +187,7 @@ fn write_list<O: Offset>(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
 
@@ -221,7 +222,7 @@ fn write_list<O: Offset>(
         );
     }
 
-    write(
+    try_write(
         array
             .values()
             .slice(first.to_usize(), last.to_usize() - first.to_usize())
@@ -232,7 +233,7 @@ fn write_list<O: Offset>(
         offset,
         is_little_endian,
         compression,
-    );
+    )
 }
 
 pub fn write_struct(
@@ -243,7 +244,7 @@ pub fn write_struct(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     write_bitmap(
         array.validity(),
         array.len(),
@@ -252,8 +253,8 @@ pub fn write_struct(
         offset,
         compression,
     );
-    array.values().iter().for_each(|array| {
-        write(
+    array.values().iter().try_for_each(|array| {
+        try_write(
             array.as_ref(),
             buffers,
             arrow_data,
@@ -261,8 +262,8 @@ pub fn write_struct(
             offset,
             is_little_endian,
             compression,
-        );
-    });
+        )
+    })
 }
 
 pub fn write_union(
@@ -273,7 +274,7 @@ pub fn write_union(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     write_buffer(
         array.types(),
         buffers,
@@ -293,8 +294,8 @@ pub fn write_union(
             compression,
         );
     }
-    array.fields().iter().for_each(|array| {
-        write(
+    array.fields().iter().try_for_each(|array| {
+        try_write(
             array.as_ref(),
             buffers,
             arrow_data,
@@ -303,7 +304,7 @@ pub fn write_union(
             is_little_endian,
             compression,
         )
-    });
+    })
 }
 
 fn write_map(
@@ -314,7 +315,7 @@ fn write_map(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
 
@@ -349,7 +350,7 @@ fn write_map(
         );
     }
 
-    write(
+    try_write(
         array
             .field()
             .slice(first as usize, last as usize - first as usize)
@@ -360,7 +361,7 @@ fn write_map(
         offset,
         is_little_endian,
         compression,
-    );
+    )
 }
 
 fn write_fixed_size_list(
@@ -371,7 +372,7 @@ fn write_fixed_size_list(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     write_bitmap(
         array.validity(),
         array.len(),
@@ -380,7 +381,7 @@ fn write_fixed_size_list(
         offset,
         compression,
     );
-    write(
+    try_write(
         array.values().as_ref(),
         buffers,
         arrow_data,
@@ -388,7 +389,7 @@ fn write_fixed_size_list(
         offset,
         is_little_endian,
         compression,
-    );
+    )
 }
 
 // use `write_keys` to either write keys or values
@@ -402,7 +403,7 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
     is_little_endian: bool,
     compression: Option<Compression>,
     write_keys: bool,
-) -> usize {
+) -> Result<usize> {
     if write_keys {
         write_primitive(
             array.keys(),
@@ -412,9 +413,9 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
             is_little_endian,
             compression,
         );
-        array.keys().len()
+        Ok(array.keys().len())
     } else {
-        write(
+        try_write(
             array.values().as_ref(),
             buffers,
             arrow_data,
@@ -422,12 +423,14 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
             offset,
             is_little_endian,
             compression,
-        );
-        array.values().len()
+        )?;
+        Ok(array.values().len())
     }
 }
 
 /// Writes an [`Array`] to `arrow_data`
+/// # Panics
+/// This function panics iff [`try_write`] errors.
 pub fn write(
     array: &dyn Array,
     buffers: &mut Vec<ipc::Buffer>,
@@ -437,6 +440,31 @@ pub fn write(
     is_little_endian: bool,
     compression: Option<Compression>,
 ) {
+    try_write(
+        array,
+        buffers,
+        arrow_data,
+        nodes,
+        offset,
+        is_little_endian,
+        compression,
+    )
+    .unwrap()
+}
+
+/// Writes an [`Array`] to `arrow_data`
+/// # Errors
+/// This function errors iff the array is (or contains) a run-end encoded or a view array, which
+/// can't be written to IPC yet.
+pub fn try_write(
+    array: &dyn Array,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<ipc::FieldNode>,
+    offset: &mut i64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+) -> Result<()> {
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
         null_count: array.null_count() as i64,
@@ -504,7 +532,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         LargeList => write_list::<i64>(
             array.as_any().downcast_ref().unwrap(),
             buffers,
@@ -513,7 +541,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         FixedSizeList => write_fixed_size_list(
             array.as_any().downcast_ref().unwrap(),
             buffers,
@@ -522,7 +550,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         Struct => write_struct(
             array.as_any().downcast_ref().unwrap(),
             buffers,
@@ -531,7 +559,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             write_dictionary::<$T>(
                 array.as_any().downcast_ref().unwrap(),
@@ -542,7 +570,7 @@ pub fn write(
                 is_little_endian,
                 compression,
                 true,
-            );
+            )?;
         }),
         Union => {
             write_union(
@@ -553,7 +581,7 @@ pub fn write(
                 offset,
                 is_little_endian,
                 compression,
-            );
+            )?;
         }
         Map => {
             write_map(
@@ -564,9 +592,20 @@ pub fn write(
                 offset,
                 is_little_endian,
                 compression,
-            );
+            )?;
+        }
+        RunEndEncoded => {
+            return Err(Error::NotYetImplemented(
+                "Writing RunEndEncoded arrays to IPC".to_string(),
+            ))
+        }
+        BinaryView | Utf8View => {
+            return Err(Error::NotYetImplemented(
+                "Writing view arrays to IPC".to_string(),
+            ))
         }
     }
+    Ok(())
 }
 
 #[inline]
@@ -8,7 +8,7 @@ use std::io::Write;
 use super::super::IpcField;
 use super::common::{encode_chunk, DictionaryTracker, EncodedData, WriteOptions};
 use super::common_sync::{write_continuation, write_message};
-use super::{default_ipc_fields, schema_to_bytes};
+use super::{default_ipc_fields, try_schema_to_bytes};
 
 use crate::array::Array;
 use crate::chunk::Chunk;
@@ -59,7 +59,7 @@ impl<W: Write> StreamWriter<W> {
         });
 
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(schema, self.ipc_fields.as_ref().unwrap()),
+            ipc_message: try_schema_to_bytes(schema, self.ipc_fields.as_ref().unwrap())?,
             arrow_data: vec![],
         };
         write_message(&mut self.writer, encoded_message)?;
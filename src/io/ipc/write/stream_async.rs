@@ -8,7 +8,7 @@ use super::super::IpcField;
 pub use super::common::WriteOptions;
 use super::common::{encode_chunk, DictionaryTracker, EncodedData};
 use super::common_async::{write_continuation, write_message};
-use super::{default_ipc_fields, schema_to_bytes, Record};
+use super::{default_ipc_fields, try_schema_to_bytes, Record};
 
 use crate::datatypes::*;
 use crate::error::{Error, Result};
@@ -85,12 +85,12 @@ where
         schema: &Schema,
         ipc_fields: &[IpcField],
     ) -> BoxFuture<'a, Result<Option<W>>> {
-        let message = EncodedData {
-            ipc_message: schema_to_bytes(schema, ipc_fields),
+        let message = try_schema_to_bytes(schema, ipc_fields).map(|ipc_message| EncodedData {
+            ipc_message,
             arrow_data: vec![],
-        };
+        });
         async move {
-            write_message(&mut writer, message).await?;
+            write_message(&mut writer, message?).await?;
             Ok(Some(writer))
         }
         .boxed()
@@ -62,6 +62,28 @@ where
     }
 }
 
+/// Serializes the next row of `iterators` (one per field of `schema`) into `buffer` as a JSON
+/// object.
+fn serialize_record(
+    schema: &Schema,
+    iterators: &mut [Box<dyn StreamingIterator<Item = [u8]> + Send + Sync + '_>],
+    buffer: &mut Vec<u8>,
+) -> Result<(), Error> {
+    let mut is_first_row = true;
+    write!(buffer, "{{")?;
+    for (f, ref mut it) in schema.fields.iter().zip(iterators.iter_mut()) {
+        if !is_first_row {
+            write!(buffer, ",")?;
+        }
+        write!(buffer, "\"{}\":", f.name)?;
+
+        buffer.extend_from_slice(it.next().unwrap());
+        is_first_row = false;
+    }
+    write!(buffer, "}}")?;
+    Ok(())
+}
+
 /// [`FallibleStreamingIterator`] that serializes a [`Chunk`] into bytes of JSON
 /// in a (pandas-compatible) record-oriented format.
 ///
@@ -109,18 +131,7 @@ impl<'a> FallibleStreamingIterator for RecordSerializer<'a> {
             return Ok(());
         }
 
-        let mut is_first_row = true;
-        write!(&mut self.buffer, "{{")?;
-        for (f, ref mut it) in self.schema.fields.iter().zip(self.iterators.iter_mut()) {
-            if !is_first_row {
-                write!(&mut self.buffer, ",")?;
-            }
-            write!(&mut self.buffer, "\"{}\":", f.name)?;
-
-            self.buffer.extend_from_slice(it.next().unwrap());
-            is_first_row = false;
-        }
-        write!(&mut self.buffer, "}}")?;
+        serialize_record(&self.schema, &mut self.iterators, &mut self.buffer)?;
 
         self.index += 1;
         Ok(())
@@ -153,3 +164,108 @@ where
     writer.write_all(&[b']'])?;
     Ok(())
 }
+
+/// How a [`Writer`] frames the records it writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Framing {
+    /// A JSON array of records, e.g. `[{"a":1},{"a":2}]`.
+    Array,
+    /// One record per line ([NDJSON](http://ndjson.org/)), e.g. `{"a":1}\n{"a":2}\n`.
+    Lines,
+}
+
+/// Writes [`Chunk`]s to an implementer of [`Write`] as JSON records (in the same format as
+/// [`RecordSerializer`]), one row at a time.
+///
+/// Only the serialized bytes of a single row are kept in memory; errors of the writer are
+/// returned as soon as they happen.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::json::write::{Framing, Writer};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+/// let chunk = Chunk::new(vec![Int32Array::from([Some(1), None]).boxed()]);
+///
+/// let mut writer = Writer::new(vec![], schema, Framing::Array);
+/// writer.write(&chunk)?;
+/// writer.write(&chunk)?;
+/// let buffer = writer.finish()?;
+/// assert_eq!(buffer, br#"[{"a":1},{"a":null},{"a":1},{"a":null}]"#);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: Schema,
+    framing: Framing,
+    buffer: Vec<u8>,
+    is_first_row: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`] of records of `schema` framed according to `framing`.
+    pub fn new(writer: W, schema: Schema, framing: Framing) -> Self {
+        Self {
+            writer,
+            schema,
+            framing,
+            buffer: vec![],
+            is_first_row: true,
+        }
+    }
+
+    /// Writes every row of `chunk` as a record.
+    /// # Errors
+    /// Errors iff the number of arrays of `chunk` differs from the number of fields of the
+    /// schema or the writer errors.
+    pub fn write<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<(), Error> {
+        if chunk.arrays().len() != self.schema.fields.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The chunk has {} arrays but the schema has {} fields",
+                chunk.arrays().len(),
+                self.schema.fields.len()
+            )));
+        }
+
+        let mut iterators = chunk
+            .arrays()
+            .iter()
+            .map(|array| new_serializer(array.as_ref()))
+            .collect::<Vec<_>>();
+        for _ in 0..chunk.len() {
+            self.buffer.clear();
+            match self.framing {
+                Framing::Array => self
+                    .buffer
+                    .push(if self.is_first_row { b'[' } else { b',' }),
+                Framing::Lines => {}
+            }
+            serialize_record(&self.schema, &mut iterators, &mut self.buffer)?;
+            if self.framing == Framing::Lines {
+                self.buffer.push(b'\n');
+            }
+            self.writer.write_all(&self.buffer)?;
+            self.is_first_row = false;
+        }
+        Ok(())
+    }
+
+    /// Finishes the framing (e.g. closes the JSON array), flushes the writer and returns it.
+    /// # Errors
+    /// Errors iff the writer errors.
+    pub fn finish(mut self) -> Result<W, Error> {
+        if self.framing == Framing::Array {
+            if self.is_first_row {
+                self.writer.write_all(b"[")?;
+            }
+            self.writer.write_all(b"]")?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
@@ -12,7 +12,11 @@ use crate::temporal_conversions::{
     timestamp_s_to_datetime, timestamp_us_to_datetime,
 };
 use crate::util::lexical_to_bytes_mut;
-use crate::{array::*, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*,
+    datatypes::DataType,
+    types::{f16, NativeType},
+};
 
 use super::utf8;
 
@@ -69,6 +73,19 @@ where
     ))
 }
 
+fn f16_serializer<'a>(
+    array: &'a PrimitiveArray<f16>,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        |x, buf| match x.map(|x| x.to_f32()) {
+            Some(x) if !x.is_nan() => lexical_to_bytes_mut(x, buf),
+            _ => buf.extend(b"null"),
+        },
+        vec![],
+    ))
+}
+
 fn utf8_serializer<'a, O: Offset>(
     array: &'a Utf8Array<O>,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
@@ -247,6 +264,7 @@ pub(crate) fn new_serializer<'a>(
         DataType::UInt16 => primitive_serializer::<u16>(array.as_any().downcast_ref().unwrap()),
         DataType::UInt32 => primitive_serializer::<u32>(array.as_any().downcast_ref().unwrap()),
         DataType::UInt64 => primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap()),
+        DataType::Float16 => f16_serializer(array.as_any().downcast_ref().unwrap()),
         DataType::Float32 => float_serializer::<f32>(array.as_any().downcast_ref().unwrap()),
         DataType::Float64 => float_serializer::<f64>(array.as_any().downcast_ref().unwrap()),
         DataType::Utf8 => utf8_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
@@ -424,6 +424,12 @@ pub fn to_array(
             Ok(Box::new(array))
         }
         Map => to_map(json_col, data_type, field, dictionaries),
+        RunEndEncoded => Err(Error::NotYetImplemented(
+            "Reading RunEndEncoded arrays from the JSON integration format".to_string(),
+        )),
+        BinaryView | Utf8View => Err(Error::NotYetImplemented(
+            "Reading view arrays from the JSON integration format".to_string(),
+        )),
     }
 }
 
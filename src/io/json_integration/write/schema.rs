@@ -31,6 +31,9 @@ fn serialize_data_type(data_type: &DataType) -> Value {
         DataType::Struct(_) => json!({"name": "struct"}),
         DataType::Union(_, _, _) => json!({"name": "union"}),
         DataType::Map(_, _) => json!({"name": "map"}),
+        DataType::RunEndEncoded(_, _) => json!({"name": "runendencoded"}),
+        DataType::BinaryView => json!({"name": "binaryview"}),
+        DataType::Utf8View => json!({"name": "utf8view"}),
         DataType::List(_) => json!({ "name": "list"}),
         DataType::LargeList(_) => json!({ "name": "largelist"}),
         DataType::FixedSizeList(_, length) => {
@@ -109,6 +112,10 @@ fn serialize_field(field: &Field, ipc_field: &IpcField) -> ArrowJsonField {
         | DataType::List(field) => {
             vec![serialize_field(field, &ipc_field.fields[0])]
         }
+        DataType::RunEndEncoded(run_ends, values) => vec![
+            serialize_field(run_ends, &ipc_field.fields[0]),
+            serialize_field(values, &ipc_field.fields[1]),
+        ],
         _ => vec![],
     };
     let metadata = serialize_metadata(&field.metadata);
@@ -6,6 +6,13 @@ pub mod write;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 
+/// The name of the [`DataType::Extension`](crate::datatypes::DataType::Extension) of
+/// `FixedSizeBinary(16)` columns annotated with parquet's `UUID` logical type.
+///
+/// Columns of this extension type are written with the `UUID` annotation, and columns
+/// with this annotation are read to this extension type.
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
 impl From<parquet2::error::Error> for Error {
     fn from(error: parquet2::error::Error) -> Self {
         match error {
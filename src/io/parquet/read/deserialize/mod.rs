@@ -95,6 +95,8 @@ fn is_primitive(data_type: &DataType) -> bool {
             | crate::datatypes::PhysicalType::LargeUtf8
             | crate::datatypes::PhysicalType::Binary
             | crate::datatypes::PhysicalType::LargeBinary
+            | crate::datatypes::PhysicalType::BinaryView
+            | crate::datatypes::PhysicalType::Utf8View
             | crate::datatypes::PhysicalType::FixedSizeBinary
             | crate::datatypes::PhysicalType::Dictionary(_)
     )
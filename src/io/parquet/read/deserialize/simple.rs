@@ -6,7 +6,10 @@ use parquet2::{
 };
 
 use crate::{
-    array::{Array, BinaryArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BinaryViewArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray,
+        StringViewArray, Utf8Array,
+    },
     datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{Error, Result},
     types::{days_ms, NativeType},
@@ -39,6 +42,17 @@ where
     iter.map(|x| x.map(|x| x.into()))
 }
 
+/// Converts an iterator of arrays into an iterator of their view arrays, e.g. [`Utf8Array`] into
+/// [`StringViewArray`]
+#[inline]
+fn views<A, V, I>(iter: I) -> impl Iterator<Item = Result<V>>
+where
+    V: From<A>,
+    I: Iterator<Item = Result<A>>,
+{
+    iter.map(|x| x.map(V::from))
+}
+
 #[inline]
 fn op<T, I, F>(iter: I, op: F) -> impl Iterator<Item = Result<PrimitiveArray<T>>>
 where
@@ -292,6 +306,24 @@ pub fn page_iter_to_arrays<'a, I: Pages + 'a>(
         LargeUtf8 => dyn_iter(binary::Iter::<i64, Utf8Array<i64>, _>::new(
             pages, data_type, chunk_size, num_rows,
         )),
+        BinaryView => {
+            let iter = binary::Iter::<i32, BinaryArray<i32>, _>::new(
+                pages,
+                DataType::Binary,
+                chunk_size,
+                num_rows,
+            );
+            dyn_iter(views::<_, BinaryViewArray, _>(iter))
+        }
+        Utf8View => {
+            let iter = binary::Iter::<i32, Utf8Array<i32>, _>::new(
+                pages,
+                DataType::Utf8,
+                chunk_size,
+                num_rows,
+            );
+            dyn_iter(views::<_, StringViewArray, _>(iter))
+        }
 
         Dictionary(key_type, _, _) => {
             return match_integer_type!(key_type, |$K| {
@@ -4,11 +4,12 @@ use parquet2::indexes::FilteredPage;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::datatypes::Schema;
+use crate::datatypes::{Field, Schema};
 use crate::error::Result;
-use crate::io::parquet::read::read_columns_many;
 
-use super::{RowGroupDeserializer, RowGroupMetaData};
+use super::{
+    read_columns, to_deserializer, ColumnChunkMetaData, RowGroupDeserializer, RowGroupMetaData,
+};
 
 /// An iterator of [`Chunk`]s coming from row groups of a parquet file.
 ///
@@ -62,6 +63,14 @@ impl<R: Read + Seek> FileReader<R> {
     pub fn schema(&self) -> &Schema {
         &self.row_groups.schema
     }
+
+    /// Converts this reader into an iterator of [`RowGroupTask`]s over the row groups
+    /// that were not yet read, so that they can be deserialized in parallel.
+    ///
+    /// Chunks remaining from a row group that has already been read are discarded.
+    pub fn into_row_group_tasks(self) -> RowGroupTasks<R> {
+        self.row_groups.into_tasks()
+    }
 }
 
 impl<R: Read + Seek> Iterator for FileReader<R> {
@@ -144,7 +153,7 @@ impl<R: Read + Seek> RowGroupReader<R> {
     }
 
     #[inline]
-    fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
+    fn _next_task(&mut self) -> Result<Option<RowGroupTask>> {
         if self.schema.fields.is_empty() {
             return Ok(None);
         }
@@ -178,18 +187,45 @@ impl<R: Read + Seek> RowGroupReader<R> {
             })
             .unwrap_or_else(|| row_group.num_rows());
 
-        let column_chunks = read_columns_many(
-            &mut self.reader,
-            &row_group,
-            self.schema.fields.clone(),
-            self.chunk_size,
-            Some(self.remaining_rows),
-            pages,
-        )?;
+        // reads all the necessary columns for all fields from the row group
+        let columns = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| {
+                read_columns(&mut self.reader, row_group.columns(), &field.name).map(|columns| {
+                    let columns = columns
+                        .into_iter()
+                        .map(|(meta, chunk)| (meta.clone(), chunk))
+                        .collect();
+                    (field.clone(), columns)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-        let result = RowGroupDeserializer::new(column_chunks, num_rows, Some(self.remaining_rows));
+        let task = RowGroupTask {
+            columns,
+            pages,
+            num_rows,
+            row_group_rows: row_group.num_rows(),
+            chunk_size: self.chunk_size,
+            limit: self.remaining_rows,
+        };
         self.remaining_rows = self.remaining_rows.saturating_sub(num_rows);
-        Ok(Some(result))
+        Ok(Some(task))
+    }
+
+    #[inline]
+    fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
+        self._next_task()?
+            .map(|task| task.deserialize())
+            .transpose()
+    }
+
+    /// Converts this reader into an iterator of [`RowGroupTask`]s, each of them owning the
+    /// column chunks of a row group.
+    pub fn into_tasks(self) -> RowGroupTasks<R> {
+        RowGroupTasks { reader: self }
     }
 }
 
@@ -204,3 +240,71 @@ impl<R: Read + Seek> Iterator for RowGroupReader<R> {
         self.row_groups.size_hint()
     }
 }
+
+/// An [`Iterator<Item=RowGroupTask>`] from row groups of a parquet file.
+///
+/// # Implementation
+/// Advancing this iterator is IO-bounded - each iteration reads all the column chunks of a row
+/// group to memory. The returned [`RowGroupTask`]s are self-contained and [`Send`], so that
+/// the CPU-bounded deserialization can be run on any thread pool.
+pub struct RowGroupTasks<R: Read + Seek> {
+    reader: RowGroupReader<R>,
+}
+
+impl<R: Read + Seek> Iterator for RowGroupTasks<R> {
+    type Item = Result<RowGroupTask>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader._next_task().transpose()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.reader.size_hint()
+    }
+}
+
+/// The column chunks of a row group of a parquet file, read to memory and ready to be
+/// deserialized independently of the file and of other row groups.
+#[derive(Debug, Clone)]
+pub struct RowGroupTask {
+    columns: Vec<(Field, Vec<(ColumnChunkMetaData, Vec<u8>)>)>,
+    pages: Option<Vec<Vec<Vec<FilteredPage>>>>,
+    num_rows: usize,
+    row_group_rows: usize,
+    chunk_size: Option<usize>,
+    limit: usize,
+}
+
+impl RowGroupTask {
+    /// Returns the number of rows that this task deserializes
+    pub fn num_rows(&self) -> usize {
+        self.num_rows.min(self.limit)
+    }
+
+    /// Converts this task into a [`RowGroupDeserializer`].
+    /// # Implementation
+    /// This operation is CPU-bounded; the returned [`RowGroupDeserializer`] decodes
+    /// the pages as it is iterated.
+    pub fn deserialize(self) -> Result<RowGroupDeserializer> {
+        let num_rows = self.row_group_rows.min(self.limit);
+        let chunk_size = self.chunk_size;
+
+        let mut pages = self.pages.map(|pages| pages.into_iter());
+        let column_chunks = self
+            .columns
+            .into_iter()
+            .map(|(field, columns)| {
+                let (metas, chunks): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+                let columns = metas.iter().zip(chunks).collect();
+                let pages = pages.as_mut().and_then(|pages| pages.next());
+                to_deserializer(columns, field, num_rows, chunk_size, pages)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RowGroupDeserializer::new(
+            column_chunks,
+            self.num_rows,
+            Some(self.limit),
+        ))
+    }
+}
@@ -35,7 +35,7 @@ pub use parquet2::{
 use crate::{array::Array, error::Result};
 
 pub use deserialize::{column_iter_to_arrays, get_page_iterator};
-pub use file::{FileReader, RowGroupReader};
+pub use file::{FileReader, RowGroupReader, RowGroupTask, RowGroupTasks};
 pub use row_group::*;
 pub use schema::{infer_schema, FileMetaData};
 
@@ -8,6 +8,7 @@ use parquet2::schema::{
 };
 
 use crate::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
+use crate::io::parquet::UUID_EXTENSION_NAME;
 
 /// Converts [`ParquetType`]s to a [`Field`], ignoring parquet fields that do not contain
 /// any physical column.
@@ -155,6 +156,11 @@ fn from_fixed_len_byte_array(
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             DataType::Decimal(precision, scale)
         }
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => DataType::Extension(
+            UUID_EXTENSION_NAME.to_string(),
+            Box::new(DataType::FixedSizeBinary(length)),
+            None,
+        ),
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             DataType::Decimal(precision, scale)
         }
@@ -17,6 +17,7 @@ use crate::datatypes::IntervalUnit;
 use crate::datatypes::{DataType, Field, PhysicalType};
 use crate::error::Error;
 use crate::error::Result;
+use crate::scalar::{new_scalar, Scalar};
 
 mod binary;
 mod boolean;
@@ -552,3 +553,37 @@ pub fn deserialize(field: &Field, row_groups: &[RowGroupMetaData]) -> Result<Sta
 
     Ok(statistics.into())
 }
+
+/// Arrow-deserialized parquet statistics of a column chunk of a single row group, as
+/// [`Scalar`]s. Statistics that are not present in the row group are null scalars.
+#[derive(Debug, PartialEq)]
+pub struct ScalarStatistics {
+    /// number of nulls. This is a [`PrimitiveScalar<u64>`](crate::scalar::PrimitiveScalar)
+    /// for non-nested types
+    pub null_count: Box<dyn Scalar>,
+    /// number of distinct values. This is a
+    /// [`PrimitiveScalar<u64>`](crate::scalar::PrimitiveScalar) for non-nested types
+    pub distinct_count: Box<dyn Scalar>,
+    /// Minimum
+    pub min_value: Box<dyn Scalar>,
+    /// Maximum
+    pub max_value: Box<dyn Scalar>,
+}
+
+/// Deserializes the statistics of the column chunks of `row_group` associated to `field`'s name
+/// into [`ScalarStatistics`] of `field`'s [`DataType`].
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_row_group(
+    field: &Field,
+    row_group: &RowGroupMetaData,
+) -> Result<ScalarStatistics> {
+    let statistics = deserialize(field, std::slice::from_ref(row_group))?;
+    Ok(ScalarStatistics {
+        null_count: new_scalar(statistics.null_count.as_ref(), 0),
+        distinct_count: new_scalar(statistics.distinct_count.as_ref(), 0),
+        min_value: new_scalar(statistics.min_value.as_ref(), 0),
+        max_value: new_scalar(statistics.max_value.as_ref(), 0),
+    })
+}
@@ -623,7 +623,7 @@ fn transverse_recursive<T, F: Fn(&DataType) -> T + Clone>(
     use crate::datatypes::PhysicalType::*;
     match data_type.to_physical_type() {
         Null | Boolean | Primitive(_) | Binary | FixedSizeBinary | LargeBinary | Utf8
-        | Dictionary(_) | LargeUtf8 => encodings.push(map(data_type)),
+        | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => encodings.push(map(data_type)),
         List | FixedSizeList | LargeList => {
             let a = data_type.to_logical_type();
             if let DataType::List(inner) = a {
@@ -647,6 +647,14 @@ fn transverse_recursive<T, F: Fn(&DataType) -> T + Clone>(
         }
         Union => todo!(),
         Map => todo!(),
+        RunEndEncoded => {
+            // run-end encoded arrays are written (once supported) as their values
+            if let DataType::RunEndEncoded(_, values) = data_type.to_logical_type() {
+                transverse_recursive(&values.data_type, map, encodings)
+            } else {
+                unreachable!()
+            }
+        }
     }
 }
 
@@ -196,6 +196,56 @@ fn to_parquet_leaves_recursive(type_: ParquetType, leaves: &mut Vec<ParquetPrimi
     }
 }
 
+/// Returns `array` where the null slots of every [`StructArray`] are also null slots of its
+/// fields (recursively), so that the values of null structs are written as undefined instead
+/// of as values of a valid struct.
+fn propagate_struct_validity(array: &dyn Array, parent: Option<&Bitmap>) -> Box<dyn Array> {
+    let validity = match (array.validity(), parent) {
+        (Some(validity), Some(parent)) => Some(validity & parent),
+        (None, Some(parent)) => Some(parent.clone()),
+        (validity, None) => validity.cloned(),
+    };
+
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|field| propagate_struct_validity(field.as_ref(), validity.as_ref()))
+                .collect();
+            Box::new(StructArray::new(
+                array.data_type().clone(),
+                values,
+                validity,
+            ))
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            // the validity of a list applies to its lists, not to its values
+            Box::new(ListArray::new(
+                array.data_type().clone(),
+                array.offsets().clone(),
+                propagate_struct_validity(array.values().as_ref(), None),
+                validity,
+            ))
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Box::new(ListArray::new(
+                array.data_type().clone(),
+                array.offsets().clone(),
+                propagate_struct_validity(array.values().as_ref(), None),
+                validity,
+            ))
+        }
+        Null => array.to_boxed(),
+        _ if parent.is_some() => array.with_validity(validity),
+        _ => array.to_boxed(),
+    }
+}
+
 /// Returns a vector of iterators of [`Page`], one per leaf column in the array
 pub fn array_to_columns<A: AsRef<dyn Array> + Send + Sync>(
     array: A,
@@ -203,6 +253,7 @@ pub fn array_to_columns<A: AsRef<dyn Array> + Send + Sync>(
     options: WriteOptions,
     encoding: &[Encoding],
 ) -> Result<Vec<DynIter<'static, Result<Page>>>> {
+    let array = propagate_struct_validity(array.as_ref(), None);
     let array = array.as_ref();
     let nested = to_nested(array, &type_)?;
 
@@ -15,6 +15,7 @@ use crate::{
     io::ipc::write::default_ipc_fields,
     io::ipc::write::schema_to_bytes,
     io::parquet::write::decimal_length_from_precision,
+    io::parquet::UUID_EXTENSION_NAME,
 };
 
 use super::super::ARROW_SCHEMA_META_KEY;
@@ -46,6 +47,18 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
     } else {
         Repetition::Required
     };
+    if let DataType::Extension(extension, inner, _) = field.data_type() {
+        if extension == UUID_EXTENSION_NAME && inner.as_ref() == &DataType::FixedSizeBinary(16) {
+            return Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::FixedLenByteArray(16),
+                repetition,
+                None,
+                Some(PrimitiveLogicalType::Uuid),
+                None,
+            )?);
+        }
+    }
     // create type from field
     match field.data_type().to_logical_type() {
         DataType::Null => Ok(ParquetType::try_from_primitive(
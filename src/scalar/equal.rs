@@ -55,5 +55,7 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         FixedSizeList => dyn_eq!(FixedSizeListScalar, lhs, rhs),
         Union => dyn_eq!(UnionScalar, lhs, rhs),
         Map => unimplemented!("{:?}", Map),
+        // `new_scalar` maps arrays of these types to scalars of other types
+        BinaryView | Utf8View | RunEndEncoded => unreachable!(),
     }
 }
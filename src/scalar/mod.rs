@@ -83,6 +83,10 @@ macro_rules! dyn_new_list {
 }
 
 /// creates a new [`Scalar`] from an [`Array`].
+///
+/// The slots of a [`RunEndEncodedArray`] are [`PrimitiveScalar`]s of the type of its values.
+/// The slots of a [`BinaryViewArray`] and of a [`StringViewArray`] are [`BinaryScalar<i32>`]s
+/// and [`Utf8Scalar<i32>`]s respectively, of type [`DataType::Binary`] and [`DataType::Utf8`].
 pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
     use PhysicalType::*;
     match array.data_type().to_physical_type() {
@@ -112,6 +116,14 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         LargeUtf8 => dyn_new_utf8!(array, index, i64),
         Binary => dyn_new_binary!(array, index, i32),
         LargeBinary => dyn_new_binary!(array, index, i64),
+        BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            Box::new(BinaryScalar::<i32>::new(array.get(index)))
+        }
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<StringViewArray>().unwrap();
+            Box::new(Utf8Scalar::<i32>::new(array.get(index)))
+        }
         List => dyn_new_list!(array, index, i32),
         LargeList => dyn_new_list!(array, index, i64),
         Struct => {
@@ -157,6 +169,19 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
             ))
         }
         Map => todo!(),
+        RunEndEncoded => {
+            let primitive = values_primitive_type(array.data_type());
+            with_match_primitive_type!(primitive, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<RunEndEncodedArray<$T>>()
+                    .unwrap();
+                Box::new(PrimitiveScalar::new(
+                    array.values().data_type().clone(),
+                    array.get(index),
+                ))
+            })
+        }
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             let array = array
                 .as_any()
@@ -174,3 +199,14 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         }),
     }
 }
+
+/// Returns the [`DataType`] of the [`Scalar`]s that [`new_scalar`] returns for arrays of type
+/// `data_type`.
+pub(crate) fn scalar_data_type(data_type: &DataType) -> &DataType {
+    match data_type.to_logical_type() {
+        DataType::BinaryView => &DataType::Binary,
+        DataType::Utf8View => &DataType::Utf8,
+        DataType::RunEndEncoded(_, values) => values.data_type(),
+        _ => data_type,
+    }
+}
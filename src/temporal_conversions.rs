@@ -1,7 +1,7 @@
 //! Conversion methods for dates and times.
 
 use chrono::{
-    format::{parse, Parsed, StrftimeItems},
+    format::{parse, Item, Parsed, StrftimeItems},
     Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
 };
 
@@ -47,9 +47,9 @@ pub fn date32_to_date(days: i32) -> NaiveDate {
 pub fn date64_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp_opt(
         // extract seconds from milliseconds
-        v / MILLISECONDS,
+        v.div_euclid(MILLISECONDS),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
+        (v.rem_euclid(MILLISECONDS) * MICROSECONDS) as u32,
     )
     .expect("invalid or out-of-range datetime")
 }
@@ -114,9 +114,9 @@ pub fn timestamp_s_to_datetime(seconds: i64) -> NaiveDateTime {
 pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp_opt(
         // extract seconds from milliseconds
-        v / MILLISECONDS,
+        v.div_euclid(MILLISECONDS),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
+        (v.rem_euclid(MILLISECONDS) * MICROSECONDS) as u32,
     )
     .expect("invalid or out-of-range datetime")
 }
@@ -126,9 +126,9 @@ pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp_opt(
         // extract seconds from microseconds
-        v / MICROSECONDS,
+        v.div_euclid(MICROSECONDS),
         // discard extracted seconds and convert microseconds to nanoseconds
-        (v % MICROSECONDS * MILLISECONDS) as u32,
+        (v.rem_euclid(MICROSECONDS) * MILLISECONDS) as u32,
     )
     .expect("invalid or out-of-range datetime")
 }
@@ -138,9 +138,9 @@ pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_ns_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp_opt(
         // extract seconds from nanoseconds
-        v / NANOSECONDS,
+        v.div_euclid(NANOSECONDS),
         // discard extracted seconds
-        (v % NANOSECONDS) as u32,
+        v.rem_euclid(NANOSECONDS) as u32,
     )
     .expect("invalid or out-of-range datetime")
 }
@@ -219,6 +219,21 @@ pub fn parse_offset(offset: &str) -> Result<FixedOffset> {
         .expect("FixedOffset::east out of bounds"))
 }
 
+/// Parses a strftime `format` (e.g. `"%m/%d/%Y"`) into the [`Item`]s used to format
+/// dates and times with `chrono`.
+/// # Errors
+/// If `format` contains an invalid specifier.
+pub fn parse_format(format: &str) -> Result<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(Error::InvalidArgumentError(format!(
+            "\"{}\" is not a valid strftime format",
+            format
+        )));
+    }
+    Ok(items)
+}
+
 /// Parses `value` to `Option<i64>` consistent with the Arrow's definition of timestamp with timezone.
 /// `tz` must be built from `timezone` (either via [`parse_offset`] or `chrono-tz`).
 #[inline]
@@ -337,50 +352,76 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
-fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
-    let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
-    let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
-    chrono::NaiveDate::from_ymd_opt(new_year, new_month as u32, 1)
+/// Adds `months` to `date`, clamping the day to the last day of the resulting month
+/// (e.g. January 31st plus one month is February 28th, or 29th on leap years).
+fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total = date.year() * 12 + date.month0() as i32 + months;
+    let year = total.div_euclid(12);
+    let month = total.rem_euclid(12) as u32 + 1;
+
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .expect("invalid or out-of-range date");
+    let days_in_month = first_of_next.pred_opt().unwrap().day();
+
+    NaiveDate::from_ymd_opt(year, month, date.day().min(days_in_month))
         .expect("invalid or out-of-range date")
 }
 
-fn get_days_between_months(year: i32, month: u32, months: i32) -> i64 {
-    add_month(year, month, months)
-        .signed_duration_since(
-            chrono::NaiveDate::from_ymd_opt(year, month, 1).expect("invalid or out-of-range date"),
-        )
-        .num_days()
+/// Adds the months and days of `interval` to `datetime` in wall-clock time.
+/// The nanoseconds of the `interval` are not considered.
+fn add_calendar_interval(datetime: NaiveDateTime, interval: months_days_ns) -> NaiveDateTime {
+    add_months(datetime.date(), interval.months()).and_time(datetime.time())
+        + chrono::Duration::days(interval.days() as i64)
+}
+
+/// The nanoseconds since the epoch of `datetime`.
+/// # Panics
+/// This function panics iff `datetime` is out of the range of an `i64` of nanoseconds.
+#[inline]
+fn timestamp_nanos(datetime: chrono::DateTime<chrono::Utc>) -> i64 {
+    datetime
+        .timestamp_nanos_opt()
+        .expect("timestamp in nanoseconds out of range")
+}
+
+#[inline]
+pub(crate) fn datetime_to_timestamp(datetime: NaiveDateTime, time_unit: TimeUnit) -> i64 {
+    let datetime = datetime.and_utc();
+    match time_unit {
+        TimeUnit::Second => datetime.timestamp(),
+        TimeUnit::Millisecond => datetime.timestamp_millis(),
+        TimeUnit::Microsecond => timestamp_nanos(datetime) / 1000,
+        TimeUnit::Nanosecond => timestamp_nanos(datetime),
+    }
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
+///
+/// Months are added in calendar time, clamping the day of the month to the last day of the
+/// resulting month (e.g. `2021-01-31 + 1 month = 2021-02-28`).
 #[inline]
 pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_days_ns) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime = match time_unit {
-        TimeUnit::Second => timestamp_s_to_datetime(timestamp),
-        TimeUnit::Millisecond => timestamp_ms_to_datetime(timestamp),
-        TimeUnit::Microsecond => timestamp_us_to_datetime(timestamp),
-        TimeUnit::Nanosecond => timestamp_ns_to_datetime(timestamp),
-    };
-
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        + interval.days() as i64;
+    let datetime = timestamp_to_naive_datetime(timestamp, time_unit);
 
-    // add; no leap hours are considered
-    let new_datetime_tz = datetime
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+    // no leap hours are considered
+    let new_datetime =
+        add_calendar_interval(datetime, interval) + chrono::Duration::nanoseconds(interval.ns());
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
-    }
+    datetime_to_timestamp(new_datetime, time_unit)
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
+///
+/// Months and days are added in local (wall-clock) time of `timezone`, so that e.g. adding
+/// one day across a daylight saving time transition keeps the local time of the day.
+/// Months are clamped to the last day of the resulting month, like in [`add_naive_interval`].
+/// When the resulting local time is ambiguous, the earliest instant is used; when it does not
+/// exist (it falls on a gap), the offset of `timestamp` is used.
+/// The nanoseconds of the `interval` are added in absolute time.
 #[inline]
 pub fn add_interval<T: chrono::TimeZone>(
     timestamp: i64,
@@ -388,23 +429,18 @@ pub fn add_interval<T: chrono::TimeZone>(
     interval: months_days_ns,
     timezone: &T,
 ) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
+    use chrono::{LocalResult, Offset};
 
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            + interval.days() as i64;
+    // convert to a DateTime of a given offset.
+    let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
 
-    // add; tz will take care of leap hours
-    let new_datetime_tz = datetime_tz
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+    let new_local = add_calendar_interval(datetime_tz.naive_local(), interval);
+    let new_datetime_tz = match timezone.from_local_datetime(&new_local) {
+        LocalResult::Single(datetime) => datetime,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => timezone.from_utc_datetime(&(new_local - datetime_tz.offset().fix())),
+    };
+    let new_datetime_tz = new_datetime_tz + chrono::Duration::nanoseconds(interval.ns());
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
-    }
+    datetime_to_timestamp(new_datetime_tz.naive_utc(), time_unit)
 }
@@ -347,6 +347,14 @@ impl PartialEq for f16 {
 }
 
 // see https://github.com/starkat99/half-rs/blob/main/src/binary16.rs
+impl PartialOrd for f16 {
+    /// Compares two [`f16`] as their `f32` values, so that `NaN` is unordered.
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<std::cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
 impl f16 {
     /// The difference between 1.0 and the next largest representable number.
     pub const EPSILON: f16 = f16(0x1400u16);
@@ -416,6 +424,78 @@ impl f16 {
         f32::from_bits(sign | exp | man)
     }
 
+    /// Casts this `f16` to `f64`
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Casts an `f64` into `f16`, rounding to the nearest value (ties to even)
+    pub fn from_f64(value: f64) -> Self {
+        let bits: u64 = value.to_bits();
+        // the high word holds the sign, exponent and the 20 most significant bits of the
+        // mantissa; the low word only matters as a sticky bit when rounding
+        let x = (bits >> 32) as u32;
+        let sticky = (bits as u32 != 0) as u32;
+
+        // Extract IEEE754 components
+        let sign = x & 0x8000_0000u32;
+        let exp = x & 0x7FF0_0000u32;
+        let man = x & 0x000F_FFFFu32;
+
+        // Check for all exponent bits being set, which is Infinity or NaN
+        if exp == 0x7FF0_0000u32 {
+            // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+            let nan_bit = if man == 0 && sticky == 0 {
+                0
+            } else {
+                0x0200u32
+            };
+            return f16(((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 10)) as u16);
+        }
+
+        // The number is normalized, start assembling half precision version
+        let half_sign = sign >> 16;
+        // Unbias the exponent, then bias for half precision
+        let unbiased_exp = ((exp >> 20) as i32) - 1023;
+        let half_exp = unbiased_exp + 15;
+
+        // Check for exponent overflow, return +infinity
+        if half_exp >= 0x1F {
+            return f16((half_sign | 0x7C00u32) as u16);
+        }
+
+        // Check for underflow
+        if half_exp <= 0 {
+            // Check mantissa for what we can do
+            if 10 - half_exp > 21 {
+                // No rounding possibility, so this is a full underflow, return signed zero
+                return f16(half_sign as u16);
+            }
+            // Don't forget about hidden leading mantissa bit when assembling mantissa
+            let man = man | 0x0010_0000u32;
+            let mut half_man = man >> (11 - half_exp);
+            // Round to nearest, ties to even
+            let round_bit = 1 << (10 - half_exp);
+            if (man & round_bit) != 0 && ((man & (3 * round_bit - 1)) | sticky) != 0 {
+                half_man += 1;
+            }
+            // No exponent for subnormals
+            return f16((half_sign | half_man) as u16);
+        }
+
+        // Rebias the exponent
+        let half_exp = (half_exp as u32) << 10;
+        let half_man = man >> 10;
+        // Round to nearest, ties to even
+        let round_bit = 0x0000_0200u32;
+        if (man & round_bit) != 0 && ((man & (3 * round_bit - 1)) | sticky) != 0 {
+            // Round it
+            f16(((half_sign | half_exp | half_man) + 1) as u16)
+        } else {
+            f16((half_sign | half_exp | half_man) as u16)
+        }
+    }
+
     /// Casts an `f32` into `f16`
     pub fn from_f32(value: f32) -> Self {
         let x: u32 = value.to_bits();
@@ -525,13 +605,19 @@ impl i256 {
     }
 }
 
+impl From<i128> for i256 {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self(ethnum::I256::new(value))
+    }
+}
+
 impl Neg for i256 {
     type Output = Self;
 
     #[inline]
     fn neg(self) -> Self::Output {
-        let (a, b) = self.0.into_words();
-        Self(ethnum::I256::from_words(-a, b))
+        Self(-self.0)
     }
 }
 
@@ -557,57 +643,22 @@ impl NativeType for i256 {
 
     #[inline]
     fn to_le_bytes(&self) -> Self::Bytes {
-        let mut bytes = [0u8; 32];
-        let (a, b) = self.0.into_words();
-        let a = a.to_le_bytes();
-        (0..16).for_each(|i| {
-            bytes[i] = a[i];
-        });
-
-        let b = b.to_le_bytes();
-        (0..16).for_each(|i| {
-            bytes[i + 16] = b[i];
-        });
-
-        bytes
+        self.0.to_le_bytes()
     }
 
     #[inline]
     fn to_be_bytes(&self) -> Self::Bytes {
-        let mut bytes = [0u8; 32];
-        let (a, b) = self.0.into_words();
-
-        let b = b.to_be_bytes();
-        (0..16).for_each(|i| {
-            bytes[i] = b[i];
-        });
-
-        let a = a.to_be_bytes();
-        (0..16).for_each(|i| {
-            bytes[i + 16] = a[i];
-        });
-
-        bytes
+        self.0.to_be_bytes()
     }
 
     #[inline]
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
-        let (b, a) = bytes.split_at(16);
-        let a: [u8; 16] = a.try_into().unwrap();
-        let b: [u8; 16] = b.try_into().unwrap();
-        let a = i128::from_be_bytes(a);
-        let b = i128::from_be_bytes(b);
-        Self(ethnum::I256::from_words(a, b))
+        Self(ethnum::I256::from_be_bytes(bytes))
     }
 
     #[inline]
     fn from_le_bytes(bytes: Self::Bytes) -> Self {
-        let (b, a) = bytes.split_at(16);
-        let a: [u8; 16] = a.try_into().unwrap();
-        let b: [u8; 16] = b.try_into().unwrap();
-        let a = i128::from_le_bytes(a);
-        let b = i128::from_le_bytes(b);
-        Self(ethnum::I256::from_words(a, b))
+        Self(ethnum::I256::from_le_bytes(bytes))
     }
 }
 
@@ -65,3 +65,17 @@ fn shared_all() {
     let array = BinaryArray::<i32>::from([Some("hello"), Some(" "), None]);
     assert!(array.clone().into_mut().is_left())
 }
+
+#[test]
+fn apply_validity() {
+    let mut array = BinaryArray::<i32>::from([Some("a"), None, Some("b")]);
+    array.apply_validity(|bitmap| {
+        let mut mutable = bitmap.make_mut();
+        mutable.set(2, false);
+        mutable.into()
+    });
+    assert_eq!(
+        array,
+        BinaryArray::<i32>::from([Some(b"a".as_ref()), None, None])
+    );
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, BinaryArray, BinaryViewArray, View},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+};
+
+#[test]
+fn basics() {
+    let data = vec![
+        Some(b"hello".as_ref()),
+        None,
+        Some(b"hello world, long".as_ref()),
+    ];
+
+    let array: BinaryViewArray = data.into_iter().collect();
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.value(0), b"hello");
+    assert_eq!(array.value(1), b"");
+    assert_eq!(array.value(2), b"hello world, long");
+    assert_eq!(unsafe { array.value_unchecked(2) }, b"hello world, long");
+    assert_eq!(array.get(1), None);
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from_u8_slice([0b00000101], 3))
+    );
+
+    assert!(array.views()[0].is_inline());
+    assert!(!array.views()[2].is_inline());
+    assert_eq!(array.views()[2].prefix, *b"hell");
+    assert_eq!(array.data_buffers().len(), 1);
+    assert_eq!(array.data_buffers()[0].as_slice(), b"hello world, long");
+
+    let array2 = BinaryViewArray::new(
+        DataType::BinaryView,
+        array.views().clone(),
+        array.data_buffers().clone(),
+        array.validity().cloned(),
+    );
+    assert_eq!(array, array2);
+
+    let array = array.slice(1, 2);
+    assert_eq!(array.value(0), b"");
+    assert_eq!(array.value(1), b"hello world, long");
+    assert_eq!(array.null_count(), 1);
+}
+
+#[test]
+fn empty() {
+    let array = BinaryViewArray::new_empty(DataType::BinaryView);
+    assert_eq!(array.len(), 0);
+    assert!(array.data_buffers().is_empty());
+    assert_eq!(array.validity(), None);
+}
+
+#[test]
+fn from_slice() {
+    let array = BinaryViewArray::from_slice([b"a".as_ref(), b"a value of 13"]);
+    assert_eq!(array.validity(), None);
+    assert_eq!(
+        array.values_iter().collect::<Vec<_>>(),
+        vec![b"a".as_ref(), b"a value of 13"]
+    );
+}
+
+#[test]
+fn inline_boundary() {
+    let array = BinaryViewArray::from_slice([[1u8; 12].as_ref(), [2u8; 13].as_ref()]);
+    assert!(array.views()[0].is_inline());
+    assert!(!array.views()[1].is_inline());
+    assert_eq!(array.value(0), [1u8; 12]);
+    assert_eq!(array.value(1), [2u8; 13]);
+    assert_eq!(array.data_buffers()[0].len(), 13);
+}
+
+#[test]
+fn try_new_invalid_buffer_index() {
+    let views = vec![View {
+        length: 13,
+        prefix: *b"aaaa",
+        buf_index: 1,
+        buf_offset: 0,
+    }];
+    let buffers: Arc<[Buffer<u8>]> = vec![Buffer::from(vec![b'a'; 13])].into();
+    assert!(BinaryViewArray::try_new(DataType::BinaryView, views.into(), buffers, None).is_err());
+}
+
+#[test]
+fn try_new_out_of_bounds() {
+    let views = vec![View {
+        length: 13,
+        prefix: *b"aaaa",
+        buf_index: 0,
+        buf_offset: 1,
+    }];
+    let buffers: Arc<[Buffer<u8>]> = vec![Buffer::from(vec![b'a'; 13])].into();
+    assert!(BinaryViewArray::try_new(DataType::BinaryView, views.into(), buffers, None).is_err());
+}
+
+#[test]
+fn try_new_invalid_prefix() {
+    let views = vec![View {
+        length: 13,
+        prefix: *b"abcd",
+        buf_index: 0,
+        buf_offset: 0,
+    }];
+    let buffers: Arc<[Buffer<u8>]> = vec![Buffer::from(vec![b'a'; 13])].into();
+    assert!(BinaryViewArray::try_new(DataType::BinaryView, views.into(), buffers, None).is_err());
+}
+
+#[test]
+fn try_new_invalid_validity() {
+    let array = BinaryViewArray::from_slice([b"a"]);
+    let (data_type, views, buffers, _) = array.into_inner();
+    assert!(
+        BinaryViewArray::try_new(data_type, views, buffers, Some(Bitmap::new_zeroed(2))).is_err()
+    );
+}
+
+#[test]
+fn try_new_invalid_data_type() {
+    let array = BinaryViewArray::from_slice([b"a"]);
+    let (_, views, buffers, validity) = array.into_inner();
+    assert!(BinaryViewArray::try_new(DataType::Binary, views, buffers, validity).is_err());
+}
+
+#[test]
+fn from_binary() {
+    let binary =
+        BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(b"a value longer than 12")]);
+    let binary = binary.slice(1, 2);
+
+    let array: BinaryViewArray = binary.clone().into();
+    assert_eq!(array.data_type(), &DataType::BinaryView);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        binary.iter().collect::<Vec<_>>()
+    );
+    // the values are not copied
+    assert_eq!(array.data_buffers()[0], *binary.values());
+
+    let result: BinaryArray<i32> = array.into();
+    assert_eq!(result, binary);
+}
+
+#[test]
+fn null() {
+    let array = BinaryViewArray::new_null(DataType::BinaryView, 2);
+    assert_eq!(array.null_count(), 2);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![None, None]);
+}
+
+#[test]
+fn with_validity() {
+    let array = BinaryViewArray::from_slice([b"a", b"b"]);
+    let array = array.with_validity(Some(Bitmap::from([true, false])));
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some(b"a".as_ref()), None]
+    );
+}
+
+#[test]
+fn debug() {
+    let array = BinaryViewArray::from([Some([1, 2].as_ref()), Some(&[]), None]);
+
+    assert_eq!(format!("{:?}", array), "BinaryViewArray[[1, 2], [], None]");
+}
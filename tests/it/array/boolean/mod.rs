@@ -88,6 +88,33 @@ fn into_mut_invalid() {
     let _ = a.into_mut().left().unwrap();
 }
 
+#[test]
+fn into_mut_sliced() {
+    // the original array is dropped, so that the slice is not shared
+    let a = BooleanArray::from_iter((0..12).map(|x| (x % 3 != 0).then(|| x % 2 == 0))).slice(0, 3);
+    let mut mutable = a.into_mut().right().unwrap();
+    mutable.push(Some(true));
+    let a: BooleanArray = mutable.into();
+    assert_eq!(
+        a,
+        BooleanArray::from([None, Some(false), Some(true), Some(true)])
+    );
+}
+
+#[test]
+fn apply_validity() {
+    let mut array = BooleanArray::from([Some(true), None, Some(false)]);
+    array.apply_validity(|bitmap| {
+        let mut mutable = bitmap.make_mut();
+        mutable.set(1, true);
+        mutable.into()
+    });
+    assert_eq!(
+        array,
+        BooleanArray::from([Some(true), Some(false), Some(false)])
+    );
+}
+
 #[test]
 fn empty() {
     let array = BooleanArray::new_empty(DataType::Boolean);
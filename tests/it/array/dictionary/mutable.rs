@@ -74,3 +74,130 @@ fn push_utf8() {
         .collect::<HashedMap<_, _>>();
     assert_eq!(*new.map(), expected_map);
 }
+
+#[test]
+fn extend_from_self() -> Result<()> {
+    let lhs = [Some("A"), Some("B"), None, Some("A")];
+    let rhs = [Some("C"), None, Some("B"), Some("C")];
+
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend(lhs)?;
+    let mut b = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    b.try_extend(rhs)?;
+
+    a.try_extend_from_self(&b)?;
+
+    let mut expected_keys = MutablePrimitiveArray::<i32>::from_slice([0, 1]);
+    expected_keys.push(None);
+    expected_keys.extend_from_slice(&[0, 2]);
+    expected_keys.push(None);
+    expected_keys.extend_from_slice(&[1, 2]);
+    assert_eq!(*a.keys(), expected_keys);
+
+    let mut expected = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    expected.try_extend(lhs.into_iter().chain(rhs))?;
+
+    let a: DictionaryArray<i32> = a.into();
+    let expected: DictionaryArray<i32> = expected.into();
+    assert_eq!(a, expected);
+
+    // `self` is extended with new values and keeps using its existing ones
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend(lhs)?;
+    a.try_extend_from_self(&b)?;
+    a.try_push(Some("C"))?;
+    a.try_push(Some("D"))?;
+    assert_eq!(a.keys().values()[8..], [2, 4]);
+    Ok(())
+}
+
+#[test]
+fn extend_from_self_validity() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend([Some(1), Some(2)])?;
+    let mut b = MutableDictionaryArray::<i32, MutablePrimitiveArray<i32>>::new();
+    b.try_extend([None, Some(2)])?;
+
+    a.try_extend_from_self(&b)?;
+    b.try_extend_from_self(&a)?;
+
+    let a: DictionaryArray<i32> = a.into();
+    assert_eq!(
+        a.keys(),
+        &PrimitiveArray::<i32>::from([Some(0), Some(1), None, Some(1)])
+    );
+    let b: DictionaryArray<i32> = b.into();
+    assert_eq!(
+        b.keys(),
+        &PrimitiveArray::<i32>::from([None, Some(0), Some(1), Some(0), None, Some(0)])
+    );
+    Ok(())
+}
+
+#[test]
+fn push_duplicates() -> Result<()> {
+    let mut utf8 = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    let mut binary = MutableDictionaryArray::<i32, MutableBinaryArray<i32>>::new();
+    let mut primitive = MutableDictionaryArray::<i32, MutablePrimitiveArray<i64>>::new();
+    for value in [1, 2, 1, 3, 2, 1] {
+        utf8.try_push(Some(value.to_string()))?;
+        binary.try_push(Some(value.to_string().into_bytes()))?;
+        primitive.try_push(Some(value as i64))?;
+    }
+
+    assert_eq!(utf8.len(), 6);
+    assert_eq!(utf8.values().len(), 3);
+    assert_eq!(binary.len(), 6);
+    assert_eq!(binary.values().len(), 3);
+    assert_eq!(primitive.len(), 6);
+    assert_eq!(primitive.values().len(), 3);
+    assert_eq!(primitive.keys().values().as_slice(), &[0, 1, 0, 2, 1, 0]);
+    Ok(())
+}
+
+#[test]
+fn with_capacities() {
+    let array = MutableDictionaryArray::<i32, MutablePrimitiveArray<i64>>::with_capacities(10, 5);
+    assert_eq!(array.len(), 0);
+    assert!(array.keys().capacity() >= 10);
+    assert!(array.values().capacity() >= 5);
+    assert!(array.map().capacity() >= 5);
+}
+
+#[test]
+fn push_valid_key() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_push(Some("A"))?;
+    a.try_push(Some("B"))?;
+
+    a.try_push_valid_key(1)?;
+    a.try_push_valid_key(0)?;
+    assert!(a.try_push_valid_key(2).is_err());
+    assert!(a.try_push_valid_key(-1).is_err());
+
+    assert_eq!(a.keys().values().as_slice(), &[0, 1, 1, 0]);
+    assert_eq!(a.values().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn extend_from_self_unhashed_values() -> Result<()> {
+    // the values of `b` are not hashed: its keys must still reference them
+    let values = MutableUtf8Array::<i32>::from([Some("A"), Some("B")]);
+    let mut b = MutableDictionaryArray::<i32, _>::from(values);
+    b.try_push_valid_key(1)?;
+    b.try_push_valid_key(0)?;
+
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_push(Some("C"))?;
+    a.try_extend_from_self(&b)?;
+
+    let keys = a.keys().values().as_slice().to_vec();
+    let values = a.values();
+    let decoded = keys
+        .iter()
+        .map(|key| values.value(*key as usize))
+        .collect::<Vec<_>>();
+    assert_eq!(decoded, vec!["C", "B", "A"]);
+    Ok(())
+}
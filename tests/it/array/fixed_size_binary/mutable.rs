@@ -168,3 +168,75 @@ fn extend_from_self() {
         MutableFixedSizeBinaryArray::from([Some([1u8, 2u8]), None, Some([1u8, 2u8]), None])
     );
 }
+
+#[test]
+fn uuids() {
+    let uuids = [
+        *b"\x67\xe5\x50\x44\x10\xb1\x42\x6f\x92\x47\xbb\x68\x0e\x5f\xe0\xc8",
+        *b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    ];
+    let data_type = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        None,
+    );
+
+    let mut array = MutableFixedSizeBinaryArray::try_from_slice(data_type.clone(), uuids).unwrap();
+    assert_eq!(array.len(), 2);
+    assert_eq!(unsafe { array.value_unchecked(0) }, &uuids[0]);
+    assert_eq!(array.as_box().data_type(), &data_type);
+
+    let array = uuids
+        .iter()
+        .map(|uuid| Some(*uuid))
+        .chain(std::iter::once(None))
+        .collect::<MutableFixedSizeBinaryArray>();
+    let array: FixedSizeBinaryArray = array.into();
+    assert_eq!(array.size(), 16);
+    assert_eq!(array.value(1), &uuids[1]);
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, true, false])));
+
+    let array: FixedSizeBinaryArray = uuids
+        .into_iter()
+        .collect::<MutableFixedSizeBinaryArray>()
+        .into();
+    assert_eq!(array, FixedSizeBinaryArray::from_slice(uuids));
+}
+
+#[test]
+fn try_from_slice_wrong_size() {
+    let result =
+        MutableFixedSizeBinaryArray::try_from_slice(DataType::FixedSizeBinary(8), [[0u8; 16]]);
+    assert!(result.is_err());
+    let result = MutableFixedSizeBinaryArray::try_from_slice(DataType::Binary, [[0u8; 16]]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn try_push_wrong_size() {
+    let mut array = MutableFixedSizeBinaryArray::new(16);
+    let error = array.try_push(Some([0u8; 4])).unwrap_err();
+    assert!(error.to_string().contains("(16)"));
+    assert!(error.to_string().contains("length 4"));
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn set_validity_and_apply_values() {
+    let mut array = MutableFixedSizeBinaryArray::from_slice([[1u8, 2], [3, 4]]);
+    array.apply_values(|x| x.reverse());
+    array.set_validity(Some(MutableBitmap::from([true, false])));
+
+    assert_eq!(
+        array,
+        MutableFixedSizeBinaryArray::from([Some([2u8, 1]), None])
+    );
+    assert_eq!(array.value(1), &[4, 3]);
+}
+
+#[test]
+#[should_panic]
+fn set_validity_wrong_len() {
+    let mut array = MutableFixedSizeBinaryArray::from_slice([[1u8, 2], [3, 4]]);
+    array.set_validity(Some(MutableBitmap::from([true])));
+}
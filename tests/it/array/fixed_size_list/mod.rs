@@ -102,3 +102,56 @@ fn wrong_data_type() {
     )
     .is_err());
 }
+
+#[test]
+fn lengths() {
+    let array = data();
+    assert_eq!(array.lengths(), Int32Array::from([Some(2), None]));
+}
+
+#[test]
+fn reshape() {
+    let values = Float32Array::from_vec((0..12).map(|x| x as f32).collect());
+
+    let array = FixedSizeListArray::try_from_values(values.clone(), 3).unwrap();
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.size(), 3);
+    assert_eq!(
+        array.data_type(),
+        &FixedSizeListArray::default_datatype(DataType::Float32, 3)
+    );
+    assert_eq!(
+        array.value(1).as_ref(),
+        Float32Array::from_slice([3.0, 4.0, 5.0])
+    );
+
+    assert_eq!(array.into_flat_values::<f32>().unwrap(), values);
+}
+
+#[test]
+fn reshape_nulls() {
+    let values = Int32Array::from([Some(0), None, Some(2), Some(3), Some(4), Some(5)]);
+    let array = FixedSizeListArray::try_from_values(values, 2)
+        .unwrap()
+        .with_validity(Some([true, false, true].into()));
+
+    // the items of null lists are null
+    let expected = Int32Array::from([Some(0), None, None, None, Some(4), Some(5)]);
+    assert_eq!(array.clone().into_flat_values::<i32>().unwrap(), expected);
+
+    let expected = Int32Array::from([None, None, Some(4), Some(5)]);
+    assert_eq!(
+        array.slice(1, 2).into_flat_values::<i32>().unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn reshape_errors() {
+    let values = Int32Array::from_slice([1, 2, 3]);
+    assert!(FixedSizeListArray::try_from_values(values.clone(), 2).is_err());
+    assert!(FixedSizeListArray::try_from_values(values.clone(), 0).is_err());
+
+    let array = FixedSizeListArray::try_from_values(values, 3).unwrap();
+    assert!(array.into_flat_values::<i64>().is_err());
+}
@@ -86,3 +86,33 @@ fn extend_from_self() {
 
     assert_eq!(a, b);
 }
+
+#[test]
+fn wrong_size() {
+    let mut a = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 2);
+    a.try_push(Some(vec![Some(1), Some(2)])).unwrap();
+    assert!(a.try_push(Some(vec![Some(3)])).is_err());
+    assert!(a.try_push(Some(vec![Some(4), Some(5), Some(6)])).is_err());
+    a.try_push(Some(vec![Some(7), None])).unwrap();
+    assert_eq!(a.len(), 4);
+    let a: FixedSizeListArray = a.into();
+
+    let mut expected = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 2);
+    expected
+        .try_extend([
+            Some(vec![Some(1), Some(2)]),
+            None,
+            None,
+            Some(vec![Some(7), None]),
+        ])
+        .unwrap();
+    let expected: FixedSizeListArray = expected.into();
+    assert_eq!(a, expected);
+}
+
+#[test]
+fn reserve() {
+    let mut a = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 3);
+    a.reserve(10);
+    assert!(a.values().capacity() >= 30);
+}
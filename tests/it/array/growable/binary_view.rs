@@ -0,0 +1,69 @@
+use arrow2::array::{
+    growable::{Growable, GrowableBinaryView, GrowableStringView},
+    BinaryViewArray, StringViewArray,
+};
+
+#[test]
+fn validity() {
+    let array = BinaryViewArray::from([
+        Some(b"a".as_ref()),
+        Some(b"a value longer than 12"),
+        None,
+        Some(b"defh"),
+    ]);
+
+    let mut a = GrowableBinaryView::new(vec![&array], false, 0);
+
+    a.extend(0, 1, 2);
+    a.extend_validity(1);
+
+    let result: BinaryViewArray = a.into();
+
+    let expected = BinaryViewArray::from([Some(b"a value longer than 12".as_ref()), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn multiple_buffers() {
+    let array1 = BinaryViewArray::from_slice([b"a first value longer than 12".as_ref(), b"a"]);
+    let array2 = BinaryViewArray::from([Some(b"a second value longer than 12".as_ref()), None]);
+
+    let mut a = GrowableBinaryView::new(vec![&array1, &array2], false, 4);
+
+    a.extend(1, 0, 2);
+    a.extend(0, 0, 2);
+
+    let result: BinaryViewArray = a.into();
+
+    // the data buffers are shared, not copied
+    assert_eq!(result.data_buffers().len(), 2);
+    let expected = BinaryViewArray::from([
+        Some(b"a second value longer than 12".as_ref()),
+        None,
+        Some(b"a first value longer than 12"),
+        Some(b"a"),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn string_view() {
+    let array1 = StringViewArray::from_slice(["a first string longer than 12", "a"]);
+    let array2 = StringViewArray::from([Some("b"), Some("a second string longer than 12")]);
+    let array2 = array2.slice(1, 1);
+
+    let mut a = GrowableStringView::new(vec![&array1, &array2], false, 0);
+
+    a.extend(0, 0, 1);
+    a.extend(1, 0, 1);
+    a.extend(0, 1, 1);
+
+    let result: StringViewArray = a.into();
+
+    let expected = StringViewArray::from_slice([
+        "a first string longer than 12",
+        "a second string longer than 12",
+        "a",
+    ]);
+    assert_eq!(result, expected);
+}
@@ -44,11 +44,11 @@ fn test_multi() -> Result<()> {
     array2.try_extend(data2)?;
     let array2: DictionaryArray<i32> = array2.into();
 
-    // same values, less keys
+    // merged values, remapped keys
     original_data1.extend(original_data2.iter().cloned());
     let expected = DictionaryArray::try_from_keys(
-        PrimitiveArray::from(&[Some(1), None, Some(3), None]),
-        Utf8Array::<i32>::from_slice(["a", "b", "c", "b", "a"]).boxed(),
+        PrimitiveArray::from(&[Some(1), None, Some(1), None]),
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
     )
     .unwrap();
 
@@ -62,3 +62,42 @@ fn test_multi() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_multi_primitive() -> Result<()> {
+    let mut array1 = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+    array1.try_extend([Some(1), Some(2), None])?;
+    let array1: DictionaryArray<u8> = array1.into();
+
+    let mut array2 = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+    array2.try_extend([Some(3), Some(1)])?;
+    let array2: DictionaryArray<u8> = array2.into();
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2], false, 0);
+    growable.extend(1, 0, 2);
+    growable.extend_validity(1);
+    growable.extend(0, 1, 2);
+
+    let result: DictionaryArray<u8> = growable.into();
+
+    let expected = DictionaryArray::try_from_keys(
+        PrimitiveArray::from([Some(2u8), Some(0), None, Some(1), None]),
+        Int64Array::from_slice([1, 2, 3]).boxed(),
+    )?;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "The merged values of the dictionaries do not fit in keys of type Int8")]
+fn test_keys_overflow() {
+    let dictionary = |range: std::ops::Range<i32>| -> DictionaryArray<i8> {
+        let mut array = MutableDictionaryArray::<i8, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(range.map(Some)).unwrap();
+        array.into()
+    };
+    let array1 = dictionary(0..100);
+    let array2 = dictionary(100..200);
+
+    GrowableDictionary::new(&[&array1, &array2], false, 0);
+}
@@ -0,0 +1,73 @@
+use arrow2::{
+    array::{
+        growable::{Growable, GrowableMap},
+        Int32Array, MapArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+};
+
+fn entries() -> DataType {
+    DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ])
+}
+
+fn map(keys: &[&str], values: &[i32], offsets: Vec<i32>, validity: Option<Bitmap>) -> MapArray {
+    let data_type = DataType::Map(Box::new(Field::new("entries", entries(), false)), false);
+    let field = StructArray::new(
+        entries(),
+        vec![
+            Utf8Array::<i32>::from_slice(keys).boxed(),
+            Int32Array::from_slice(values).boxed(),
+        ],
+        None,
+    );
+    MapArray::new(
+        data_type,
+        offsets.try_into().unwrap(),
+        field.boxed(),
+        validity,
+    )
+}
+
+#[test]
+fn basic() {
+    // [{a: 1}, {b: 2, c: 3}, {d: 4}]
+    let array = map(&["a", "b", "c", "d"], &[1, 2, 3, 4], vec![0, 1, 3, 4], None);
+
+    let mut a = GrowableMap::new(vec![&array], false, 0);
+    a.extend(0, 1, 2);
+
+    let result: MapArray = a.into();
+
+    let expected = map(&["b", "c", "d"], &[2, 3, 4], vec![0, 2, 3], None);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn multiple_with_validity() {
+    let array1 = map(&["a", "b"], &[1, 2], vec![0, 1, 2], None);
+    let array2 = map(
+        &["c"],
+        &[3],
+        vec![0, 0, 1],
+        Some(Bitmap::from([false, true])),
+    );
+
+    let mut a = GrowableMap::new(vec![&array1, &array2], false, 0);
+    a.extend(1, 0, 2);
+    a.extend_validity(1);
+    a.extend(0, 0, 1);
+
+    let result: MapArray = a.into();
+
+    let expected = map(
+        &["c", "a"],
+        &[3, 1],
+        vec![0, 0, 1, 1, 2],
+        Some(Bitmap::from([false, true, false, true])),
+    );
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,47 @@
+use arrow2::array::{
+    growable::{Growable, GrowableRunEndEncoded},
+    *,
+};
+
+#[test]
+fn extend() {
+    let array = ree_encode(&Int32Array::from([
+        Some(1),
+        Some(1),
+        None,
+        Some(2),
+        Some(2),
+    ]));
+
+    for length in 1..array.len() {
+        for index in 0..(array.len() - length + 1) {
+            let mut a = GrowableRunEndEncoded::new(vec![&array]);
+
+            a.extend(0, index, length);
+            let expected = array.slice(index, length);
+
+            let result: RunEndEncodedArray<i32> = a.into();
+
+            assert_eq!(expected, result);
+        }
+    }
+}
+
+#[test]
+fn merges_runs() {
+    let array1 = ree_encode(&Int32Array::from([Some(1), Some(1), Some(2)]));
+    let array2 = ree_encode(&Int32Array::from([Some(2), None, Some(3)]));
+
+    let mut a = GrowableRunEndEncoded::new(vec![&array1, &array2]);
+    a.extend(0, 1, 2);
+    a.extend(1, 0, 2);
+    a.extend_validity(2);
+    a.extend(1, 2, 1);
+
+    let result: RunEndEncodedArray<i32> = a.into();
+    assert_eq!(result.run_ends().as_slice(), &[1, 3, 6, 7]);
+    assert_eq!(
+        result.iter().collect::<Vec<_>>(),
+        vec![Some(1), Some(2), Some(2), None, None, None, Some(3)]
+    );
+}
@@ -125,3 +125,55 @@ fn many() {
     );
     assert_eq!(expected, result.as_ref())
 }
+
+#[test]
+fn many_with_nulls() {
+    let (fields, values) = some_values();
+
+    let array1 = StructArray::new(
+        fields.clone(),
+        values.clone(),
+        Some([true, false, false, true, true].into()),
+    );
+    let array2 = StructArray::new(fields.clone(), values, None);
+
+    let mut mutable = GrowableStruct::new(vec![&array1, &array2], false, 0);
+
+    mutable.extend(0, 0, 5);
+    mutable.extend(1, 3, 2);
+    let result: StructArray = mutable.into();
+
+    let expected_string = Utf8Array::<i32>::from([
+        Some("a"),
+        None,
+        None,
+        Some("mark"),
+        Some("doe"),
+        Some("mark"),
+        Some("doe"),
+    ]);
+    let expected_int =
+        PrimitiveArray::<i32>::from([Some(1), None, None, Some(4), Some(5), Some(4), Some(5)]);
+
+    let expected = StructArray::new(
+        fields,
+        vec![expected_string.boxed(), expected_int.boxed()],
+        Some([true, false, false, true, true, true, true].into()),
+    );
+    assert_eq!(result, expected)
+}
+
+#[test]
+fn extension() {
+    let (fields, values) = some_values();
+    let data_type = DataType::Extension("ext".to_string(), Box::new(fields), None);
+
+    let array = StructArray::new(data_type.clone(), values, None);
+
+    let mut mutable = GrowableStruct::new(vec![&array], false, 0);
+    mutable.extend(0, 1, 2);
+    let result = mutable.as_box();
+
+    assert_eq!(result.data_type(), &data_type);
+    assert_eq!(result.len(), 2);
+}
@@ -68,3 +68,128 @@ fn dense() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dense_multiple_arrays_and_nulls() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![3, 5]), UnionMode::Dense);
+    // [1, "a"]
+    let array1 = UnionArray::new(
+        data_type.clone(),
+        vec![3, 5].into(),
+        vec![
+            Int32Array::from_slice([1]).boxed(),
+            Utf8Array::<i32>::from_slice(["a"]).boxed(),
+        ],
+        Some(vec![0, 0].into()),
+    );
+    // ["b", 2]
+    let array2 = UnionArray::new(
+        data_type.clone(),
+        vec![5, 3].into(),
+        vec![
+            Int32Array::from_slice([2]).boxed(),
+            Utf8Array::<i32>::from_slice(["b"]).boxed(),
+        ],
+        Some(vec![0, 0].into()),
+    );
+
+    let mut a = GrowableUnion::new(vec![&array1, &array2], 10);
+    a.extend(0, 0, 2);
+    a.extend_validity(1);
+    a.extend(1, 0, 2);
+    let result: UnionArray = a.into();
+
+    // [1, "a", null, "b", 2]
+    let expected = UnionArray::new(
+        data_type,
+        vec![3, 5, 3, 5, 3].into(),
+        vec![
+            Int32Array::from([Some(1), None, Some(2)]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1, 2].into()),
+    );
+    assert_eq!(expected, result);
+
+    Ok(())
+}
+
+enum Value<'a> {
+    Int(Option<i32>),
+    Str(Option<&'a str>),
+}
+
+fn dense_union(values: &[Value]) -> Result<UnionArray> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let mut array = MutableUnionArray::try_new(
+        data_type,
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    )?;
+    for value in values {
+        match value {
+            Value::Int(value) => array.try_push::<MutablePrimitiveArray<i32>, _>(0, *value)?,
+            Value::Str(value) => array.try_push::<MutableUtf8Array<i32>, _>(1, *value)?,
+        }
+    }
+    Ok(array.into())
+}
+
+#[test]
+fn dense_runs() -> Result<()> {
+    let array1 = dense_union(&[
+        Value::Int(Some(1)),
+        Value::Int(Some(2)),
+        Value::Str(Some("a")),
+        Value::Int(None),
+    ])?;
+    let array2 = dense_union(&[Value::Str(Some("b")), Value::Str(None), Value::Int(Some(3))])?;
+
+    let mut a = GrowableUnion::new(vec![&array1, &array2], 10);
+
+    a.extend(0, 0, 4);
+    a.extend(1, 0, 3);
+    a.extend(0, 1, 2);
+
+    let result: UnionArray = a.into();
+
+    let expected = dense_union(&[
+        Value::Int(Some(1)),
+        Value::Int(Some(2)),
+        Value::Str(Some("a")),
+        Value::Int(None),
+        Value::Str(Some("b")),
+        Value::Str(None),
+        Value::Int(Some(3)),
+        Value::Int(Some(2)),
+        Value::Str(Some("a")),
+    ])?;
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn different_data_types_is_error() -> Result<()> {
+    let dense = dense_union(&[Value::Int(Some(1))])?;
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let sparse = UnionArray::new_null(data_type, 1);
+
+    assert!(GrowableUnion::try_new(vec![&dense, &sparse], 0).is_err());
+    assert!(GrowableUnion::try_new(vec![], 0).is_err());
+    Ok(())
+}
@@ -68,3 +68,28 @@ fn test_nested_display() {
     let expected = "ListArray[[[1, 2], [3, 4]], [[5, 6, 7], [], [8]], [[9, 10]]]";
     assert_eq!(format!("{:?}", nested), expected);
 }
+
+#[test]
+fn lengths() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend([
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![None, Some(4)]),
+        ])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let expected = Int32Array::from([Some(3), None, Some(0), Some(2)]);
+    assert_eq!(array.lengths(), expected);
+
+    let expected = Int32Array::from([None, Some(0)]);
+    assert_eq!(array.slice(1, 2).lengths(), expected);
+
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    array.try_extend([None, Some(vec![Some(1)])]).unwrap();
+    let array: ListArray<i64> = array.into();
+    assert_eq!(array.lengths(), Int64Array::from([None, Some(1)]));
+}
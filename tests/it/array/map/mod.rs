@@ -52,3 +52,156 @@ fn basics() {
         )) as Box<dyn Array>
     );
 }
+
+#[test]
+fn mutable() {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.append_key_value("a", Some(1)).unwrap();
+    array.append_key_value("b", None).unwrap();
+    array.try_push_valid().unwrap();
+    array.try_push(None::<Vec<(&str, Option<i32>)>>).unwrap();
+    array.try_push(Some(vec![("c", Some(3))])).unwrap();
+    let array: MapArray = array.into();
+
+    assert_eq!(
+        array.data_type(),
+        &MapArray::default_datatype(DataType::Utf8, DataType::Int32)
+    );
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 3]);
+    assert_eq!(array.validity(), Some(&[true, false, true].into()));
+
+    let entries = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        entries.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        entries.values()[1].as_ref(),
+        &Int32Array::from([Some(1), None, Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        format!("{:?}", array),
+        "MapArray[[{key: a, value: 1}, {key: b, value: None}], None, [{key: c, value: 3}]]"
+    );
+}
+
+#[test]
+fn mutable_unequal_lengths() {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.mut_keys().push(Some("a"));
+    assert!(array.try_push_valid().is_err());
+}
+
+#[test]
+fn mutable_invalid_data_type() {
+    let keys = MutableUtf8Array::<i32>::new();
+    let values = MutablePrimitiveArray::<i32>::new();
+    let data_type = MapArray::default_datatype(DataType::Utf8, DataType::Int64);
+    assert!(MutableMapArray::try_new(data_type, keys, values).is_err());
+}
+
+#[test]
+fn from_hash_maps() {
+    let maps = vec![
+        std::collections::HashMap::from([("a", Some(1))]),
+        std::collections::HashMap::new(),
+    ];
+    let array: MutableMapArray<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>> = maps.into();
+    let array: MapArray = array.into();
+
+    assert_eq!(array.offsets().as_slice(), &[0, 1, 1]);
+    assert_eq!(array.validity(), None);
+}
+
+type Entries<'a> = Vec<(&'a str, Option<i64>)>;
+
+fn utf8_i64_map(rows: Vec<Option<Entries>>) -> MapArray {
+    MapArray::try_from_iter::<MutableUtf8Array<i32>, MutablePrimitiveArray<i64>, _, _, _, _>(rows)
+        .unwrap()
+}
+
+#[test]
+fn try_from_iter() {
+    let array = utf8_i64_map(vec![
+        Some(vec![("a", Some(1)), ("b", None)]),
+        None,
+        Some(vec![]),
+        Some(vec![("c", Some(3))]),
+    ]);
+
+    assert_eq!(
+        array.data_type(),
+        &MapArray::default_datatype(DataType::Utf8, DataType::Int64)
+    );
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2, 3]);
+    assert_eq!(array.validity(), Some(&[true, false, true, true].into()));
+    assert_eq!(
+        array.keys().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        array.values().as_ref(),
+        &Int64Array::from([Some(1), None, Some(3)]) as &dyn Array
+    );
+
+    let sliced = array.slice(2, 2);
+    assert_eq!(sliced.len(), 2);
+    assert_eq!(sliced.validity(), None);
+    assert_eq!(sliced.value(0).len(), 0);
+    let entries = sliced.value(1);
+    let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        entries.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["c"]) as &dyn Array
+    );
+    assert_eq!(
+        entries.values()[1].as_ref(),
+        &Int64Array::from_slice([3]) as &dyn Array
+    );
+    // the keys and values are not sliced
+    assert_eq!(sliced.keys().len(), 3);
+}
+
+#[test]
+fn null_keys() {
+    let data_type = MapArray::default_datatype(DataType::Utf8, DataType::Int64);
+    let entries_type = match &data_type {
+        DataType::Map(field, _) => field.data_type().clone(),
+        _ => unreachable!(),
+    };
+    let entries = StructArray::new(
+        entries_type,
+        vec![
+            Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+            Int64Array::from_slice([1, 2]).boxed(),
+        ],
+        None,
+    );
+    let offsets = vec![0, 2].try_into().unwrap();
+    assert!(MapArray::try_new(data_type, offsets, entries.boxed(), None).is_err());
+}
+
+#[test]
+fn sorted_keys() {
+    let array = utf8_i64_map(vec![
+        Some(vec![("a", None), ("b", None)]),
+        Some(vec![("a", None)]),
+    ]);
+    let field = match array.data_type() {
+        DataType::Map(field, _) => field.clone(),
+        _ => unreachable!(),
+    };
+    let sorted = DataType::Map(field, true);
+    let offsets = array.offsets().clone();
+    assert!(MapArray::try_new(sorted.clone(), offsets, array.field().clone(), None).is_ok());
+
+    let array = utf8_i64_map(vec![Some(vec![("b", None), ("a", None)])]);
+    let offsets = array.offsets().clone();
+    assert!(MapArray::try_new(sorted, offsets, array.field().clone(), None).is_err());
+}
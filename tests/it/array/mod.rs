@@ -1,4 +1,5 @@
 mod binary;
+mod binary_view;
 mod boolean;
 mod dictionary;
 mod equal;
@@ -9,11 +10,16 @@ mod list;
 mod map;
 mod ord;
 mod primitive;
+mod run_end_encoded;
+mod string_view;
 mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use arrow2::array::{
+    clone, new_empty_array, new_null_array, try_new_empty_array, try_new_null_array, Array,
+    PrimitiveArray, RunEndEncodedArray,
+};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::{DataType, Field, UnionMode};
 
@@ -73,6 +79,17 @@ fn empty() {
     assert!(a);
 }
 
+#[test]
+fn run_end_encoded_of_non_primitive() {
+    let data_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Int32);
+    assert!(try_new_empty_array(data_type.clone()).is_ok());
+    assert_eq!(try_new_null_array(data_type, 10).unwrap().len(), 10);
+
+    let data_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Utf8);
+    assert!(try_new_empty_array(data_type.clone()).is_err());
+    assert!(try_new_null_array(data_type, 10).is_err());
+}
+
 #[test]
 fn test_clone() {
     let datatypes = vec![
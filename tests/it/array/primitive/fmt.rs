@@ -1,7 +1,7 @@
 use arrow2::{
     array::*,
     datatypes::*,
-    types::{days_ms, months_days_ns},
+    types::{days_ms, i256, months_days_ns},
 };
 
 #[test]
@@ -203,6 +203,21 @@ fn debug_decimal() {
     );
 }
 
+#[test]
+fn debug_decimal256() {
+    let array = Int256Array::from(&[
+        Some(i256::from(12345)),
+        None,
+        Some(i256::from(-5)),
+        Some(i256::from(-12305)),
+    ])
+    .to(DataType::Decimal256(50, 2));
+    assert_eq!(
+        format!("{:?}", array),
+        "Decimal256(50, 2)[123.45, None, -0.05, -123.05]"
+    );
+}
+
 #[test]
 fn debug_decimal1() {
     let array = Int128Array::from(&[Some(12345), None, Some(23456)]).to(DataType::Decimal(5, 1));
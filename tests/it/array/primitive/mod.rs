@@ -125,6 +125,27 @@ fn into_mut_3() {
     assert!(array.into_mut().is_right());
 }
 
+#[test]
+fn apply_values_mut() {
+    let mut array = Int32Array::from([Some(1), None, Some(3)]);
+    let ptr = array.values().as_slice().as_ptr();
+    array.apply_values_mut(|values| values.iter_mut().for_each(|x| *x += 1));
+    assert_eq!(array, Int32Array::from([Some(2), None, Some(4)]));
+    assert_eq!(array.values().as_slice().as_ptr(), ptr);
+
+    // shared values are copied
+    let shared = array.clone();
+    array.apply_values_mut(|values| values.iter_mut().for_each(|x| *x += 1));
+    assert_eq!(array, Int32Array::from([Some(3), None, Some(5)]));
+    assert_ne!(array.values().as_slice().as_ptr(), ptr);
+    assert_eq!(shared, Int32Array::from([Some(2), None, Some(4)]));
+
+    // sliced values are copied
+    let mut array = Int32Array::from_slice([1, 2, 3]).slice(1, 2);
+    array.apply_values_mut(|values| values.iter_mut().for_each(|x| *x *= 10));
+    assert_eq!(array, Int32Array::from_slice([20, 30]));
+}
+
 #[test]
 fn into_iter() {
     let data = vec![Some(1), None, Some(10)];
@@ -329,3 +329,28 @@ fn extend_from_self() {
         MutablePrimitiveArray::from([Some(1), None, Some(1), None])
     );
 }
+
+#[test]
+fn extend_from_self_validity() {
+    let valid = MutablePrimitiveArray::from([Some(1), Some(2)]);
+    let nulls = MutablePrimitiveArray::from([None, Some(3)]);
+
+    let mut a = valid.clone();
+    a.try_extend_from_self(&nulls).unwrap();
+    let mut expected = valid.clone();
+    expected.extend(nulls.iter().map(|x| x.copied()));
+    assert_eq!(a, expected);
+    assert_eq!(a.validity().unwrap().len(), 4);
+
+    let mut a = nulls.clone();
+    a.try_extend_from_self(&valid).unwrap();
+    assert_eq!(
+        a,
+        MutablePrimitiveArray::from([None, Some(3), Some(1), Some(2)])
+    );
+    assert_eq!(a.validity().unwrap().len(), 4);
+    assert_eq!(
+        Int32Array::from(a),
+        Int32Array::from([None, Some(3), Some(1), Some(2)])
+    );
+}
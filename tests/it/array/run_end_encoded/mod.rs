@@ -0,0 +1,176 @@
+mod mutable;
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{new_scalar, PrimitiveScalar};
+
+fn data_type() -> DataType {
+    RunEndEncodedArray::<i32>::default_data_type(DataType::Int32)
+}
+
+#[test]
+fn basics() {
+    let values = Int32Array::from([Some(1), None, Some(2)]);
+    let array = RunEndEncodedArray::new(data_type(), vec![2, 3, 6].into(), values);
+
+    assert_eq!(array.len(), 6);
+    assert_eq!(array.offset(), 0);
+    assert_eq!(array.validity(), None);
+    assert_eq!(array.get(0), Some(1));
+    assert_eq!(array.get(1), Some(1));
+    assert_eq!(array.get(2), None);
+    assert_eq!(array.get(5), Some(2));
+    assert_eq!(array.get_physical_index(3), 2);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some(1), Some(1), None, Some(2), Some(2), Some(2)]
+    );
+    assert_eq!(
+        format!("{:?}", array),
+        "RunEndEncodedArray[1, 1, None, 2, 2, 2]"
+    );
+}
+
+#[test]
+fn try_new_errors() {
+    let values = || Int32Array::from_slice([1, 2]);
+    let try_new =
+        |run_ends: Vec<i32>| RunEndEncodedArray::try_new(data_type(), run_ends.into(), values());
+
+    assert!(try_new(vec![1, 3]).is_ok());
+    // not strictly increasing
+    assert!(try_new(vec![3, 3]).is_err());
+    // the first run is empty
+    assert!(try_new(vec![0, 3]).is_err());
+    // different lengths
+    assert!(try_new(vec![3]).is_err());
+
+    // values of a different type
+    let data_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Date32);
+    assert!(RunEndEncodedArray::try_new(data_type, vec![1, 2].into(), values()).is_err());
+
+    // run ends of a different type
+    let data_type = DataType::RunEndEncoded(
+        Box::new(Field::new("run_ends", DataType::Int64, false)),
+        Box::new(Field::new("values", DataType::Int32, true)),
+    );
+    assert!(RunEndEncodedArray::try_new(data_type, vec![1, 2].into(), values()).is_err());
+
+    assert!(RunEndEncodedArray::try_new(DataType::Int32, vec![1, 2].into(), values()).is_err());
+}
+
+#[test]
+fn slice() {
+    let values = Int32Array::from([Some(1), None, Some(2)]);
+    let array = RunEndEncodedArray::new(data_type(), vec![2, 3, 6].into(), values);
+
+    let sliced = array.slice(1, 3);
+    assert_eq!(sliced.len(), 3);
+    assert_eq!(sliced.offset(), 1);
+    assert_eq!(sliced.run_ends(), array.run_ends());
+    assert_eq!(sliced.get_physical_index(0), 0);
+    assert_eq!(sliced.get_physical_index(1), 1);
+    assert_eq!(
+        sliced.iter().collect::<Vec<_>>(),
+        vec![Some(1), None, Some(2)]
+    );
+    assert_eq!(
+        ree_decode(&sliced),
+        Int32Array::from([Some(1), None, Some(2)])
+    );
+
+    let sliced = sliced.slice(2, 1);
+    assert_eq!(sliced.iter().collect::<Vec<_>>(), vec![Some(2)]);
+
+    let sliced = array.slice(6, 0);
+    assert_eq!(sliced.iter().count(), 0);
+    assert_eq!(ree_decode(&sliced), Int32Array::from_slice([]));
+}
+
+#[test]
+fn encode_decode() {
+    let array = Int32Array::from([Some(1), Some(1), None, None, Some(1), Some(2), Some(2)]);
+
+    let encoded = ree_encode(&array);
+    assert_eq!(encoded.run_ends().as_slice(), &[2, 4, 5, 7]);
+    assert_eq!(
+        encoded.values(),
+        &Int32Array::from([Some(1), None, Some(1), Some(2)])
+    );
+    assert_eq!(ree_decode(&encoded), array);
+
+    let array = array.slice(1, 4);
+    assert_eq!(ree_decode(&ree_encode(&array)), array);
+}
+
+#[test]
+fn encode_decode_logical_type() {
+    let array = Int32Array::from_slice([1, 1, 2]).to(DataType::Date32);
+
+    let encoded = ree_encode(&array);
+    assert_eq!(
+        encoded.data_type(),
+        &RunEndEncodedArray::<i32>::default_data_type(DataType::Date32)
+    );
+    let decoded = ree_decode(&encoded);
+    assert_eq!(decoded.data_type(), &DataType::Date32);
+    assert_eq!(decoded, array);
+}
+
+#[test]
+fn encode_empty_and_nulls() {
+    let array = Int32Array::from_slice([]);
+    let encoded = ree_encode(&array);
+    assert!(encoded.is_empty());
+    assert!(encoded.run_ends().is_empty());
+
+    let array = Int32Array::new_null(DataType::Int32, 3);
+    let encoded = ree_encode(&array);
+    assert_eq!(encoded.run_ends().as_slice(), &[3]);
+    assert_eq!(ree_decode(&encoded), array);
+}
+
+#[test]
+fn empty_and_null() {
+    let array = new_empty_array(data_type());
+    assert_eq!(array.len(), 0);
+
+    let array = new_null_array(data_type(), 3);
+    let array = array
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i32>>()
+        .unwrap();
+    assert_eq!(array.run_ends().as_slice(), &[3]);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![None, None, None]);
+}
+
+#[test]
+fn equal() {
+    let lhs = RunEndEncodedArray::new(
+        data_type(),
+        vec![1, 3].into(),
+        Int32Array::from_slice([1, 1]),
+    );
+    let rhs = RunEndEncodedArray::new(data_type(), vec![3].into(), Int32Array::from_slice([1]));
+    assert_eq!(lhs, rhs);
+    assert_eq!(&lhs as &dyn Array, &rhs as &dyn Array);
+
+    let rhs = RunEndEncodedArray::new(data_type(), vec![2].into(), Int32Array::from_slice([1]));
+    assert!(lhs != rhs);
+    assert_eq!(lhs.slice(1, 2), rhs);
+}
+
+#[test]
+fn scalar() {
+    let array = ree_encode(&Int32Array::from([Some(1), None]));
+
+    let scalar = new_scalar(&array, 0);
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<i32>>()
+        .unwrap();
+    assert_eq!(scalar.value(), &Some(1));
+
+    let scalar = new_scalar(&array, 1);
+    assert!(!scalar.is_valid());
+}
@@ -0,0 +1,66 @@
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Error;
+
+#[test]
+fn push() {
+    let mut array = MutableRunEndEncodedArray::<i32>::new();
+    array.push(Some(1));
+    array.push(Some(1));
+    array.push(None);
+    array.push(None);
+    array.push(Some(2));
+    array.push_null();
+
+    assert_eq!(array.len(), 6);
+    assert_eq!(array.run_ends(), &[2, 4, 5, 6]);
+    assert_eq!(array.values().len(), 4);
+
+    let array: RunEndEncodedArray<i32> = array.into();
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some(1), Some(1), None, None, Some(2), None]
+    );
+}
+
+#[test]
+fn extend_constant() {
+    let mut array = MutableRunEndEncodedArray::<i32>::new();
+    array.extend_constant(0, Some(1));
+    assert!(array.is_empty());
+
+    array.extend_constant(2, Some(1));
+    array.extend_constant(3, Some(1));
+    array.extend_constant(1, None);
+    assert_eq!(array.run_ends(), &[5, 6]);
+
+    let result = array.try_extend_constant(i32::MAX as usize, Some(1));
+    assert!(matches!(result, Err(Error::Overflow)));
+    assert_eq!(array.run_ends(), &[5, 6]);
+}
+
+#[test]
+fn try_extend_and_from_iter() {
+    let mut array = MutableRunEndEncodedArray::<i32>::new();
+    array.try_extend([Some(1), Some(1), Some(3)]).unwrap();
+    array.try_push(Some(3)).unwrap();
+    assert_eq!(array.run_ends(), &[2, 4]);
+
+    let other = [Some(1), Some(1), Some(3), Some(3)]
+        .into_iter()
+        .collect::<MutableRunEndEncodedArray<i32>>();
+    assert_eq!(other.run_ends(), array.run_ends());
+}
+
+#[test]
+fn as_box_keeps_data_type() {
+    let data_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Date32);
+    let mut array = MutableRunEndEncodedArray::<i32>::with_capacity_from(2, data_type.clone());
+    array.push(Some(1));
+
+    let boxed = array.as_box();
+    assert_eq!(boxed.data_type(), &data_type);
+    assert_eq!(boxed.len(), 1);
+    assert_eq!(array.len(), 0);
+    assert_eq!(MutableArray::data_type(&array), &data_type);
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{BinaryViewArray, StringViewArray, Utf8Array, View},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+};
+
+#[test]
+fn basics() {
+    let data = vec![Some("hello"), None, Some("hello world, long")];
+
+    let array: StringViewArray = data.into_iter().collect();
+
+    assert_eq!(array.value(0), "hello");
+    assert_eq!(array.value(1), "");
+    assert_eq!(array.value(2), "hello world, long");
+    assert_eq!(unsafe { array.value_unchecked(2) }, "hello world, long");
+    assert_eq!(array.get(1), None);
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from_u8_slice([0b00000101], 3))
+    );
+
+    let array2 = StringViewArray::new(
+        DataType::Utf8View,
+        array.views().clone(),
+        array.data_buffers().clone(),
+        array.validity().cloned(),
+    );
+    assert_eq!(array, array2);
+
+    let array = array.slice(1, 2);
+    assert_eq!(array.value(0), "");
+    assert_eq!(array.value(1), "hello world, long");
+}
+
+#[test]
+fn try_new_invalid_utf8() {
+    let mut value = b"abcd".to_vec();
+    value.extend(std::iter::repeat(0xff).take(9));
+    let views = vec![View::new(&value, 0, 0)];
+    let buffers: Arc<[Buffer<u8>]> = vec![Buffer::from(value)].into();
+
+    assert!(StringViewArray::try_new(
+        DataType::Utf8View,
+        views.clone().into(),
+        buffers.clone(),
+        None
+    )
+    .is_err());
+    // the same views are valid binary
+    assert!(BinaryViewArray::try_new(DataType::BinaryView, views.into(), buffers, None).is_ok());
+}
+
+#[test]
+fn try_new_invalid_inlined_utf8() {
+    let views = vec![View::new_inline(&[0xff, 0xfe])];
+    assert!(
+        StringViewArray::try_new(DataType::Utf8View, views.into(), Arc::new([]), None).is_err()
+    );
+}
+
+#[test]
+fn try_new_invalid_data_type() {
+    let array = StringViewArray::from_slice(["a"]);
+    let (_, views, buffers, validity) = array.into_inner();
+    assert!(StringViewArray::try_new(DataType::BinaryView, views, buffers, validity).is_err());
+}
+
+#[test]
+fn from_utf8() {
+    let utf8 = Utf8Array::<i32>::from([Some("a"), None, Some("a string longer than 12")]);
+
+    let array: StringViewArray = utf8.clone().into();
+    assert_eq!(array.data_type(), &DataType::Utf8View);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        utf8.iter().collect::<Vec<_>>()
+    );
+
+    let result: Utf8Array<i32> = array.into();
+    assert_eq!(result, utf8);
+}
+
+#[test]
+fn to_binary() {
+    let array = StringViewArray::from([Some("a"), None, Some("a string longer than 12")]);
+    let binary = array.to_binary();
+    assert_eq!(binary.data_type(), &DataType::BinaryView);
+    assert_eq!(
+        binary.iter().collect::<Vec<_>>(),
+        vec![Some(b"a".as_ref()), None, Some(b"a string longer than 12")]
+    );
+}
+
+#[test]
+fn debug() {
+    let array = StringViewArray::from([Some("aa"), Some(""), None]);
+
+    assert_eq!(format!("{:?}", array), "StringViewArray[aa, , None]");
+}
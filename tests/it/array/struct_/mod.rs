@@ -25,3 +25,30 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn column_by_name() {
+    let boolean = BooleanArray::from_slice([false, true]).boxed();
+    let int = Int32Array::from_slice([42, 28]).boxed();
+    let duplicate = Int32Array::from_slice([1, 2]).boxed();
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+        Field::new("c", DataType::Int32, false),
+    ];
+
+    let array = StructArray::new(
+        DataType::Struct(fields),
+        vec![boolean.clone(), int.clone(), duplicate],
+        None,
+    );
+
+    assert_eq!(array.field_index("b"), Some(0));
+    assert_eq!(array.field_index("c"), Some(1));
+    assert_eq!(array.field_index("d"), None);
+
+    assert_eq!(array.column_by_name("b"), Some(boolean.as_ref()));
+    assert_eq!(array.column_by_name("c"), Some(int.as_ref()));
+    assert!(array.column_by_name("d").is_none());
+}
@@ -31,3 +31,98 @@ fn push() {
         &Vec::from([1, 0, 2])
     );
 }
+
+#[test]
+fn struct_of_lists() {
+    type List = MutableListArray<i32, MutablePrimitiveArray<i32>>;
+    type FixedList = MutableFixedSizeListArray<MutableUtf8Array<i32>>;
+
+    let list = List::new();
+    let fixed = FixedList::new(MutableUtf8Array::<i32>::new(), 2);
+    let data_type = DataType::Struct(vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("fixed", fixed.data_type().clone(), true),
+    ]);
+    let mut a = MutableStructArray::new(data_type.clone(), vec![Box::new(list), Box::new(fixed)]);
+
+    a.value::<List>(0)
+        .unwrap()
+        .try_push(Some(vec![Some(1), Some(2)]))
+        .unwrap();
+    a.value::<FixedList>(1)
+        .unwrap()
+        .try_push(Some(vec![Some("a"), None]))
+        .unwrap();
+    a.push(true);
+    MutableArray::push_null(&mut a);
+    a.value::<List>(0)
+        .unwrap()
+        .try_push(Some(Vec::<Option<i32>>::new()))
+        .unwrap();
+    a.value::<FixedList>(1)
+        .unwrap()
+        .try_push(Some(vec![Some("b"), Some("c")]))
+        .unwrap();
+    a.push(true);
+
+    assert_eq!(a.len(), 3);
+    assert!(a.value::<List>(2).is_none());
+    assert!(a.value::<FixedList>(0).is_none());
+
+    let mut list = List::new();
+    list.try_extend([Some(vec![Some(1), Some(2)]), None, Some(vec![])])
+        .unwrap();
+    let mut fixed = FixedList::new(MutableUtf8Array::<i32>::new(), 2);
+    fixed
+        .try_extend([
+            Some(vec![Some("a"), None]),
+            None,
+            Some(vec![Some("b"), Some("c")]),
+        ])
+        .unwrap();
+    let expected = StructArray::new(
+        data_type,
+        vec![list.as_box(), fixed.as_box()],
+        Some([true, false, true].into()),
+    );
+
+    let a: StructArray = a.into();
+    assert_eq!(a, expected);
+}
+
+#[test]
+fn two_fields_with_nulls() {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    let mut a = MutableStructArray::new(data_type.clone(), values);
+
+    for (number, string, valid) in [
+        (Some(1), Some("x"), true),
+        (None, None, false),
+        (Some(3), None, true),
+        (None, None, false),
+    ] {
+        a.value::<MutablePrimitiveArray<i32>>(0)
+            .unwrap()
+            .push(number);
+        a.value::<MutableUtf8Array<i32>>(1).unwrap().push(string);
+        a.push(valid);
+    }
+    assert_eq!(a.len(), 4);
+
+    let expected = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from([Some(1), None, Some(3), None]).boxed(),
+            Utf8Array::<i32>::from([Some("x"), None, None, None]).boxed(),
+        ],
+        Some([true, false, true, false].into()),
+    );
+    assert_eq!(a.as_box().as_ref(), &expected as &dyn Array);
+}
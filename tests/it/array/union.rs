@@ -371,3 +371,98 @@ fn types_must_be_in_bounds() -> Result<()> {
     assert!(UnionArray::try_new(data_type, types, fields.clone(), None).is_err());
     Ok(())
 }
+
+fn mutable_union(mode: UnionMode, ids: Option<Vec<i32>>) -> Result<MutableUnionArray> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, ids, mode);
+    MutableUnionArray::try_new(
+        data_type,
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    )
+}
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let mut array = mutable_union(UnionMode::Dense, None)?;
+    array.try_push::<MutablePrimitiveArray<i32>, _>(0, Some(1))?;
+    array.try_push_null(0)?;
+    array.try_push::<MutableUtf8Array<i32>, _>(1, Some("c"))?;
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.types(), &[0, 0, 1]);
+    assert_eq!(array.offsets(), Some([0, 1, 0].as_ref()));
+
+    let array: UnionArray = array.into();
+    assert_eq!(format!("{:?}", array), "UnionArray[1, None, c]");
+    assert_eq!(array.fields()[1].len(), 1);
+
+    let value = array.value(2);
+    let value = value.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+    assert_eq!(value.value(), Some("c"));
+    Ok(())
+}
+
+#[test]
+fn mutable_sparse() -> Result<()> {
+    let mut array = mutable_union(UnionMode::Sparse, None)?;
+    array.try_push::<MutablePrimitiveArray<i32>, _>(0, Some(1))?;
+    array.try_push::<MutableUtf8Array<i32>, _>(1, Some("b"))?;
+    MutableArray::push_null(&mut array);
+
+    assert_eq!(array.offsets(), None);
+
+    let array: UnionArray = array.into();
+    assert_eq!(format!("{:?}", array), "UnionArray[1, b, None]");
+    assert_eq!(
+        array.fields()[0].as_ref(),
+        &Int32Array::from([Some(1), None, None]) as &dyn Array
+    );
+    assert_eq!(
+        array.fields()[1].as_ref(),
+        &Utf8Array::<i32>::from([None, Some("b"), None]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn mutable_with_ids() -> Result<()> {
+    let mut array = mutable_union(UnionMode::Dense, Some(vec![5, 7]))?;
+    array.try_push::<MutableUtf8Array<i32>, _>(7, Some("a"))?;
+    array.try_push::<MutablePrimitiveArray<i32>, _>(5, Some(2))?;
+
+    // not a type id of the union
+    assert!(array
+        .try_push::<MutablePrimitiveArray<i32>, _>(0, Some(1))
+        .is_err());
+    // the field of type id 5 is not utf8
+    assert!(array
+        .try_push::<MutableUtf8Array<i32>, _>(5, Some("a"))
+        .is_err());
+    assert_eq!(array.len(), 2);
+
+    let array: UnionArray = array.into();
+    assert_eq!(array.types().as_slice(), &[7, 5]);
+    assert_eq!(format!("{:?}", array), "UnionArray[a, 2]");
+    Ok(())
+}
+
+#[test]
+fn mutable_invalid() {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+
+    // wrong data type
+    let values: Vec<Box<dyn MutableArray>> = vec![Box::new(MutableUtf8Array::<i32>::new())];
+    assert!(MutableUnionArray::try_new(data_type.clone(), values).is_err());
+
+    // not empty
+    let values: Vec<Box<dyn MutableArray>> =
+        vec![Box::new(MutablePrimitiveArray::<i32>::from([Some(1)]))];
+    assert!(MutableUnionArray::try_new(data_type, values).is_err());
+}
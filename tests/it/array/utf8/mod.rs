@@ -216,3 +216,20 @@ fn iter_nth() {
     assert_eq!(array.iter().nth(1), Some(Some(" ")));
     assert_eq!(array.iter().nth(10), None);
 }
+
+#[test]
+fn lengths() {
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some(""), Some("héllo")]);
+    assert_eq!(
+        array.lengths(),
+        Int32Array::from([Some(5), None, Some(0), Some(6)])
+    );
+    assert_eq!(
+        array.char_lengths(),
+        Int32Array::from([Some(5), None, Some(0), Some(5)])
+    );
+
+    let array = Utf8Array::<i64>::from([Some("ü"), None]).slice(1, 1);
+    assert_eq!(array.lengths(), Int64Array::from([None]));
+    assert_eq!(array.char_lengths(), Int64Array::from([None]));
+}
@@ -67,3 +67,47 @@ fn shared_all() {
     let array = Utf8Array::<i32>::from([Some("hello"), Some(" "), None]);
     assert!(array.clone().into_mut().is_left())
 }
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn shared_offsets_unique_values() {
+    let offsets: OffsetsBuffer<i32> = vec![0, 1, 3].try_into().unwrap();
+    let array = Utf8Array::<i32>::new(
+        DataType::Utf8,
+        offsets.clone(),
+        b"abc".to_vec().into(),
+        Some(Bitmap::from([true, false])),
+    );
+    let expected = array.clone();
+    let array = array.into_mut().left().unwrap();
+    assert_eq!(array, expected);
+    assert_eq!(array.values().as_slice(), b"abc");
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some("world")]).slice(0, 2);
+    let mutable = array.into_mut().right().unwrap();
+    assert_eq!(mutable.len(), 2);
+    let array: Utf8Array<i32> = mutable.into();
+    assert_eq!(array, Utf8Array::<i32>::from([Some("hello"), None]));
+
+    // an offsetted slice can't be converted without a copy
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some("world")]).slice(1, 2);
+    assert!(array.into_mut().is_left());
+}
+
+#[test]
+fn apply_validity() {
+    let mut array = Utf8Array::<i32>::from([Some("hello"), None, Some("world")]);
+    array.apply_validity(|bitmap| {
+        let mut mutable = bitmap.make_mut();
+        mutable.set(0, false);
+        mutable.into()
+    });
+    assert_eq!(array, Utf8Array::<i32>::from([None, None, Some("world")]));
+
+    let mut array = Utf8Array::<i32>::from_slice(["hello"]);
+    array.apply_validity(|_| unreachable!());
+    assert_eq!(array.validity(), None);
+}
@@ -61,6 +61,14 @@ fn fast_paths() {
     let c = Bitmap::from_iter([false, false]);
     let b = b | &c;
     assert_eq!(b, MutableBitmap::from_iter([true, false]));
+
+    let mut b = MutableBitmap::from([true, false]);
+    b.and_not_with(&Bitmap::from_iter([true, true]));
+    assert_eq!(b, MutableBitmap::from_iter([false, false]));
+
+    let mut b = MutableBitmap::from([true, false]);
+    b.and_not_with(&Bitmap::from_iter([false, false]));
+    assert_eq!(b, MutableBitmap::from_iter([true, false]));
 }
 
 proptest! {
@@ -76,4 +84,24 @@ proptest! {
 
         assert_eq!(b, not_b);
     }
+
+    /// Asserts that `and_not_with` equals `lhs & !rhs` on each bit, for any offset of `rhs`
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn and_not_with(
+        values in prop::collection::vec(any::<(bool, bool)>(), 1..1000),
+        offset in 0usize..9,
+    ) {
+        let mut lhs: MutableBitmap = values.iter().map(|x| x.0).collect();
+        let rhs: Bitmap = std::iter::repeat(false)
+            .take(offset)
+            .chain(values.iter().map(|x| x.1))
+            .collect();
+        let rhs = rhs.slice(offset, values.len());
+
+        lhs.and_not_with(&rhs);
+
+        let expected: MutableBitmap = values.iter().map(|(x, y)| x & !y).collect();
+        assert_eq!(lhs, expected);
+    }
 }
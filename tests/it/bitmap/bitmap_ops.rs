@@ -1,6 +1,6 @@
 use proptest::prelude::*;
 
-use arrow2::bitmap::{and, or, xor, Bitmap};
+use arrow2::bitmap::{and, and_not, or, xor, Bitmap};
 
 use crate::bitmap::bitmap_strategy;
 
@@ -13,6 +13,60 @@ proptest! {
 
         assert_eq!(!&bitmap, not_bitmap);
     }
+
+    /// Asserts that binary operations equal the operations on each bit, aligned or not
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary(
+        values in prop::collection::vec(any::<(bool, bool)>(), 1..1000),
+        lhs_offset in 0usize..9,
+        rhs_offset in 0usize..9,
+    ) {
+        let lhs = bitmap_with_offset(values.iter().map(|x| x.0), lhs_offset);
+        let rhs = bitmap_with_offset(values.iter().map(|x| x.1), rhs_offset);
+
+        let expected: Bitmap = values.iter().map(|(x, y)| x & y).collect();
+        assert_eq!(&lhs & &rhs, expected);
+        let expected: Bitmap = values.iter().map(|(x, y)| x | y).collect();
+        assert_eq!(&lhs | &rhs, expected);
+        let expected: Bitmap = values.iter().map(|(x, y)| x ^ y).collect();
+        assert_eq!(&lhs ^ &rhs, expected);
+        let expected: Bitmap = values.iter().map(|(x, y)| x & !y).collect();
+        assert_eq!(and_not(&lhs, &rhs), expected);
+    }
+}
+
+fn bitmap_with_offset<I: Iterator<Item = bool>>(values: I, offset: usize) -> Bitmap {
+    let bitmap: Bitmap = std::iter::repeat(false)
+        .take(offset)
+        .chain(values)
+        .collect();
+    let length = bitmap.len() - offset;
+    bitmap.slice(offset, length)
+}
+
+#[test]
+fn aligned_large() {
+    // 1000 bits are 15 u64s and a remainder of 5 bytes
+    let lhs: Bitmap = (0..1000).map(|x| x % 3 == 0).collect();
+    let rhs: Bitmap = (0..1000).map(|x| x % 5 == 0).collect();
+
+    let result = &lhs & &rhs;
+    assert_eq!(result.unset_bits(), 1000 - 67);
+    assert!((0..1000).all(|i| result.get_bit(i) == (i % 15 == 0)));
+
+    let result = &lhs | &rhs;
+    assert!((0..1000).all(|i| result.get_bit(i) == (i % 3 == 0 || i % 5 == 0)));
+
+    let result = &lhs ^ &rhs;
+    assert!((0..1000).all(|i| result.get_bit(i) == ((i % 3 == 0) != (i % 5 == 0))));
+
+    let result = and_not(&lhs, &rhs);
+    assert!((0..1000).all(|i| result.get_bit(i) == (i % 3 == 0 && i % 5 != 0)));
+
+    let result = !&lhs;
+    assert_eq!(result.unset_bits(), 334);
+    assert!((0..1000).all(|i| result.get_bit(i) == (i % 3 != 0)));
 }
 
 #[test]
@@ -38,4 +92,11 @@ fn test_fast_paths() {
     assert_eq!(xor(&all_false, &all_true), all_true);
     assert_eq!(xor(&all_false, &all_false), all_false);
     assert_eq!(xor(&toggled, &toggled), all_false);
+
+    assert_eq!(and_not(&all_true, &all_true), all_false);
+    assert_eq!(and_not(&all_true, &all_false), all_true);
+    assert_eq!(and_not(&all_false, &all_true), all_false);
+    assert_eq!(and_not(&all_false, &toggled), all_false);
+    assert_eq!(and_not(&toggled, &all_false), toggled);
+    assert_eq!(and_not(&all_true, &toggled), Bitmap::from(&[false, true]));
 }
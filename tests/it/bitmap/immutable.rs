@@ -39,3 +39,118 @@ fn debug() {
 
     assert_eq!(format!("{:?}", b), "[0b111110__, 0b_______1]");
 }
+
+#[test]
+fn take() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let values = (0..100).map(|_| rng.gen::<bool>()).collect::<Vec<_>>();
+    // sliced, so that the offset is not a multiple of 8
+    let bitmap = Bitmap::from(values.as_slice()).slice(3, 90);
+    let values = &values[3..93];
+
+    let indices = (0..200)
+        .map(|_| rng.gen_range(0..90u32))
+        .collect::<Vec<_>>();
+    let expected = indices
+        .iter()
+        .map(|&index| values[index as usize])
+        .collect::<Vec<_>>();
+
+    let result = bitmap.take(&indices);
+    assert_eq!(result, Bitmap::from(expected.as_slice()));
+    assert_eq!(
+        result.unset_bits(),
+        expected.iter().filter(|x| !**x).count()
+    );
+}
+
+#[test]
+#[should_panic]
+fn take_out_of_bounds() {
+    let bitmap = Bitmap::from([true, false, true]);
+    let _ = bitmap.take(&[3u32]);
+}
+
+#[test]
+fn take_validity() {
+    use arrow2::bitmap::take_validity;
+
+    let indices = [2u32, 0, 0, 1];
+    assert_eq!(take_validity(None, &indices), None);
+
+    let all_valid = Bitmap::from([true, true, true]);
+    assert_eq!(take_validity(Some(&all_valid), &indices), None);
+
+    let validity = Bitmap::from([true, false, false]);
+    assert_eq!(
+        take_validity(Some(&validity), &indices),
+        Some(Bitmap::from([false, true, true, false]))
+    );
+}
+
+#[test]
+fn from_sorted_indices() {
+    let bitmap = Bitmap::from_sorted_indices(20, [0, 3, 3, 8, 19]);
+    assert_eq!(bitmap.len(), 20);
+    assert_eq!(bitmap.unset_bits(), 16);
+    assert!((0..20).all(|i| bitmap.get_bit(i) == [0, 3, 8, 19].contains(&i)));
+
+    let bitmap = Bitmap::from_sorted_indices(10, []);
+    assert_eq!(bitmap, Bitmap::new_zeroed(10));
+
+    let bitmap = Bitmap::from_sorted_indices(0, []);
+    assert!(bitmap.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn from_sorted_indices_unsorted() {
+    let _ = Bitmap::from_sorted_indices(10, [3, 1]);
+}
+
+#[test]
+#[should_panic]
+fn from_sorted_indices_out_of_bounds() {
+    let _ = Bitmap::from_sorted_indices(10, [1, 10]);
+}
+
+#[test]
+fn slice_is_zero_copy() {
+    let b = Bitmap::from_iter((0..100).map(|x| x % 3 == 0));
+    let ptr = b.as_slice().0.as_ptr();
+
+    let sliced = b.clone().slice(13, 50);
+    let (slice, offset, length) = sliced.as_slice();
+    assert_eq!(slice.as_ptr(), ptr.wrapping_add(1));
+    assert_eq!(offset, 5);
+    assert_eq!(length, 50);
+
+    // slices of unaligned slices keep sharing the same bytes
+    let sliced = sliced.slice(7, 20);
+    let (slice, offset, length) = sliced.as_slice();
+    assert_eq!(slice.as_ptr(), ptr.wrapping_add(2));
+    assert_eq!(offset, 4);
+    assert_eq!(length, 20);
+}
+
+#[test]
+fn slice_unaligned() {
+    let values = (0..100).map(|x| x % 3 == 0).collect::<Vec<_>>();
+    let b = Bitmap::from_iter(values.iter().copied());
+
+    for (offset, length) in [(1, 98), (3, 5), (13, 50), (61, 39), (99, 1), (7, 0)] {
+        let sliced = b.clone().slice(offset, length);
+        let expected = &values[offset..offset + length];
+
+        assert_eq!(sliced.len(), length);
+        assert_eq!(sliced.iter().collect::<Vec<_>>(), expected);
+        assert!((0..length).all(|i| sliced.get_bit(i) == expected[i]));
+        assert_eq!(
+            sliced.unset_bits(),
+            expected.iter().filter(|x| !**x).count()
+        );
+        assert_eq!(sliced, Bitmap::from_iter(expected.iter().copied()));
+    }
+}
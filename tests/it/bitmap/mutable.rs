@@ -26,6 +26,34 @@ fn try_new_invalid() {
     assert!(MutableBitmap::try_new(vec![], 2).is_err());
 }
 
+#[test]
+fn set_range() {
+    for (start, end) in [(0, 0), (0, 30), (3, 5), (3, 8), (3, 21), (8, 16), (9, 30)] {
+        let mut a = MutableBitmap::from_len_zeroed(30);
+        a.set_range(start, end);
+        assert!((0..30).all(|i| a.get(i) == (start..end).contains(&i)));
+        assert_eq!(a.unset_bits(), 30 - (end - start));
+
+        let mut a = MutableBitmap::from_len_set(30);
+        a.clear_range(start, end);
+        assert!((0..30).all(|i| a.get(i) != (start..end).contains(&i)));
+    }
+}
+
+#[test]
+#[should_panic]
+fn set_range_out_of_bounds() {
+    let mut a = MutableBitmap::from_len_zeroed(10);
+    a.set_range(5, 11);
+}
+
+#[test]
+#[should_panic]
+fn clear_range_inverted() {
+    let mut a = MutableBitmap::from_len_set(10);
+    a.clear_range(5, 4);
+}
+
 #[test]
 fn clear() {
     let mut a = MutableBitmap::from_len_zeroed(10);
@@ -424,6 +452,35 @@ fn extend_bitmap_other() {
     );
 }
 
+#[test]
+fn extend_from_slice_offsets() {
+    let slice = (0..40u8)
+        .map(|x| x.wrapping_mul(37) ^ 0b10100101)
+        .collect::<Vec<_>>();
+    let bits = MutableBitmap::from_vec(slice.clone(), slice.len() * 8)
+        .iter()
+        .collect::<Vec<_>>();
+
+    for own in [0, 3, 8, 13] {
+        for offset in [0, 1, 7, 8, 9, 15, 64, 67] {
+            for length in [0, 1, 5, 8, 63, 64, 65, 130, 200] {
+                let mut a = MutableBitmap::from_iter((0..own).map(|x| x % 2 == 0));
+                a.extend_from_slice(&slice, offset, length);
+
+                let mut expected = MutableBitmap::from_iter((0..own).map(|x| x % 2 == 0));
+                bits[offset..offset + length]
+                    .iter()
+                    .for_each(|x| expected.push(*x));
+                assert_eq!(a, expected, "{} {} {}", own, offset, length);
+                assert_eq!(
+                    Bitmap::from(a).unset_bits(),
+                    Bitmap::from(expected).unset_bits()
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn shrink_to_fit() {
     let mut a = MutableBitmap::with_capacity(1025);
@@ -8,6 +8,7 @@ mod bit_chunks_exact;
 mod chunk_iter;
 mod fmt;
 mod iterator;
+mod set_positions;
 mod slice_iterator;
 mod zip_validity;
 
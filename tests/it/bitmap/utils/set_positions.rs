@@ -0,0 +1,57 @@
+use proptest::prelude::*;
+
+use arrow2::bitmap::utils::set_positions;
+use arrow2::bitmap::Bitmap;
+
+use crate::bitmap::bitmap_strategy;
+
+proptest! {
+    /// Asserts that the positions are the positions of the set bits, in ascending order
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn check_invariants(bitmap in bitmap_strategy()) {
+        let iter = set_positions(&bitmap);
+        assert_eq!(iter.len(), bitmap.len() - bitmap.unset_bits());
+
+        let expected = bitmap
+            .iter()
+            .enumerate()
+            .filter(|(_, is_set)| *is_set)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn basics() {
+    let bitmap = Bitmap::from([true, false, false, true, true]);
+    assert_eq!(set_positions(&bitmap).collect::<Vec<_>>(), vec![0, 3, 4]);
+
+    let bitmap = Bitmap::new_zeroed(100);
+    assert_eq!(set_positions(&bitmap).next(), None);
+
+    let bitmap = Bitmap::new();
+    assert_eq!(set_positions(&bitmap).next(), None);
+}
+
+#[test]
+fn sparse_with_offset() {
+    let bitmap = Bitmap::from_sorted_indices(1000, [3, 64, 65, 500, 998, 999]);
+    let positions = set_positions(&bitmap).collect::<Vec<_>>();
+    assert_eq!(positions, vec![3, 64, 65, 500, 998, 999]);
+
+    let bitmap = bitmap.slice(3, 990);
+    let positions = set_positions(&bitmap).collect::<Vec<_>>();
+    assert_eq!(positions, vec![0, 61, 62, 497]);
+}
+
+#[test]
+fn ignores_bits_beyond_length() {
+    // the last byte has set bits beyond the bitmap's length
+    let bitmap = Bitmap::from_u8_vec(vec![0b1111_0001], 3);
+    assert_eq!(set_positions(&bitmap).collect::<Vec<_>>(), vec![0]);
+
+    let bitmap = Bitmap::from_u8_vec(vec![0b1111_0001], 8).slice(1, 4);
+    assert_eq!(set_positions(&bitmap).collect::<Vec<_>>(), vec![3]);
+}
@@ -43,3 +43,62 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[cfg(feature = "bytes")]
+#[test]
+fn from_bytes_zero_copy() {
+    let bytes = bytes::Bytes::from(vec![1u8, 2, 3, 4, 5]);
+    let ptr = bytes.as_ptr();
+
+    let buffer = Buffer::from_bytes_zero_copy(bytes);
+    assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5]);
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+
+    let sliced = buffer.clone().slice(1, 3);
+    assert_eq!(sliced.as_slice(), &[2, 3, 4]);
+    assert_eq!(sliced.as_slice().as_ptr(), ptr.wrapping_add(1));
+
+    // the memory is shared: it cannot be mutated
+    let mut buffer = buffer;
+    assert_eq!(buffer.get_mut(), None);
+    drop(sliced);
+    assert_eq!(buffer.get_mut(), None);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn try_from_bytes_zero_copy() {
+    let values = [1u32, 2, 3];
+    let bytes = bytes::Bytes::from(
+        values
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect::<Vec<_>>(),
+    );
+    // a `Vec<u8>` is not guaranteed to be aligned to 4 bytes
+    if bytes.as_ptr() as usize % std::mem::align_of::<u32>() == 0 {
+        let buffer = Buffer::<u32>::try_from_bytes_zero_copy(bytes.clone()).unwrap();
+        assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+        assert_eq!(buffer.slice(1, 2).as_slice(), &[2, 3]);
+        // misaligned
+        assert!(Buffer::<u32>::try_from_bytes_zero_copy(bytes.slice(1..5)).is_err());
+    }
+    // length not a multiple of the size
+    assert!(Buffer::<u32>::try_from_bytes_zero_copy(bytes.slice(0..6)).is_err());
+
+    let buffer = Buffer::<u32>::try_from_bytes_zero_copy(bytes::Bytes::new()).unwrap();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn get_mut_sliced() {
+    let buffer: Buffer<u32> = vec![1, 2, 3, 4].into();
+
+    let mut sliced = buffer.clone().slice(0, 2);
+    drop(buffer);
+    assert_eq!(sliced.get_mut(), Some(&mut vec![1, 2]));
+    assert_eq!(sliced.as_slice(), &[1, 2]);
+
+    let mut sliced = sliced.slice(1, 1);
+    assert_eq!(sliced.get_mut(), None);
+}
@@ -0,0 +1,120 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::scalar::{BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+
+#[test]
+fn rows() {
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), None]).boxed(),
+        BooleanArray::from([Some(true), Some(false), None]).boxed(),
+    ]);
+
+    let rows = chunk.rows();
+    assert_eq!(rows.len(), 3);
+
+    let values = rows
+        .map(|row| (row.index(), row.len(), row.iter().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    let expected: Vec<Vec<Box<dyn Scalar>>> = vec![
+        vec![
+            Box::new(PrimitiveScalar::from(Some(1i32))),
+            Box::new(Utf8Scalar::<i32>::from(Some("a"))),
+            Box::new(BooleanScalar::from(Some(true))),
+        ],
+        vec![
+            Box::new(PrimitiveScalar::<i32>::from(None)),
+            Box::new(Utf8Scalar::<i32>::from(Some("b"))),
+            Box::new(BooleanScalar::from(Some(false))),
+        ],
+        vec![
+            Box::new(PrimitiveScalar::from(Some(3i32))),
+            Box::new(Utf8Scalar::<i32>::from(None::<&str>)),
+            Box::new(BooleanScalar::from(None)),
+        ],
+    ];
+    for (index, (row_index, len, row)) in values.into_iter().enumerate() {
+        assert_eq!(row_index, index);
+        assert_eq!(len, 3);
+        for (value, expected) in row.iter().zip(expected[index].iter()) {
+            assert_eq!(value.as_ref(), expected.as_ref());
+        }
+    }
+
+    let row = chunk.rows().nth(2).unwrap();
+    let value = row.get(0);
+    let value = value
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<i32>>()
+        .unwrap();
+    assert_eq!(value.value(), &Some(3));
+    assert!(!row.get(1).is_valid());
+}
+
+#[test]
+fn rows_empty() {
+    let chunk = Chunk::new(vec![Int32Array::from_slice([]).boxed()]);
+    assert_eq!(chunk.rows().count(), 0);
+}
+
+#[test]
+#[cfg(feature = "compute_concatenate")]
+fn concatenate() {
+    use std::sync::Arc;
+
+    let chunk = |ints: &[Option<i32>], strings: &[&str]| -> Chunk<Arc<dyn Array>> {
+        let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        dictionary.try_extend(strings.iter().map(Some)).unwrap();
+        let dictionary: DictionaryArray<i32> = dictionary.into();
+        Chunk::new(vec![Int32Array::from(ints).arced(), dictionary.arced()])
+    };
+
+    let chunks = [
+        chunk(&[Some(1), None], &["a", "b"]),
+        chunk(&[Some(3)], &["b"]),
+        chunk(&[None, Some(5), Some(6)], &["c", "a", "c"]),
+    ];
+
+    let result = Chunk::concatenate(&chunks).unwrap();
+    assert_eq!(result.len(), 6);
+    assert_eq!(
+        result[0].as_ref(),
+        &Int32Array::from([Some(1), None, Some(3), None, Some(5), Some(6)]) as &dyn Array
+    );
+
+    let dictionary = result[1]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    // the values of the dictionaries are merged
+    assert_eq!(
+        dictionary.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        dictionary.keys(),
+        &Int32Array::from_slice([0, 1, 1, 2, 0, 2])
+    );
+
+    // a single chunk is cloned
+    assert_eq!(Chunk::concatenate(&chunks[..1]).unwrap(), chunks[0]);
+}
+
+#[test]
+#[cfg(feature = "compute_concatenate")]
+fn concatenate_invalid() {
+    use std::sync::Arc;
+
+    assert!(Chunk::<Arc<dyn Array>>::concatenate(&[]).is_err());
+
+    let a = Chunk::new(vec![Int32Array::from_slice([1]).arced()]);
+    let b = Chunk::new(vec![
+        Int32Array::from_slice([1]).arced(),
+        Int32Array::from_slice([1]).arced(),
+    ]);
+    assert!(Chunk::concatenate(&[a.clone(), b]).is_err());
+
+    let c = Chunk::new(vec![Int64Array::from_slice([1]).arced()]);
+    assert!(Chunk::concatenate(&[a, c]).is_err());
+}
@@ -0,0 +1,86 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{approx_count_distinct, approx_distinct, HyperLogLog};
+
+fn assert_within(estimate: u64, expected: u64, tolerance: f64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(
+        error < tolerance,
+        "estimate {} of {} has a relative error of {}",
+        estimate,
+        expected,
+        error
+    );
+}
+
+#[test]
+fn empty() {
+    let array = Int64Array::from_slice([]);
+    assert_eq!(approx_count_distinct(&array).unwrap(), 0);
+
+    let array = Int64Array::from([None, None]);
+    assert_eq!(approx_count_distinct(&array).unwrap(), 0);
+}
+
+#[test]
+fn small() {
+    let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a"), Some("c")]);
+    assert_eq!(approx_count_distinct(&array).unwrap(), 3);
+
+    let array = BinaryArray::<i64>::from_slice([b"a", b"a"]);
+    assert_eq!(approx_count_distinct(&array).unwrap(), 1);
+}
+
+#[test]
+fn primitive() {
+    let array = Int64Array::from_vec((0..100_000i64).map(|x| x % 50_000).collect());
+    assert_within(approx_count_distinct(&array).unwrap(), 50_000, 0.05);
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i32>::from_iter_values((0..20_000).map(|x| format!("value{}", x)));
+    assert_within(approx_count_distinct(&array).unwrap(), 20_000, 0.05);
+}
+
+#[test]
+fn merge() {
+    let lhs = Int32Array::from_vec((0..30_000).collect());
+    let rhs = Int32Array::from_vec((20_000..60_000).collect());
+
+    let mut sketch = HyperLogLog::new();
+    sketch.extend(&lhs).unwrap();
+    let mut other = HyperLogLog::new();
+    other.extend(&rhs).unwrap();
+    sketch.merge(&other);
+
+    assert_within(sketch.count(), 60_000, 0.05);
+}
+
+#[test]
+fn unsupported() {
+    let array = Float32Array::from_slice([1.0]);
+    assert!(approx_count_distinct(&array).is_err());
+}
+
+#[test]
+fn precision() {
+    let array = Int64Array::from_vec((0..20_000).collect());
+    assert_within(approx_distinct(&array, 4).unwrap(), 20_000, 0.8);
+    assert_within(approx_distinct(&array, 12).unwrap(), 20_000, 0.08);
+    assert_within(approx_distinct(&array, 18).unwrap(), 20_000, 0.02);
+
+    let array = Int64Array::from_vec((0..5).collect());
+    assert_eq!(approx_distinct(&array, 10).unwrap(), 5);
+
+    assert!(approx_distinct(&array, 3).is_err());
+    assert!(approx_distinct(&array, 19).is_err());
+    assert_eq!(HyperLogLog::try_with_precision(8).unwrap().precision(), 8);
+    assert_eq!(HyperLogLog::new().precision(), 14);
+}
+
+#[test]
+#[should_panic]
+fn merge_different_precisions() {
+    let mut sketch = HyperLogLog::new();
+    sketch.merge(&HyperLogLog::try_with_precision(12).unwrap());
+}
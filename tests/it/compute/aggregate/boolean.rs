@@ -0,0 +1,46 @@
+use arrow2::array::BooleanArray;
+use arrow2::compute::aggregate::{all, any};
+
+#[test]
+fn any_values() {
+    assert_eq!(any(&BooleanArray::from_slice([false, true])), Some(true));
+    assert_eq!(any(&BooleanArray::from_slice([false, false])), Some(false));
+    assert_eq!(
+        any(&BooleanArray::from([None, Some(true), None])),
+        Some(true)
+    );
+    assert_eq!(any(&BooleanArray::from([None, Some(false)])), Some(false));
+}
+
+#[test]
+fn all_values() {
+    assert_eq!(all(&BooleanArray::from_slice([true, true])), Some(true));
+    assert_eq!(all(&BooleanArray::from_slice([true, false])), Some(false));
+    assert_eq!(
+        all(&BooleanArray::from([None, Some(false), None])),
+        Some(false)
+    );
+    assert_eq!(all(&BooleanArray::from([None, Some(true)])), Some(true));
+}
+
+#[test]
+fn empty_and_all_null() {
+    let empty = BooleanArray::from_slice([]);
+    assert_eq!(any(&empty), None);
+    assert_eq!(all(&empty), None);
+
+    let nulls = BooleanArray::from([None, None]);
+    assert_eq!(any(&nulls), None);
+    assert_eq!(all(&nulls), None);
+}
+
+#[test]
+fn sliced() {
+    let array = BooleanArray::from_slice([true, false, false, true]).slice(1, 2);
+    assert_eq!(any(&array), Some(false));
+    assert_eq!(all(&array), Some(false));
+
+    let array = BooleanArray::from([Some(false), Some(true), None]).slice(1, 2);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), Some(true));
+}
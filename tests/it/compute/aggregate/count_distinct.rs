@@ -0,0 +1,113 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{approx_distinct, count_distinct};
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from([Some(1), None, Some(2), Some(1), None]);
+    assert_eq!(count_distinct(&array, false).unwrap(), 2);
+    assert_eq!(count_distinct(&array, true).unwrap(), 3);
+
+    let array = Int32Array::from_slice([1, 1]);
+    assert_eq!(count_distinct(&array, true).unwrap(), 1);
+
+    let array = Int32Array::from_slice([]);
+    assert_eq!(count_distinct(&array, true).unwrap(), 0);
+}
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from([Some(true), None, Some(true)]);
+    assert_eq!(count_distinct(&array, false).unwrap(), 1);
+    assert_eq!(count_distinct(&array, true).unwrap(), 2);
+}
+
+#[test]
+fn binary_and_utf8() {
+    let array = Utf8Array::<i64>::from([Some("a"), Some(""), Some("a"), None]);
+    assert_eq!(count_distinct(&array, false).unwrap(), 2);
+
+    let array = BinaryArray::<i32>::from_slice([b"a", b"b", b"a"]).slice(1, 2);
+    assert_eq!(count_distinct(&array, false).unwrap(), 2);
+}
+
+#[test]
+fn dictionary() {
+    // "b" is not referenced and "a" is referenced by two different keys
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None, Some("c")]);
+    let keys = Int32Array::from([Some(0), Some(2), Some(4), None, Some(0)]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+    assert_eq!(count_distinct(&array, false).unwrap(), 2);
+    assert_eq!(count_distinct(&array, true).unwrap(), 3);
+
+    // null values referenced by valid keys are nulls
+    let values = Utf8Array::<i32>::from([Some("a"), None]);
+    let keys = Int32Array::from_slice([0, 1, 0]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+    assert_eq!(count_distinct(&array, false).unwrap(), 1);
+    assert_eq!(count_distinct(&array, true).unwrap(), 2);
+}
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        Some(vec![Some(3), None]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    assert_eq!(count_distinct(&array, false).unwrap(), 3);
+    assert_eq!(count_distinct(&array, true).unwrap(), 4);
+}
+
+#[test]
+fn struct_() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Int32Array::from_slice([1, 1, 2, 1]).boxed(),
+            Utf8Array::<i32>::from_slice(["x", "y", "x", "x"]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(count_distinct(&array, false).unwrap(), 3);
+}
+
+#[test]
+fn unsupported() {
+    let array = Float64Array::from_slice([1.0]);
+    assert!(count_distinct(&array, false).is_err());
+}
+
+#[test]
+fn approx_vs_exact() {
+    let values = (0..1_000_000u64)
+        .map(|x| Some((x.wrapping_mul(2_654_435_761) % 300_000) as i64))
+        .map(|x| x.filter(|x| x % 97 != 0))
+        .collect::<Vec<_>>();
+    let array = Int64Array::from(values);
+
+    let exact = count_distinct(&array, false).unwrap() as f64;
+    for (precision, tolerance) in [(10, 0.15), (14, 0.04), (16, 0.02)] {
+        let estimate = approx_distinct(&array, precision).unwrap() as f64;
+        let error = (estimate - exact).abs() / exact;
+        assert!(
+            error < tolerance,
+            "estimate {} of {} with precision {} has a relative error of {}",
+            estimate,
+            exact,
+            precision,
+            error
+        );
+    }
+}
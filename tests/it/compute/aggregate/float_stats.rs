@@ -0,0 +1,66 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+
+#[test]
+fn mixed() {
+    let array = Float64Array::from([
+        Some(1.0),
+        None,
+        Some(f64::NAN),
+        Some(-3.5),
+        Some(f64::NEG_INFINITY),
+        None,
+        Some(f64::NAN),
+        Some(f64::INFINITY),
+        Some(2.0),
+    ]);
+    let expected = FloatStats {
+        null_count: 2,
+        nan_count: 2,
+        inf_count: 2,
+        min: Some(f64::NEG_INFINITY),
+        max: Some(f64::INFINITY),
+    };
+    assert_eq!(float_stats(&array), expected);
+
+    // slice [NaN, -3.5, -inf, None]
+    let expected = FloatStats {
+        null_count: 1,
+        nan_count: 1,
+        inf_count: 1,
+        min: Some(f64::NEG_INFINITY),
+        max: Some(-3.5),
+    };
+    assert_eq!(float_stats(&array.slice(2, 4)), expected);
+}
+
+#[test]
+fn no_values() {
+    let expected = FloatStats {
+        null_count: 2,
+        nan_count: 1,
+        inf_count: 0,
+        min: None,
+        max: None,
+    };
+    let array = Float32Array::from([None, Some(f32::NAN), None]);
+    assert_eq!(float_stats(&array), expected);
+
+    let array = Float64Array::from_vec(vec![]);
+    let stats = float_stats(&array);
+    assert_eq!(stats.null_count, 0);
+    assert_eq!(stats.min, None);
+}
+
+#[test]
+fn no_nulls() {
+    let array = Float64Array::from_slice([0.5, -0.5, 3.0]);
+    let expected = FloatStats {
+        null_count: 0,
+        nan_count: 0,
+        inf_count: 0,
+        min: Some(-0.5),
+        max: Some(3.0),
+    };
+    assert_eq!(float_stats(&array), expected);
+}
@@ -0,0 +1,295 @@
+use std::ops::Range;
+
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    aggregate_by_ranges, group_by, max, max_string, min, min_string, sum, Aggregation,
+};
+use arrow2::datatypes::DataType;
+use arrow2::error::{Error, Result};
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+#[test]
+fn aggregations() -> Result<()> {
+    let keys =
+        Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a"), Some("b"), None]).boxed();
+    let values = Int32Array::from([Some(1), None, Some(3), Some(4), None, Some(6)]).boxed();
+
+    let aggregations = [
+        Aggregation::Sum,
+        Aggregation::Min,
+        Aggregation::Max,
+        Aggregation::Count,
+        Aggregation::Mean,
+        Aggregation::First,
+        Aggregation::Last,
+    ];
+    let (keys, values) = group_by(&[keys], &vec![values; aggregations.len()], &aggregations)?;
+
+    assert_eq!(
+        keys[0].as_ref(),
+        &Utf8Array::<i32>::from([Some("a"), Some("b"), None]) as &dyn Array
+    );
+    let expected: Vec<Box<dyn Array>> = vec![
+        Int32Array::from([Some(5), None, Some(9)]).boxed(),
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Int32Array::from([Some(4), None, Some(6)]).boxed(),
+        UInt64Array::from_slice([2, 0, 2]).boxed(),
+        Float64Array::from([Some(2.5), None, Some(4.5)]).boxed(),
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Int32Array::from([Some(4), None, Some(6)]).boxed(),
+    ];
+    assert_eq!(values, expected);
+    Ok(())
+}
+
+#[test]
+fn multiple_keys() -> Result<()> {
+    let keys = vec![
+        Int64Array::from_slice([1, 1, 2, 1, 2]).boxed(),
+        BooleanArray::from_slice([true, false, true, true, true]).boxed(),
+    ];
+    let values = vec![Float64Array::from_slice([1.0, 2.0, 3.0, 4.0, 5.0]).boxed()];
+
+    let (keys, values) = group_by(&keys, &values, &[Aggregation::Sum])?;
+
+    assert_eq!(
+        keys[0].as_ref(),
+        &Int64Array::from_slice([1, 1, 2]) as &dyn Array
+    );
+    assert_eq!(
+        keys[1].as_ref(),
+        &BooleanArray::from_slice([true, false, true]) as &dyn Array
+    );
+    assert_eq!(
+        values[0].as_ref(),
+        &Float64Array::from_slice([5.0, 2.0, 8.0]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn first_last_utf8() -> Result<()> {
+    let keys = vec![Int32Array::from_slice([1, 2, 1, 1]).boxed()];
+    let values = Utf8Array::<i32>::from([Some("x"), None, None, Some("z")]).boxed();
+
+    let (_, values) = group_by(
+        &keys,
+        &[values.clone(), values],
+        &[Aggregation::First, Aggregation::Last],
+    )?;
+
+    let expected = Utf8Array::<i32>::from([Some("x"), None]);
+    assert_eq!(values[0].as_ref(), &expected as &dyn Array);
+    let expected = Utf8Array::<i32>::from([Some("z"), None]);
+    assert_eq!(values[1].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let keys = vec![Int32Array::from_slice([]).boxed()];
+    let values = vec![Int32Array::from_slice([]).boxed()];
+
+    let (keys, values) = group_by(&keys, &values, &[Aggregation::Count])?;
+    assert_eq!(keys[0].len(), 0);
+    assert_eq!(values[0].len(), 0);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let keys = vec![Int32Array::from_slice([1, 2]).boxed()];
+    let values = vec![Utf8Array::<i32>::from_slice(["a", "b"]).boxed()];
+
+    // sum of strings
+    assert!(group_by(&keys, &values, &[Aggregation::Sum]).is_err());
+    // one aggregation per value
+    assert!(group_by(&keys, &values, &[]).is_err());
+    // different lengths
+    let values = vec![Int32Array::from_slice([1]).boxed()];
+    assert!(group_by(&keys, &values, &[Aggregation::Count]).is_err());
+    // no keys
+    assert!(group_by(&[], &[], &[]).is_err());
+}
+
+/// Aggregates each range by slicing `values` and using the non-grouped kernels.
+fn naive_by_ranges(
+    values: &dyn Array,
+    ranges: &[Range<usize>],
+    op: fn(&dyn Array) -> Result<Box<dyn Scalar>>,
+) -> Vec<Box<dyn Scalar>> {
+    ranges
+        .iter()
+        .map(|range| op(values.slice(range.start, range.len()).as_ref()).unwrap())
+        .collect()
+}
+
+fn scalars(array: &dyn Array) -> Vec<Box<dyn Scalar>> {
+    (0..array.len())
+        .map(|i| arrow2::scalar::new_scalar(array, i))
+        .collect()
+}
+
+#[test]
+fn by_ranges_primitive() -> Result<()> {
+    let values = Int64Array::from([Some(3), None, Some(-1), Some(7), None, None, Some(2)]);
+    let ranges = [0..3, 3..4, 4..6, 6..7, 7..7];
+
+    for (aggregation, op) in [
+        (
+            Aggregation::Sum,
+            sum as fn(&dyn Array) -> Result<Box<dyn Scalar>>,
+        ),
+        (Aggregation::Min, min),
+        (Aggregation::Max, max),
+    ] {
+        let result = aggregate_by_ranges(&values, &ranges, aggregation)?;
+        assert_eq!(
+            scalars(result.as_ref()),
+            naive_by_ranges(&values, &ranges, op)
+        );
+    }
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::Count)?;
+    assert_eq!(
+        result.as_ref(),
+        &UInt64Array::from_slice([2, 1, 0, 1, 0]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn by_ranges_decimal() -> Result<()> {
+    let values =
+        Int128Array::from([Some(100), Some(-250), None, Some(1)]).to(DataType::Decimal(5, 2));
+    let ranges = [0..2, 2..4];
+
+    for (aggregation, op) in [
+        (
+            Aggregation::Sum,
+            sum as fn(&dyn Array) -> Result<Box<dyn Scalar>>,
+        ),
+        (Aggregation::Min, min),
+        (Aggregation::Max, max),
+    ] {
+        let result = aggregate_by_ranges(&values, &ranges, aggregation)?;
+        assert_eq!(
+            scalars(result.as_ref()),
+            naive_by_ranges(&values, &ranges, op)
+        );
+    }
+    assert_eq!(
+        scalars(aggregate_by_ranges(&values, &ranges, Aggregation::Sum)?.as_ref())[0].as_ref(),
+        &PrimitiveScalar::<i128>::from(Some(-150)).to(DataType::Decimal(5, 2)) as &dyn Scalar
+    );
+
+    assert!(aggregate_by_ranges(&values, &ranges, Aggregation::Mean).is_err());
+    Ok(())
+}
+
+#[test]
+fn by_ranges_utf8() -> Result<()> {
+    let values = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c"), None, Some("ab")]);
+    let ranges = [0..3, 3..5, 4..5, 5..6];
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::Min)?;
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    let expected = ranges
+        .iter()
+        .map(|range| min_string(&values.slice(range.start, range.len())).map(|x| x.to_string()))
+        .collect::<Vec<_>>();
+    let result = result
+        .iter()
+        .map(|x| x.map(|x| x.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::Max)?;
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    let expected = ranges
+        .iter()
+        .map(|range| max_string(&values.slice(range.start, range.len())).map(|x| x.to_string()))
+        .collect::<Vec<_>>();
+    let result = result
+        .iter()
+        .map(|x| x.map(|x| x.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+
+    // strings cannot be summed
+    assert!(aggregate_by_ranges(&values, &ranges, Aggregation::Sum).is_err());
+    Ok(())
+}
+
+#[test]
+fn by_ranges_out_of_bounds() {
+    let values = Int32Array::from_slice([1, 2]);
+    let ranges = [0..1, 0..3];
+    assert!(aggregate_by_ranges(&values, &ranges, Aggregation::Sum).is_err());
+    #[allow(clippy::reversed_empty_ranges)]
+    let ranges = [0..1, 2..1];
+    assert!(aggregate_by_ranges(&values, &ranges, Aggregation::Sum).is_err());
+}
+
+#[test]
+fn sum_overflow() -> Result<()> {
+    let keys = vec![Int32Array::from_slice([1, 2, 1, 2]).boxed()];
+    let values = Int8Array::from_slice([100, 100, 100, -100]);
+
+    // grouped and ranged sums agree
+    let result = group_by(&keys, &[values.clone().boxed()], &[Aggregation::Sum]);
+    assert!(matches!(result, Err(Error::Overflow)));
+    let result = aggregate_by_ranges(&values, &[0..1, 2..4], Aggregation::Sum)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int8Array::from_slice([100, 0]) as &dyn Array
+    );
+    let result = aggregate_by_ranges(&values, &[0..2, 2..4], Aggregation::Sum);
+    assert!(matches!(result, Err(Error::Overflow)));
+
+    // floats do not overflow
+    let keys = vec![Int32Array::from_slice([1, 1]).boxed()];
+    let values = Float32Array::from_slice([f32::MAX, f32::MAX]).boxed();
+    let (_, values) = group_by(&keys, &[values], &[Aggregation::Sum])?;
+    assert_eq!(
+        values[0].as_ref(),
+        &Float32Array::from_slice([f32::INFINITY]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn by_ranges_first_last_boolean() -> Result<()> {
+    let values = BooleanArray::from([Some(true), None, Some(false), None, Some(true)]);
+    let ranges = [0..3, 3..4, 1..5];
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::Min)?;
+    let expected = BooleanArray::from([Some(false), None, Some(false)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::Max)?;
+    let expected = BooleanArray::from([Some(true), None, Some(true)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::First)?;
+    let expected = BooleanArray::from([Some(true), None, Some(false)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = aggregate_by_ranges(&values, &ranges, Aggregation::Last)?;
+    let expected = BooleanArray::from([Some(false), None, Some(true)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn decimal_sum_overflow() {
+    let values = Int128Array::from_slice([60, 50, 1]).to(DataType::Decimal(2, 0));
+
+    let ranges = [0..2, 2..3];
+    assert!(aggregate_by_ranges(&values, &ranges, Aggregation::Sum).is_err());
+    let ranges = [0..1, 1..3];
+    assert!(aggregate_by_ranges(&values, &ranges, Aggregation::Sum).is_ok());
+
+    let keys = Int32Array::from_slice([1, 1, 2]).boxed();
+    assert!(group_by(&[keys], &[values.boxed()], &[Aggregation::Sum]).is_err());
+}
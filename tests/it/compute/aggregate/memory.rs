@@ -17,3 +17,50 @@ fn utf8() {
     let a = Utf8Array::<i32>::from_slice(["aaa"]);
     assert_eq!(3 + 2 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn sliced() {
+    let a = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    assert_eq!(4 * 4 + 1, estimated_bytes_size(&a));
+    assert_eq!(2 * 4 + 1, estimated_bytes_size(&a.slice(1, 2)));
+
+    let a = Utf8Array::<i32>::from_slice(["a", "bb", "ccc"]);
+    assert_eq!(6 + 4 * 4, estimated_bytes_size(&a));
+    assert_eq!(5 + 3 * 4, estimated_bytes_size(&a.slice(1, 2)));
+}
+
+#[test]
+fn sliced_list() {
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    a.try_extend([
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+        Some(vec![Some(4), Some(5), Some(6)]),
+    ])
+    .unwrap();
+    let a: ListArray<i32> = a.into();
+
+    // 6 values and 4 offsets
+    assert_eq!(6 * 8 + 4 * 4, estimated_bytes_size(&a));
+    // the values of the last two lists and 3 offsets
+    assert_eq!(4 * 8 + 3 * 4, estimated_bytes_size(&a.slice(1, 2)));
+}
+
+#[test]
+fn fixed_size_list() {
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed();
+    let data_type = FixedSizeListArray::default_datatype(arrow2::datatypes::DataType::Int32, 2);
+    let a = FixedSizeListArray::new(data_type, values, None);
+
+    assert_eq!(6 * 4, estimated_bytes_size(&a));
+    assert_eq!(2 * 4, estimated_bytes_size(&a.slice(1, 1)));
+}
+
+#[test]
+fn array_method() {
+    let a = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    assert_eq!(estimated_bytes_size(&a), a.estimated_bytes_size());
+
+    let a = a.slice(1, 2).boxed();
+    assert_eq!(2 * 4 + 1, a.estimated_bytes_size());
+}
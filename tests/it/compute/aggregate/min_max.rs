@@ -1,8 +1,9 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    can_max, can_min, max, max_binary, max_boolean, max_f16, max_primitive, max_string, min,
+    min_binary, min_boolean, min_f16, min_max_primitive, min_primitive, min_string,
 };
-use arrow2::{array::*, datatypes::DataType};
+use arrow2::scalar::{BinaryScalar, PrimitiveScalar, Scalar};
+use arrow2::{array::*, datatypes::DataType, types::f16};
 
 #[test]
 fn test_primitive_array_min_max() {
@@ -25,6 +26,32 @@ fn test_primitive_min_max_1() {
     assert_eq!(Some(5), max_primitive(&a));
 }
 
+#[test]
+fn test_primitive_min_max_single_pass() {
+    let a = Int32Array::from_slice([5, 6, 7, 8, 9]);
+    assert_eq!((Some(5), Some(9)), min_max_primitive(&a));
+
+    let a = Int32Array::from(&[None, None, Some(5), Some(2)]);
+    assert_eq!((Some(2), Some(5)), min_max_primitive(&a));
+
+    // more than the number of lanes, with an offset on the validity
+    let a = Int64Array::from_iter((0..100).map(|x| if x % 3 == 0 { None } else { Some(x - 50) }));
+    let a = a.slice(1, 95);
+    assert_eq!((Some(-49), Some(45)), min_max_primitive(&a));
+    assert_eq!(
+        (min_primitive(&a), max_primitive(&a)),
+        min_max_primitive(&a)
+    );
+
+    let a = Float64Array::from_slice([3.0, -1.5, 10.0, 2.0, 0.0, 8.0, 7.0, 6.0, 5.0, -3.0]);
+    assert_eq!((Some(-3.0), Some(10.0)), min_max_primitive(&a));
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!((None, None), min_max_primitive(&a));
+    let a = Int32Array::from_slice([]);
+    assert_eq!((None, None), min_max_primitive(&a));
+}
+
 #[test]
 fn decimal() {
     let a = Int128Array::from(&[None, None, Some(5), Some(2)]);
@@ -140,6 +167,20 @@ fn test_string_min_max_1() {
     assert_eq!(Some("b"), max_string(&a));
 }
 
+#[test]
+fn test_string_min_max_empty() {
+    let a = Utf8Array::<i64>::from_slice::<&str, _>([]);
+    assert_eq!(None, min_string(&a));
+    assert_eq!(None, max_string(&a));
+}
+
+#[test]
+fn test_string_min_max_sliced() {
+    let a = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("b"), Some("d")]).slice(1, 3);
+    assert_eq!(Some("b"), min_string(&a));
+    assert_eq!(Some("c"), max_string(&a));
+}
+
 #[test]
 fn test_boolean_min_max_empty() {
     let a = BooleanArray::new_empty(DataType::Boolean);
@@ -237,3 +278,72 @@ fn test_max_not_lexi() {
     let out = max_primitive(&arr).unwrap();
     assert_eq!(out, maximum);
 }
+
+#[test]
+fn test_decimal_min_max_with_nulls() {
+    // the extreme values are behind the validity mask
+    let a = Int128Array::from_slice([-100, 5, 2, 100])
+        .to(DataType::Decimal(5, 2))
+        .with_validity(Some([false, true, true, false].into()));
+    assert_eq!(
+        &PrimitiveScalar::<i128>::from(Some(2)).to(DataType::Decimal(5, 2)) as &dyn Scalar,
+        min(&a).unwrap().as_ref()
+    );
+    assert_eq!(
+        &PrimitiveScalar::<i128>::from(Some(5)).to(DataType::Decimal(5, 2)) as &dyn Scalar,
+        max(&a).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_large_binary_dyn_min_max() {
+    let a = BinaryArray::<i64>::from([Some(b"b"), None, Some(b"c"), Some(b"a")]);
+    assert_eq!(
+        &BinaryScalar::<i64>::new(Some(b"a".to_vec())) as &dyn Scalar,
+        min(&a).unwrap().as_ref()
+    );
+    assert_eq!(
+        &BinaryScalar::<i64>::new(Some(b"c".to_vec())) as &dyn Scalar,
+        max(&a).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_can_min_max() {
+    assert!(can_min(&DataType::Int32));
+    assert!(can_max(&DataType::Decimal(5, 2)));
+    assert!(can_max(&DataType::LargeBinary));
+}
+
+#[test]
+fn test_f16_min_max() {
+    let a = Float16Array::from([
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_bits(0x0001)),
+        None,
+        Some(f16::from_f32(f32::NEG_INFINITY)),
+        Some(f16::from_f32(-0.5)),
+    ]);
+    assert_eq!(min_f16(&a).map(|x| x.to_bits()), Some(0xFC00));
+    assert_eq!(max_f16(&a), Some(f16::from_f32(1.0)));
+    assert!(can_min(&DataType::Float16) && can_max(&DataType::Float16));
+    assert_eq!(
+        max(&a).unwrap().as_ref(),
+        &PrimitiveScalar::from(Some(f16::from_f32(1.0))) as &dyn Scalar
+    );
+
+    // NaN is ordered as for `f32`
+    let a = Float16Array::from_slice([f16::from_f32(f32::NAN), f16::from_f32(2.0)]);
+    let b = Float32Array::from_slice([f32::NAN, 2.0]);
+    assert_eq!(
+        max_f16(&a).map(|x| x.to_f32().to_bits()),
+        max_primitive(&b).map(|x| x.to_bits())
+    );
+    assert_eq!(
+        min_f16(&a).map(|x| x.to_f32().to_bits()),
+        min_primitive(&b).map(|x| x.to_bits())
+    );
+
+    let a = Float16Array::from([None::<f16>]);
+    assert_eq!(min_f16(&a), None);
+}
@@ -1,3 +1,10 @@
+mod approx_count_distinct;
+mod boolean;
+mod count_distinct;
+mod float_stats;
+mod group_by;
 mod memory;
 mod min_max;
+mod moments;
+mod quantile;
 mod sum;
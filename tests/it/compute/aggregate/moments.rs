@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{kurtosis, skewness, std_dev, var};
+
+fn assert_close(result: Option<f64>, expected: f64) {
+    let result = result.unwrap();
+    assert!(
+        (result - expected).abs() < 1e-6,
+        "{} != {}",
+        result,
+        expected
+    );
+}
+
+#[test]
+fn variance() {
+    let array = Float64Array::from([Some(2.0), Some(4.0), None, Some(4.0), Some(4.0), Some(5.0)]);
+    let array2 = Float64Array::from_slice([5.0, 7.0, 9.0]);
+
+    // mean 3.8, squared deviations sum to 4.8
+    assert_close(var(&array, 0), 0.96);
+    assert_close(var(&array, 1), 1.2);
+    assert_close(std_dev(&array, 0), 0.96f64.sqrt());
+    assert_close(std_dev(&array, 1), 1.2f64.sqrt());
+
+    assert_close(var(&array2, 0), 8.0 / 3.0);
+    assert_close(var(&array2, 1), 4.0);
+
+    let array = Int64Array::from([Some(i64::MAX), None]);
+    assert_close(var(&array, 0), 0.0);
+    assert_eq!(var(&array, 1), None);
+}
+
+#[test]
+fn variance_stability() {
+    // a naive sum of squares loses all precision with this offset
+    let offset = 1e9;
+    let array = Float64Array::from_iter((0..1000).map(|x| Some(offset + (x % 4) as f64)));
+    assert_close(var(&array, 0), 1.25);
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from([None, None]);
+    assert_eq!(var(&array, 0), None);
+    assert_eq!(std_dev(&array, 0), None);
+    assert_eq!(skewness(&array), None);
+    assert_eq!(kurtosis(&array), None);
+
+    let array = Int32Array::from_slice([1]);
+    assert_eq!(var(&array, 1), None);
+    assert_eq!(var(&array, 2), None);
+}
+
+#[test]
+fn higher_moments() {
+    // symmetric values have no skewness
+    let array = Int32Array::from([Some(1), Some(2), None, Some(3)]);
+    assert_close(skewness(&array), 0.0);
+    // m2 = 2/3, m4 = 2/3 => 3 * (2/3) / (4/9) - 3
+    assert_close(kurtosis(&array), -1.5);
+
+    // mean 2, deviations [-1, -1, 2]: m2 = 6, m3 = 6
+    let array = UInt8Array::from_slice([1, 1, 4]);
+    assert_close(skewness(&array), 3f64.sqrt() * 6.0 / 6f64.powf(1.5));
+    // m4 = 1 + 1 + 16 = 18 => 3 * 18 / 36 - 3
+    assert_close(kurtosis(&array), -1.5);
+}
@@ -0,0 +1,51 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{median, quantile, QuantileInterpolation};
+
+use QuantileInterpolation::*;
+
+#[test]
+fn interpolations() {
+    // sorted: [1, 2, 3, 4]; the 0.4 quantile is at position 1.2
+    let array = Int32Array::from([Some(4), None, Some(1), Some(3), Some(2)]);
+    let q = |interpolation| quantile(&array, 0.4, interpolation).unwrap();
+    assert!((q(Linear) - 2.2).abs() < 1e-12);
+    assert_eq!(q(Lower), 2.0);
+    assert_eq!(q(Upper), 3.0);
+    assert_eq!(q(Midpoint), 2.5);
+    assert_eq!(q(Nearest), 2.0);
+
+    // position 2.5: ties are resolved to the even position, as NumPy does
+    let array = Float64Array::from_slice([10.0, 40.0, 30.0, 20.0, 60.0, 50.0]);
+    assert_eq!(quantile(&array, 0.5, Nearest), Some(30.0));
+    // position 1.5
+    assert_eq!(quantile(&array, 0.3, Nearest), Some(30.0));
+    assert_eq!(quantile(&array, 0.5, Linear), Some(35.0));
+}
+
+#[test]
+fn extremes() {
+    let array = UInt64Array::from_slice([7, 3, 9, 1]);
+    for interpolation in [Linear, Lower, Upper, Midpoint, Nearest] {
+        assert_eq!(quantile(&array, 0.0, interpolation), Some(1.0));
+        assert_eq!(quantile(&array, 1.0, interpolation), Some(9.0));
+    }
+}
+
+#[test]
+fn median_values() {
+    assert_eq!(median(&Int8Array::from_slice([3, 1, 2])), Some(2.0));
+    assert_eq!(median(&Int8Array::from_slice([3, 1, 2, 10])), Some(2.5));
+    assert_eq!(median(&Int8Array::from_slice([5])), Some(5.0));
+}
+
+#[test]
+fn empty() {
+    assert_eq!(median(&Int32Array::from([None, None])), None);
+    assert_eq!(quantile(&Float32Array::from_vec(vec![]), 0.2, Upper), None);
+}
+
+#[test]
+#[should_panic]
+fn invalid_quantile() {
+    quantile(&Int32Array::from_slice([1]), 1.5, Linear);
+}
@@ -1,8 +1,9 @@
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{can_sum, sum, sum_decimal, sum_f16, sum_primitive};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::f16;
 
 #[test]
 fn test_primitive_array_sum() {
@@ -52,3 +53,72 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn test_decimal_sum() {
+    let a = Int128Array::from([Some(999), None, Some(-100), Some(1)]).to(DataType::Decimal(4, 2));
+    assert_eq!(Some(900), sum_decimal(&a).unwrap());
+    assert_eq!(
+        &PrimitiveScalar::<i128>::from(Some(900)).to(DataType::Decimal(4, 2)) as &dyn Scalar,
+        sum(&a).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_decimal_sum_all_nulls() {
+    let a = Int128Array::from([None, None]).to(DataType::Decimal(4, 2));
+    assert_eq!(None, sum_decimal(&a).unwrap());
+
+    let a = Int128Array::from_slice([]).to(DataType::Decimal(4, 2));
+    assert_eq!(None, sum_decimal(&a).unwrap());
+}
+
+#[test]
+fn test_decimal_sum_overflow() {
+    let a = Int128Array::from_slice([9999, 1]).to(DataType::Decimal(4, 2));
+    assert!(sum_decimal(&a).is_err());
+    assert!(sum(&a).is_err());
+
+    // the null slot would overflow the precision
+    let a = Int128Array::from_slice([9999, 1])
+        .to(DataType::Decimal(4, 2))
+        .with_validity(Some([true, false].into()));
+    assert_eq!(Some(9999), sum_decimal(&a).unwrap());
+
+    let a = Int128Array::from_slice([-9999, -1]).to(DataType::Decimal(4, 2));
+    assert!(sum_decimal(&a).is_err());
+}
+
+#[test]
+fn test_decimal_sum_default_data_type() {
+    // the default data type of `i128` is `Decimal(32, 32)`
+    let a = Int128Array::from_slice([1, 2]);
+    assert_eq!(Some(3), sum_decimal(&a).unwrap());
+}
+
+#[test]
+fn test_f16_sum() {
+    // 2048 + 1 is not representable as `f16`: the sum is accumulated in `f32`
+    let a = Float16Array::from([
+        Some(f16::from_f32(2048.0)),
+        Some(f16::from_f32(1.0)),
+        None,
+        Some(f16::from_f32(1.0)),
+    ]);
+    assert_eq!(sum_f16(&a), Some(f16::from_f32(2050.0)));
+    assert!(can_sum(&DataType::Float16));
+    assert_eq!(
+        sum(&a).unwrap().as_ref(),
+        &PrimitiveScalar::from(Some(f16::from_f32(2050.0))) as &dyn Scalar
+    );
+
+    // subnormals
+    let a = Float16Array::from_slice([f16::from_bits(0x0001), f16::from_bits(0x0001)]);
+    assert_eq!(sum_f16(&a).map(|x| x.to_bits()), Some(0x0002));
+
+    let a = Float16Array::from_slice([f16::from_f32(f32::INFINITY), f16::from_f32(1.0)]);
+    assert_eq!(sum_f16(&a).map(|x| x.to_bits()), Some(0x7C00));
+
+    let a = Float16Array::from([None::<f16>]);
+    assert_eq!(sum_f16(&a), None);
+}
@@ -4,3 +4,55 @@ mod mul;
 mod pow;
 mod rem;
 mod sub;
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::{abs, checked_abs, clamp, sign};
+
+#[test]
+fn test_abs() {
+    let a = Int32Array::from([Some(-1), None, Some(2), Some(0), Some(i32::MIN + 1)]);
+    let result = abs(&a);
+    let expected = Int32Array::from([Some(1), None, Some(2), Some(0), Some(i32::MAX)]);
+    assert_eq!(result, expected);
+
+    let a = Float64Array::from([Some(-1.5), None, Some(2.0)]);
+    let expected = Float64Array::from([Some(1.5), None, Some(2.0)]);
+    assert_eq!(abs(&a), expected);
+}
+
+#[test]
+fn test_checked_abs() {
+    let a = Int32Array::from([Some(-1), None, Some(i32::MIN), Some(i32::MAX)]);
+    let result = checked_abs(&a);
+    let expected = Int32Array::from([Some(1), None, None, Some(i32::MAX)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_sign() {
+    let a = Int64Array::from([Some(-10), None, Some(0), Some(3)]);
+    let expected = Int8Array::from([Some(-1), None, Some(0), Some(1)]);
+    assert_eq!(sign(&a), expected);
+
+    let a = Float32Array::from([Some(-0.5), Some(-0.0), Some(f32::NAN), Some(f32::INFINITY)]);
+    let expected = Int8Array::from([Some(-1), Some(0), Some(0), Some(1)]);
+    assert_eq!(sign(&a), expected);
+}
+
+#[test]
+fn test_clamp() {
+    let a = Int32Array::from([Some(-10), None, Some(5), Some(20)]);
+    let expected = Int32Array::from([Some(0), None, Some(5), Some(10)]);
+    assert_eq!(clamp(&a, 0, 10), expected);
+
+    let a = UInt8Array::from([Some(1), Some(200)]);
+    let expected = UInt8Array::from([Some(1), Some(100)]);
+    assert_eq!(clamp(&a, 0, 100), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_invalid_bounds() {
+    let a = Int32Array::from([Some(1)]);
+    clamp(&a, 10, 0);
+}
@@ -16,3 +16,42 @@ fn test_raise_power_scalar_checked() {
     let expected = Int8Array::from(&[Some(1i8), None, None]);
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_pow_scalar() {
+    let a = Float64Array::from(&[Some(2f64), Some(-8f64), None]);
+    let actual = pow_scalar(&a, 3.0);
+    let expected = Float64Array::from(&[Some(8f64), Some(-512f64), None]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_pow() {
+    let base = Float32Array::from(&[Some(2f32), Some(4f32), None, Some(1f32)]);
+    let exponent = Float32Array::from(&[Some(2f32), Some(0.5f32), Some(1f32), None]);
+    let actual = pow(&base, &exponent);
+    let expected = Float32Array::from(&[Some(4f32), Some(2f32), None, None]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_pow_checked() {
+    let base = Int32Array::from(&[Some(2), Some(-3), Some(2), None]);
+    let exponent = UInt32Array::from(&[Some(10), Some(3), Some(31), Some(1)]);
+    let actual = checked_pow(&base, &exponent);
+    let expected = Int32Array::from(&[Some(1024), Some(-27), None, None]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_square() {
+    let a = Int16Array::from(&[Some(-4i16), None, Some(200i16)]);
+    let actual = checked_square(&a);
+    let expected = Int16Array::from(&[Some(16i16), None, None]);
+    assert_eq!(expected, actual);
+
+    let a = Float64Array::from(&[Some(1.5f64), None]);
+    let actual = square(&a);
+    let expected = Float64Array::from(&[Some(2.25f64), None]);
+    assert_eq!(expected, actual);
+}
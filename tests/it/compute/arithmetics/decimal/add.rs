@@ -25,11 +25,45 @@ fn test_add_normal() {
 }
 
 #[test]
-#[should_panic]
-fn test_add_decimal_wrong_precision() {
-    let a = PrimitiveArray::from([None]).to(DataType::Decimal(5, 2));
-    let b = PrimitiveArray::from([None]).to(DataType::Decimal(6, 2));
-    add(&a, &b);
+fn test_add_different_scales() {
+    // 1.23 + 0.0001, 10.00 + 1.5
+    let a =
+        PrimitiveArray::from([Some(1_23i128), Some(10_00i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveArray::from([Some(0_0001i128), Some(1_5000i128), Some(2i128)])
+        .to(DataType::Decimal(6, 4));
+    let result = add(&a, &b);
+    let expected = PrimitiveArray::from([Some(1_2301i128), Some(11_5000i128), None])
+        .to(DataType::Decimal(7, 4));
+    assert_eq!(result, expected);
+
+    let b = PrimitiveArray::from([Some(1_00i128), Some(1_00i128), Some(1_00i128)])
+        .to(DataType::Decimal(6, 2));
+    let result = add(&a, &b);
+    let expected =
+        PrimitiveArray::from([Some(2_23i128), Some(11_00i128), None]).to(DataType::Decimal(6, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_different_scales_overflow() {
+    let a = PrimitiveArray::from([Some(10i128.pow(37)), Some(1i128)]).to(DataType::Decimal(38, 0));
+    let b = PrimitiveArray::from([Some(1i128), Some(1i128)]).to(DataType::Decimal(38, 2));
+    let result = checked_add(&a, &b);
+    let expected = PrimitiveArray::from([None, Some(1_01i128)]).to(DataType::Decimal(38, 2));
+    assert_eq!(result, expected);
+
+    let result = saturating_add(&a, &b);
+    let max = 10i128.pow(38) - 1;
+    let expected = PrimitiveArray::from([Some(max), Some(1_01i128)]).to(DataType::Decimal(38, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic(expected = "Overflow in rescaling presented for precision 38")]
+fn test_add_different_scales_panic() {
+    let a = PrimitiveArray::from([Some(10i128.pow(37))]).to(DataType::Decimal(38, 0));
+    let b = PrimitiveArray::from([Some(1i128)]).to(DataType::Decimal(38, 2));
+    let _ = add(&a, &b);
 }
 
 #[test]
@@ -2,7 +2,7 @@
 
 use arrow2::array::*;
 use arrow2::compute::arithmetics::decimal::{
-    adaptive_div, checked_div, div, div_scalar, saturating_div,
+    adaptive_div, checked_div, checked_div_with_scale, div, div_scalar, saturating_div,
 };
 use arrow2::compute::arithmetics::{ArrayCheckedDiv, ArrayDiv};
 use arrow2::datatypes::DataType;
@@ -53,11 +53,43 @@ fn test_divide_normal() {
 }
 
 #[test]
-#[should_panic]
-fn test_divide_decimal_wrong_precision() {
-    let a = PrimitiveArray::from([None]).to(DataType::Decimal(5, 2));
-    let b = PrimitiveArray::from([None]).to(DataType::Decimal(6, 2));
-    div(&a, &b);
+fn test_divide_different_scales() {
+    // 1.00 / 0.4, 10.00 / 2.0
+    let a =
+        PrimitiveArray::from([Some(1_00i128), Some(10_00i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveArray::from([Some(0_4i128), Some(2_0i128), Some(1i128)])
+        .to(DataType::Decimal(4, 1));
+    let result = div(&a, &b);
+    let expected =
+        PrimitiveArray::from([Some(2_50i128), Some(5_00i128), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_divide_with_scale() {
+    // 1.00 / 0.3, 1.00 / 0.0
+    let a = PrimitiveArray::from([Some(1_00i128), Some(1_00i128)]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveArray::from([Some(0_3i128), Some(0i128)]).to(DataType::Decimal(4, 1));
+    let result = checked_div_with_scale(&a, &b, 10, 4).unwrap();
+    let expected = PrimitiveArray::from([Some(3_3333i128), None]).to(DataType::Decimal(10, 4));
+    assert_eq!(result, expected);
+
+    // 1.000000 / 3 with a scale smaller than the dividend's
+    let a = PrimitiveArray::from([Some(1_000000i128)]).to(DataType::Decimal(10, 6));
+    let b = PrimitiveArray::from([Some(3i128)]).to(DataType::Decimal(2, 0));
+    let result = checked_div_with_scale(&a, &b, 5, 2).unwrap();
+    let expected = PrimitiveArray::from([Some(0_33i128)]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+
+    // 99.99 / 0.01 does not fit in a precision of 3
+    let a = PrimitiveArray::from([Some(99_99i128)]).to(DataType::Decimal(4, 2));
+    let b = PrimitiveArray::from([Some(0_01i128)]).to(DataType::Decimal(3, 2));
+    let result = checked_div_with_scale(&a, &b, 3, 0).unwrap();
+    let expected = PrimitiveArray::from([None]).to(DataType::Decimal(3, 0));
+    assert_eq!(result, expected);
+
+    assert!(checked_div_with_scale(&a, &b, 39, 0).is_err());
+    assert!(checked_div_with_scale(&a, &b, 3, 4).is_err());
 }
 
 #[test]
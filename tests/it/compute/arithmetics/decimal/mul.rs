@@ -50,11 +50,33 @@ fn test_multiply_normal() {
 }
 
 #[test]
-#[should_panic]
-fn test_multiply_decimal_wrong_precision() {
-    let a = PrimitiveArray::from([None]).to(DataType::Decimal(5, 2));
-    let b = PrimitiveArray::from([None]).to(DataType::Decimal(6, 2));
-    mul(&a, &b);
+fn test_multiply_different_scales() {
+    // 1.23 * 2.5, 10.00 * 0.1
+    let a =
+        PrimitiveArray::from([Some(1_23i128), Some(10_00i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveArray::from([Some(2_5i128), Some(0_1i128), Some(1i128)])
+        .to(DataType::Decimal(4, 1));
+    let result = mul(&a, &b);
+    let expected =
+        PrimitiveArray::from([Some(3_07i128), Some(1_00i128), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+
+    // the same rule as for arrays with the same precision and scale
+    let b = PrimitiveArray::from([Some(2_00i128), Some(0_10i128), Some(1i128)])
+        .to(DataType::Decimal(6, 2));
+    let result = mul(&a, &b);
+    let expected =
+        PrimitiveArray::from([Some(2_46i128), Some(1_00i128), None]).to(DataType::Decimal(6, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_multiply_different_scales_overflow() {
+    let a = PrimitiveArray::from([Some(10i128.pow(19)), Some(2i128)]).to(DataType::Decimal(20, 0));
+    let b = PrimitiveArray::from([Some(10i128.pow(19)), Some(2i128)]).to(DataType::Decimal(18, 1));
+    let result = checked_mul(&a, &b);
+    let expected = PrimitiveArray::from([None, Some(4i128)]).to(DataType::Decimal(21, 1));
+    assert_eq!(result, expected);
 }
 
 #[test]
@@ -25,11 +25,21 @@ fn test_subtract_normal() {
 }
 
 #[test]
-#[should_panic]
-fn test_subtract_decimal_wrong_precision() {
-    let a = PrimitiveArray::from([None]).to(DataType::Decimal(5, 2));
-    let b = PrimitiveArray::from([None]).to(DataType::Decimal(6, 2));
-    sub(&a, &b);
+fn test_subtract_different_scales() {
+    // 1.23 - 0.0001, 10.00 - 1.5
+    let a =
+        PrimitiveArray::from([Some(1_23i128), Some(10_00i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveArray::from([Some(0_0001i128), Some(1_5000i128), Some(2i128)])
+        .to(DataType::Decimal(6, 4));
+    let result = sub(&a, &b);
+    let expected = PrimitiveArray::from([Some(1_2299i128), Some(8_5000i128), None])
+        .to(DataType::Decimal(7, 4));
+    assert_eq!(result, expected);
+
+    let result = checked_sub(&b, &a);
+    let expected = PrimitiveArray::from([Some(-1_2299i128), Some(-8_5000i128), None])
+        .to(DataType::Decimal(7, 4));
+    assert_eq!(result, expected);
 }
 
 #[test]
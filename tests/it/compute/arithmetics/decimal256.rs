@@ -0,0 +1,95 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal256::{add, checked_add, checked_sub, sub};
+use arrow2::compute::arithmetics::{self, can_add, can_sub};
+use arrow2::datatypes::DataType;
+use arrow2::types::i256;
+
+fn array(values: &[Option<i128>], precision: usize) -> Int256Array {
+    values
+        .iter()
+        .map(|x| x.map(i256::from))
+        .collect::<Int256Array>()
+        .to(DataType::Decimal256(precision, 2))
+}
+
+#[test]
+fn test_add() {
+    let a = array(&[Some(11111), Some(-11100), None, Some(22200)], 50);
+    let b = array(&[Some(22222), Some(22200), None, Some(-33300)], 50);
+
+    let result = add(&a, &b);
+    let expected = array(&[Some(33333), Some(11100), None, Some(-11100)], 50);
+    assert_eq!(result, expected);
+
+    let result = arithmetics::add(&a, &b);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+#[should_panic]
+fn test_add_overflow() {
+    let a = array(&[Some(99999)], 5);
+    let b = array(&[Some(1)], 5);
+    add(&a, &b);
+}
+
+#[test]
+fn test_add_max() {
+    let a = Int256Array::from(&[Some(i256(ethnum::I256::MAX)), Some(i256(ethnum::I256::MIN))])
+        .to(DataType::Decimal256(76, 0));
+    let b = Int256Array::from(&[Some(i256::from(1)), Some(i256::from(-1))])
+        .to(DataType::Decimal256(76, 0));
+
+    let result = checked_add(&a, &b);
+    let expected = Int256Array::from(&[None, None]).to(DataType::Decimal256(76, 0));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_checked_add() {
+    let a = array(&[Some(99000), Some(11100), None, Some(-99999)], 5);
+    let b = array(&[Some(1000), Some(22200), None, Some(-1)], 5);
+
+    let result = checked_add(&a, &b);
+    let expected = array(&[None, Some(33300), None, None], 5);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_sub() {
+    let a = array(&[Some(11111), Some(-11100), None, Some(22200)], 50);
+    let b = array(&[Some(22222), Some(22200), None, Some(-33300)], 50);
+
+    let result = sub(&a, &b);
+    let expected = array(&[Some(-11111), Some(-33300), None, Some(55500)], 50);
+    assert_eq!(result, expected);
+
+    let result = arithmetics::sub(&a, &b);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn test_checked_sub() {
+    let a = array(&[Some(-99000), Some(11100), None, Some(99999)], 5);
+    let b = array(&[Some(1000), Some(22200), None, Some(-1)], 5);
+
+    let result = checked_sub(&a, &b);
+    let expected = array(&[None, Some(-11100), None, None], 5);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_can_add() {
+    assert!(can_add(
+        &DataType::Decimal256(5, 2),
+        &DataType::Decimal256(5, 2)
+    ));
+    assert!(!can_add(
+        &DataType::Decimal256(5, 2),
+        &DataType::Decimal256(6, 2)
+    ));
+    assert!(!can_sub(
+        &DataType::Decimal256(5, 2),
+        &DataType::Decimal256(5, 3)
+    ));
+}
@@ -1,5 +1,6 @@
 mod basic;
 mod decimal;
+mod decimal256;
 mod time;
 
 use arrow2::array::*;
@@ -383,3 +383,149 @@ fn test_add_interval_tz() {
     let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_add_interval_end_of_month() {
+    let timestamp = PrimitiveArray::from_slice([
+        1612051200i64, // 2021-01-31
+        1580428800i64, // 2020-01-31
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let interval = months_days_ns::new(1, 0, 0);
+
+    let expected = PrimitiveArray::from_slice([
+        1614470400i64, // 2021-02-28
+        1582934400i64, // 2020-02-29
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
+    assert_eq!(result, expected);
+
+    // 2021-03-31 - 1 month = 2021-02-28
+    let timestamp =
+        PrimitiveArray::from_slice([1617148800i64]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let intervals = PrimitiveArray::from_slice([interval]);
+    let expected =
+        PrimitiveArray::from_slice([1614470400i64]).to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = sub_interval(&timestamp, &intervals).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_interval_months() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    // 2021-01-31 10:00
+    let timestamp = PrimitiveArray::from_slice([1612087200i64; 5]).to(data_type.clone());
+    let intervals = PrimitiveArray::from([
+        Some(months_days_ns::new(-2, 0, 0)),
+        Some(months_days_ns::new(13, 0, 0)),
+        Some(months_days_ns::new(-11, 0, 0)),
+        Some(months_days_ns::new(1, 1, 0)),
+        None,
+    ]);
+
+    let expected = PrimitiveArray::from([
+        Some(1606730400i64), // 2020-11-30 10:00
+        Some(1646042400),    // 2022-02-28 10:00
+        Some(1582970400),    // 2020-02-29 10:00
+        Some(1614592800),    // 2021-03-01 10:00, months are added before days
+        None,
+    ])
+    .to(data_type);
+
+    let result = add_interval(&timestamp, &intervals).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_interval_end_of_month_offset() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("-03:00".to_string()));
+
+    // 2021-01-30 23:00 -03:00 is 2021-01-31 02:00 UTC; months are added in local time
+    let timestamp = PrimitiveArray::from_slice([1612058400i64]).to(data_type.clone());
+    let interval = months_days_ns::new(1, 0, 0);
+
+    // 2021-02-28 23:00 -03:00
+    let expected = PrimitiveArray::from_slice([1614564000i64]).to(data_type);
+
+    let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_interval_errors() {
+    let timestamp =
+        PrimitiveArray::from_slice([1i64, 2]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let intervals = PrimitiveArray::from_slice([months_days_ns::new(0, 1, 0)]);
+    assert!(add_interval(&timestamp, &intervals).is_err());
+    assert!(sub_interval(&timestamp, &intervals).is_err());
+
+    let timestamp = PrimitiveArray::from_slice([1i64]).to(DataType::Date64);
+    assert!(add_interval(&timestamp, &intervals).is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_add_interval_dst_gap_and_fold() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+    let interval = months_days_ns::new(0, 1, 0);
+
+    // 2021-03-13 02:30 EST; 2021-03-14 02:30 does not exist and the offset of EST is used,
+    // resulting in 2021-03-14 03:30 EDT
+    let timestamp = PrimitiveArray::from_slice([1615620600i64]).to(data_type.clone());
+    let expected = PrimitiveArray::from_slice([1615707000i64]).to(data_type.clone());
+    let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
+    assert_eq!(result, expected);
+
+    // 2021-11-06 01:30 EDT; 2021-11-07 01:30 is ambiguous and the earliest (EDT) is used
+    let timestamp = PrimitiveArray::from_slice([1636176600i64]).to(data_type.clone());
+    let expected = PrimitiveArray::from_slice([1636263000i64]).to(data_type);
+    let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_add_interval_dst() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+
+    // 2021-03-13 12:00 EST
+    let timestamp = PrimitiveArray::from_slice([1615654800i64]).to(data_type.clone());
+
+    // one day later is 2021-03-14 12:00 EDT, 23 hours later
+    let interval = months_days_ns::new(0, 1, 0);
+    let expected = PrimitiveArray::from_slice([1615737600i64]).to(data_type.clone());
+
+    let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
+    assert_eq!(result, expected);
+
+    let result = sub_interval_scalar(&expected, &Some(interval).into()).unwrap();
+    assert_eq!(result, timestamp);
+
+    // 24 hours are added in absolute time
+    let interval = months_days_ns::new(0, 0, 24 * 60 * 60 * 1_000_000_000);
+    let expected = PrimitiveArray::from_slice([1615654800i64 + 24 * 60 * 60]).to(data_type);
+
+    let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_subtract_timestamps_timezone() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let timestamp_a = PrimitiveArray::from([Some(100_010i64), None]).to(data_type.clone());
+    let timestamp_b = PrimitiveArray::from([Some(100_000i64), Some(1i64)]).to(data_type);
+
+    let expected =
+        PrimitiveArray::from([Some(10i64), None]).to(DataType::Duration(TimeUnit::Second));
+
+    let result = subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result, expected);
+
+    let timestamp_c = PrimitiveArray::from([Some(100_000i64), Some(1i64)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert!(subtract_timestamps(&timestamp_a, &timestamp_c).is_err());
+}
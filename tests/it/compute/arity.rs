@@ -0,0 +1,72 @@
+use arrow2::array::*;
+use arrow2::compute::arity::{binary_mut, unary_mut};
+use arrow2::datatypes::DataType;
+
+fn ptr(array: &Int32Array) -> *const i32 {
+    array.values().as_slice().as_ptr()
+}
+
+#[test]
+fn unary_mut_unique() {
+    let array = Int32Array::from([Some(5), None, Some(10)]);
+    let before = ptr(&array);
+
+    let result = unary_mut(array, |x| x * 2);
+    assert_eq!(result, Int32Array::from([Some(10), None, Some(20)]));
+    assert_eq!(ptr(&result), before);
+}
+
+#[test]
+fn unary_mut_shared() {
+    let array = Int32Array::from([Some(5), None, Some(10)]);
+    let shared = array.clone();
+
+    let result = unary_mut(array, |x| x * 2);
+    assert_eq!(result, Int32Array::from([Some(10), None, Some(20)]));
+    assert_ne!(ptr(&result), ptr(&shared));
+    // the shared array is unchanged
+    assert_eq!(shared, Int32Array::from([Some(5), None, Some(10)]));
+}
+
+#[test]
+fn binary_mut_lhs_unique() {
+    let lhs = Int32Array::from([Some(5), Some(6), None]).to(DataType::Date32);
+    let rhs = Int32Array::from([Some(1), None, Some(3)]);
+    let _shared = rhs.clone();
+    let before = ptr(&lhs);
+
+    let result = binary_mut(lhs, rhs, |l, r| l - r);
+    let expected = Int32Array::from([Some(4), None, None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+    assert_eq!(ptr(&result), before);
+}
+
+#[test]
+fn binary_mut_rhs_unique() {
+    let lhs = Int32Array::from([Some(5), Some(6), None]).to(DataType::Date32);
+    let rhs = Int32Array::from([Some(1), None, Some(3)]);
+    let shared = lhs.clone();
+    let before = ptr(&rhs);
+
+    // the operation is not commutative: the order of the arguments is preserved
+    let result = binary_mut(lhs, rhs, |l, r| l - r);
+    let expected = Int32Array::from([Some(4), None, None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+    assert_eq!(ptr(&result), before);
+    assert_eq!(
+        shared,
+        Int32Array::from([Some(5), Some(6), None]).to(DataType::Date32)
+    );
+}
+
+#[test]
+fn binary_mut_shared() {
+    let lhs = Int32Array::from_slice([5, 6, 7]);
+    let rhs = Int32Array::from_slice([1, 2, 3]);
+    let (lhs_shared, rhs_shared) = (lhs.clone(), rhs.clone());
+
+    let result = binary_mut(lhs, rhs, |l, r| l - r);
+    assert_eq!(result, Int32Array::from_slice([4, 4, 4]));
+    assert_ne!(ptr(&result), ptr(&lhs_shared));
+    assert_ne!(ptr(&result), ptr(&rhs_shared));
+}
@@ -39,3 +39,42 @@ fn test_not() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_popcount() {
+    let a = Int8Array::from(&[Some(0), Some(-1), None, Some(0b0101_0110)]);
+    let result = popcount(&a);
+    let expected = UInt32Array::from(&[Some(0), Some(8), None, Some(4)]);
+
+    assert_eq!(result, expected);
+
+    let a = UInt64Array::from_slice([u64::MAX, 1 << 63]);
+    assert_eq!(popcount(&a), UInt32Array::from_slice([64, 1]));
+}
+
+#[test]
+fn test_bit_aggregations() {
+    let a = UInt8Array::from(&[Some(0b1110), None, Some(0b0111), Some(0b1100)]);
+
+    assert_eq!(bit_and_agg(&a), Some(0b0100));
+    assert_eq!(bit_or_agg(&a), Some(0b1111));
+    assert_eq!(bit_xor_agg(&a), Some(0b0101));
+
+    let a = Int32Array::from_slice([-1, 6, 3]);
+    assert_eq!(bit_and_agg(&a), Some(2));
+    assert_eq!(bit_or_agg(&a), Some(-1));
+    assert_eq!(bit_xor_agg(&a), Some(-6));
+}
+
+#[test]
+fn test_bit_aggregations_empty() {
+    let a = Int32Array::from_slice([]);
+    assert_eq!(bit_and_agg(&a), None);
+    assert_eq!(bit_or_agg(&a), None);
+    assert_eq!(bit_xor_agg(&a), None);
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(bit_and_agg(&a), None);
+    assert_eq!(bit_or_agg(&a), None);
+    assert_eq!(bit_xor_agg(&a), None);
+}
@@ -447,3 +447,48 @@ fn test_any_all() {
     assert!(!any(&array));
     assert!(!all(&array));
 }
+
+#[test]
+fn dyn_null_logic() {
+    let lhs = BooleanArray::from(&[Some(false), Some(true), None]);
+    let rhs = BooleanArray::from(&[None, None, None]);
+
+    let result = and_dyn(&lhs, &rhs, NullLogic::Propagate).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, None, None]));
+    let result = and_dyn(&lhs, &rhs, NullLogic::Kleene).unwrap();
+    assert_eq!(result, BooleanArray::from(&[Some(false), None, None]));
+
+    let result = or_dyn(&lhs, &rhs, NullLogic::Propagate).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, None, None]));
+    let result = or_dyn(&lhs, &rhs, NullLogic::Kleene).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, Some(true), None]));
+
+    let scalar = BooleanScalar::new(None);
+    let result = and_scalar_dyn(&lhs, &scalar, NullLogic::Propagate).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, None, None]));
+    let result = and_scalar_dyn(&lhs, &scalar, NullLogic::Kleene).unwrap();
+    assert_eq!(result, BooleanArray::from(&[Some(false), None, None]));
+
+    let result = or_scalar_dyn(&lhs, &scalar, NullLogic::Propagate).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, None, None]));
+    let result = or_scalar_dyn(&lhs, &scalar, NullLogic::Kleene).unwrap();
+    assert_eq!(result, BooleanArray::from(&[None, Some(true), None]));
+
+    let array = BooleanArray::from(&[Some(true), None]);
+    assert_eq!(any_dyn(&array, NullLogic::Propagate).unwrap(), Some(true));
+    assert_eq!(any_dyn(&array, NullLogic::Kleene).unwrap(), Some(true));
+    assert_eq!(all_dyn(&array, NullLogic::Propagate).unwrap(), Some(false));
+    assert_eq!(all_dyn(&array, NullLogic::Kleene).unwrap(), None);
+}
+
+#[test]
+fn dyn_non_boolean() {
+    let array = Int32Array::from_slice([1, 2]);
+    let boolean = BooleanArray::from_slice([true, false]);
+    assert!(and_dyn(&array, &boolean, NullLogic::Kleene).is_err());
+    assert!(or_dyn(&boolean, &array, NullLogic::Propagate).is_err());
+    assert!(any_dyn(&array, NullLogic::Kleene).is_err());
+
+    let scalar = arrow2::scalar::PrimitiveScalar::from(Some(1i32));
+    assert!(and_scalar_dyn(&boolean, &scalar, NullLogic::Kleene).is_err());
+}
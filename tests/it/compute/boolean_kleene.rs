@@ -214,3 +214,123 @@ fn array_or_none() {
 
     assert_eq!(result, expected);
 }
+
+const VALUES: [Option<bool>; 3] = [Some(true), Some(false), None];
+
+fn kleene_and(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+fn kleene_or(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+#[test]
+fn truth_table() {
+    // every combination of (lhs, rhs)
+    let lhs = VALUES
+        .iter()
+        .flat_map(|x| std::iter::repeat(*x).take(3))
+        .collect::<Vec<_>>();
+    let rhs = VALUES.iter().cycle().take(9).copied().collect::<Vec<_>>();
+    let and_expected = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| kleene_and(*l, *r))
+        .collect::<Vec<_>>();
+    let or_expected = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| kleene_or(*l, *r))
+        .collect::<Vec<_>>();
+    let lhs = BooleanArray::from(lhs);
+    let rhs = BooleanArray::from(rhs);
+
+    assert_eq!(and(&lhs, &rhs), BooleanArray::from(and_expected));
+    assert_eq!(or(&lhs, &rhs), BooleanArray::from(or_expected));
+}
+
+#[test]
+fn truth_table_scalar() {
+    for array_validity in [true, false] {
+        let values = if array_validity {
+            VALUES.to_vec()
+        } else {
+            vec![Some(true), Some(false)]
+        };
+        let array = BooleanArray::from(values.clone());
+        for scalar in VALUES {
+            let and_expected = values
+                .iter()
+                .map(|x| kleene_and(*x, scalar))
+                .collect::<BooleanArray>();
+            let or_expected = values
+                .iter()
+                .map(|x| kleene_or(*x, scalar))
+                .collect::<BooleanArray>();
+
+            let scalar = BooleanScalar::new(scalar);
+            assert_eq!(and_scalar(&array, &scalar), and_expected);
+            assert_eq!(or_scalar(&array, &scalar), or_expected);
+        }
+    }
+}
+
+#[test]
+fn scalar_short_circuit_drops_validity() {
+    let array = BooleanArray::from(&[Some(true), Some(false), None]);
+
+    let result = and_scalar(&array, &BooleanScalar::new(Some(false)));
+    assert!(result.validity().is_none());
+
+    let result = or_scalar(&array, &BooleanScalar::new(Some(true)));
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn any_all() {
+    let cases = [
+        (vec![], Some(false), Some(true)),
+        (vec![Some(true), Some(true)], Some(true), Some(true)),
+        (vec![Some(false), Some(false)], Some(false), Some(false)),
+        (vec![Some(true), Some(false)], Some(true), Some(false)),
+        (vec![Some(true), None], Some(true), None),
+        (vec![Some(false), None], None, Some(false)),
+        (vec![None, None], None, None),
+    ];
+    for (values, any_expected, all_expected) in cases {
+        let array = BooleanArray::from(values.clone());
+        assert_eq!(any_kleene(&array), any_expected, "any({:?})", values);
+        assert_eq!(all_kleene(&array), all_expected, "all({:?})", values);
+    }
+
+    // nulls outside of the slice are ignored
+    let array = BooleanArray::from(&[None, Some(true), Some(true), None]).slice(1, 2);
+    assert_eq!(any_kleene(&array), Some(true));
+    assert_eq!(all_kleene(&array), Some(true));
+}
+
+#[test]
+fn any_all_ignoring_nulls() {
+    let cases = [
+        (vec![], false, false),
+        (vec![Some(true), Some(true)], true, true),
+        (vec![Some(false), Some(false)], false, false),
+        (vec![Some(true), None], true, false),
+        (vec![Some(false), None], false, false),
+        (vec![None, None], false, false),
+    ];
+    for (values, any_expected, all_expected) in cases {
+        let array = BooleanArray::from(values.clone());
+        assert_eq!(any(&array), any_expected, "any({:?})", values);
+        assert_eq!(all(&array), all_expected, "all({:?})", values);
+    }
+}
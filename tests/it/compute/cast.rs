@@ -1,7 +1,9 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, cast_with_format, is_utf8_valid, try_cast_binary_to_utf8, CastOptions,
+};
 use arrow2::datatypes::*;
-use arrow2::types::{days_ms, months_days_ns, NativeType};
+use arrow2::types::{days_ms, f16, i256, months_days_ns, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -90,6 +92,65 @@ fn f32_as_u8_overflow() {
     assert_eq!(expected, b.as_ref());
 }
 
+#[test]
+fn f64_to_f16() {
+    let array = Float64Array::from(&[
+        Some(1.5),
+        Some(-65520.0),
+        Some(f64::INFINITY),
+        Some(f64::NAN),
+        // the smallest subnormal, and a value rounded to it
+        Some(2f64.powi(-24)),
+        Some(1e-7),
+        None,
+    ]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Float16Array>().unwrap();
+
+    let bits = c.iter().map(|x| x.map(|x| x.to_bits())).collect::<Vec<_>>();
+    assert_eq!(
+        bits,
+        vec![
+            Some(0x3E00),
+            Some(0xFC00),
+            Some(0x7C00),
+            Some(0x7E00),
+            Some(0x0001),
+            Some(0x0002),
+            None
+        ]
+    );
+}
+
+#[test]
+fn f32_to_f16() {
+    let array = Float32Array::from(&[Some(1.5), Some(f32::NEG_INFINITY), Some(f32::NAN), None]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Float16Array>().unwrap();
+
+    let bits = c.iter().map(|x| x.map(|x| x.to_bits())).collect::<Vec<_>>();
+    assert_eq!(bits, vec![Some(0x3E00), Some(0xFC00), Some(0x7E00), None]);
+}
+
+#[test]
+fn f16_to_f64() {
+    let array = Float16Array::from(&[
+        Some(f16::from_f32(1.5)),
+        Some(f16::from_bits(0x0001)),
+        Some(f16::from_f32(f32::NEG_INFINITY)),
+        Some(f16::from_f32(f32::NAN)),
+        None,
+    ]);
+    let b = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+
+    assert_eq!(c.value(0), 1.5);
+    assert_eq!(c.value(1), 2f64.powi(-24));
+    assert_eq!(c.value(2), f64::NEG_INFINITY);
+    assert!(c.value(3).is_nan());
+    assert!(c.is_null(4));
+}
+
 #[test]
 fn i32_to_u8() {
     let array = Int32Array::from_slice([-5, 6, -7, 8, 100000000]);
@@ -279,6 +340,57 @@ fn float32_to_decimal() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn float64_to_decimal() {
+    let array = Float64Array::from(&[
+        Some(9.994), // rounds down to the largest value of (3, 2)
+        Some(9.996), // rounds up to 10.00, which does not fit in (3, 2)
+        Some(-9.994),
+        Some(-9.996),
+        Some(0.125), // ties away from zero
+        Some(f64::NAN),
+        Some(f64::INFINITY),
+        Some(f64::NEG_INFINITY),
+        None,
+    ]);
+
+    let b = cast(&array, &DataType::Decimal(3, 2), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+
+    let expected = Int128Array::from(&[
+        Some(999),
+        None,
+        Some(-999),
+        None,
+        Some(13),
+        None,
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Decimal(3, 2));
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn float64_to_decimal_not_safe() {
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+
+    // `NaN` and infinite values are null even when the cast is not safe
+    let array = Float64Array::from(&[Some(9.99), Some(f64::NAN), Some(f64::INFINITY), None]);
+    let b = cast(&array, &DataType::Decimal(3, 2), options).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+    let expected = Int128Array::from(&[Some(999), None, None, None]).to(DataType::Decimal(3, 2));
+    assert_eq!(c, &expected);
+
+    // overflows error
+    let array = Float64Array::from_slice([9.99, 10.0]);
+    assert!(cast(&array, &DataType::Decimal(3, 2), options).is_err());
+}
+
 #[test]
 fn int32_to_decimal_scaled() {
     // 10 and -10 can't be represented with precision 1 and scale 1
@@ -336,6 +448,80 @@ fn decimal_to_decimal_fast() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn decimal_to_decimal256() {
+    // increase scale and precision
+    let array = Int128Array::from(&[Some(2), Some(i128::MAX), Some(-2), Some(i128::MIN), None])
+        .to(DataType::Decimal(38, 0));
+
+    let b = cast(&array, &DataType::Decimal256(76, 2), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Int256Array>().unwrap();
+
+    let expected = Int256Array::from(&[
+        Some(i256::from(200)),
+        Some(i256(ethnum::I256::new(i128::MAX) * 100)),
+        Some(i256::from(-200)),
+        Some(i256(ethnum::I256::new(i128::MIN) * 100)),
+        None,
+    ])
+    .to(DataType::Decimal256(76, 2));
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal256_to_decimal() {
+    // i128::MAX + 1 and 1000 do not fit in a precision of 3
+    let array = Int256Array::from(&[
+        Some(i256::from(-999)),
+        Some(i256(ethnum::I256::new(i128::MAX) + 1)),
+        Some(i256::from(1000)),
+        Some(i256::from(12345)),
+        None,
+    ])
+    .to(DataType::Decimal256(40, 1));
+
+    let b = cast(&array, &DataType::Decimal(3, 0), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Int128Array>().unwrap();
+
+    let expected =
+        Int128Array::from(&[Some(-99), None, Some(100), None, None]).to(DataType::Decimal(3, 0));
+    assert_eq!(c, &expected);
+
+    // unsafe casts error on overflow
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    assert!(cast(&array, &DataType::Decimal(3, 0), options).is_err());
+    assert!(cast(&array, &DataType::Decimal(38, 1), options).is_err());
+}
+
+#[test]
+fn decimal256_to_decimal256() {
+    // decrease scale and precision
+    let array = Int256Array::from(&[
+        Some(i256(ethnum::I256::MIN)),
+        Some(i256::from(-25)),
+        Some(i256(ethnum::I256::MAX)),
+        Some(i256::from(999999)),
+        None,
+    ])
+    .to(DataType::Decimal256(76, 1));
+
+    let b = cast(&array, &DataType::Decimal256(5, 0), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Int256Array>().unwrap();
+
+    let expected = Int256Array::from(&[
+        None,
+        Some(i256::from(-2)),
+        None,
+        Some(i256::from(99999)),
+        None,
+    ])
+    .to(DataType::Decimal256(5, 0));
+    assert_eq!(c, &expected);
+}
+
 #[test]
 fn decimal_to_float() {
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
@@ -461,6 +647,8 @@ fn consistency() {
         Time32(TimeUnit::Millisecond),
         Decimal(1, 2),
         Decimal(2, 2),
+        Decimal256(1, 2),
+        Decimal256(2, 2),
         Date64,
         Utf8,
         LargeUtf8,
@@ -776,6 +964,60 @@ fn naive_timestamp_to_utf8() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn date32_to_utf8_with_format() {
+    let array = Int32Array::from([Some(0), None, Some(10_000)]).to(DataType::Date32);
+
+    let result = cast_with_format(&array, &DataType::Utf8, "%m/%d/%Y").unwrap();
+    let expected = Utf8Array::<i32>::from([Some("01/01/1970"), None, Some("05/19/1997")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast_with_format(&array, &DataType::LargeUtf8, "%Y").unwrap();
+    let expected = Utf8Array::<i64>::from([Some("1970"), None, Some("1997")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn temporal_to_utf8_with_format() {
+    let array = Int32Array::from_slice([3_723_004]).to(DataType::Time32(TimeUnit::Millisecond));
+    let result = cast_with_format(&array, &DataType::Utf8, "%H:%M:%S%.3f").unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["01:02:03.004"]),
+        result.as_ref()
+    );
+
+    let array = Int64Array::from_slice([851020797000000000])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let result = cast_with_format(&array, &DataType::Utf8, "%d/%m/%Y %H:%M").unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["19/12/1996 18:39"]),
+        result.as_ref()
+    );
+
+    let array = Int64Array::from_slice([851020797000000000]).to(DataType::Timestamp(
+        TimeUnit::Nanosecond,
+        Some("-02:00".to_string()),
+    ));
+    let result = cast_with_format(&array, &DataType::Utf8, "%H:%M %z").unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["16:39 -0200"]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn utf8_with_invalid_format() {
+    let array = Int32Array::from_slice([0]).to(DataType::Date32);
+
+    // invalid specifier
+    assert!(cast_with_format(&array, &DataType::Utf8, "%Q").is_err());
+    // a date has no hours
+    assert!(cast_with_format(&array, &DataType::Utf8, "%H").is_err());
+    // not a temporal type
+    let array = Int32Array::from_slice([0]);
+    assert!(cast_with_format(&array, &DataType::Utf8, "%Y").is_err());
+}
+
 #[test]
 fn null_array_from_and_to_others() {
     macro_rules! typed_test {
@@ -860,3 +1102,399 @@ fn dict_keys() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+const UNSAFE: CastOptions = CastOptions {
+    wrapped: false,
+    partial: false,
+    safe: false,
+};
+
+/// Asserts that casting `array` to `expected.data_type()` yields `expected` in safe mode
+/// and errors in unsafe mode.
+fn test_safe_and_unsafe(array: &dyn Array, expected: &dyn Array) {
+    let result = cast(array, expected.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    assert!(cast(array, expected.data_type(), UNSAFE).is_err());
+}
+
+#[test]
+fn safe_utf8_to_numeric() {
+    let array = Utf8Array::<i32>::from([Some("5"), Some("seven"), None]);
+    test_safe_and_unsafe(&array, &Int32Array::from([Some(5), None, None]));
+    test_safe_and_unsafe(&array, &Float64Array::from([Some(5.0), None, None]));
+
+    // valid values cast in unsafe mode
+    let array = Utf8Array::<i32>::from([Some("5"), None]);
+    let result = cast(&array, &DataType::Int32, UNSAFE).unwrap();
+    assert_eq!(Int32Array::from([Some(5), None]), result.as_ref());
+}
+
+#[test]
+fn utf8_to_boolean() {
+    let array = Utf8Array::<i32>::from([
+        Some("true"),
+        Some("FALSE"),
+        Some("T"),
+        Some("f"),
+        Some("1"),
+        Some("0"),
+        Some("Yes"),
+        Some("nO"),
+        Some(""),
+        None,
+    ]);
+    let expected = BooleanArray::from([
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        None,
+        None,
+    ]);
+    let result = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    // empty strings are null also in unsafe mode
+    let result = cast(&array, &DataType::Boolean, UNSAFE).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i64>::from([Some("True"), Some("maybe"), Some("2"), None]);
+    test_safe_and_unsafe(&array, &BooleanArray::from([Some(true), None, None, None]));
+    assert!(can_cast_types(&DataType::LargeUtf8, &DataType::Boolean));
+}
+
+#[test]
+fn safe_utf8_to_temporal() {
+    let array = Utf8Array::<i32>::from([Some("1970-01-02"), Some("yesterday"), None]);
+    test_safe_and_unsafe(
+        &array,
+        &Int32Array::from([Some(1), None, None]).to(DataType::Date32),
+    );
+    test_safe_and_unsafe(
+        &array,
+        &Int64Array::from([Some(86_400_000), None, None]).to(DataType::Date64),
+    );
+
+    let array = Utf8Array::<i32>::from([Some("1996-12-19T16:39:57-02:00"), Some("a")]);
+    test_safe_and_unsafe(
+        &array,
+        &Int64Array::from([Some(851013597000000000), None])
+            .to(DataType::Timestamp(TimeUnit::Nanosecond, None)),
+    );
+    test_safe_and_unsafe(
+        &array,
+        &Int64Array::from([Some(851020797000000000), None]).to(DataType::Timestamp(
+            TimeUnit::Nanosecond,
+            Some("-02:00".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn safe_float_to_int() {
+    let array = Float64Array::from([Some(1.5), Some(f64::NAN), Some(f64::INFINITY), None]);
+    test_safe_and_unsafe(&array, &Int32Array::from([Some(1), None, None, None]));
+
+    let array = Float64Array::from([Some(1.5), Some(1e10)]);
+    test_safe_and_unsafe(&array, &Int32Array::from([Some(1), None]));
+
+    // overflowing integers
+    let array = Int32Array::from([Some(1), Some(-1)]);
+    test_safe_and_unsafe(&array, &UInt8Array::from([Some(1), None]));
+}
+
+#[test]
+fn safe_decimal() {
+    // rescaling
+    let array = Int128Array::from([Some(1), Some(9)]).to(DataType::Decimal(1, 0));
+    test_safe_and_unsafe(
+        &array,
+        &Int128Array::from([Some(10), None]).to(DataType::Decimal(1, 1)),
+    );
+
+    // to integer
+    let array = Int128Array::from([Some(10), Some(3000)]).to(DataType::Decimal(4, 1));
+    test_safe_and_unsafe(&array, &Int8Array::from([Some(1), None]));
+
+    // from integer
+    let array = Int32Array::from([Some(1), Some(1000)]);
+    test_safe_and_unsafe(
+        &array,
+        &Int128Array::from([Some(1), None]).to(DataType::Decimal(2, 0)),
+    );
+
+    // from float (`NaN` is null in both modes, so only the overflow fails)
+    let array = Float64Array::from([Some(1.0), Some(f64::NAN), Some(100.0)]);
+    test_safe_and_unsafe(
+        &array,
+        &Int128Array::from([Some(10), None, None]).to(DataType::Decimal(2, 1)),
+    );
+}
+
+#[test]
+fn safe_timestamp_to_timestamp() {
+    // widening overflows
+    let array =
+        Int64Array::from([Some(1), Some(i64::MAX)]).to(DataType::Timestamp(TimeUnit::Second, None));
+    test_safe_and_unsafe(
+        &array,
+        &Int64Array::from([Some(1_000), None]).to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+    );
+
+    // narrowing truncates
+    let array = Int64Array::from([Some(1_000), Some(1_500), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    test_safe_and_unsafe(
+        &array,
+        &Int64Array::from([Some(1), Some(1), None]).to(DataType::Timestamp(TimeUnit::Second, None)),
+    );
+
+    // narrowing without truncation is allowed in unsafe mode
+    let array =
+        Int64Array::from([Some(1_000), None]).to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let result = cast(&array, &DataType::Timestamp(TimeUnit::Second, None), UNSAFE).unwrap();
+    let expected =
+        Int64Array::from([Some(1), None]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn list_to_large_list() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let expected_data = data
+        .iter()
+        .map(|x| x.as_ref().map(|x| x.iter().map(|x| x.map(|x| x as i64))));
+    let mut expected = MutableListArray::<i64, MutablePrimitiveArray<i64>>::new();
+    expected.try_extend(expected_data).unwrap();
+    let expected: ListArray<i64> = expected.into();
+
+    let result = cast(&array, expected.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    // round-trip
+    let result = cast(result.as_ref(), array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn list_to_fixed_size_list_invalid_length() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        Some(vec![Some(3)]),
+        None,
+        Some(vec![Some(4), None]),
+    ];
+
+    let mut list = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i64> = list.into();
+
+    let inner = MutablePrimitiveArray::<i32>::new();
+    let mut fixed = MutableFixedSizeListArray::<MutablePrimitiveArray<i32>>::new(inner, 2);
+    fixed
+        .try_extend([
+            Some(vec![Some(1i32), Some(2)]),
+            None,
+            None,
+            Some(vec![Some(4), None]),
+        ])
+        .unwrap();
+    let fixed: FixedSizeListArray = fixed.into();
+
+    let result = cast(&list, fixed.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(fixed, result.as_ref());
+
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    assert!(cast(&list, fixed.data_type(), options).is_err());
+
+    // nulls slots are not checked in unsafe mode
+    let list = list.slice(2, 2);
+    let result = cast(&list, fixed.data_type(), options).unwrap();
+    assert_eq!(fixed.slice(2, 2), result.as_ref());
+}
+
+#[test]
+fn map_to_map() {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend(vec![
+            Some(vec![("a", Some(1)), ("b", None)]),
+            None,
+            Some(vec![("c", Some(3))]),
+        ])
+        .unwrap();
+    let array: MapArray = array.into();
+
+    let to_type = MapArray::default_datatype(DataType::LargeUtf8, DataType::Float64);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let mut expected = MutableMapArray::<MutableUtf8Array<i64>, MutablePrimitiveArray<f64>>::new();
+    expected
+        .try_extend(vec![
+            Some(vec![("a", Some(1.0)), ("b", None)]),
+            None,
+            Some(vec![("c", Some(3.0))]),
+        ])
+        .unwrap();
+    let expected: MapArray = expected.into();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let to_type = MapArray::default_datatype(DataType::Utf8, DataType::Struct(vec![]));
+    assert!(!can_cast_types(array.data_type(), &to_type));
+}
+
+#[test]
+fn struct_to_struct() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Int32Array::from([Some(1), None]).boxed(),
+            Utf8Array::<i32>::from([Some("x"), Some("y")]).boxed(),
+        ],
+        None,
+    );
+
+    // reordered, cast and with an additional nullable field
+    let to_fields = vec![
+        Field::new("b", DataType::LargeUtf8, true),
+        Field::new("c", DataType::Boolean, true),
+        Field::new("a", DataType::Int64, true),
+    ];
+    let expected = StructArray::new(
+        DataType::Struct(to_fields.clone()),
+        vec![
+            Utf8Array::<i64>::from([Some("x"), Some("y")]).boxed(),
+            BooleanArray::new_null(DataType::Boolean, 2).boxed(),
+            Int64Array::from([Some(1), None]).boxed(),
+        ],
+        None,
+    );
+    assert!(can_cast_types(array.data_type(), expected.data_type()));
+
+    let result = cast(&array, expected.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    // round-trip
+    let result = cast(result.as_ref(), array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    // missing non-nullable fields cannot be created
+    let to_type = DataType::Struct(vec![Field::new("d", DataType::Int32, false)]);
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+}
+
+#[test]
+fn binary_is_utf8_valid() {
+    let array = BinaryArray::<i64>::from([
+        Some(&b"ok"[..]),
+        None,
+        Some(&[0xc3u8, 0x28][..]),
+        Some("ñ".as_bytes()),
+        Some(&b""[..]),
+    ]);
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(true), Some(true)]);
+    assert_eq!(is_utf8_valid(&array), expected);
+}
+
+#[test]
+fn binary_try_to_utf8() {
+    let array = BinaryArray::<i32>::from([Some(&b"ab"[..]), None, Some("ñ".as_bytes())]);
+    let result = try_cast_binary_to_utf8(&array).unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from([Some("ab"), None, Some("ñ")])
+    );
+
+    let array = BinaryArray::<i64>::from([Some(&b"ab"[..]), Some(&[b'a', b'b', 0xff][..])]);
+    let error = try_cast_binary_to_utf8(&array).unwrap_err().to_string();
+    assert!(error.contains("index 1"), "{}", error);
+    assert!(error.contains("position 2"), "{}", error);
+}
+
+#[test]
+fn binary_try_to_utf8_invalid_null_slot() {
+    let array = BinaryArray::<i32>::from([Some(&b"ab"[..]), Some(&[0xffu8][..]), Some(&b"c"[..])]);
+    let array = array.with_validity(Some([true, false, true].into()));
+
+    let result = try_cast_binary_to_utf8(&array).unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from([Some("ab"), None, Some("c")])
+    );
+}
+
+#[test]
+fn run_end_encoded_to_primitive() {
+    let array = ree_encode(&Int32Array::from([Some(1), Some(1), None, Some(3)]));
+
+    assert!(can_cast_types(array.data_type(), &DataType::Float64));
+    let result = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let expected = Float64Array::from([Some(1.0), Some(1.0), None, Some(3.0)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = cast(&array.slice(1, 2), &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("1"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn primitive_to_run_end_encoded() {
+    let array = Int64Array::from([Some(1), Some(1), None, None, Some(3)]);
+    let to_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Int32);
+
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i32>>()
+        .unwrap();
+    assert_eq!(result.run_ends().as_slice(), &[2, 4, 5]);
+    assert_eq!(result.values(), &Int32Array::from([Some(1), None, Some(3)]));
+}
+
+#[test]
+fn run_end_encoded_to_run_end_encoded() {
+    // 1.2 and 1.4 are in different runs, but are cast to the same value
+    let array = ree_encode(&Float64Array::from_slice([1.2, 1.4, 2.0]));
+    let to_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Int32);
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i32>>()
+        .unwrap();
+    assert_eq!(result.run_ends().as_slice(), &[2, 3]);
+    assert_eq!(result.values(), &Int32Array::from_slice([1, 2]));
+}
+
+#[test]
+fn to_run_end_encoded_unsupported() {
+    let to_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Utf8);
+    let array = Int32Array::from_slice([1, 2]);
+
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+}
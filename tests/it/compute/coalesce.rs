@@ -0,0 +1,91 @@
+use arrow2::array::*;
+use arrow2::compute::coalesce::coalesce;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let a = Int32Array::from([Some(1), None, None, None, Some(5), None]);
+    let b = Int32Array::from([Some(10), Some(20), None, None, None, None]);
+    let c = Int32Array::from([None, Some(200), Some(300), None, Some(500), Some(600)]);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+    let expected = Int32Array::from([Some(1), Some(20), Some(300), None, Some(5), Some(600)]);
+    assert_eq!(expected, result.as_ref());
+
+    // the order of the arrays matters
+    let result = coalesce(&[&c, &b, &a]).unwrap();
+    let expected = Int32Array::from([Some(10), Some(200), Some(300), None, Some(500), Some(600)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn single_and_without_nulls() {
+    let a = Int32Array::from([Some(1), None]);
+    assert_eq!(a, coalesce(&[&a]).unwrap().as_ref());
+
+    let b = Int32Array::from_slice([1, 2]);
+    let c = Int32Array::from([None, Some(3)]);
+    assert_eq!(b, coalesce(&[&b, &c]).unwrap().as_ref());
+}
+
+#[test]
+fn utf8_sliced() {
+    let a = Utf8Array::<i32>::from([Some("a"), None, None, Some("d")]).slice(1, 3);
+    let b = Utf8Array::<i32>::from([Some("x"), Some("y"), None]);
+
+    let result = coalesce(&[&a, &b]).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("x"), Some("y"), Some("d")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn errors() {
+    assert!(coalesce(&[]).is_err());
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(coalesce(&[&a, &b]).is_err());
+
+    let b = Int64Array::from_slice([1, 2]);
+    assert!(coalesce(&[&a, &b]).is_err());
+
+    let b = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    assert!(coalesce(&[&a, &b]).is_err());
+}
+
+#[test]
+fn three_way_list() {
+    let list = |data: Vec<Option<Vec<Option<i32>>>>| {
+        let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data).unwrap();
+        ListArray::<i32>::from(array)
+    };
+    let a = list(vec![Some(vec![Some(1)]), None, None, None]);
+    let b = list(vec![None, Some(vec![]), None, None]);
+    let c = list(vec![
+        Some(vec![Some(3)]),
+        Some(vec![Some(4)]),
+        Some(vec![None, Some(5)]),
+        None,
+    ]);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+    let expected = list(vec![
+        Some(vec![Some(1)]),
+        Some(vec![]),
+        Some(vec![None, Some(5)]),
+        None,
+    ]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn all_null_rows() {
+    let a = Utf8Array::<i32>::from([None, None, Some("a")]);
+    let b = Utf8Array::<i32>::from([None::<&str>, None, None]);
+    let c = Utf8Array::<i32>::new_null(DataType::Utf8, 3);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+    let expected = Utf8Array::<i32>::from([None, None, Some("a")]);
+    assert_eq!(expected, result.as_ref());
+}
@@ -2,7 +2,7 @@ use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::comparison::{self, boolean::*, primitive, utf8};
 use arrow2::datatypes::{DataType, DataType::*, IntegerType, IntervalUnit, TimeUnit};
-use arrow2::scalar::new_scalar;
+use arrow2::scalar::{new_scalar, DictionaryScalar, Scalar, Utf8Scalar};
 
 #[test]
 fn consistency() {
@@ -18,6 +18,8 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Decimal(38, 2),
+        Decimal256(76, 2),
         Float16,
         Float32,
         Float64,
@@ -144,6 +146,92 @@ fn test_lt() {
     );
 }
 
+#[test]
+fn test_lt_f16() {
+    use arrow2::types::f16;
+    let a = Float16Array::from([
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_f32(f32::NAN)),
+        Some(f16::from_f32(-0.0)),
+        Some(f16::from_f32(f32::NEG_INFINITY)),
+        Some(f16::from_bits(0x0001)),
+        None,
+    ]);
+    let b = Float16Array::from([
+        Some(f16::from_f32(2.0)),
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_f32(0.0)),
+        Some(f16::from_f32(-65504.0)),
+        Some(f16::from_f32(0.0)),
+        Some(f16::from_f32(1.0)),
+    ]);
+
+    let result = comparison::lt(&a, &b);
+    let expected = BooleanArray::from([
+        Some(true),
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(false),
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    let result = comparison::eq(&a, &b);
+    let expected = BooleanArray::from([
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(false),
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    // NaN is unordered, as for `f32`
+    let result = comparison::gt_eq(&a, &b);
+    let expected = BooleanArray::from([
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        None,
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_lt_decimal256() {
+    use arrow2::types::i256;
+    let a = Int256Array::from(&[
+        Some(i256(ethnum::I256::MIN)),
+        Some(i256::from(-1)),
+        Some(i256::from(1)),
+        None,
+    ])
+    .to(Decimal256(76, 2));
+    let b = Int256Array::from(&[
+        Some(i256(ethnum::I256::MAX)),
+        Some(i256::from(-2)),
+        Some(i256::from(1)),
+        Some(i256::from(1)),
+    ])
+    .to(Decimal256(76, 2));
+
+    let result = comparison::lt(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), Some(false), None])
+    );
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(false), Some(true), None])
+    );
+}
+
 #[test]
 fn test_lt_scalar_true() {
     cmp_bool_scalar!(lt_scalar, &[false, true], true, &[true, false]);
@@ -414,3 +502,49 @@ fn primitive_and_validity() {
     let expected = BooleanArray::from_slice([false, true]);
     assert_eq!(primitive::eq_and_validity(&a1, &a2), expected);
 }
+
+fn dictionary_scalar(value: &str) -> DictionaryScalar<i32> {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(Utf8), false);
+    let value: Box<dyn Scalar> = Box::new(Utf8Scalar::<i32>::from(Some(value)));
+    DictionaryScalar::new(data_type, Some(value))
+}
+
+#[test]
+fn dictionary_eq_scalar() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend([Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let scalar = dictionary_scalar("a");
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    assert_eq!(comparison::eq_scalar(&array, &scalar), expected);
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(false)]);
+    assert_eq!(comparison::neq_scalar(&array, &scalar), expected);
+
+    // absent from the dictionary
+    let scalar = dictionary_scalar("c");
+    let expected = BooleanArray::from([Some(false), None, Some(false), Some(false)]);
+    assert_eq!(comparison::eq_scalar(&array, &scalar), expected);
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(true)]);
+    assert_eq!(comparison::neq_scalar(&array, &scalar), expected);
+
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(true)]);
+    assert_eq!(comparison::lt_scalar(&array, &scalar), expected);
+}
+
+#[test]
+fn dictionary_eq_scalar_repeated_values() {
+    // dictionary values are not necessarily unique
+    let keys = Int32Array::from([Some(0), Some(1), None, Some(2)]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(Utf8), false);
+    let array = DictionaryArray::try_new(data_type, keys, values).unwrap();
+
+    let scalar = dictionary_scalar("a");
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(comparison::eq_scalar(&array, &scalar), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false)]);
+    assert_eq!(comparison::neq_scalar(&array, &scalar), expected);
+}
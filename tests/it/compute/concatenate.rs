@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::concatenate::{concat_str, concatenate, NullHandling};
+use arrow2::datatypes::{DataType, Field, IntegerType, UnionMode};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +116,298 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn binary_array_slices() -> Result<()> {
+    let input_1 = Utf8Array::<i64>::from([Some("a"), Some("bc"), None, Some("def")]).slice(1, 3);
+    let input_2 = Utf8Array::<i64>::from_slice(["gh", "i"]).slice(1, 1);
+    let arr = concatenate(&[&input_1, &input_2])?;
+
+    let expected = Utf8Array::<i64>::from([Some("bc"), None, Some("def"), Some("i")]);
+    assert_eq!(expected, arr.as_ref());
+
+    let input_1 = BinaryArray::<i32>::from_slice([b"a", b"b"]).slice(1, 1);
+    let input_2 = BinaryArray::<i32>::from([None, Some(b"cd")]);
+    let arr = concatenate(&[&input_1, &input_2])?;
+
+    let expected = BinaryArray::<i32>::from([Some(b"b".as_ref()), None, Some(b"cd".as_ref())]);
+    assert_eq!(expected, arr.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn dictionary_arrays_merge_values() -> Result<()> {
+    let arrays = (0..100)
+        .map(|i| {
+            let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+            let data = [
+                Some(format!("v{}", i % 7)),
+                None,
+                Some(format!("v{}", (i + 1) % 7)),
+            ];
+            array.try_extend(data).unwrap();
+            array.into()
+        })
+        .collect::<Vec<DictionaryArray<i32>>>();
+    let arrays = arrays.iter().map(|x| x as &dyn Array).collect::<Vec<_>>();
+
+    let result = concatenate(&arrays)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.len(), 300);
+
+    let values = result
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    let mut unique = values.values_iter().collect::<Vec<_>>();
+    unique.sort_unstable();
+    unique.dedup();
+    assert_eq!(unique.len(), values.len());
+    assert_eq!(values.len(), 7);
+
+    for i in 0..100 {
+        let keys = result.keys();
+        let key = |row: usize| keys.is_valid(row).then(|| keys.value(row) as usize);
+        assert_eq!(
+            key(i * 3).map(|key| values.value(key)),
+            Some(format!("v{}", i % 7).as_str())
+        );
+        assert_eq!(key(i * 3 + 1), None);
+        assert_eq!(
+            key(i * 3 + 2).map(|key| values.value(key)),
+            Some(format!("v{}", (i + 1) % 7).as_str())
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn dictionary_arrays_primitive_values() -> Result<()> {
+    let mut a = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+    a.try_extend([Some(1), Some(2), None, Some(1)])?;
+    let a: DictionaryArray<u8> = a.into();
+    let mut b = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+    b.try_extend([Some(3), Some(2)])?;
+    let b: DictionaryArray<u8> = b.into();
+
+    let result = concatenate(&[&a, &b.slice(1, 1)])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from_slice([1, 2, 3]) as &dyn Array
+    );
+    let expected = UInt8Array::from([Some(0), Some(1), None, Some(0), Some(1)]);
+    assert_eq!(result.keys(), &expected);
+    Ok(())
+}
+
+#[test]
+fn dictionary_arrays_promote_keys() -> Result<()> {
+    let dictionary = |range: std::ops::Range<i32>| -> Result<DictionaryArray<u8>> {
+        let mut array = MutableDictionaryArray::<u8, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(range.map(Some))?;
+        Ok(array.into())
+    };
+    let a = dictionary(0..200)?;
+    let b = dictionary(100..250)?;
+    // 250 unique values fit in `u8`
+    assert_eq!(concatenate(&[&a, &b])?.len(), 350);
+
+    // 300 unique values are promoted to `u16` keys
+    let c = dictionary(250..300)?;
+    let result = concatenate(&[&a, &b, &c])?;
+    assert_eq!(
+        result.data_type(),
+        &DataType::Dictionary(IntegerType::UInt16, Box::new(DataType::Int32), false)
+    );
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u16>>()
+        .unwrap();
+    assert_eq!(result.len(), 400);
+    assert_eq!(result.values().len(), 300);
+    assert_eq!(
+        result.keys().values().as_slice(),
+        (0..200).chain(100..300).collect::<Vec<u16>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn list_of_dictionary_arrays() -> Result<()> {
+    let list = |range: std::ops::Range<i32>| -> Result<ListArray<i32>> {
+        let mut array =
+            MutableListArray::<i32, MutableDictionaryArray<i8, MutablePrimitiveArray<i32>>>::new();
+        array.try_extend(range.map(|x| Some(vec![Some(x), Some(x + 1)])))?;
+        Ok(array.into())
+    };
+    let a = list(0..50)?;
+    let b = list(25..75)?;
+
+    let result = concatenate(&[&a, &b])?;
+    assert_eq!(result.data_type(), a.data_type());
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.len(), 100);
+    assert_eq!(result.values().len(), 200);
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "The merged values of the dictionaries do not fit in keys of type Int8")]
+fn list_of_dictionary_arrays_key_overflow() {
+    let list = |range: std::ops::Range<i32>| -> ListArray<i32> {
+        let mut array =
+            MutableListArray::<i32, MutableDictionaryArray<i8, MutablePrimitiveArray<i32>>>::new();
+        array
+            .try_extend(range.map(|x| Some(vec![Some(x)])))
+            .unwrap();
+        array.into()
+    };
+    let _ = concatenate(&[&list(0..100), &list(100..200)]);
+}
+
+#[test]
+fn concat_str_three_columns() -> Result<()> {
+    let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d"), None]);
+    let b = Utf8Array::<i32>::from([Some("x"), None, None, Some(""), None]);
+    let c = Utf8Array::<i32>::from([Some("1"), Some("2"), Some("3"), Some("4"), None]);
+
+    let result = concat_str(&[&a, &b, &c], ", ", NullHandling::Propagate)?;
+    let expected = Utf8Array::<i32>::from([Some("a, x, 1"), None, None, Some("d, , 4"), None]);
+    assert_eq!(result, expected);
+
+    let result = concat_str(&[&a, &b, &c], ", ", NullHandling::Skip)?;
+    let expected = Utf8Array::<i32>::from([
+        Some("a, x, 1"),
+        Some("b, 2"),
+        Some("3"),
+        Some("d, , 4"),
+        Some(""),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn concat_str_empty_separator() -> Result<()> {
+    let a = Utf8Array::<i64>::from([Some("hé"), None, Some("💖")]);
+    let b = Utf8Array::<i64>::from([Some("llo"), Some("b"), Some("!")]);
+
+    let result = concat_str(&[&a, &b], "", NullHandling::Propagate)?;
+    assert_eq!(
+        result,
+        Utf8Array::<i64>::from([Some("héllo"), None, Some("💖!")])
+    );
+
+    let result = concat_str(&[&a, &b], "", NullHandling::Skip)?;
+    assert_eq!(
+        result,
+        Utf8Array::<i64>::from([Some("héllo"), Some("b"), Some("💖!")])
+    );
+    Ok(())
+}
+
+#[test]
+fn concat_str_sliced() -> Result<()> {
+    let a = Utf8Array::<i32>::from([None, Some("a"), Some("b")]).slice(1, 2);
+    let b = Utf8Array::<i32>::from([Some("x"), None]);
+
+    let result = concat_str(&[&a, &b], "+", NullHandling::Propagate)?;
+    assert_eq!(result, Utf8Array::<i32>::from([Some("a+x"), None]));
+    Ok(())
+}
+
+#[test]
+fn concat_str_errors() {
+    assert!(concat_str::<i32>(&[], "", NullHandling::Skip).is_err());
+
+    let a = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let b = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(concat_str(&[&a, &b], "", NullHandling::Skip).is_err());
+}
+
+#[test]
+fn dense_unions() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![7, 3]), UnionMode::Dense);
+    // [1, "a", 2]
+    let lhs = UnionArray::new(
+        data_type.clone(),
+        vec![7, 3, 7].into(),
+        vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            Utf8Array::<i32>::from_slice(["a"]).boxed(),
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    // ["c", 3, "b"], with its children in a different order than its slots
+    let rhs = UnionArray::new(
+        data_type.clone(),
+        vec![3, 7, 3].into(),
+        vec![
+            Int32Array::from_slice([3]).boxed(),
+            Utf8Array::<i32>::from_slice(["b", "c"]).boxed(),
+        ],
+        Some(vec![1, 0, 0].into()),
+    );
+
+    let result = concatenate(&[&lhs, &rhs.slice(0, 2)])?;
+
+    // [1, "a", 2, "c", 3]
+    let expected = UnionArray::new(
+        data_type,
+        vec![7, 3, 7, 3, 7].into(),
+        vec![
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "c"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1, 2].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sparse_unions() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let array = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from([Some(1), None, Some(2)]).boxed(),
+            Utf8Array::<i32>::from([None, Some("a"), None]).boxed(),
+        ],
+        None,
+    );
+
+    let result = concatenate(&[&array, &array.slice(1, 2)])?;
+
+    // [1, "a", 2, "a", 2]
+    let expected = UnionArray::new(
+        data_type,
+        vec![0, 1, 0, 1, 0].into(),
+        vec![
+            Int32Array::from([Some(1), None, Some(2), None, Some(2)]).boxed(),
+            Utf8Array::<i32>::from([None, Some("a"), None, Some("a"), None]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use arrow2::array::*;
+use arrow2::compute::cumulative::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Error;
+
+#[test]
+fn nulls_do_not_reset() {
+    let array = Int32Array::from([None, Some(3), None, None, Some(-1), Some(5), None, Some(2)]);
+
+    let result = cumprod(&array).unwrap();
+    let expected = Int32Array::from([
+        None,
+        Some(3),
+        None,
+        None,
+        Some(-3),
+        Some(-15),
+        None,
+        Some(-30),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = cummax(&array);
+    let expected = Int32Array::from([None, Some(3), None, None, Some(3), Some(5), None, Some(5)]);
+    assert_eq!(result, expected);
+
+    let result = cummin(&array);
+    let expected = Int32Array::from([
+        None,
+        Some(3),
+        None,
+        None,
+        Some(-1),
+        Some(-1),
+        None,
+        Some(-1),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn overflow() {
+    let array = UInt8Array::from_slice([16, 16]);
+    assert!(matches!(cumprod(&array), Err(Error::Overflow)));
+
+    // values of null slots are not accumulated
+    let array = Int8Array::new(
+        DataType::Int8,
+        vec![10, 100, 12].into(),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(
+        cumprod(&array).unwrap(),
+        Int8Array::from([Some(10), None, Some(120)])
+    );
+}
+
+#[test]
+fn floats() {
+    let array = Float64Array::from([Some(f64::NAN), Some(1.0), None, Some(f64::NAN), Some(2.0)]);
+
+    let result = cummax(&array);
+    let expected = Float64Array::from([Some(f64::NAN), Some(1.0), None, Some(1.0), Some(2.0)]);
+    assert_eq!(format!("{:?}", result), format!("{:?}", expected));
+
+    let result = cummin(&array);
+    let expected = Float64Array::from([Some(f64::NAN), Some(1.0), None, Some(1.0), Some(1.0)]);
+    assert_eq!(format!("{:?}", result), format!("{:?}", expected));
+
+    let array = Float32Array::from_slice([f32::MAX, 2.0]);
+    let result = cumprod(&array).unwrap();
+    assert_eq!(result, Float32Array::from_slice([f32::MAX, f32::INFINITY]));
+}
+
+#[test]
+fn data_type_and_slices() {
+    let array = Int64Array::from_slice([1, 2, 3, 4])
+        .to(DataType::Duration(arrow2::datatypes::TimeUnit::Second))
+        .slice(1, 3);
+    let result = cumprod(&array).unwrap();
+    assert_eq!(result.data_type(), array.data_type());
+    assert_eq!(result.values().as_slice(), &[2, 6, 24]);
+
+    let array = Int32Array::from_vec(vec![]);
+    assert_eq!(cumprod(&array).unwrap().len(), 0);
+}
@@ -1,6 +1,10 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
+use arrow2::chunk::Chunk;
 use arrow2::compute::filter::*;
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 #[test]
 fn array_slice() {
@@ -128,6 +132,265 @@ fn masked_true_values() {
     assert_eq!(expected, c.as_ref());
 }
 
+#[test]
+fn dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend([Some("a"), None, Some("b"), Some("a"), Some("c")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    let mask = BooleanArray::from_slice([true, true, false, true, false]);
+
+    let expected_keys = Int32Array::from([Some(0), None, Some(0)]);
+    let values_ptr = |array: &DictionaryArray<i32>| {
+        array
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap()
+            .values()
+            .as_ptr()
+    };
+
+    let result = filter(&array, &mask).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.keys(), &expected_keys);
+    assert_eq!(values_ptr(result), values_ptr(&array));
+
+    let result = build_filter(&mask).unwrap()(&array);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.keys(), &expected_keys);
+    assert_eq!(values_ptr(result), values_ptr(&array));
+}
+
+fn masks(len: usize) -> Vec<BooleanArray> {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut random = |probability: f64| {
+        (0..len)
+            .map(|_| rng.gen_bool(probability))
+            .collect::<Vec<_>>()
+    };
+    vec![
+        BooleanArray::from_slice(random(0.5)),
+        BooleanArray::from_slice(random(0.02)),
+        BooleanArray::from_slice(random(0.98)),
+        BooleanArray::from_slice(vec![true; len]),
+        BooleanArray::from_slice(vec![false; len]),
+        BooleanArray::from_slice((0..len).map(|i| i % 2 == 0).collect::<Vec<_>>()),
+    ]
+}
+
+/// checks that `filter` and `build_filter` match taking the selected indices
+fn check_filter(array: &dyn Array) {
+    for mask in masks(array.len()) {
+        let indices = mask
+            .values()
+            .iter()
+            .enumerate()
+            .filter(|(_, is_selected)| *is_selected)
+            .map(|(index, _)| index as i32)
+            .collect::<Vec<_>>();
+        let expected = take(array, &Int32Array::from_vec(indices)).unwrap();
+
+        let result = filter(array, &mask).unwrap();
+        assert_eq!(result.as_ref(), expected.as_ref());
+
+        let result = build_filter(&mask).unwrap()(array);
+        assert_eq!(result.as_ref(), expected.as_ref());
+    }
+}
+
+#[test]
+fn filter_patterns() {
+    let len = 1000;
+
+    let array = Int32Array::from_iter((0..len).map(|i| if i % 7 == 0 { None } else { Some(i) }));
+    check_filter(&array);
+
+    let array = Utf8Array::<i32>::from_iter((0..len).map(|i| {
+        if i % 7 == 0 {
+            None
+        } else {
+            Some(i.to_string())
+        }
+    }));
+    check_filter(&array);
+    check_filter(&array.slice(3, len as usize - 10));
+
+    let array = Utf8Array::<i64>::from_iter_values((0..len).map(|i| "a".repeat(i as usize % 5)));
+    check_filter(&array);
+
+    let array = BinaryArray::<i64>::from_iter((0..len).map(|i| {
+        if i % 5 == 0 {
+            None
+        } else {
+            Some(i.to_le_bytes())
+        }
+    }));
+    check_filter(&array);
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend((0..len).map(|i| {
+        if i % 11 == 0 {
+            None
+        } else {
+            Some((0..i % 4).map(Some).collect::<Vec<_>>())
+        }
+    }))
+    .unwrap();
+    let array: ListArray<i32> = list.into();
+    check_filter(&array);
+}
+
+fn wide_chunk(len: usize) -> Chunk<Box<dyn Array>> {
+    let columns = (0..30)
+        .map(|column| match column % 3 {
+            0 => Int32Array::from_iter((0..len).map(|i| {
+                if (i + column) % 5 == 0 {
+                    None
+                } else {
+                    Some(i as i32)
+                }
+            }))
+            .boxed(),
+            1 => Utf8Array::<i32>::from_iter_values((0..len).map(|i| (i * column).to_string()))
+                .boxed(),
+            _ => BooleanArray::from_iter((0..len).map(|i| Some(i % 2 == 0))).boxed(),
+        })
+        .collect();
+    Chunk::new(columns)
+}
+
+#[test]
+fn filter_chunk_wide() {
+    let chunk = wide_chunk(100);
+    let mask = BooleanArray::from_iter((0..100).map(|i| match i % 3 {
+        0 => None,
+        1 => Some(true),
+        _ => Some(i % 4 == 0),
+    }));
+
+    let result = filter_chunk(&chunk, &mask).unwrap();
+    assert_eq!(result.arrays().len(), 30);
+    for (result, column) in result.arrays().iter().zip(chunk.arrays()) {
+        let expected = filter(column.as_ref(), &mask).unwrap();
+        assert_eq!(result, &expected);
+    }
+    // nulls of the mask are not selected
+    assert_eq!(result.len(), 33 + 8);
+}
+
+#[test]
+fn filter_chunk_all_true_all_false() {
+    let chunk = wide_chunk(10);
+
+    let mask = BooleanArray::from_slice([true; 10]);
+    let result = filter_chunk(&chunk, &mask).unwrap();
+    assert_eq!(result, chunk);
+
+    let mask = BooleanArray::from_slice([false; 10]);
+    let result = filter_chunk(&chunk, &mask).unwrap();
+    assert_eq!(result.len(), 0);
+    for (result, column) in result.arrays().iter().zip(chunk.arrays()) {
+        assert_eq!(result.data_type(), column.data_type());
+    }
+
+    let mask = BooleanArray::from([None::<bool>; 10]);
+    let result = filter_chunk(&chunk, &mask).unwrap();
+    assert_eq!(result.len(), 0);
+
+    let mask = BooleanArray::from_slice([true; 3]);
+    assert!(filter_chunk(&chunk, &mask).is_err());
+}
+
+#[test]
+fn map_array() {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.clone(), false)),
+        false,
+    );
+    let field = StructArray::new(
+        entries.clone(),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed(),
+            Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+        ],
+        None,
+    );
+    // [{a: 1}, {b: 2, c: 3}, null, {d: 4}]
+    let array = MapArray::new(
+        data_type.clone(),
+        vec![0, 1, 3, 3, 4].try_into().unwrap(),
+        field.boxed(),
+        Some([true, true, false, true].into()),
+    );
+
+    // null values of the filter are not selected
+    let mask = BooleanArray::from([Some(false), Some(true), Some(true), None]);
+    let result = filter(&array, &mask).unwrap();
+
+    // [{b: 2, c: 3}, null]
+    let field = StructArray::new(
+        entries,
+        vec![
+            Utf8Array::<i32>::from_slice(["b", "c"]).boxed(),
+            Int32Array::from_slice([2, 3]).boxed(),
+        ],
+        None,
+    );
+    let expected = MapArray::new(
+        data_type,
+        vec![0, 2, 2].try_into().unwrap(),
+        field.boxed(),
+        Some([true, false].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn dense_union_array() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![4, 2]), UnionMode::Dense);
+    // [1, "a", 2, "b"]
+    let array = UnionArray::new(
+        data_type.clone(),
+        vec![4, 2, 4, 2].into(),
+        vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    );
+
+    let mask = BooleanArray::from_slice([false, true, true, false]);
+    let result = filter(&array, &mask).unwrap();
+
+    // ["a", 2]
+    let expected = UnionArray::new(
+        data_type,
+        vec![2, 4].into(),
+        vec![
+            Int32Array::from_slice([2]).boxed(),
+            Utf8Array::<i32>::from_slice(["a"]).boxed(),
+        ],
+        Some(vec![0, 0].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 /*
 #[test]
 fn dictionary_array() {
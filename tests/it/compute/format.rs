@@ -0,0 +1,103 @@
+use arrow2::array::*;
+use arrow2::compute::format::format_array;
+
+#[test]
+fn integers() {
+    let array = Int32Array::from([Some(42), None, Some(-7)]);
+
+    let cases = [
+        ("%d", [Some("42"), None, Some("-7")]),
+        ("%5d", [Some("   42"), None, Some("   -7")]),
+        ("%-5d|", [Some("42   |"), None, Some("-7   |")]),
+        ("%05d", [Some("00042"), None, Some("-0007")]),
+        ("%+d", [Some("+42"), None, Some("-7")]),
+        ("% d", [Some(" 42"), None, Some("-7")]),
+        ("%.3i", [Some("042"), None, Some("-007")]),
+        ("%x", [Some("2a"), None, Some("fffffff9")]),
+    ];
+    for (format, expected) in cases {
+        let result = format_array(&array, format).unwrap();
+        assert_eq!(result, Utf8Array::<i32>::from(expected), "{}", format);
+    }
+
+    let array = Int8Array::from_slice([-1, 8]);
+    let result = format_array(&array, "%o").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["377", "10"]));
+
+    let array = UInt64Array::from_slice([u64::MAX]);
+    let result = format_array(&array, "%X").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["FFFFFFFFFFFFFFFF"]));
+}
+
+#[test]
+fn floats() {
+    let array = Float64Array::from([Some(1234.5678), None, Some(-0.001)]);
+
+    let cases = [
+        ("%f", [Some("1234.567800"), None, Some("-0.001000")]),
+        ("%.2f", [Some("1234.57"), None, Some("-0.00")]),
+        ("%10.1f", [Some("    1234.6"), None, Some("      -0.0")]),
+        ("%e", [Some("1.234568e+03"), None, Some("-1.000000e-03")]),
+        ("%.1E", [Some("1.2E+03"), None, Some("-1.0E-03")]),
+        ("%s", [Some("1234.5678"), None, Some("-0.001")]),
+    ];
+    for (format, expected) in cases {
+        let result = format_array(&array, format).unwrap();
+        assert_eq!(result, Utf8Array::<i32>::from(expected), "{}", format);
+    }
+
+    let array = Float32Array::from_slice([f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+    let result = format_array(&array, "%.2f").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["NaN", "inf", "-inf"]));
+
+    // integers can be formatted as floats
+    let array = Int64Array::from_slice([3]);
+    let result = format_array(&array, "%.1f").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["3.0"]));
+}
+
+#[test]
+fn text() {
+    let array = Utf8Array::<i64>::from([Some("hello"), None, Some("héllo")]);
+    let result = format_array(&array, "[%-7.3s] 100%%").unwrap();
+    let expected = Utf8Array::<i32>::from([Some("[hel    ] 100%"), None, Some("[hél    ] 100%")]);
+    assert_eq!(result, expected);
+
+    let array = BooleanArray::from([Some(true), None, Some(false)]);
+    let result = format_array(&array, "%6s").unwrap();
+    let expected = Utf8Array::<i32>::from([Some("  true"), None, Some(" false")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn sliced() {
+    let array = Int32Array::from([Some(1), None, Some(3), Some(4)]).slice(1, 3);
+    let result = format_array(&array, "%03d").unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from([None, Some("003"), Some("004")])
+    );
+}
+
+#[test]
+fn invalid_formats() {
+    let array = Int32Array::from_slice([1]);
+    // no conversion
+    assert!(format_array(&array, "value").is_err());
+    assert!(format_array(&array, "100%%").is_err());
+    // more than one conversion
+    assert!(format_array(&array, "%d %d").is_err());
+    // unknown or missing conversion
+    assert!(format_array(&array, "%q").is_err());
+    assert!(format_array(&array, "%5").is_err());
+    assert!(format_array(&array, "%#x").is_err());
+
+    // incompatible data types
+    let array = Float64Array::from_slice([1.0]);
+    assert!(format_array(&array, "%d").is_err());
+    assert!(format_array(&array, "%x").is_err());
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(format_array(&array, "%f").is_err());
+    let array = BooleanArray::from_slice([true]);
+    assert!(format_array(&array, "%d").is_err());
+}
@@ -1,7 +1,7 @@
-use arrow2::array::new_null_array;
+use arrow2::array::*;
 use arrow2::compute::hash::*;
 use arrow2::datatypes::DataType::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{Field, IntegerType, TimeUnit};
 
 #[test]
 fn consistency() {
@@ -36,6 +36,20 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        List(Box::new(Field::new("item", Int32, true))),
+        LargeList(Box::new(Field::new("item", Utf8, true))),
+        FixedSizeList(Box::new(Field::new("item", Int32, true)), 2),
+        List(Box::new(Field::new("item", Float32, true))),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Utf8, true),
+        ]),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Float64, true),
+        ]),
+        Dictionary(IntegerType::Int32, Box::new(Utf8), false),
+        Dictionary(IntegerType::UInt8, Box::new(Float32), false),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -47,3 +61,97 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn dictionary_equals_decoded() {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c")]).boxed();
+    let keys = Int32Array::from([Some(2), Some(0), None, Some(1), Some(0)]);
+    let dictionary = DictionaryArray::try_from_keys(keys, values).unwrap();
+
+    let decoded = Utf8Array::<i32>::from([Some("c"), Some("a"), None, None, Some("a")]);
+
+    assert_eq!(hash(&dictionary).unwrap(), hash(&decoded).unwrap());
+    assert_eq!(
+        hash_columns(&[&dictionary]).unwrap(),
+        hash_columns(&[&decoded]).unwrap()
+    );
+}
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        Some(vec![Some(3), None]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let result = hash(&array).unwrap();
+    assert_eq!(result.validity(), array.validity());
+    assert_eq!(result.value(0), result.value(3));
+    assert_eq!(result.value(2), result.value(5));
+    assert_ne!(result.value(0), result.value(2));
+    assert_ne!(result.value(0), result.value(4));
+
+    // equal lists hash equally regardless of their offsets
+    let sliced = array.slice(3, 3);
+    assert_eq!(hash(&sliced).unwrap(), result.slice(3, 3));
+}
+
+#[test]
+fn fixed_size_list() {
+    let values = Int32Array::from([Some(1), Some(2), Some(3), None, Some(1), Some(2)]).boxed();
+    let data_type = FixedSizeList(Box::new(Field::new("item", Int32, true)), 2);
+    let array = FixedSizeListArray::new(data_type, values, None);
+
+    let result = hash(&array).unwrap();
+    assert_eq!(result.value(0), result.value(2));
+    assert_ne!(result.value(0), result.value(1));
+}
+
+#[test]
+fn struct_() {
+    let a = Int32Array::from([Some(1), Some(1), None, Some(1)]).boxed();
+    let b = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), Some("a")]).boxed();
+    let fields = vec![Field::new("a", Int32, true), Field::new("b", Utf8, true)];
+    let array = StructArray::new(Struct(fields), vec![a.clone(), b.clone()], None);
+
+    let result = hash(&array).unwrap();
+    assert_eq!(result.value(0), result.value(3));
+    assert_ne!(result.value(0), result.value(1));
+    assert_ne!(result.value(0), result.value(2));
+
+    // a struct hashes as the row of its fields
+    assert_eq!(result, hash_columns(&[a.as_ref(), b.as_ref()]).unwrap());
+}
+
+#[test]
+fn columns() {
+    let a = Int32Array::from([Some(1), None, Some(1), None, Some(1)]);
+    let b = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), Some("b"), None]);
+    let result = hash_columns(&[&a, &b]).unwrap();
+
+    assert_eq!(result.null_count(), 0);
+    assert_eq!(result.value(0), result.value(2));
+    assert_eq!(result.value(1), result.value(3));
+    assert_ne!(result.value(0), result.value(4));
+
+    // the order of the columns matters
+    assert_ne!(result, hash_columns(&[&b, &a]).unwrap());
+}
+
+#[test]
+fn columns_invalid() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(hash_columns(&[]).is_err());
+    assert!(hash_columns(&[&a, &b]).is_err());
+
+    let c = Float32Array::from_slice([1.0, 2.0]);
+    assert!(hash_columns(&[&a, &c]).is_err());
+}
@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::if_then_else::if_then_else;
+use arrow2::compute::if_then_else::{if_then_else, zip};
+use arrow2::datatypes::{DataType, Field, UnionMode};
 use arrow2::error::Result;
 
 #[test]
@@ -40,3 +41,231 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn utf8_nulls_pred() -> Result<()> {
+    let lhs = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]);
+    let rhs = Utf8Array::<i32>::from_slice(["w", "x", "y", "z"]);
+    let predicate = BooleanArray::from(&[Some(true), None, Some(true), Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = Utf8Array::<i32>::from([Some("a"), None, None, Some("z")]);
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn boolean_nulls_pred() -> Result<()> {
+    let lhs = BooleanArray::from_slice([true, true, true]);
+    let rhs = BooleanArray::from_slice([false, false, false]);
+    let predicate = BooleanArray::from(&[None, Some(false), Some(true)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = BooleanArray::from(&[None, Some(false), Some(true)]);
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn invalid_arguments() {
+    let predicate = BooleanArray::from_slice([true, false]);
+
+    // different lengths
+    let lhs = Int32Array::from_slice([1, 2]);
+    let rhs = Int32Array::from_slice([3]);
+    assert!(if_then_else(&predicate, &lhs, &rhs).is_err());
+
+    // different data types
+    let rhs = Int64Array::from_slice([3, 4]);
+    assert!(if_then_else(&predicate, &lhs, &rhs).is_err());
+}
+
+fn dictionary(values: &[Option<&str>]) -> DictionaryArray<i32> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().copied()).unwrap();
+    array.into()
+}
+
+fn dictionary_values(array: &dyn Array) -> Utf8Array<i32> {
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn dictionary_disjoint() -> Result<()> {
+    let lhs = dictionary(&[Some("a"), Some("b"), None]);
+    let rhs = dictionary(&[Some("c"), None, Some("d")]);
+    let predicate = BooleanArray::from(&[Some(true), Some(false), Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = dictionary(&[Some("a"), None, Some("d")]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(
+        dictionary_values(result.as_ref()),
+        Utf8Array::<i32>::from_slice(["a", "b", "c", "d"])
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_overlapping() -> Result<()> {
+    let lhs = dictionary(&[Some("a"), Some("b"), Some("a"), Some("b")]);
+    let rhs = dictionary(&[Some("c"), Some("b"), Some("a"), Some("c")]);
+    let predicate = BooleanArray::from(&[Some(false), None, Some(false), Some(true)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = dictionary(&[Some("c"), None, Some("a"), Some("b")]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(
+        dictionary_values(result.as_ref()),
+        Utf8Array::<i32>::from_slice(["a", "b", "c"])
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_identical() -> Result<()> {
+    let lhs = dictionary(&[Some("a"), Some("b"), Some("a")]);
+    let rhs = dictionary(&[Some("a"), Some("b"), Some("b")]);
+    let predicate = BooleanArray::from_slice([true, false, false]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = dictionary(&[Some("a"), Some("b"), Some("b")]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(
+        dictionary_values(result.as_ref()),
+        Utf8Array::<i32>::from_slice(["a", "b"])
+    );
+    Ok(())
+}
+
+#[test]
+fn zip_scalars() -> Result<()> {
+    let lhs = Int32Array::from_slice([1, 2, 3, 4]);
+    let rhs = Int32Array::from_slice([0]);
+    let predicate = BooleanArray::from(&[Some(true), Some(false), None, Some(false)]);
+
+    let result = zip(&predicate, &lhs, &rhs)?;
+    let expected = Int32Array::from(&[Some(1), Some(0), None, Some(0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let predicate = BooleanArray::from_slice([false, true, true, false]);
+    let result = zip(&predicate, &rhs, &lhs)?;
+    let expected = Int32Array::from_slice([1, 0, 0, 4]);
+    assert_eq!(expected, result.as_ref());
+
+    let lhs = Utf8Array::<i32>::from_slice(["a"]);
+    let rhs = Utf8Array::<i32>::from([None::<&str>]);
+    let result = zip(&predicate, &lhs, &rhs)?;
+    let expected = Utf8Array::<i32>::from([None, Some("a"), Some("a"), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let lhs = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert!(zip(&predicate, &lhs, &rhs).is_err());
+    Ok(())
+}
+
+#[test]
+fn zip_dictionary_scalar() -> Result<()> {
+    let lhs = dictionary(&[Some("a"), Some("b"), Some("c")]);
+    let rhs = dictionary(&[Some("z")]);
+    let predicate = BooleanArray::from_slice([true, false, true]);
+    let result = zip(&predicate, &lhs, &rhs)?;
+
+    let expected = dictionary(&[Some("a"), Some("z"), Some("c")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn map() -> Result<()> {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.clone(), false)),
+        false,
+    );
+    let map = |keys: &[&str], values: &[i32], offsets: Vec<i32>| {
+        let field = StructArray::new(
+            entries.clone(),
+            vec![
+                Utf8Array::<i32>::from_slice(keys).boxed(),
+                Int32Array::from_slice(values).boxed(),
+            ],
+            None,
+        );
+        MapArray::new(
+            data_type.clone(),
+            offsets.try_into().unwrap(),
+            field.boxed(),
+            None,
+        )
+    };
+
+    // [{a: 1}, {b: 2, c: 3}]
+    let lhs = map(&["a", "b", "c"], &[1, 2, 3], vec![0, 1, 3]);
+    // [{}, {d: 4}]
+    let rhs = map(&["d"], &[4], vec![0, 0, 1]);
+    let predicate = BooleanArray::from_slice([false, true]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = map(&["b", "c"], &[2, 3], vec![0, 0, 2]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn dense_union() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    // [1, "x", 2]
+    let lhs = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            Utf8Array::<i32>::from_slice(["x"]).boxed(),
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    // ["y", "z", 3]
+    let rhs = UnionArray::new(
+        data_type.clone(),
+        vec![1, 1, 0].into(),
+        vec![
+            Int32Array::from_slice([3]).boxed(),
+            Utf8Array::<i32>::from_slice(["y", "z"]).boxed(),
+        ],
+        Some(vec![0, 1, 0].into()),
+    );
+    let predicate = BooleanArray::from(&[Some(false), Some(true), None]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    // ["y", "x", null]
+    let expected = UnionArray::new(
+        data_type,
+        vec![1, 1, 0].into(),
+        vec![
+            Int32Array::from([None]).boxed(),
+            Utf8Array::<i32>::from_slice(["y", "x"]).boxed(),
+        ],
+        Some(vec![0, 1, 0].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
@@ -87,3 +87,43 @@ fn consistency() {
         }
     });
 }
+
+fn char_length_test_string<O: Offset>() {
+    let array = Utf8Array::<O>::from([
+        Some("hello"),
+        Some("héllo"),
+        None,
+        Some("💖"),
+        Some("e\u{301}"),
+        Some(""),
+    ]);
+    let result = char_length(&array).unwrap();
+
+    let data_type = if O::IS_LARGE {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+    let expected = [Some(5usize), Some(5), None, Some(1), Some(2), Some(0)]
+        .into_iter()
+        .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+        .collect::<PrimitiveArray<O>>()
+        .to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn char_length_utf8() {
+    char_length_test_string::<i32>()
+}
+
+#[test]
+fn char_length_large_utf8() {
+    char_length_test_string::<i64>()
+}
+
+#[test]
+fn char_length_unsupported() {
+    let array = BinaryArray::<i32>::from_slice([b"a"]);
+    assert!(char_length(&array).is_err());
+}
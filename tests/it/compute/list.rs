@@ -0,0 +1,465 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::list::*;
+use arrow2::offset::Offset;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+fn utf8_list<O: Offset>() -> ListArray<O> {
+    let data = vec![
+        Some(vec![Some("a"), Some("b"), Some("c")]),
+        None,
+        Some(vec![]),
+        Some(vec![Some("d"), None]),
+        Some(vec![Some("e")]),
+    ];
+    let mut array = MutableListArray::<O, MutableUtf8Array<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn test_element<O: Offset>() {
+    let list = utf8_list::<O>();
+
+    let cases = [
+        (0, vec![Some("a"), None, None, Some("d"), Some("e")]),
+        (1, vec![Some("b"), None, None, None, None]),
+        (-1, vec![Some("c"), None, None, None, Some("e")]),
+        (-2, vec![Some("b"), None, None, Some("d"), None]),
+        (3, vec![None, None, None, None, None]),
+        (-4, vec![None, None, None, None, None]),
+    ];
+    for (index, expected) in cases {
+        let result = list_element(&list, index).unwrap();
+        let expected = Utf8Array::<i32>::from(expected);
+        assert_eq!(result.as_ref(), &expected as &dyn Array, "{}", index);
+    }
+}
+
+#[test]
+fn element() {
+    test_element::<i32>()
+}
+
+#[test]
+fn element_large() {
+    test_element::<i64>()
+}
+
+fn test_contains<O: Offset>() {
+    let list = utf8_list::<O>();
+
+    let result = list_contains(&list, &Utf8Scalar::<i32>::new(Some("d"))).unwrap();
+    let expected = BooleanArray::from([Some(false), None, Some(false), Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = list_contains(&list, &Utf8Scalar::<i32>::new(None::<&str>)).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::new_null(result.data_type().clone(), 5)
+    );
+
+    let result = list_contains(&list, &PrimitiveScalar::<i32>::from(Some(1)));
+    assert!(result.is_err());
+}
+
+#[test]
+fn contains() {
+    test_contains::<i32>()
+}
+
+#[test]
+fn contains_large() {
+    test_contains::<i64>()
+}
+
+#[test]
+fn contains_any() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let search = Int32Array::from([Some(2), Some(1), Some(1), Some(1), None]);
+    let result = list_contains_any(&list, &search).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), None]);
+    assert_eq!(result, expected);
+
+    let sliced = list.slice(1, 2);
+    let search = Int32Array::from_slice([3, 3]);
+    let result = list_contains_any(&sliced, &search).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(true), None]));
+
+    assert!(list_contains_any(&list, &Int32Array::from_slice([1])).is_err());
+    let search = Int64Array::from_slice([1, 1, 1, 1, 1]);
+    assert!(list_contains_any(&list, &search).is_err());
+}
+
+fn test_contains_array<O: Offset>() {
+    let list = utf8_list::<O>();
+
+    let search = Utf8Array::<i32>::from([Some("c"), Some("a"), Some("a"), None, Some("f")]);
+    let result = list_contains_array(&list, &search).unwrap();
+    let expected = BooleanArray::from([Some(true), None, Some(false), None, Some(false)]);
+    assert_eq!(result, expected);
+
+    // null values within lists never match
+    let search = Utf8Array::<i32>::from_slice(["d", "e"]);
+    let result = list_contains_array(&list.slice(3, 2), &search).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(true), Some(true)]));
+
+    assert!(list_contains_array(&list, &search).is_err());
+    let search = Int32Array::from_slice([1, 1, 1, 1, 1]);
+    assert!(list_contains_array(&list, &search).is_err());
+}
+
+#[test]
+fn contains_array() {
+    test_contains_array::<i32>()
+}
+
+#[test]
+fn contains_array_large() {
+    test_contains_array::<i64>()
+}
+
+#[test]
+fn contains_array_primitive() {
+    let data = vec![
+        Some(vec![Some(1), None]),
+        Some(vec![None]),
+        None,
+        Some(vec![Some(4), Some(5)]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let search = Int32Array::from_slice([1, 1, 1, 5]);
+    let result = list_contains_array(&list, &search).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn contains_str() {
+    let list = utf8_list::<i32>();
+    let result = list_contains_str(&list, "d").unwrap();
+    let expected = BooleanArray::from([Some(false), None, Some(false), Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = list_contains_str(&list.slice(3, 2), "e").unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), Some(true)]));
+
+    let mut list = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    list.try_extend([Some(vec![Some(1)])]).unwrap();
+    let list: ListArray<i64> = list.into();
+    assert!(list_contains_str(&list, "a").is_err());
+}
+
+fn test_explode<O: Offset>() {
+    let list = utf8_list::<O>();
+
+    let (values, rows) = explode(&list).unwrap();
+    let expected =
+        Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), Some("d"), None, Some("e")]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(rows, Int64Array::from_slice([0, 0, 0, 3, 3, 4]));
+
+    // without nulls, sliced
+    let list = list.slice(2, 3);
+    let (values, rows) = explode(&list).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("d"), None, Some("e")]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(rows, Int64Array::from_slice([1, 1, 2]));
+}
+
+#[test]
+fn explode_utf8() {
+    test_explode::<i32>()
+}
+
+#[test]
+fn explode_large() {
+    test_explode::<i64>()
+}
+
+#[test]
+fn nested() {
+    // a list of lists of utf8
+    let data = vec![
+        Some(vec![Some(vec![Some("a")]), None]),
+        Some(vec![Some(vec![Some("b"), Some("c")])]),
+    ];
+    let mut array = MutableListArray::<i32, MutableListArray<i32, MutableUtf8Array<i32>>>::new();
+    array.try_extend(data).unwrap();
+    let list: ListArray<i32> = array.into();
+
+    let result = list_element(&list, -1).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.validity(), Some(&Bitmap::from([false, true])));
+    let inner = list_element(result, 1).unwrap();
+    let expected = Utf8Array::<i32>::from([None, Some("c")]);
+    assert_eq!(inner.as_ref(), &expected as &dyn Array);
+
+    let (values, rows) = explode(&list).unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(rows, Int64Array::from_slice([0, 0, 1]));
+}
+
+type NestedInt = MutableListArray<i32, MutableListArray<i32, MutablePrimitiveArray<i32>>>;
+
+type NestedIntData = Vec<Option<Vec<Option<Vec<Option<i32>>>>>>;
+
+fn nested_int(data: NestedIntData) -> ListArray<i32> {
+    let mut array = NestedInt::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn int_list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn flatten() {
+    let list = nested_int(vec![
+        Some(vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(3)])]),
+        None,
+        Some(vec![Some(vec![]), Some(vec![Some(4), None])]),
+        Some(vec![]),
+    ]);
+
+    let result = list_flatten(&list).unwrap();
+    let expected = int_list(vec![
+        Some(vec![Some(1), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None]),
+        Some(vec![]),
+    ]);
+    assert_eq!(result, expected);
+
+    // sliced
+    let result = list_flatten(&list.slice(2, 2)).unwrap();
+    assert_eq!(result, expected.slice(2, 2));
+}
+
+#[test]
+fn flatten_null_inner() {
+    let list = nested_int(vec![
+        Some(vec![Some(vec![Some(1)]), None, Some(vec![Some(2)])]),
+        None,
+        Some(vec![None]),
+    ]);
+
+    let result = list_flatten(&list).unwrap();
+    let expected = int_list(vec![
+        Some(vec![Some(1), None, Some(2)]),
+        None,
+        Some(vec![None]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn flatten_all() {
+    let data = vec![
+        Some(vec![Some(vec![Some(vec![Some(1)]), Some(vec![Some(2)])])]),
+        None,
+        Some(vec![Some(vec![]), Some(vec![Some(vec![Some(3)])])]),
+    ];
+    let mut array = MutableListArray::<i32, NestedInt>::new();
+    array.try_extend(data).unwrap();
+    let list: ListArray<i32> = array.into();
+
+    let result = list_flatten_all(&list).unwrap();
+    let expected = int_list(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(3)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // a list of non-lists is already flat
+    let result = list_flatten_all(&expected).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn flatten_errors() {
+    let list = int_list(vec![Some(vec![Some(1)])]);
+    assert!(list_flatten(&list).is_err());
+
+    let array = Int32Array::from_slice([1]);
+    assert!(list_flatten_all(&array).is_err());
+}
+
+#[test]
+fn slice() {
+    let list = int_list(vec![
+        Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+        None,
+        Some(vec![Some(5), None]),
+        Some(vec![]),
+    ]);
+
+    let cases = [
+        (
+            1,
+            Some(3),
+            vec![
+                Some(vec![Some(2), Some(3)]),
+                None,
+                Some(vec![None]),
+                Some(vec![]),
+            ],
+        ),
+        (
+            0,
+            None,
+            vec![
+                Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+                None,
+                Some(vec![Some(5), None]),
+                Some(vec![]),
+            ],
+        ),
+        (
+            -2,
+            None,
+            vec![
+                Some(vec![Some(3), Some(4)]),
+                None,
+                Some(vec![Some(5), None]),
+                Some(vec![]),
+            ],
+        ),
+        (
+            0,
+            Some(-1),
+            vec![
+                Some(vec![Some(1), Some(2), Some(3)]),
+                None,
+                Some(vec![Some(5)]),
+                Some(vec![]),
+            ],
+        ),
+        // out of bounds indices are clamped
+        (
+            -10,
+            Some(10),
+            vec![
+                Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+                None,
+                Some(vec![Some(5), None]),
+                Some(vec![]),
+            ],
+        ),
+        (
+            3,
+            Some(10),
+            vec![Some(vec![Some(4)]), None, Some(vec![]), Some(vec![])],
+        ),
+        // stop before start is empty
+        (
+            2,
+            Some(1),
+            vec![Some(vec![]), None, Some(vec![]), Some(vec![])],
+        ),
+        (
+            i64::MIN,
+            Some(i64::MAX),
+            vec![
+                Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+                None,
+                Some(vec![Some(5), None]),
+                Some(vec![]),
+            ],
+        ),
+    ];
+    for (start, stop, expected) in cases {
+        let result = list_slice(&list, start, stop);
+        assert_eq!(result, int_list(expected), "{} {:?}", start, stop);
+    }
+
+    // sliced
+    let result = list_slice(&list.slice(2, 2), -1, None);
+    assert_eq!(result, int_list(vec![Some(vec![None]), Some(vec![])]));
+}
+
+#[test]
+fn aggregations() {
+    let list = int_list(vec![
+        Some(vec![Some(3), None, Some(-1), Some(4)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, None]),
+        Some(vec![Some(7)]),
+    ]);
+
+    let result = list_sum::<i32, i32>(&list).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([Some(6), None, None, None, Some(7)])
+    );
+
+    let result = list_mean::<i32, i32>(&list).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from([Some(2.0), None, None, None, Some(7.0)])
+    );
+
+    let result = list_min::<i32, i32>(&list).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([Some(-1), None, None, None, Some(7)])
+    );
+
+    let result = list_max::<i32, i32>(&list).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([Some(4), None, None, None, Some(7)])
+    );
+
+    // sliced
+    let result = list_sum::<i32, i32>(&list.slice(3, 2)).unwrap();
+    assert_eq!(result, Int32Array::from([None, Some(7)]));
+}
+
+#[test]
+fn aggregations_floats() {
+    let mut list = MutableListArray::<i64, MutablePrimitiveArray<f64>>::new();
+    list.try_extend([
+        Some(vec![Some(f64::NAN), Some(1.5), Some(-2.0)]),
+        Some(vec![Some(f64::NAN)]),
+    ])
+    .unwrap();
+    let list: ListArray<i64> = list.into();
+
+    let result = list_min::<i64, f64>(&list).unwrap();
+    assert_eq!(result.value(0), -2.0);
+    assert!(result.value(1).is_nan());
+
+    let result = list_max::<i64, f64>(&list).unwrap();
+    assert_eq!(result.value(0), 1.5);
+
+    let result = list_sum::<i64, f64>(&list).unwrap();
+    assert!(result.value(0).is_nan());
+}
+
+#[test]
+fn aggregations_errors() {
+    let list = int_list(vec![Some(vec![Some(1)])]);
+    assert!(list_sum::<i32, i64>(&list).is_err());
+    assert!(list_mean::<i32, f32>(&list).is_err());
+
+    let list = utf8_list::<i32>();
+    assert!(list_max::<i32, i32>(&list).is_err());
+}
@@ -0,0 +1,63 @@
+use arrow2::array::*;
+use arrow2::compute::math::*;
+
+#[test]
+fn logarithms() {
+    let array = Float64Array::from([Some(1.0), Some(100.0), Some(-1.0), None]);
+
+    let result = log10(&array, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(
+        result,
+        Float64Array::from([Some(0.0), Some(2.0), None, None])
+    );
+
+    let result = log10(&array, InvalidMode::PropagateNaN);
+    assert_eq!(result.validity(), array.validity());
+    assert!(result.value(2).is_nan());
+
+    let result = log(&array, 10.0, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(
+        result,
+        Float64Array::from([Some(0.0), Some(2.0), None, None])
+    );
+
+    let array = Float32Array::from([Some(1.0), Some(8.0), Some(-8.0), None]);
+    let result = log2(&array, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(
+        result,
+        Float32Array::from([Some(0.0), Some(3.0), None, None])
+    );
+
+    let result = ln(&array, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(
+        result.validity(),
+        Float32Array::from([Some(0.0), Some(0.0), None, None]).validity()
+    );
+}
+
+#[test]
+fn exponentials() {
+    let array = Float64Array::from([Some(0.0), Some(3.0), Some(f64::NAN), None]);
+
+    let result = exp2(&array, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(
+        result,
+        Float64Array::from([Some(1.0), Some(8.0), None, None])
+    );
+
+    let result = exp(&array, InvalidMode::PropagateNaN);
+    assert_eq!(result.value(0), 1.0);
+    assert!(result.value(2).is_nan());
+}
+
+#[test]
+fn roots() {
+    let array = Float64Array::from([Some(4.0), Some(-8.0), None]);
+
+    let result = sqrt(&array, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(result, Float64Array::from([Some(2.0), None, None]));
+
+    let result = cbrt(&array, InvalidMode::ProduceNullOnInvalid);
+    assert_eq!(result.value(1), -2.0);
+    assert_eq!(result.null_count(), 1);
+}
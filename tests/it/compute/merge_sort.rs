@@ -285,3 +285,70 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn merge_empty() -> Result<()> {
+    let empty = Int32Array::from_vec(vec![]);
+    let a = Int32Array::from([None, Some(1), Some(3)]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+
+    let result = merge_sort(&empty, &a, &options, None)?;
+    assert_eq!(result.as_ref(), &a as &dyn Array);
+
+    let result = merge_sort(&a, &empty, &options, None)?;
+    assert_eq!(result.as_ref(), &a as &dyn Array);
+
+    let result = merge_sort(&empty, &empty, &options, None)?;
+    assert_eq!(result.as_ref(), &empty as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn merge_slices_one_side_empty() -> Result<()> {
+    let a0: &dyn Array = &Int32Array::from_slice([0, 1]);
+    let a1: &dyn Array = &Int32Array::from_slice([2, 3]);
+    let a2: &dyn Array = &Int32Array::from_slice([4, 5]);
+
+    let options = SortOptions::default();
+    let arrays = vec![a0, a1, a2];
+    let pairs = vec![(arrays.as_ref(), &options)];
+    let comparator = build_comparator(&pairs)?;
+
+    // all slices from the right are kept when the left has none
+    let right = [(1, 0, 2), (2, 0, 2)];
+    let result =
+        merge_sort_slices(std::iter::empty(), right.iter(), &comparator).collect::<Vec<_>>();
+    assert_eq!(result, right);
+
+    // empty slices are skipped
+    let left = [(0, 0, 0), (0, 0, 2)];
+    let right = [(1, 0, 0), (1, 0, 2), (2, 0, 0), (2, 0, 2)];
+    let result = merge_sort_slices(left.iter(), right.iter(), &comparator).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0, 2), (1, 0, 2), (2, 0, 2)]);
+    Ok(())
+}
+
+#[test]
+fn merge_nulls_last_descending() -> Result<()> {
+    let a = Int32Array::from([Some(5), Some(3), None]);
+    let b = Int32Array::from([Some(4), Some(3), Some(1), None]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+
+    let result = merge_sort(&a, &b, &options, None)?;
+    let expected = Int32Array::from([Some(5), Some(4), Some(3), Some(3), Some(1), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn merge_different_types() {
+    let a = Int32Array::from_slice([1]);
+    let b = Int64Array::from_slice([1]);
+    assert!(merge_sort(&a, &b, &SortOptions::default(), None).is_err());
+}
@@ -10,14 +10,20 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_coalesce")]
+mod coalesce;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_cumulative")]
+mod cumulative;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_format")]
+mod format;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
@@ -28,14 +34,24 @@ mod length;
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
+#[cfg(feature = "compute_math")]
+mod math;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nullif")]
+mod nullif;
 #[cfg(feature = "compute_partition")]
 mod partition;
+#[cfg(feature = "compute_rank")]
+mod rank;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_struct_utils")]
+mod struct_utils;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
@@ -47,4 +63,5 @@ mod utf8;
 #[cfg(feature = "compute_window")]
 mod window;
 
+mod arity;
 mod arity_assign;
@@ -0,0 +1,108 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::nullif::*;
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn primitive() {
+    let lhs = Int32Array::from([None, None, Some(1), Some(1), Some(1), Some(0)]);
+    let rhs = Int32Array::from([None, Some(1), None, Some(1), Some(0), None]);
+    let result = nullif(&lhs, &rhs);
+
+    // a null rhs is never equal, regardless of the value of its slot
+    let expected = Int32Array::from([None, None, Some(1), None, Some(1), Some(0)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let lhs = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("c")]);
+    let rhs = Utf8Array::<i64>::from([Some("a"), Some("c"), Some("c"), None]);
+    let result = try_nullif(&lhs, &rhs).unwrap();
+
+    let expected = Utf8Array::<i64>::from([None, Some("b"), None, Some("c")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn boolean_sliced() {
+    let lhs = BooleanArray::from([Some(true), Some(true), Some(false), Some(false)]).slice(1, 3);
+    let rhs = BooleanArray::from_slice([true, true, false]);
+    let result = try_nullif(&lhs, &rhs).unwrap();
+
+    let expected = BooleanArray::from([None, Some(false), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn list() {
+    let mut lhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    lhs.try_extend([
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+        None,
+        Some(vec![]),
+    ])
+    .unwrap();
+    let lhs: ListArray<i32> = lhs.into();
+
+    let mut rhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    rhs.try_extend([
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(4)]),
+        None,
+        None,
+    ])
+    .unwrap();
+    let rhs: ListArray<i32> = rhs.into();
+
+    let result = try_nullif(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result.validity(),
+        Some(&Bitmap::from([false, true, false, true]))
+    );
+}
+
+#[test]
+fn struct_() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Struct(fields);
+    let lhs = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from([Some(1), Some(2), None, Some(4)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), None]).boxed(),
+        ],
+        None,
+    );
+    let rhs = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from([Some(1), Some(3), None, Some(4)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), Some("d")]).boxed(),
+        ],
+        Some(Bitmap::from([true, true, false, true])),
+    );
+
+    let result = try_nullif(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result.validity(),
+        Some(&Bitmap::from([false, true, true, true]))
+    );
+}
+
+#[test]
+fn errors() {
+    let lhs = Int32Array::from_slice([1, 2]);
+    let rhs = Int64Array::from_slice([1, 2]);
+    assert!(try_nullif(&lhs, &rhs).is_err());
+
+    let rhs = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    assert!(try_nullif(&lhs, &rhs).is_err());
+
+    let rhs = Int32Array::from_slice([1]);
+    assert!(try_nullif(&lhs, &rhs).is_err());
+}
@@ -0,0 +1,139 @@
+use arrow2::array::*;
+use arrow2::compute::rank::*;
+use arrow2::compute::sort::SortOptions;
+
+#[test]
+fn methods() {
+    let array = Int32Array::from([Some(3), Some(1), Some(3), None, Some(2), Some(3)]);
+
+    let cases = [
+        (RankMethod::Average, [4, 1, 4, 0, 2, 4]),
+        (RankMethod::Min, [3, 1, 3, 0, 2, 3]),
+        (RankMethod::Max, [5, 1, 5, 0, 2, 5]),
+        (RankMethod::Dense, [3, 1, 3, 0, 2, 3]),
+        (RankMethod::Ordinal, [3, 1, 4, 0, 2, 5]),
+    ];
+    for (method, expected) in cases {
+        let expected = expected
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i != 3).then(|| *x))
+            .collect::<UInt32Array>();
+        assert_eq!(rank(&array, method, false), expected, "{:?}", method);
+    }
+}
+
+#[test]
+fn descending() {
+    let array = Int32Array::from([Some(3), Some(1), Some(3), None, Some(2)]);
+
+    let result = rank(&array, RankMethod::Min, true);
+    let expected = UInt32Array::from([Some(1), Some(4), Some(1), None, Some(3)]);
+    assert_eq!(result, expected);
+
+    let result = rank(&array, RankMethod::Ordinal, true);
+    let expected = UInt32Array::from([Some(1), Some(4), Some(2), None, Some(3)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn average() {
+    let array = Int32Array::from([Some(3), Some(1), Some(3), None, Some(2), Some(3)]);
+
+    let result = average_rank(&array, false);
+    let expected =
+        Float64Array::from([Some(4.0), Some(1.0), Some(4.0), None, Some(2.0), Some(4.0)]);
+    assert_eq!(result, expected);
+
+    let array = Int32Array::from_slice([1, 1, 2, 2]);
+    let result = average_rank(&array, true);
+    let expected = Float64Array::from_slice([3.5, 3.5, 1.5, 1.5]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn nan() {
+    let array = Float64Array::from([Some(f64::NAN), Some(1.0), None, Some(f64::NAN), Some(-1.0)]);
+
+    let result = rank(&array, RankMethod::Min, false);
+    let expected = UInt32Array::from([Some(3), Some(2), None, Some(3), Some(1)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn sliced_and_empty() {
+    let array = Int32Array::from_slice([5, 3, 4, 3]).slice(1, 3);
+    let result = rank(&array, RankMethod::Dense, false);
+    assert_eq!(result, UInt32Array::from_slice([1, 2, 1]));
+
+    let array = Int32Array::from_vec(vec![]);
+    assert_eq!(rank(&array, RankMethod::Min, false).len(), 0);
+}
+
+#[test]
+fn with_options_methods() {
+    let array = Int32Array::from([Some(3), Some(1), Some(3), None, Some(2), None]);
+
+    let nulls_last = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let cases = [
+        (RankMethod::Average, [3, 1, 3, 5, 2, 5]),
+        (RankMethod::Min, [3, 1, 3, 5, 2, 5]),
+        (RankMethod::Max, [4, 1, 4, 6, 2, 6]),
+        (RankMethod::Dense, [3, 1, 3, 4, 2, 4]),
+        (RankMethod::Ordinal, [3, 1, 4, 5, 2, 6]),
+    ];
+    for (method, expected) in cases {
+        let result = rank_with_options(&array, method, &nulls_last).unwrap();
+        assert_eq!(result, UInt32Array::from_slice(expected), "{:?}", method);
+    }
+
+    let nulls_first = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let cases = [
+        (RankMethod::Average, [3, 6, 3, 1, 5, 1]),
+        (RankMethod::Min, [3, 6, 3, 1, 5, 1]),
+        (RankMethod::Max, [4, 6, 4, 2, 5, 2]),
+        (RankMethod::Dense, [2, 4, 2, 1, 3, 1]),
+        (RankMethod::Ordinal, [3, 6, 4, 1, 5, 2]),
+    ];
+    for (method, expected) in cases {
+        let result = rank_with_options(&array, method, &nulls_first).unwrap();
+        assert_eq!(result, UInt32Array::from_slice(expected), "{:?}", method);
+    }
+}
+
+#[test]
+fn with_options_average() {
+    let array = Int32Array::from([Some(3), None, Some(1), Some(3), None]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+
+    let result = average_rank_with_options(&array, &options).unwrap();
+    let expected = Float64Array::from_slice([4.5, 1.5, 3.0, 4.5, 1.5]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn with_options_utf8() {
+    let array = Utf8Array::<i32>::from([Some("b"), Some("a"), None, Some("b"), Some("c")]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+
+    let result = rank_with_options(&array, RankMethod::Dense, &options).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 3, 4, 2, 1]));
+}
+
+#[test]
+fn with_options_unsupported() {
+    let array = NullArray::new(arrow2::datatypes::DataType::Null, 2);
+    assert!(rank_with_options(&array, RankMethod::Min, &SortOptions::default()).is_err());
+}
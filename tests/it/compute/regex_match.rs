@@ -1,4 +1,6 @@
-use arrow2::array::{BooleanArray, Utf8Array};
+use arrow2::array::{
+    Array, BooleanArray, ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array,
+};
 use arrow2::compute::regex_match::*;
 use arrow2::error::Result;
 use arrow2::offset::Offset;
@@ -56,3 +58,213 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn test_regexp_match() {
+    let array = Utf8Array::<i32>::from([Some("2021-01-31"), Some("2021-02"), Some("arrow"), None]);
+
+    let result = regexp_match(
+        &array,
+        r"(?P<year>\d{4})-(?P<month>\d{2})(?:-(?P<day>\d{2}))?",
+    )
+    .unwrap();
+
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend([
+            Some(vec![Some("2021"), Some("01"), Some("31")]),
+            Some(vec![Some("2021"), Some("02"), None]),
+            None,
+            None,
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_regexp_match_no_groups() {
+    let array = Utf8Array::<i64>::from_slice(["arrow", "parquet"]);
+
+    let result = regexp_match(&array, "ar+").unwrap();
+
+    let mut expected = MutableListArray::<i64, MutableUtf8Array<i64>>::new();
+    expected
+        .try_extend([Some(vec![Some("arr")]), Some(vec![Some("ar")])])
+        .unwrap();
+    let expected: ListArray<i64> = expected.into();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_regexp_match_invalid() {
+    let array = Utf8Array::<i32>::from_slice(["arrow"]);
+    assert!(regexp_match(&array, "(").is_err());
+}
+
+#[test]
+fn test_regexp_split() {
+    let array = Utf8Array::<i32>::from([Some("a1b22c333"), Some(""), None, Some("abc")]);
+
+    let result = regexp_split(&array, r"\d+", None).unwrap();
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend([
+            Some(vec![Some("a"), Some("b"), Some("c"), Some("")]),
+            Some(vec![]),
+            None,
+            Some(vec![Some("abc")]),
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result, expected);
+
+    let result = regexp_split(&array, r"\d+", Some(1)).unwrap();
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend([
+            Some(vec![Some("a"), Some("b22c333")]),
+            Some(vec![]),
+            None,
+            Some(vec![Some("abc")]),
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_regexp_split_invalid() {
+    let array = Utf8Array::<i32>::from_slice(["arrow"]);
+    assert!(regexp_split(&array, "(", None).is_err());
+}
+
+#[test]
+fn test_regexp_replace() {
+    let array = Utf8Array::<i32>::from([Some("a1b22c333"), Some(""), None, Some("aaa")]);
+
+    let result = regexp_replace(&array, r"\d+", "#", None).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a#b#c#"), Some(""), None, Some("aaa")]);
+    assert_eq!(result, expected);
+
+    let result = regexp_replace(&array, r"(\d+)", "<$1>", Some(2)).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a<1>b<22>c333"), Some(""), None, Some("aaa")]);
+    assert_eq!(result, expected);
+
+    // matches are non-overlapping, from left to right
+    let result = regexp_replace(&array, "aa", "b", None).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a1b22c333"), Some(""), None, Some("ba")]);
+    assert_eq!(result, expected);
+
+    let result = regexp_replace(&array, r"(?P<letter>[a-z])", "${letter}!", Some(1)).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a!1b22c333"), Some(""), None, Some("a!aa")]);
+    assert_eq!(result, expected);
+
+    let result = regexp_replace(&array, r"\d", "#", Some(0)).unwrap();
+    assert_eq!(result, array);
+}
+
+#[test]
+fn test_regexp_replace_invalid() {
+    let array = Utf8Array::<i32>::from_slice(["arrow"]);
+    assert!(regexp_replace(&array, "(", "", None).is_err());
+}
+
+#[test]
+fn test_regexp_extract() {
+    let array = Utf8Array::<i32>::from([Some("2021-01-31"), Some("2021-02"), Some("arrow"), None]);
+
+    let result = regexp_extract(&array, r"(?P<year>\d{4})-(\d{2})(?:-(?P<day>\d{2}))?").unwrap();
+
+    let names = result
+        .fields()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["year", "2", "day"]);
+
+    let expected = [
+        Utf8Array::<i32>::from([Some("2021"), Some("2021"), None, None]),
+        Utf8Array::<i32>::from([Some("01"), Some("02"), None, None]),
+        Utf8Array::<i32>::from([Some("31"), None, None, None]),
+    ];
+    for (result, expected) in result.values().iter().zip(expected.iter()) {
+        assert_eq!(result.as_ref(), expected as &dyn Array);
+    }
+    assert_eq!(result.validity(), Some(&[true, true, false, false].into()));
+}
+
+#[test]
+fn test_regexp_extract_no_groups() {
+    let array = Utf8Array::<i64>::from_slice(["arrow", "parquet", "avro"]);
+
+    let result = regexp_extract(&array, "ar+").unwrap();
+
+    assert_eq!(result.fields().len(), 1);
+    assert_eq!(result.fields()[0].name, "0");
+    assert_eq!(
+        result.values()[0].as_ref(),
+        &Utf8Array::<i64>::from([Some("arr"), Some("ar"), None]) as &dyn Array
+    );
+}
+
+#[test]
+fn test_regexp_extract_invalid() {
+    let array = Utf8Array::<i32>::from_slice(["arrow"]);
+    assert!(regexp_extract(&array, "(").is_err());
+    assert!(regexp_extract_all(&array, "(").is_err());
+}
+
+#[test]
+fn test_regexp_extract_all() {
+    let array = Utf8Array::<i32>::from([Some("a1b2c"), Some("abc"), None]);
+
+    let result = regexp_extract_all(&array, r"(?P<letter>[a-z])(\d)?").unwrap();
+
+    let names = result
+        .fields()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["letter", "2"]);
+
+    let mut letters = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    letters
+        .try_extend([
+            Some(vec![Some("a"), Some("b"), Some("c")]),
+            Some(vec![Some("a"), Some("b"), Some("c")]),
+            None,
+        ])
+        .unwrap();
+    let letters: ListArray<i32> = letters.into();
+
+    let mut digits = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    digits
+        .try_extend([
+            Some(vec![Some("1"), Some("2"), None]),
+            Some(vec![None, None, None]),
+            None,
+        ])
+        .unwrap();
+    let digits: ListArray<i32> = digits.into();
+
+    assert_eq!(result.values()[0].as_ref(), &letters as &dyn Array);
+    assert_eq!(result.values()[1].as_ref(), &digits as &dyn Array);
+    assert_eq!(result.validity(), Some(&[true, true, false].into()));
+}
+
+#[test]
+fn test_regexp_extract_all_no_match() {
+    let array = Utf8Array::<i32>::from_slice(["arrow", "avro"]);
+
+    let result = regexp_extract_all(&array, r"\d").unwrap();
+
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend([Some(Vec::<Option<&str>>::new()), Some(vec![])])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result.values()[0].as_ref(), &expected as &dyn Array);
+}
@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::sort::{lexsort, lexsort_to_indices, SortColumn, SortOptions};
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -210,3 +210,94 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 */
+
+#[test]
+fn test_lex_sort_radix_matches_comparison() {
+    // integer columns are radix sorted when there is no limit; a limit equal to the length
+    // sorts by comparison
+    let c1 = Int8Array::from([
+        Some(i8::MIN),
+        None,
+        Some(-1),
+        Some(i8::MAX),
+        Some(-1),
+        None,
+        Some(0),
+        Some(-1),
+    ]);
+    let c2 = Utf8Array::<i32>::from([
+        Some("b"),
+        Some("a"),
+        None,
+        Some("c"),
+        Some("a"),
+        Some("b"),
+        Some("a"),
+        Some("a"),
+    ]);
+    let c3 = UInt64Array::from([
+        Some(u64::MAX),
+        Some(1),
+        Some(2),
+        None,
+        Some(3),
+        Some(0),
+        Some(1),
+        Some(0),
+    ]);
+    let c4 = Int64Array::from([
+        Some(-5),
+        Some(i64::MIN),
+        Some(5),
+        Some(0),
+        None,
+        Some(i64::MAX),
+        Some(1),
+        Some(-5),
+    ]);
+
+    for (descending, nulls_first) in [(false, false), (false, true), (true, false), (true, true)] {
+        let options = Some(SortOptions {
+            descending,
+            nulls_first,
+        });
+        let input = vec![
+            SortColumn {
+                values: &c1,
+                options,
+            },
+            SortColumn {
+                values: &c2,
+                options: None,
+            },
+            SortColumn {
+                values: &c3,
+                options,
+            },
+            SortColumn {
+                values: &c4,
+                options,
+            },
+        ];
+        let radix = lexsort::<i32>(&input, None).unwrap();
+        let comparison = lexsort::<i32>(&input, Some(c1.len())).unwrap();
+        assert_eq!(radix, comparison);
+    }
+}
+
+#[test]
+fn test_lex_sort_stable() {
+    let c1 = Int32Array::from([Some(1), None, Some(0), Some(1), None, Some(0)]);
+    let input = vec![
+        SortColumn {
+            values: &c1,
+            options: None,
+        },
+        SortColumn {
+            values: &c1,
+            options: None,
+        },
+    ];
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 4, 2, 5, 0, 3]));
+}
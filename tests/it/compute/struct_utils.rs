@@ -0,0 +1,45 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::struct_utils::*;
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn pack() {
+    let a = Int32Array::from([Some(1), None, Some(3)]);
+    let b = Utf8Array::<i32>::from([Some("x"), Some("y"), None]);
+
+    let result = struct_pack(vec![("a", a.clone().boxed()), ("b", b.clone().boxed())]).unwrap();
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let expected = StructArray::new(data_type, vec![a.boxed(), b.boxed()], None);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn pack_errors() {
+    assert!(struct_pack(vec![]).is_err());
+
+    let a = Int32Array::from_slice([1, 2]).boxed();
+    let b = Int32Array::from_slice([1]).boxed();
+    assert!(struct_pack(vec![("a", a.clone()), ("b", b)]).is_err());
+
+    assert!(struct_pack(vec![("a", a.clone()), ("a", a)]).is_err());
+}
+
+#[test]
+fn unpack() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = BooleanArray::from_slice([true, false]);
+    let array = struct_pack(vec![("a", a.clone().boxed()), ("b", b.clone().boxed())])
+        .unwrap()
+        .with_validity(Some(Bitmap::from([true, false])));
+
+    let fields = struct_unpack(&array);
+    assert_eq!(fields.len(), 2);
+    // the validity of the struct is not applied to its children
+    assert_eq!(fields["a"], &a as &dyn Array);
+    assert_eq!(fields["b"], &b as &dyn Array);
+}
@@ -342,3 +342,84 @@ fn consistency() {
         }
     });
 }
+
+fn utf8_chars<O: Offset>() -> Result<()> {
+    let cases = vec![
+        // multi-byte characters are never split
+        (
+            vec![Some("héllo"), None, Some("💖💗💘")],
+            1,
+            Some(2),
+            vec![Some("él"), None, Some("💗💘")],
+        ),
+        (
+            vec![Some("héllo"), None, Some("💖💗💘")],
+            -2,
+            None,
+            vec![Some("lo"), None, Some("💗💘")],
+        ),
+        (
+            vec![Some("héllo"), None, Some("💖💗💘")],
+            -4,
+            Some(1),
+            vec![Some("é"), None, Some("💖")],
+        ),
+        // positions are clamped
+        (
+            vec![Some("héllo"), None, Some("💖💗💘")],
+            10,
+            Some(2),
+            vec![Some(""), None, Some("")],
+        ),
+        (
+            vec![Some("héllo"), None, Some("💖💗💘")],
+            -10,
+            Some(2),
+            vec![Some("hé"), None, Some("💖💗")],
+        ),
+        (
+            vec![Some("héllo"), None, Some("💖💗💘")],
+            3,
+            Some(10),
+            vec![Some("lo"), None, Some("")],
+        ),
+        // a combining accent is a character on its own
+        (vec![Some("e\u{301}a")], 1, None, vec![Some("\u{301}a")]),
+        (vec![Some("e\u{301}a")], 0, Some(1), vec![Some("e")]),
+    ];
+
+    cases
+        .into_iter()
+        .try_for_each::<_, Result<()>>(|(array, start, length, expected)| {
+            let array = Utf8Array::<O>::from(array);
+            let expected = Utf8Array::<O>::from(expected);
+
+            let result = utf8_substring(&array, start, &length)?;
+            let result = result.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+            assert_eq!(&expected, result);
+
+            // `substring` of strings is also by characters
+            let result = substring(&array, start, &length)?;
+            let result = result.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+            assert_eq!(&expected, result);
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+#[test]
+fn utf8_chars_string() -> Result<()> {
+    utf8_chars::<i32>()
+}
+
+#[test]
+fn utf8_chars_large_string() -> Result<()> {
+    utf8_chars::<i64>()
+}
+
+#[test]
+fn utf8_substring_binary() {
+    let array = BinaryArray::<i32>::from_slice([b"hello"]);
+    assert!(utf8_substring(&array, 0, &None).is_err());
+}
@@ -1,5 +1,5 @@
 use arrow2::compute::take::{can_take, take};
-use arrow2::datatypes::{DataType, Field, IntervalUnit};
+use arrow2::datatypes::{DataType, Field, IntervalUnit, UnionMode};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
 use arrow2::{bitmap::Bitmap, buffer::Buffer};
@@ -152,6 +152,35 @@ fn consistency() {
     });
 }
 
+#[test]
+fn dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b")]).unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    let indices = Int32Array::from([Some(2), None, Some(0), Some(1)]);
+
+    let result = take(&array, &indices).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from([Some(1), None, Some(0), None])
+    );
+
+    let values = |array: &DictionaryArray<i32>| {
+        array
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap()
+            .values()
+            .as_ptr()
+    };
+    assert_eq!(values(result), values(&array));
+}
+
 #[test]
 fn empty() {
     let indices = Int32Array::from_slice([]);
@@ -305,3 +334,153 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn fixed_size_list() {
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int32, 3);
+    let values = Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6, 7, 8]).boxed();
+    let validity = Bitmap::from([true, false, true]);
+    let array = FixedSizeListArray::new(data_type.clone(), values, Some(validity));
+
+    let indices = Int32Array::from([Some(2), None, Some(0), Some(1)]);
+    let result = take(&array, &indices).unwrap();
+
+    let values = Int32Array::from([
+        Some(6),
+        Some(7),
+        Some(8),
+        None,
+        None,
+        None,
+        Some(0),
+        Some(1),
+        Some(2),
+        Some(3),
+        Some(4),
+        Some(5),
+    ])
+    .boxed();
+    let validity = Bitmap::from([true, false, true, false]);
+    let expected = FixedSizeListArray::new(data_type, values, Some(validity));
+    assert_eq!(expected, result.as_ref());
+
+    assert!(take(&array, &Int32Array::from_slice([3])).is_err());
+}
+
+#[test]
+fn map() {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.clone(), false)),
+        false,
+    );
+
+    let field = StructArray::new(
+        entries.clone(),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed(),
+            Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+        ],
+        None,
+    );
+    // [{a: 1}, {b: 2, c: 3}, null, {d: 4}]
+    let array = MapArray::new(
+        data_type.clone(),
+        vec![0, 1, 3, 3, 4].try_into().unwrap(),
+        field.boxed(),
+        Some(Bitmap::from([true, true, false, true])),
+    );
+
+    let indices = Int32Array::from([Some(3), None, Some(1), Some(2)]);
+    let result = take(&array, &indices).unwrap();
+
+    let field = StructArray::new(
+        entries,
+        vec![
+            Utf8Array::<i32>::from_slice(["d", "b", "c"]).boxed(),
+            Int32Array::from_slice([4, 2, 3]).boxed(),
+        ],
+        None,
+    );
+    let expected = MapArray::new(
+        data_type,
+        vec![0, 1, 1, 3, 3].try_into().unwrap(),
+        field.boxed(),
+        Some(Bitmap::from([true, false, true, false])),
+    );
+    assert_eq!(expected, result.as_ref());
+
+    assert!(take(&array, &Int32Array::from_slice([0, 4])).is_err());
+}
+
+fn union_fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]
+}
+
+#[test]
+fn sparse_union() {
+    let data_type = DataType::Union(union_fields(), None, UnionMode::Sparse);
+    // [1, "y", 3]
+    let array = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from([Some(1), None, Some(3)]).boxed(),
+            Utf8Array::<i32>::from_slice(["x", "y", "z"]).boxed(),
+        ],
+        None,
+    );
+
+    let indices = Int32Array::from([Some(1), None, Some(2), Some(0)]);
+    let result = take(&array, &indices).unwrap();
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![1, 0, 0, 0].into(),
+        vec![
+            Int32Array::from([None, None, Some(3), Some(1)]).boxed(),
+            Utf8Array::<i32>::from([Some("y"), None, Some("z"), Some("x")]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+
+    assert!(take(&array, &Int32Array::from_slice([3])).is_err());
+}
+
+#[test]
+fn dense_union() {
+    let data_type = DataType::Union(union_fields(), Some(vec![5, 7]), UnionMode::Dense);
+    // [10, "a", 20, "b"]
+    let array = UnionArray::new(
+        data_type.clone(),
+        vec![5, 7, 5, 7].into(),
+        vec![
+            Int32Array::from_slice([10, 20]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    );
+
+    let indices = Int32Array::from([Some(3), None, Some(0), Some(2), Some(1)]);
+    let result = take(&array, &indices).unwrap();
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![7, 5, 5, 5, 7].into(),
+        vec![
+            Int32Array::from([None, Some(10), Some(20)]).boxed(),
+            Utf8Array::<i32>::from_slice(["b", "a"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 2, 1].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+
+    assert!(take(&array, &Int32Array::from_slice([-1])).is_err());
+}
@@ -278,6 +278,115 @@ fn test_data_tz() -> Vec<TestData> {
     ]
 }
 
+#[test]
+fn date_parts() {
+    // 2024-02-29, 2024-12-30, 2025-01-01 and 1970-01-01
+    let array = Int32Array::from([Some(19782), Some(20087), Some(20089), None, Some(0)])
+        .to(DataType::Date32);
+
+    let expected = Int32Array::from([Some(2024), Some(2025), Some(2025), None, Some(1970)]);
+    assert_eq!(iso_year(&array).unwrap(), expected);
+    let expected = UInt32Array::from([Some(9), Some(1), Some(1), None, Some(1)]);
+    assert_eq!(iso_week(&array).unwrap(), expected);
+    let expected = UInt32Array::from([Some(1), Some(4), Some(1), None, Some(1)]);
+    assert_eq!(quarter(&array).unwrap(), expected);
+    let expected = UInt32Array::from([Some(60), Some(365), Some(1), None, Some(1)]);
+    assert_eq!(day_of_year(&array).unwrap(), expected);
+
+    // Thursday, Monday, Wednesday and Thursday
+    let expected = UInt32Array::from([Some(4), Some(1), Some(3), None, Some(4)]);
+    assert_eq!(day_of_week(&array, chrono::Weekday::Mon).unwrap(), expected);
+    let expected = UInt32Array::from([Some(5), Some(2), Some(4), None, Some(5)]);
+    assert_eq!(day_of_week(&array, chrono::Weekday::Sun).unwrap(), expected);
+    let expected = UInt32Array::from([Some(6), Some(3), Some(5), None, Some(6)]);
+    assert_eq!(day_of_week(&array, chrono::Weekday::Sat).unwrap(), expected);
+
+    let expected = Int64Array::from([
+        Some(1709164800),
+        Some(1735516800),
+        Some(1735689600),
+        None,
+        Some(0),
+    ]);
+    assert_eq!(epoch(&array).unwrap(), expected);
+}
+
+#[test]
+fn epoch_timestamps() {
+    let array = Int64Array::from([Some(1500), Some(-1500), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let expected = Int64Array::from([Some(1), Some(-2), None]);
+    assert_eq!(epoch(&array).unwrap(), expected);
+
+    // the epoch does not depend on the timezone
+    let array = Int64Array::from([Some(1500), None]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+05:00".to_string()),
+    ));
+    let expected = Int64Array::from([Some(1), None]);
+    assert_eq!(epoch(&array).unwrap(), expected);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn year_boundary_tz() {
+    // 2023-12-31T14:30:00Z (2023-12-31T23:30 in Tokyo) and
+    // 2023-12-31T15:30:00Z (2024-01-01T00:30 in Tokyo)
+    let values = Int64Array::from([Some(1704033000), Some(1704036600), None]);
+    let tokyo = values.clone().to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("Asia/Tokyo".to_string()),
+    ));
+    let utc = values.to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let expected = Int32Array::from([Some(2023), Some(2024), None]);
+    assert_eq!(year(&tokyo).unwrap(), expected);
+    assert_eq!(iso_year(&tokyo).unwrap(), expected);
+    let expected = Int32Array::from([Some(2023), Some(2023), None]);
+    assert_eq!(year(&utc).unwrap(), expected);
+    assert_eq!(iso_year(&utc).unwrap(), expected);
+
+    let expected = UInt32Array::from([Some(4), Some(1), None]);
+    assert_eq!(quarter(&tokyo).unwrap(), expected);
+    let expected = UInt32Array::from([Some(4), Some(4), None]);
+    assert_eq!(quarter(&utc).unwrap(), expected);
+
+    let expected = UInt32Array::from([Some(365), Some(1), None]);
+    assert_eq!(day_of_year(&tokyo).unwrap(), expected);
+    let expected = UInt32Array::from([Some(365), Some(365), None]);
+    assert_eq!(day_of_year(&utc).unwrap(), expected);
+
+    let expected = UInt32Array::from([Some(52), Some(1), None]);
+    assert_eq!(iso_week(&tokyo).unwrap(), expected);
+    let expected = UInt32Array::from([Some(52), Some(52), None]);
+    assert_eq!(iso_week(&utc).unwrap(), expected);
+
+    // Sunday and Monday in Tokyo
+    let expected = UInt32Array::from([Some(1), Some(2), None]);
+    assert_eq!(day_of_week(&tokyo, chrono::Weekday::Sun).unwrap(), expected);
+    let expected = UInt32Array::from([Some(1), Some(1), None]);
+    assert_eq!(day_of_week(&utc, chrono::Weekday::Sun).unwrap(), expected);
+
+    let expected = UInt32Array::from([Some(23), Some(0), None]);
+    assert_eq!(hour(&tokyo).unwrap(), expected);
+
+    let expected = Int64Array::from([Some(1704033000), Some(1704036600), None]);
+    assert_eq!(epoch(&tokyo).unwrap(), expected);
+    assert_eq!(epoch(&utc).unwrap(), expected);
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+#[test]
+fn named_timezone_without_chrono_tz() {
+    let array = Int64Array::from([Some(1704033000)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("Asia/Tokyo".to_string()),
+    ));
+    assert!(year(&array).is_err());
+    assert!(quarter(&array).is_err());
+    assert!(day_of_week(&array, chrono::Weekday::Mon).is_err());
+}
+
 #[test]
 fn consistency_hour() {
     consistency_check(can_hour, hour);
@@ -323,6 +432,33 @@ fn consistency_iso_week() {
     consistency_check(can_iso_week, iso_week);
 }
 
+#[test]
+fn consistency_iso_year() {
+    consistency_check(can_iso_year, iso_year);
+}
+
+#[test]
+fn consistency_quarter() {
+    consistency_check(can_quarter, quarter);
+}
+
+#[test]
+fn consistency_day_of_year() {
+    consistency_check(can_day_of_year, day_of_year);
+}
+
+#[test]
+fn consistency_day_of_week() {
+    consistency_check(can_day_of_week, |array| {
+        day_of_week(array, chrono::Weekday::Sun)
+    });
+}
+
+#[test]
+fn consistency_epoch() {
+    consistency_check(can_epoch, epoch);
+}
+
 fn consistency_check<O: arrow2::types::NativeType>(
     can_extract: fn(&DataType) -> bool,
     extract: fn(&dyn Array) -> arrow2::error::Result<PrimitiveArray<O>>,
@@ -372,3 +508,120 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn truncate_naive() {
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    // 1970-01-01T00:00:00.000 (a Thursday), 1969-12-31T23:59:59.999 and
+    // 2021-05-24T17:25:30.500
+    let array =
+        Int64Array::from([Some(0), Some(-1), None, Some(1621877130500)]).to(data_type.clone());
+
+    let cases = [
+        (Granularity::Second, [0, -1000, 1621877130000]),
+        (Granularity::Minute, [0, -60000, 1621877100000]),
+        (Granularity::Hour, [0, -3600000, 1621875600000]),
+        (Granularity::Day, [0, -86400000, 1621814400000]),
+        (Granularity::Week, [-259200000, -259200000, 1621814400000]),
+        (Granularity::Month, [0, -2678400000, 1619827200000]),
+        (Granularity::Year, [0, -31536000000, 1609459200000]),
+    ];
+    for (granularity, [a, b, c]) in cases {
+        let expected = Int64Array::from([Some(a), Some(b), None, Some(c)]).to(data_type.clone());
+        assert_eq!(
+            truncate(&array, granularity).unwrap(),
+            expected,
+            "{:?}",
+            granularity
+        );
+    }
+}
+
+#[test]
+fn round_naive() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    // 1970-01-01T11:59:59, 1970-01-01T12:00:00 and 1970-01-17T00:00:00
+    let array = Int64Array::from([Some(43199), Some(43200), Some(1382400)]).to(data_type.clone());
+
+    let expected = Int64Array::from([Some(0), Some(86400), Some(1382400)]).to(data_type.clone());
+    assert_eq!(round(&array, Granularity::Day).unwrap(), expected);
+
+    let expected = Int64Array::from([Some(0), Some(0), Some(2678400)]).to(data_type.clone());
+    assert_eq!(round(&array, Granularity::Month).unwrap(), expected);
+
+    let expected = Int64Array::from([Some(43200), Some(43200), Some(1382400)]).to(data_type);
+    assert_eq!(round(&array, Granularity::Hour).unwrap(), expected);
+}
+
+#[test]
+fn truncate_fixed_offset() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("-03:00".to_string()));
+    // 1970-01-01T01:00:00Z is 1969-12-31T22:00:00-03:00
+    let array = Int64Array::from([Some(3600)]).to(data_type.clone());
+
+    let expected = Int64Array::from([Some(-75600)]).to(data_type.clone());
+    assert_eq!(truncate(&array, Granularity::Day).unwrap(), expected);
+
+    let expected = Int64Array::from([Some(-31525200)]).to(data_type);
+    assert_eq!(truncate(&array, Granularity::Year).unwrap(), expected);
+}
+
+#[test]
+fn truncate_unsupported() {
+    let array = Int64Array::from([Some(1)]);
+    assert!(truncate(&array, Granularity::Day).is_err());
+    assert!(round(&array, Granularity::Second).is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn truncate_dst() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+    // 2021-03-14T12:00:00-04:00, on the 23 hours long day of the spring forward
+    let array = Int64Array::from([Some(1615737600)]).to(data_type.clone());
+
+    // 2021-03-14T00:00:00-05:00
+    let expected = Int64Array::from([Some(1615698000)]).to(data_type.clone());
+    assert_eq!(truncate(&array, Granularity::Day).unwrap(), expected);
+    // 11 hours after midnight, 12 hours before the next one
+    assert_eq!(round(&array, Granularity::Day).unwrap(), expected);
+    // the hour is truncated in absolute time
+    assert_eq!(
+        truncate(&array, Granularity::Hour).unwrap(),
+        Int64Array::from([Some(1615737600)]).to(data_type)
+    );
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Berlin".to_string()));
+    // 2021-03-31T12:00:00+02:00, a Wednesday after the spring forward of 2021-03-28
+    let array = Int64Array::from([Some(1617184800)]).to(data_type.clone());
+
+    let cases = [
+        // 2021-03-29T00:00:00+02:00
+        (Granularity::Week, 1616968800),
+        // 2021-03-01T00:00:00+01:00
+        (Granularity::Month, 1614553200),
+        // 2021-01-01T00:00:00+01:00
+        (Granularity::Year, 1609455600),
+    ];
+    for (granularity, expected) in cases {
+        let expected = Int64Array::from([Some(expected)]).to(data_type.clone());
+        assert_eq!(truncate(&array, granularity).unwrap(), expected);
+    }
+    // 2021-04-01T00:00:00+02:00
+    let expected = Int64Array::from([Some(1617228000)]).to(data_type);
+    assert_eq!(round(&array, Granularity::Month).unwrap(), expected);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn truncate_dst_midnight_gap() {
+    // on 2018-11-04, clocks in Sao Paulo jumped from 00:00 (-03:00) to 01:00 (-02:00)
+    let data_type =
+        DataType::Timestamp(TimeUnit::Millisecond, Some("America/Sao_Paulo".to_string()));
+    // 2018-11-04T12:00:00-02:00
+    let array = Int64Array::from([Some(1541340000000), None]).to(data_type.clone());
+
+    // the day starts at the end of the gap, 2018-11-04T01:00:00-02:00
+    let expected = Int64Array::from([Some(1541300400000), None]).to(data_type);
+    assert_eq!(truncate(&array, Granularity::Day).unwrap(), expected);
+}
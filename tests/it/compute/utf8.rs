@@ -369,3 +369,257 @@ fn consistency_upper() {
         }
     });
 }
+
+fn split_expected<O: Offset>(expected: Vec<Option<Vec<&str>>>) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutableUtf8Array<O>>::new();
+    array
+        .try_extend(
+            expected
+                .into_iter()
+                .map(|x| x.map(|x| x.into_iter().map(Some))),
+        )
+        .unwrap();
+    array.into()
+}
+
+fn test_split<O: Offset>() -> Result<()> {
+    let array = Utf8Array::<O>::from([
+        Some("a,b,c"),
+        Some("a,b,"),
+        Some(",a"),
+        Some(""),
+        None,
+        Some("abc"),
+    ]);
+
+    let result = split(&array, ",", None)?;
+    let expected = split_expected::<O>(vec![
+        Some(vec!["a", "b", "c"]),
+        Some(vec!["a", "b", ""]),
+        Some(vec!["", "a"]),
+        Some(vec![]),
+        None,
+        Some(vec!["abc"]),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = split(&array, ",", Some(1))?;
+    let expected = split_expected::<O>(vec![
+        Some(vec!["a", "b,c"]),
+        Some(vec!["a", "b,"]),
+        Some(vec!["", "a"]),
+        Some(vec![]),
+        None,
+        Some(vec!["abc"]),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = split(&array, ",", Some(0))?;
+    let expected = split_expected::<O>(vec![
+        Some(vec!["a,b,c"]),
+        Some(vec!["a,b,"]),
+        Some(vec![",a"]),
+        Some(vec![]),
+        None,
+        Some(vec!["abc"]),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn split_utf8() -> Result<()> {
+    test_split::<i32>()
+}
+
+#[test]
+fn split_large_utf8() -> Result<()> {
+    test_split::<i64>()
+}
+
+#[test]
+fn split_multi_char_delimiter() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(["a::b::", "::"]);
+    let result = split(&array, "::", None)?;
+    let expected = split_expected::<i32>(vec![Some(vec!["a", "b", ""]), Some(vec!["", ""])]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn split_empty_delimiter() {
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(split(&array, "", None).is_err());
+}
+
+fn test_trim<O: Offset>() {
+    let array =
+        Utf8Array::<O>::from([Some("  ab c\t"), None, Some(""), Some(" ñüé "), Some("xñx")]);
+
+    let expected = Utf8Array::<O>::from([Some("ab c"), None, Some(""), Some("ñüé"), Some("xñx")]);
+    assert_eq!(trim(&array, None), expected);
+    let expected =
+        Utf8Array::<O>::from([Some("ab c\t"), None, Some(""), Some("ñüé "), Some("xñx")]);
+    assert_eq!(ltrim(&array, None), expected);
+    let expected =
+        Utf8Array::<O>::from([Some("  ab c"), None, Some(""), Some(" ñüé"), Some("xñx")]);
+    assert_eq!(rtrim(&array, None), expected);
+
+    let expected = Utf8Array::<O>::from([Some("ab c\t"), None, Some(""), Some("üé"), Some("")]);
+    assert_eq!(trim(&array, Some(" xñ")), expected);
+    let expected = Utf8Array::<O>::from([Some("ab c\t"), None, Some(""), Some("üé "), Some("")]);
+    assert_eq!(ltrim(&array, Some(" xñ")), expected);
+    let expected =
+        Utf8Array::<O>::from([Some("  ab c\t"), None, Some(""), Some(" ñüé"), Some("xñ")]);
+    assert_eq!(rtrim(&array, Some(" x")), expected);
+}
+
+#[test]
+fn trim_utf8() {
+    test_trim::<i32>()
+}
+
+#[test]
+fn trim_large_utf8() {
+    test_trim::<i64>()
+}
+
+#[test]
+fn trim_unicode_whitespace() {
+    // no-break space, ideographic space, em space and line separator
+    let array = Utf8Array::<i32>::from_slice(["\u{a0}a\u{3000}", "\u{2003}\u{2028}b c\n"]);
+
+    let expected = Utf8Array::<i32>::from_slice(["a", "b c"]);
+    assert_eq!(trim(&array, None), expected);
+    let expected = Utf8Array::<i32>::from_slice(["a\u{3000}", "b c\n"]);
+    assert_eq!(ltrim(&array, None), expected);
+    let expected = Utf8Array::<i32>::from_slice(["\u{a0}a", "\u{2003}\u{2028}b c"]);
+    assert_eq!(rtrim(&array, None), expected);
+}
+
+#[test]
+fn trim_sliced() {
+    let array = Utf8Array::<i32>::from([Some(" a "), Some("--b-"), None, Some("-c")]).slice(1, 3);
+
+    let expected = Utf8Array::<i32>::from([Some("b"), None, Some("c")]);
+    assert_eq!(trim(&array, Some("-")), expected);
+}
+
+fn test_pad<O: Offset>() {
+    let array = Utf8Array::<O>::from([Some("ab"), None, Some(""), Some("ñü"), Some("abcdef")]);
+
+    let expected = Utf8Array::<O>::from([
+        Some("éxéab"),
+        None,
+        Some("éxéxé"),
+        Some("éxéñü"),
+        Some("abcde"),
+    ]);
+    assert_eq!(lpad(&array, 5, "éx"), expected);
+    let expected = Utf8Array::<O>::from([
+        Some("abéxé"),
+        None,
+        Some("éxéxé"),
+        Some("ñüéxé"),
+        Some("abcde"),
+    ]);
+    assert_eq!(rpad(&array, 5, "éx"), expected);
+
+    let expected = Utf8Array::<O>::from([Some("ab"), None, Some(""), Some("ñü"), Some("abcde")]);
+    assert_eq!(lpad(&array, 5, ""), expected);
+    assert_eq!(rpad(&array, 5, ""), expected);
+
+    // multibyte values are truncated on character boundaries
+    let array = Utf8Array::<O>::from([Some("ñüé"), Some("日本語"), None]);
+    let expected = Utf8Array::<O>::from([Some("ñü"), Some("日本"), None]);
+    assert_eq!(lpad(&array, 2, "-"), expected);
+    assert_eq!(rpad(&array, 2, "-"), expected);
+
+    let expected = Utf8Array::<O>::from([Some(""), Some(""), None]);
+    assert_eq!(lpad(&array, 0, "-"), expected);
+
+    let expected = Utf8Array::<O>::from([Some("日本日ñüé"), Some("日本日日本語"), None]);
+    assert_eq!(lpad(&array, 6, "日本"), expected);
+}
+
+#[test]
+fn pad_utf8() {
+    test_pad::<i32>()
+}
+
+#[test]
+fn pad_large_utf8() {
+    test_pad::<i64>()
+}
+
+#[test]
+fn pad_sliced() {
+    let array = Utf8Array::<i32>::from([Some("abc"), None, Some("d"), Some("ef")]).slice(1, 3);
+    let expected = Utf8Array::<i32>::from([None, Some("d**"), Some("ef*")]);
+    assert_eq!(rpad(&array, 3, "*"), expected);
+}
+
+fn test_replace<O: Offset>() {
+    let array = Utf8Array::<O>::from([Some("aaaa"), None, Some(""), Some("ñaañ"), Some("xyz")]);
+
+    // matches are non-overlapping, from left to right
+    let expected = Utf8Array::<O>::from([Some("bb"), None, Some(""), Some("ñbñ"), Some("xyz")]);
+    assert_eq!(replace(&array, "aa", "b", None).unwrap(), expected);
+
+    let array = Utf8Array::<O>::from([Some("aaa"), Some("a.a.a")]);
+    let expected = Utf8Array::<O>::from([Some("ba"), Some("a.a.a")]);
+    assert_eq!(replace(&array, "aa", "b", None).unwrap(), expected);
+
+    let expected = Utf8Array::<O>::from([Some("aaa"), Some("a--a.a")]);
+    assert_eq!(replace(&array, ".", "--", Some(1)).unwrap(), expected);
+    assert_eq!(replace(&array, ".", "--", Some(0)).unwrap(), array);
+    assert_eq!(replace(&array, "", "--", None).unwrap(), array);
+
+    let expected = Utf8Array::<O>::from([Some(""), Some("..")]);
+    assert_eq!(replace(&array, "a", "", None).unwrap(), expected);
+}
+
+#[test]
+fn replace_utf8() {
+    test_replace::<i32>()
+}
+
+#[test]
+fn replace_large_utf8() {
+    test_replace::<i64>()
+}
+
+#[test]
+fn replace_sliced() {
+    let array = Utf8Array::<i32>::from([Some("a-a"), None, Some("b-b-b")]).slice(1, 2);
+    let expected = Utf8Array::<i32>::from([None, Some("b+b-b")]);
+    assert_eq!(replace(&array, "-", "+", Some(1)).unwrap(), expected);
+}
+
+#[test]
+fn test_initcap() -> Result<()> {
+    let array = Utf8Array::<i32>::from([
+        Some("hello wORLD"),
+        None,
+        Some(""),
+        Some("ñandú del-sur 2x"),
+        Some("ǆemal"),
+    ]);
+    let expected = Utf8Array::<i32>::from([
+        Some("Hello World"),
+        None,
+        Some(""),
+        Some("Ñandú Del-Sur 2x"),
+        Some("Ǆemal"),
+    ]);
+    let result = initcap(&array)?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = Utf8Array::<i64>::from([Some("a b"), None]);
+    let expected = Utf8Array::<i64>::from([Some("A B"), None]);
+    let result = initcap(&array)?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(initcap(&Int32Array::from_slice([1])).is_err());
+    Ok(())
+}
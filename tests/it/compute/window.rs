@@ -1,6 +1,9 @@
-use arrow2::array::{new_null_array, Int32Array};
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::compute::window::*;
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::error::Error;
+use arrow2::scalar::*;
 
 #[test]
 fn shift_pos() {
@@ -15,7 +18,143 @@ fn shift_pos() {
 #[test]
 fn shift_many() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
-    assert!(shift(&array, 10).is_err());
+    let expected = new_null_array(DataType::Date32, 3);
+
+    let result = shift(&array, 10).unwrap();
+    assert_eq!(expected.as_ref(), result.as_ref());
+
+    let result = shift(&array, -10).unwrap();
+    assert_eq!(expected.as_ref(), result.as_ref());
+
+    let result = shift(&array, i64::MIN).unwrap();
+    assert_eq!(expected.as_ref(), result.as_ref());
+}
+
+#[test]
+fn shift_fill() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let fill = PrimitiveScalar::from(Some(-1i32));
+
+    let cases = [
+        (1, vec![Some(-1), Some(1), None]),
+        (-1, vec![None, Some(3), Some(-1)]),
+        (-2, vec![Some(3), Some(-1), Some(-1)]),
+        (0, vec![Some(1), None, Some(3)]),
+        (5, vec![Some(-1), Some(-1), Some(-1)]),
+        (-5, vec![Some(-1), Some(-1), Some(-1)]),
+    ];
+    for (offset, expected) in cases {
+        let result = shift_with_fill(&array, offset, &fill).unwrap();
+        assert_eq!(Int32Array::from(expected), result.as_ref(), "{}", offset);
+    }
+
+    // a null fill is the same as no fill
+    let fill = PrimitiveScalar::<i32>::from(None);
+    let result = shift_with_fill(&array, 1, &fill).unwrap();
+    assert_eq!(shift(&array, 1).unwrap(), result);
+}
+
+#[test]
+fn shift_fill_utf8_and_boolean() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    let fill = Utf8Scalar::<i32>::new(Some("z"));
+    let result = shift_with_fill(&array, -2, &fill).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("c"), Some("z"), Some("z")]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = BooleanArray::from([Some(false), None]);
+    let fill = BooleanScalar::new(Some(true));
+    let result = shift_with_fill(&array, 1, &fill).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_fill_views_and_run_end_encoded() {
+    let array = StringViewArray::from([Some("a"), None, Some("c")]);
+    let fill = new_scalar(&array, 0);
+    assert_eq!(fill.data_type(), &DataType::Utf8);
+    let result = shift_with_fill(&array, 1, fill.as_ref()).unwrap();
+    let expected = StringViewArray::from([Some("a"), Some("a"), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = BinaryViewArray::from([Some(b"a".as_ref()), None]);
+    let fill = BinaryScalar::<i32>::new(Some(b"z".as_ref()));
+    let result = shift_with_fill(&array, -1, &fill).unwrap();
+    let expected = BinaryViewArray::from([None, Some(b"z".as_ref())]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = ree_encode(&Int32Array::from_slice([1, 1, 2]));
+    let fill = new_scalar(&array, 2);
+    assert_eq!(fill.data_type(), &DataType::Int32);
+    let result = shift_with_fill(&array, 2, fill.as_ref()).unwrap();
+    let expected = ree_encode(&Int32Array::from_slice([2, 2, 1]));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_fill_invalid_type() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let fill = PrimitiveScalar::from(Some(1i64));
+    assert!(shift_with_fill(&array, 1, &fill).is_err());
+}
+
+fn decode_dictionary(array: &dyn Array) -> Vec<Option<&str>> {
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    array
+        .keys_iter()
+        .map(|key| key.map(|key| values.value(key)))
+        .collect()
+}
+
+#[test]
+fn shift_dictionary() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    let keys = Int32Array::from([Some(1), None, Some(0)]);
+    let array = DictionaryArray::try_from_keys(keys, values).unwrap();
+
+    let result = shift(&array, 1).unwrap();
+    assert_eq!(result.data_type(), array.data_type());
+    assert_eq!(
+        decode_dictionary(result.as_ref()),
+        vec![None, Some("b"), None]
+    );
+
+    let fill = DictionaryScalar::<i32>::new(
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        Some(Box::new(Utf8Scalar::<i32>::new(Some("z")))),
+    );
+    let result = shift_with_fill(&array, -1, &fill).unwrap();
+    assert_eq!(result.data_type(), array.data_type());
+    assert_eq!(
+        decode_dictionary(result.as_ref()),
+        vec![None, Some("a"), Some("z")]
+    );
+}
+
+#[test]
+fn lead_lag() {
+    let a = Int32Array::from_slice([1, 2, 3]).boxed();
+    let b = Utf8Array::<i32>::from_slice(["x", "y", "z"]).boxed();
+    let chunk = Chunk::new(vec![a, b]);
+
+    let result = lead(&chunk, 0, 1, None).unwrap();
+    assert_eq!(Int32Array::from([Some(2), Some(3), None]), result.as_ref());
+
+    let fill = Utf8Scalar::<i32>::new(Some("-"));
+    let result = lag(&chunk, 1, 2, Some(&fill)).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["-", "-", "x"]);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(lead(&chunk, 2, 1, None).is_err());
 }
 
 #[test]
@@ -27,3 +166,101 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn cumsum_nulls() {
+    let array = Int32Array::from(&[None, Some(1), None, Some(3), Some(4), None]);
+    let result = cumsum(&array);
+    let expected = Int32Array::from(&[None, Some(1), None, Some(4), Some(8), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn cumsum_lanes() {
+    // lengths covering full lanes and remainders of both 4 and 8 elements
+    for length in [0, 1, 3, 4, 7, 8, 9, 17, 100] {
+        let values = (0..length).map(|x| x as i64 * 3 - 50).collect::<Vec<_>>();
+        let expected = values
+            .iter()
+            .scan(0, |acc, x| {
+                *acc += x;
+                Some(*acc)
+            })
+            .collect::<Vec<_>>();
+
+        let result = cumsum(&Int64Array::from_vec(values.clone()));
+        assert_eq!(result.values().as_slice(), expected.as_slice());
+
+        let values32 = values.iter().map(|x| *x as i32).collect::<Vec<_>>();
+        let expected32 = expected.iter().map(|x| *x as i32).collect::<Vec<_>>();
+        let result = cumsum(&Int32Array::from_vec(values32));
+        assert_eq!(result.values().as_slice(), expected32.as_slice());
+
+        let values64 = values.iter().map(|x| *x as f64).collect::<Vec<_>>();
+        let expected64 = expected.iter().map(|x| *x as f64).collect::<Vec<_>>();
+        let result = cumsum(&Float64Array::from_vec(values64));
+        assert_eq!(result.values().as_slice(), expected64.as_slice());
+    }
+}
+
+#[test]
+fn cumsum_sliced_and_wrapping() {
+    let array = Int64Array::from_slice([100, i64::MAX, 1, 2]).slice(1, 3);
+    let result = cumsum(&array);
+    assert_eq!(
+        result,
+        Int64Array::from_slice([i64::MAX, i64::MIN, i64::MIN + 2])
+    );
+
+    let array = UInt8Array::from_slice([200, 100]);
+    assert_eq!(cumsum(&array), UInt8Array::from_slice([200, 44]));
+}
+
+#[test]
+fn cumsum_preserves_data_type() {
+    let array = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    assert_eq!(cumsum(&array).data_type(), &DataType::Date32);
+}
+
+#[test]
+fn try_cumsum_overflow() {
+    let array = Int8Array::from_slice([100, 20, 10]);
+    assert!(matches!(try_cumsum(&array), Err(Error::Overflow)));
+
+    // values of null slots are not accumulated
+    let array = Int8Array::new(
+        DataType::Int8,
+        vec![100, 100, 20].into(),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(
+        try_cumsum(&array).unwrap(),
+        Int8Array::from([Some(100), None, Some(120)])
+    );
+
+    // the sum of the absolute values overflows, but no prefix sum does
+    let array = Int64Array::from_slice([i64::MAX, -1, 1, -i64::MAX]);
+    assert_eq!(
+        try_cumsum(&array).unwrap(),
+        Int64Array::from_slice([i64::MAX, i64::MAX - 1, i64::MAX, 0])
+    );
+
+    let array = Int32Array::from_slice([i32::MAX, 1, -1]);
+    assert!(matches!(try_cumsum(&array), Err(Error::Overflow)));
+
+    let array = Float32Array::from_slice([f32::MAX, f32::MAX]);
+    let result = try_cumsum(&array).unwrap();
+    assert_eq!(result, Float32Array::from_slice([f32::MAX, f32::INFINITY]));
+}
+
+#[test]
+fn try_cumsum_lanes() {
+    for length in [0, 1, 3, 4, 7, 8, 9, 17, 100] {
+        let values = (0..length).map(|x| x * 3 - 50).collect::<Vec<i32>>();
+        let array = Int32Array::from_vec(values);
+        assert_eq!(try_cumsum(&array).unwrap(), cumsum(&array));
+
+        let array = Int64Array::from_vec((0..length as i64).map(|x| x * 3 - 50).collect());
+        assert_eq!(try_cumsum(&array).unwrap(), cumsum(&array));
+    }
+}
@@ -1,7 +1,10 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::{DataType, Field, TimeUnit};
-use arrow2::{error::Result, ffi};
+use arrow2::{
+    error::{Error, Result},
+    ffi,
+};
 use std::collections::BTreeMap;
 
 fn _test_round_trip(array: Box<dyn Array>, expected: Box<dyn Array>) -> Result<()> {
@@ -308,6 +311,27 @@ fn dict() -> Result<()> {
     test_round_trip(array)
 }
 
+#[test]
+fn run_end_encoded() -> Result<()> {
+    let array = Int32Array::from([Some(1), Some(1), None, Some(2), Some(2), Some(3)]);
+    let array = ree_encode(&array);
+
+    test_round_trip(array)
+}
+
+#[test]
+fn run_end_encoded_of_non_primitive_schema() {
+    let field = Field::new(
+        "a",
+        RunEndEncodedArray::<i32>::default_data_type(DataType::Utf8),
+        true,
+    );
+    let schema_ffi = ffi::export_field_to_c(&field);
+
+    let result = unsafe { ffi::import_field_from_c(&schema_ffi) };
+    assert!(matches!(result, Err(Error::NotYetImplemented(_))));
+}
+
 #[test]
 fn schema() -> Result<()> {
     let field = Field::new(
@@ -362,3 +386,15 @@ fn extension_children() -> Result<()> {
     );
     test_round_trip_schema(field)
 }
+
+#[test]
+fn view_export() -> Result<()> {
+    let array = StringViewArray::from([Some("a"), None, Some("a string longer than 12 bytes")]);
+    let array = array.slice(1, 2).boxed();
+
+    // exporting is supported, importing is not yet
+    let array_ffi = ffi::export_array_to_c(array);
+    let result = unsafe { ffi::import_array_from_c(array_ffi, DataType::Utf8View) };
+    assert!(matches!(result, Err(Error::NotYetImplemented(_))));
+    Ok(())
+}
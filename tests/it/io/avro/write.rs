@@ -393,3 +393,77 @@ fn struct_() -> Result<()> {
 
     Ok(())
 }
+
+fn map_schema() -> Schema {
+    let map_dt = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    Schema::from(vec![
+        Field::new("map", map_dt.clone(), false),
+        Field::new("map nullable", map_dt, true),
+    ])
+}
+
+fn map_data() -> Chunk<Box<dyn Array>> {
+    let schema = map_schema();
+    let map_dt = schema.fields[0].data_type().clone();
+    let entries_dt = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+
+    Chunk::new(vec![
+        Box::new(MapArray::new(
+            map_dt.clone(),
+            vec![0, 2, 2, 3].try_into().unwrap(),
+            Box::new(StructArray::new(
+                entries_dt.clone(),
+                vec![
+                    Box::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+                    Box::new(PrimitiveArray::<i32>::from([Some(1), None, Some(3)])),
+                ],
+                None,
+            )),
+            None,
+        )),
+        Box::new(MapArray::new(
+            map_dt,
+            vec![0, 1, 1, 1].try_into().unwrap(),
+            Box::new(StructArray::new(
+                entries_dt,
+                vec![
+                    Box::new(Utf8Array::<i32>::from_slice(["a"])),
+                    Box::new(PrimitiveArray::<i32>::from([Some(1)])),
+                ],
+                None,
+            )),
+            Some([true, false, true].into()),
+        )),
+    ])
+}
+
+#[test]
+fn map() -> Result<()> {
+    let write_schema = map_schema();
+    let write_data = map_data();
+
+    let data = write_avro(&write_data, &write_schema, None)?;
+    let (result, read_schema) = read_avro(&data, None)?;
+
+    assert_eq!(read_schema, map_schema());
+
+    let expected_data = map_data();
+    for (c1, c2) in result.columns().iter().zip(expected_data.columns().iter()) {
+        assert_eq!(c1.as_ref(), c2.as_ref());
+    }
+
+    Ok(())
+}
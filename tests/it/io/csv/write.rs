@@ -5,6 +5,7 @@ use arrow2::chunk::Chunk;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
 use arrow2::io::csv::write::*;
+use arrow2::types::f16;
 
 fn data() -> Chunk<Box<dyn Array>> {
     let c1 = Utf8Array::<i32>::from_slice(["a b", "c", "d"]);
@@ -134,6 +135,10 @@ fn data_array(column: &str) -> (Chunk<Box<dyn Array>>, Vec<&'static str>) {
             UInt64Array::from_slice([3, 2, 1]).boxed(),
             vec!["3", "2", "1"],
         ),
+        "f16" => (
+            Float16Array::from_slice([f16::from_f32(3.5), f16::from_f32(-0.25)]).boxed(),
+            vec!["3.5", "-0.25"],
+        ),
         "f32" => (Float32Array::from_slice([3.1]).boxed(), vec!["3.1"]),
         "f64" => (Float64Array::from_slice([3.1]).boxed(), vec!["3.1"]),
         "date32" => {
@@ -312,6 +317,7 @@ fn write_each() -> Result<()> {
         "u16",
         "u32",
         "u64",
+        "f16",
         "f32",
         "f64",
         "date32",
@@ -2,8 +2,8 @@ use std::io::Cursor;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
-use arrow2::error::Result;
+use arrow2::datatypes::{DataType, Field, Schema, UnionMode};
+use arrow2::error::{Error, Result};
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
 use arrow2::types::{i256, months_days_ns};
@@ -387,3 +387,62 @@ fn write_decimal256() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+#[test]
+fn write_mutable_union() -> Result<()> {
+    for mode in [UnionMode::Dense, UnionMode::Sparse] {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let data_type = DataType::Union(fields, Some(vec![2, 4]), mode);
+        let mut array = MutableUnionArray::try_new(
+            data_type,
+            vec![
+                Box::new(MutablePrimitiveArray::<i32>::new()),
+                Box::new(MutableUtf8Array::<i32>::new()),
+            ],
+        )?;
+        array.try_push::<MutablePrimitiveArray<i32>, _>(2, Some(1))?;
+        array.try_push::<MutableUtf8Array<i32>, _>(4, Some("a"))?;
+        array.try_push_null(4)?;
+        array.try_push::<MutablePrimitiveArray<i32>, _>(2, Some(3))?;
+        let array = array.as_box();
+
+        let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+        let columns = Chunk::try_new(vec![array])?;
+        round_trip(columns, schema, None, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_run_end_encoded() -> Result<()> {
+    let values = Int32Array::from_slice([1, 2]);
+    let data_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Int32);
+    let array = RunEndEncodedArray::try_new(data_type, vec![2, 3].into(), values)?.boxed();
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    assert!(matches!(
+        write(&[columns], &schema, None, None),
+        Err(Error::NotYetImplemented(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn write_view() -> Result<()> {
+    let array = [Some(b"a".as_ref()), None]
+        .into_iter()
+        .collect::<BinaryViewArray>()
+        .boxed();
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    assert!(matches!(
+        write(&[columns], &schema, None, None),
+        Err(Error::NotYetImplemented(_))
+    ));
+    Ok(())
+}
@@ -4,6 +4,7 @@ use arrow2::{
     buffer::Buffer,
     datatypes::{DataType, Field, Metadata, Schema, TimeUnit},
     error::Result,
+    types::f16,
 };
 
 use super::*;
@@ -34,6 +35,21 @@ fn f32() -> Result<()> {
     test!(array, expected)
 }
 
+#[test]
+fn f16() -> Result<()> {
+    let array = Float16Array::from([
+        Some(f16::from_f32(1.5)),
+        Some(f16::from_f32(-2.5)),
+        Some(f16::from_f32(f32::NAN)),
+        None,
+        Some(f16::from_f32(5.5)),
+    ]);
+
+    let expected = r#"[1.5,-2.5,null,null,5.5]"#;
+
+    test!(array, expected)
+}
+
 #[test]
 fn f64() -> Result<()> {
     let array = Float64Array::from([Some(1.5), Some(2.5), Some(f64::NAN), None, Some(5.5)]);
@@ -380,3 +396,125 @@ fn write_timestamp() -> Result<()> {
 
     test!(array, expected)
 }
+
+/// A writer that records the size of each write and fails after `limit` bytes.
+#[derive(Default)]
+struct CountingWriter {
+    written: usize,
+    max_write: usize,
+    limit: Option<usize>,
+}
+
+impl std::io::Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Some(limit) = self.limit {
+            if self.written + buf.len() > limit {
+                return Err(std::io::Error::new(std::io::ErrorKind::Other, "full"));
+            }
+        }
+        self.written += buf.len();
+        self.max_write = self.max_write.max(buf.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn writer_chunk() -> (Schema, Chunk<Box<dyn Array>>) {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("x"), Some("y"), None]).boxed(),
+    ]);
+    (schema, chunk)
+}
+
+#[test]
+fn writer_array() -> Result<()> {
+    let (schema, chunk) = writer_chunk();
+
+    let mut writer = json_write::Writer::new(vec![], schema.clone(), json_write::Framing::Array);
+    writer.write(&chunk)?;
+    writer.write(&chunk)?;
+    let buf = writer.finish()?;
+
+    let rows = r#"{"a":1,"b":"x"},{"a":null,"b":"y"},{"a":3,"b":null}"#;
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        format!("[{},{}]", rows, rows)
+    );
+
+    let mut writer = json_write::Writer::new(vec![], schema.clone(), json_write::Framing::Array);
+    writer.write(&chunk)?;
+    assert_eq!(writer.finish()?, write_record_batch(schema, chunk)?);
+    Ok(())
+}
+
+#[test]
+fn writer_lines() -> Result<()> {
+    let (schema, chunk) = writer_chunk();
+
+    let mut writer = json_write::Writer::new(vec![], schema, json_write::Framing::Lines);
+    writer.write(&chunk)?;
+    let buf = writer.finish()?;
+
+    let expected = "{\"a\":1,\"b\":\"x\"}\n{\"a\":null,\"b\":\"y\"}\n{\"a\":3,\"b\":null}\n";
+    assert_eq!(String::from_utf8(buf).unwrap(), expected);
+    Ok(())
+}
+
+#[test]
+fn writer_empty() -> Result<()> {
+    let (schema, _) = writer_chunk();
+
+    let writer = json_write::Writer::new(vec![], schema.clone(), json_write::Framing::Array);
+    assert_eq!(writer.finish()?, b"[]");
+
+    let writer = json_write::Writer::new(vec![], schema, json_write::Framing::Lines);
+    assert!(writer.finish()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn writer_writes_row_by_row() -> Result<()> {
+    let length = 100_000;
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let chunk = Chunk::new(vec![
+        Int64Array::from_vec((0..length as i64).collect()).boxed()
+    ]);
+
+    let mut writer = json_write::Writer::new(
+        CountingWriter::default(),
+        schema,
+        json_write::Framing::Lines,
+    );
+    writer.write(&chunk)?;
+    let counter = writer.finish()?;
+
+    // `{"a":99999}\n` is the longest row
+    assert_eq!(counter.max_write, 12);
+    assert!(counter.written > length * 8);
+    Ok(())
+}
+
+#[test]
+fn writer_errors() -> Result<()> {
+    let (schema, chunk) = writer_chunk();
+
+    let counter = CountingWriter {
+        limit: Some(20),
+        ..Default::default()
+    };
+    let mut writer = json_write::Writer::new(counter, schema.clone(), json_write::Framing::Array);
+    assert!(writer.write(&chunk).is_err());
+
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1]).boxed()]);
+    let mut writer = json_write::Writer::new(vec![], schema, json_write::Framing::Array);
+    assert!(writer.write(&chunk).is_err());
+    Ok(())
+}
@@ -10,6 +10,7 @@ use arrow2::{
     io::parquet::read::statistics::*,
     io::parquet::write::*,
     offset::Offset,
+    scalar::{PrimitiveScalar, Utf8Scalar},
     types::{days_ms, NativeType},
 };
 
@@ -1301,6 +1302,72 @@ fn arrow_type() -> Result<()> {
     assert_roundtrip(schema, chunk, None)
 }
 
+#[test]
+fn uuid() -> Result<()> {
+    let data_type = DataType::Extension(
+        arrow2::io::parquet::UUID_EXTENSION_NAME.to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        None,
+    );
+    let array = FixedSizeBinaryArray::new(
+        data_type.clone(),
+        (0..48u8).collect::<Vec<_>>().into(),
+        Some([true, false, true].into()),
+    );
+    let schema = Schema::from(vec![Field::new("a1", data_type.clone(), true)]);
+    let chunk = Chunk::new(vec![array.boxed()]);
+
+    let data = integration_write(&schema, &[chunk.clone()])?;
+
+    // the column is annotated as UUID and read as such without the arrow schema
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    match &metadata.schema().fields()[0] {
+        p_read::ParquetType::PrimitiveType(primitive) => {
+            assert_eq!(
+                primitive.logical_type,
+                Some(p_read::PrimitiveLogicalType::Uuid)
+            );
+        }
+        _ => unreachable!(),
+    };
+    let fields = p_read::schema::parquet_to_arrow_schema(metadata.schema().fields());
+    assert_eq!(fields, schema.fields);
+
+    let (new_schema, new_chunks) = integration_read(&data, None)?;
+    assert_eq!(new_schema, schema);
+    assert_eq!(new_chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn read_views() -> Result<()> {
+    let utf8 = Utf8Array::<i32>::from([Some("a"), None, Some("a string longer than 12")]);
+    let binary =
+        BinaryArray::<i32>::from([Some(b"a".as_ref()), Some(b"a value longer than 12"), None]);
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Utf8, true),
+        Field::new("a2", DataType::Binary, true),
+    ]);
+    let chunk = Chunk::new(vec![utf8.clone().boxed(), binary.clone().boxed()]);
+
+    let data = integration_write(&schema, &[chunk])?;
+
+    let mut reader = Cursor::new(&data);
+    let metadata = p_read::read_metadata(&mut reader)?;
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Utf8View, true),
+        Field::new("a2", DataType::BinaryView, true),
+    ]);
+    let reader = p_read::FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+
+    let utf8: StringViewArray = utf8.into();
+    let binary: BinaryViewArray = binary.into();
+    let expected = Chunk::new(vec![utf8.boxed(), binary.boxed()]);
+    assert_eq!(chunks, vec![expected]);
+    Ok(())
+}
+
 fn data<T: NativeType, I: Iterator<Item = T>>(
     mut iter: I,
     inner_is_nullable: bool,
@@ -1565,3 +1632,82 @@ fn filter_chunk() -> Result<()> {
     assert_eq!(new_chunks, vec![chunk1]);
     Ok(())
 }
+
+#[test]
+fn row_group_statistics() -> Result<()> {
+    let chunk1 = Chunk::new(vec![
+        PrimitiveArray::from([Some(3i16), None, Some(-1)]).boxed(),
+        Utf8Array::<i32>::from([Some("b"), Some("a"), None]).boxed(),
+    ]);
+    let chunk2 = Chunk::new(vec![
+        PrimitiveArray::from_slice([7i16, 5]).boxed(),
+        Utf8Array::<i32>::from([Some("z"), Some("y")]).boxed(),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("c1", DataType::Int16, true),
+        Field::new("c2", DataType::Utf8, true),
+    ]);
+
+    let r = integration_write(&schema, &[chunk1, chunk2])?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(r))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let expected = [(1, -1i16, 3i16, 1, "a", "b"), (0, 5, 7, 0, "y", "z")];
+    for (row_group, (nulls1, min1, max1, nulls2, min2, max2)) in
+        metadata.row_groups.iter().zip(expected)
+    {
+        let statistics = deserialize_row_group(&schema.fields[0], row_group)?;
+        let expected = ScalarStatistics {
+            null_count: Box::new(PrimitiveScalar::<u64>::from(Some(nulls1))),
+            distinct_count: Box::new(PrimitiveScalar::<u64>::from(None)),
+            min_value: Box::new(PrimitiveScalar::<i16>::from(Some(min1))),
+            max_value: Box::new(PrimitiveScalar::<i16>::from(Some(max1))),
+        };
+        assert_eq!(statistics, expected);
+
+        let statistics = deserialize_row_group(&schema.fields[1], row_group)?;
+        let expected = ScalarStatistics {
+            null_count: Box::new(PrimitiveScalar::<u64>::from(Some(nulls2))),
+            distinct_count: Box::new(PrimitiveScalar::<u64>::from(None)),
+            min_value: Box::new(Utf8Scalar::<i32>::new(Some(min2))),
+            max_value: Box::new(Utf8Scalar::<i32>::new(Some(max2))),
+        };
+        assert_eq!(statistics, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn row_group_tasks_in_parallel() -> Result<()> {
+    let chunk1 = Chunk::new(vec![PrimitiveArray::from_slice([1i16, 3]).boxed()]);
+    let chunk2 = Chunk::new(vec![PrimitiveArray::from_slice([2i16, 4]).boxed()]);
+    let schema = Schema::from(vec![Field::new("c1", DataType::Int16, true)]);
+
+    let r = integration_write(&schema, &[chunk1.clone(), chunk2.clone()])?;
+
+    let mut reader = Cursor::new(r);
+    let metadata = p_read::read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let tasks = p_read::FileReader::new(reader, metadata.row_groups, schema, None, None, None)
+        .into_row_group_tasks()
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(tasks.iter().map(|x| x.num_rows()).sum::<usize>(), 4);
+
+    // each row group is decoded on its own thread
+    let handles = tasks
+        .into_iter()
+        .map(|task| std::thread::spawn(move || task.deserialize()?.collect::<Result<Vec<_>>>()))
+        .collect::<Vec<_>>();
+
+    let new_chunks = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    assert_eq!(new_chunks, vec![chunk1, chunk2]);
+    Ok(())
+}
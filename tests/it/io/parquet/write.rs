@@ -128,6 +128,87 @@ fn int64_required_delta() -> Result<()> {
     )
 }
 
+fn round_trip_array(
+    array: Box<dyn Array>,
+    version: Version,
+    encodings: Vec<Encoding>,
+) -> Result<()> {
+    let field = Field::new("a1", array.data_type().clone(), true);
+    let schema = Schema::from(vec![field]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version,
+        data_pagesize_limit: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![encodings])?;
+
+    let writer = Cursor::new(vec![]);
+    let mut writer = FileWriter::try_new(writer, schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let (result, _) = read_column(&mut Cursor::new(data), "a1")?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(())
+}
+
+/// values spanning several blocks (of 128 values) and miniblocks, with deltas of
+/// varying bit widths, including the extremes of the type
+fn delta_values<T: Copy>(min: T, max: T, f: impl Fn(usize) -> T) -> Vec<Option<T>> {
+    let mut values = (0..1000).map(f).map(Some).collect::<Vec<_>>();
+    values[3] = Some(max);
+    values[4] = Some(min);
+    values[5] = Some(max);
+    values[500] = Some(min);
+    values
+}
+
+#[test]
+fn int32_delta() -> Result<()> {
+    let values = delta_values(i32::MIN, i32::MAX, |i| {
+        (i as i32 * 7919) % 1013 - (i as i32 % 7) * (1 << (i % 24))
+    });
+    for version in [Version::V1, Version::V2] {
+        let array = Int32Array::from_iter(values.iter().copied()).boxed();
+        round_trip_array(array, version, vec![Encoding::DeltaBinaryPacked])?;
+
+        let with_nulls = values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i % 3 == 0 { None } else { *x });
+        let array = Int32Array::from_iter(with_nulls).boxed();
+        round_trip_array(array, version, vec![Encoding::DeltaBinaryPacked])?;
+    }
+    Ok(())
+}
+
+#[test]
+fn int64_delta() -> Result<()> {
+    let values = delta_values(i64::MIN, i64::MAX, |i| {
+        (i as i64 * 104729) % 10007 - (i as i64 % 5) * (1 << (i % 50))
+    });
+    for version in [Version::V1, Version::V2] {
+        let array = Int64Array::from_iter(values.iter().copied()).boxed();
+        round_trip_array(array, version, vec![Encoding::DeltaBinaryPacked])?;
+
+        let with_nulls = values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i % 4 == 1 { None } else { *x });
+        let array = Int64Array::from_iter(with_nulls).boxed();
+        round_trip_array(array, version, vec![Encoding::DeltaBinaryPacked])?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "io_parquet_compression")]
 #[test]
 fn int64_optional_v2_compressed() -> Result<()> {
@@ -585,3 +666,55 @@ fn struct_v2() -> Result<()> {
         vec![Encoding::Plain, Encoding::Plain],
     )
 }
+
+fn struct_with_nulls(version: Version) -> Result<()> {
+    let fields = vec![
+        Field::new("b", DataType::Boolean, true),
+        Field::new("c", DataType::Int32, true),
+    ];
+    let boolean = BooleanArray::from([Some(true), None, Some(false), Some(true), None]).boxed();
+    let int = Int32Array::from([Some(1), Some(2), None, Some(4), None]).boxed();
+    // the second and fourth structs are null while the values of their fields are not, and the
+    // last struct is valid while all its fields are null
+    let array = StructArray::new(
+        DataType::Struct(fields),
+        vec![boolean, int],
+        Some([true, false, true, false, true].into()),
+    );
+
+    round_trip_array(
+        array.boxed(),
+        version,
+        vec![Encoding::Plain, Encoding::Plain],
+    )
+}
+
+#[test]
+fn struct_with_nulls_v1() -> Result<()> {
+    struct_with_nulls(Version::V1)
+}
+
+#[test]
+fn struct_with_nulls_v2() -> Result<()> {
+    struct_with_nulls(Version::V2)
+}
+
+#[test]
+fn nested_struct_with_nulls() -> Result<()> {
+    let inner_fields = vec![Field::new("c", DataType::Int32, true)];
+    let int = Int32Array::from([Some(1), Some(2), Some(3), None]).boxed();
+    let inner = StructArray::new(
+        DataType::Struct(inner_fields),
+        vec![int],
+        Some([true, true, false, true].into()),
+    );
+
+    let fields = vec![Field::new("inner", inner.data_type().clone(), true)];
+    let array = StructArray::new(
+        DataType::Struct(fields),
+        vec![inner.boxed()],
+        Some([true, false, true, true].into()),
+    );
+
+    round_trip_array(array.boxed(), Version::V2, vec![Encoding::Plain])
+}
@@ -4,6 +4,7 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod chunk;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
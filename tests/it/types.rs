@@ -1,4 +1,4 @@
-use arrow2::types::{days_ms, months_days_ns, BitChunkIter, BitChunkOnes, NativeType};
+use arrow2::types::{days_ms, f16, i256, months_days_ns, BitChunkIter, BitChunkOnes, NativeType};
 
 #[test]
 fn test_basic1() {
@@ -38,3 +38,61 @@ fn days_ms_roundtrip() {
     let a = days_ms(1, 2);
     assert_eq!(a, days_ms::from_be_bytes(a.to_be_bytes()));
 }
+
+#[test]
+fn i256_roundtrip() {
+    let a = i256::from(-2);
+    let bytes = a.to_le_bytes();
+    assert_eq!(bytes[0], 254);
+    assert!(bytes[1..].iter().all(|x| *x == 255));
+    assert_eq!(a, i256::from_le_bytes(bytes));
+
+    let a = i256::from_words(1, 2);
+    let bytes = a.to_le_bytes();
+    assert_eq!(bytes[0], 2);
+    assert_eq!(bytes[16], 1);
+    assert_eq!(a, i256::from_le_bytes(bytes));
+    assert_eq!(a, i256::from_be_bytes(a.to_be_bytes()));
+}
+
+#[test]
+fn i256_neg() {
+    assert_eq!(-i256::from(5), i256::from(-5));
+    assert_eq!(-i256::from_words(1, 2), i256::from_words(-2, -2));
+    assert!(-i256::from(i128::MIN) > i256::from(i128::MAX));
+}
+
+#[test]
+fn f16_from_f64() {
+    assert_eq!(f16::from_f64(1.5).to_f32(), 1.5);
+    assert_eq!(f16::from_f64(-2.0).to_f64(), -2.0);
+    // the largest finite value, and a tie above it that rounds to infinity
+    assert_eq!(f16::from_f64(65504.0).to_bits(), 0x7BFF);
+    assert_eq!(f16::from_f64(65520.0).to_bits(), 0x7C00);
+    assert_eq!(f16::from_f64(f64::INFINITY).to_bits(), 0x7C00);
+    assert_eq!(f16::from_f64(f64::NEG_INFINITY).to_bits(), 0xFC00);
+    assert!(f16::from_f64(f64::NAN).to_f64().is_nan());
+    // the smallest subnormal, and half of it that rounds to even (zero)
+    assert_eq!(f16::from_f64(2f64.powi(-24)).to_bits(), 0x0001);
+    assert_eq!(f16::from_f64(2f64.powi(-24)).to_f64(), 2f64.powi(-24));
+    assert_eq!(f16::from_f64(2f64.powi(-25)).to_bits(), 0x0000);
+    // ties round to even, unless the low bits of the mantissa are set
+    assert_eq!(f16::from_f64(1.0 + 2f64.powi(-11)).to_bits(), 0x3C00);
+    assert_eq!(
+        f16::from_f64(1.0 + 2f64.powi(-11) + 2f64.powi(-40)).to_bits(),
+        0x3C01
+    );
+}
+
+#[test]
+fn f16_ord() {
+    let one = f16::from_f32(1.0);
+    let nan = f16::from_f32(f32::NAN);
+    assert!(f16::from_f32(-1.0) < one);
+    assert!(f16::from_f32(f32::NEG_INFINITY) < f16::from_f32(-65504.0));
+    assert_eq!(
+        f16::from_f32(-0.0).partial_cmp(&f16::from_f32(0.0)),
+        Some(std::cmp::Ordering::Equal)
+    );
+    assert_eq!(nan.partial_cmp(&one), None);
+}